@@ -37,6 +37,7 @@ use super::diff::FieldwiseDiff;
 use super::plugin_health::{CollectorOutput, MalformedPlugin};
 use super::traits::{AlertHistory, AlertPolicy, HintScorer, SnapshotDiff};
 use super::{ActivityRing, ACTIVITY_RING_CAPACITY, SNAPSHOT_CHANNEL_CAPACITY};
+use crate::tokenizer::{HeuristicTokenizer, Tokenizer};
 
 use tokio::sync::broadcast;
 
@@ -225,6 +226,10 @@ pub struct ColdWindowEngine {
     /// the engine can engage the kill-switch without re-classifying
     /// the snapshot itself (the policy already did).
     budget_ceiling: u64,
+    /// Tokenizer producers use to build the ledger. Cloned out via
+    /// [`Self::tokenizer`] so the skill collector counts the same way
+    /// the engine was configured to.
+    tokenizer: Arc<dyn Tokenizer>,
 }
 
 /// Default per-tick wall-clock budget. Matches the SC1 median budget
@@ -270,6 +275,7 @@ impl ColdWindowEngine {
             tick_budget: DEFAULT_TICK_BUDGET,
             kill_switch: KillSwitch::new(),
             budget_ceiling: u64::MAX,
+            tokenizer: Arc::new(HeuristicTokenizer),
         }
     }
 
@@ -296,6 +302,18 @@ impl ColdWindowEngine {
         self
     }
 
+    /// Replace the [`HeuristicTokenizer`] default, e.g. with the BPE
+    /// tokenizer configured for the target CLI.
+    pub fn with_tokenizer(mut self, tokenizer: Arc<dyn Tokenizer>) -> Self {
+        self.tokenizer = tokenizer;
+        self
+    }
+
+    /// Clone out the tokenizer for the ledger producers.
+    pub fn tokenizer(&self) -> Arc<dyn Tokenizer> {
+        Arc::clone(&self.tokenizer)
+    }
+
     /// Clone out the kill-switch so adapters (sync, server, dashboard)
     /// can observe engagement without holding a reference to the
     /// engine.
@@ -5,23 +5,27 @@
 //! `(source, token_estimate)` entries that the producer feeds into the
 //! tick's `TokenLedger::per_skill` attribution.
 //!
-//! Token estimation uses a deliberate **byte-based proxy**, file size
-//! divided by 4, so the cold rewalk stays cheap. This is wrong-in-the-
-//! detail (a Markdown file is not 4 chars per token) but right-in-the-
-//! shape: skills that grow show as larger consumers, skills that shrink
-//! pull back. A real BPE tokenizer is a follow-up; until then
-//! this surfaces *real* attribution rather than the synthetic
-//! the original `skill://demo` placeholder.
+//! Each skill is counted with [`count_tokens_with`] through the
+//! collector's [`Tokenizer`]: the section-aware heuristic by default, or
+//! an exact BPE tokenizer when one is configured for the target CLI
+//! (see [`crate::tokenizer::TokenizerConfig`]). The producer hands the
+//! engine's tokenizer to the collector so every ledger entry is counted
+//! the same way.
 //!
 //! The collector is **deliberately stateless and side-effect free**,
 //! mirroring the plugin collector's "cold rewalk" contract. Errors
 //! reading individual entries are surfaced via `malformed`, not
 //! silently dropped, same discipline applied elsewhere.
 
+use std::fmt;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 use skrills_snapshot::TokenEntry;
 
+use crate::tokenizer::{HeuristicTokenizer, Tokenizer};
+use crate::tokens::count_tokens_with;
+
 /// One skill-collection failure surfaced for operator visibility.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct MalformedSkillEntry {
@@ -35,7 +39,7 @@ pub struct MalformedSkillEntry {
 #[derive(Clone, Debug, Default, PartialEq)]
 pub struct SkillCollectorOutput {
     /// One entry per discovered skill: `source` is `skill://<name>`,
-    /// `tokens` is a byte-length / 4 estimate.
+    /// `tokens` is the collector tokenizer's count of the file.
     pub entries: Vec<TokenEntry>,
     /// Per-entry I/O errors (unreadable file metadata, permission
     /// denied during walk, etc.).
@@ -48,18 +52,39 @@ pub struct SkillCollectorOutput {
 /// (legacy). One token-entry per skill file. Subdirectories are
 /// recursed up to `MAX_DEPTH` to match the behavior the discovery
 /// scanner uses.
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 pub struct SkillCollector {
     skill_dirs: Vec<PathBuf>,
+    tokenizer: Arc<dyn Tokenizer>,
+}
+
+impl fmt::Debug for SkillCollector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SkillCollector")
+            .field("skill_dirs", &self.skill_dirs)
+            .field("tokenizer", &self.tokenizer.name())
+            .finish()
+    }
 }
 
 const MAX_DEPTH: usize = 6;
 
 impl SkillCollector {
-    /// Construct a collector that walks the supplied directories.
+    /// Construct a collector that walks the supplied directories,
+    /// counting with the [`HeuristicTokenizer`].
     #[must_use]
     pub fn new(skill_dirs: Vec<PathBuf>) -> Self {
-        Self { skill_dirs }
+        Self {
+            skill_dirs,
+            tokenizer: Arc::new(HeuristicTokenizer),
+        }
+    }
+
+    /// Builder: count tokens with `tokenizer`.
+    #[must_use]
+    pub fn with_tokenizer(mut self, tokenizer: Arc<dyn Tokenizer>) -> Self {
+        self.tokenizer = tokenizer;
+        self
     }
 
     /// Returns true when no directories are configured (the producer
@@ -75,7 +100,7 @@ impl SkillCollector {
     pub fn collect(&self) -> SkillCollectorOutput {
         let mut output = SkillCollectorOutput::default();
         for dir in &self.skill_dirs {
-            walk(dir, 0, &mut output);
+            walk(dir, 0, self.tokenizer.as_ref(), &mut output);
         }
         // Stable order so ledger comparisons are reproducible.
         output.entries.sort_by(|a, b| a.source.cmp(&b.source));
@@ -83,7 +108,7 @@ impl SkillCollector {
     }
 }
 
-fn walk(dir: &Path, depth: usize, output: &mut SkillCollectorOutput) {
+fn walk(dir: &Path, depth: usize, tokenizer: &dyn Tokenizer, output: &mut SkillCollectorOutput) {
     if depth > MAX_DEPTH {
         return;
     }
@@ -122,7 +147,7 @@ fn walk(dir: &Path, depth: usize, output: &mut SkillCollectorOutput) {
             }
         };
         if file_type.is_dir() {
-            walk(&path, depth + 1, output);
+            walk(&path, depth + 1, tokenizer, output);
             continue;
         }
         if !file_type.is_file() {
@@ -135,20 +160,18 @@ fn walk(dir: &Path, depth: usize, output: &mut SkillCollectorOutput) {
         if !name.eq_ignore_ascii_case("SKILL.md") && !name.eq_ignore_ascii_case("skill.md") {
             continue;
         }
-        let metadata = match entry.metadata() {
-            Ok(m) => m,
+        let content = match std::fs::read(&path) {
+            Ok(bytes) => bytes,
             Err(err) => {
                 output.malformed.push(MalformedSkillEntry {
                     source: path.display().to_string(),
-                    error_message: format!("metadata read failed: {err}"),
+                    error_message: format!("skill read failed: {err}"),
                 });
                 continue;
             }
         };
-        let bytes = metadata.len();
-        // Byte-length / 4 token-estimate; saturate to u64 max well below
-        // any realistic skill size but keep the saturation guard explicit.
-        let tokens = bytes.saturating_div(4);
+        let breakdown = count_tokens_with(&String::from_utf8_lossy(&content), tokenizer);
+        let tokens = u64::try_from(breakdown.total).unwrap_or(u64::MAX);
         let skill_name = path
             .parent()
             .and_then(|p| p.file_name())
@@ -183,7 +206,7 @@ mod tests {
     }
 
     #[test]
-    fn collect_finds_skills_and_attributes_byte_proxy_tokens() {
+    fn collect_finds_skills_and_attributes_heuristic_tokens() {
         let tmp = TempDir::new().unwrap();
         let body = "# A".repeat(1000); // 3000 bytes
         write_skill(tmp.path(), "alpha", &body);
@@ -198,9 +221,32 @@ mod tests {
         assert_eq!(output.entries[0].source, "skill://alpha");
         assert_eq!(output.entries[1].source, "skill://beta");
 
-        // alpha was 3000 bytes → 750 tokens; beta was 4 bytes → 1 token.
-        assert_eq!(output.entries[0].tokens, 750);
-        assert_eq!(output.entries[1].tokens, 1);
+        // One prose line each, newline included, at 4.0 chars/token:
+        // alpha 3001 chars → 751 tokens; beta 5 chars → 2 tokens.
+        assert_eq!(output.entries[0].tokens, 751);
+        assert_eq!(output.entries[1].tokens, 2);
+    }
+
+    #[test]
+    fn collect_counts_through_configured_tokenizer() {
+        use crate::tokenizer::Section;
+
+        struct Fixed;
+        impl Tokenizer for Fixed {
+            fn name(&self) -> &str {
+                "fixed"
+            }
+            fn count(&self, text: &str, _section: Section) -> usize {
+                usize::from(!text.is_empty()) * 7
+            }
+        }
+
+        let tmp = TempDir::new().unwrap();
+        write_skill(tmp.path(), "alpha", "prose only");
+
+        let collector =
+            SkillCollector::new(vec![tmp.path().to_path_buf()]).with_tokenizer(Arc::new(Fixed));
+        assert_eq!(collector.collect().entries[0].tokens, 7);
     }
 
     #[test]
@@ -45,6 +45,16 @@ pub enum WarningKind {
     DirectoryEntryAccessFailed,
     /// Failed to read file contents.
     FileReadFailed,
+    /// Relative link to a file that does not exist.
+    BrokenLink,
+    /// Link anchor without a matching heading.
+    BrokenAnchor,
+    /// Reference to a skill that was not discovered.
+    UnknownSkill,
+    /// File in `references/` that nothing links to.
+    OrphanedReference,
+    /// External URL that could not be reached.
+    UnreachableUrl,
 }
 
 impl fmt::Display for WarningKind {
@@ -53,6 +63,11 @@ impl fmt::Display for WarningKind {
             Self::MetadataAccessFailed => write!(f, "metadata_access_failed"),
             Self::DirectoryEntryAccessFailed => write!(f, "directory_entry_access_failed"),
             Self::FileReadFailed => write!(f, "file_read_failed"),
+            Self::BrokenLink => write!(f, "broken_link"),
+            Self::BrokenAnchor => write!(f, "broken_anchor"),
+            Self::UnknownSkill => write!(f, "unknown_skill"),
+            Self::OrphanedReference => write!(f, "orphaned_reference"),
+            Self::UnreachableUrl => write!(f, "unreachable_url"),
         }
     }
 }
@@ -69,6 +84,9 @@ pub struct Warning {
     /// Path context where the warning occurred, if applicable.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub context: Option<PathBuf>,
+    /// Line in `context` (1-indexed), if applicable.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub line: Option<usize>,
 }
 
 impl Warning {
@@ -79,6 +97,7 @@ impl Warning {
             kind,
             message: message.into(),
             context: None,
+            line: None,
         }
     }
 
@@ -88,6 +107,13 @@ impl Warning {
         self.context = Some(path.into());
         self
     }
+
+    /// Adds the line the warning refers to.
+    #[must_use]
+    pub fn with_line(mut self, line: usize) -> Self {
+        self.line = Some(line);
+        self
+    }
 }
 
 impl fmt::Display for Warning {
@@ -277,16 +303,21 @@ pub fn analyze_dependencies(skill_path: &Path, content: &str) -> DependencyAnaly
 // 2. Each pattern has been tested and is syntactically correct
 // 3. LazyLock ensures initialization happens only once at runtime
 // (Using RATIONALE instead of SAFETY since this is safe code, not unsafe)
-static URL_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+pub(crate) static URL_REGEX: LazyLock<Regex> = LazyLock::new(|| {
     Regex::new(r"https?://[^\s\)\]>]+").expect("URL_REGEX: compile-time constant")
 });
-static LINK_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+pub(crate) static LINK_REGEX: LazyLock<Regex> = LazyLock::new(|| {
     Regex::new(r"\[([^\]]*)\]\(([^)]+)\)").expect("LINK_REGEX: compile-time constant")
 });
 static IMAGE_REGEX: LazyLock<Regex> = LazyLock::new(|| {
     Regex::new(r"!\[([^\]]*)\]\(([^)]+)\)").expect("IMAGE_REGEX: compile-time constant")
 });
 
+/// Drops trailing punctuation the URL pattern picks up from prose.
+pub(crate) fn trim_url(url: &str) -> &str {
+    url.trim_end_matches(&['.', ',', ')', ']'][..])
+}
+
 fn extract_content_dependencies(
     analysis: &mut DependencyAnalysis,
     _skill_dir: &Path,
@@ -300,9 +331,7 @@ fn extract_content_dependencies(
 
         // Find external URLs
         for url_match in URL_REGEX.find_iter(line) {
-            let url = url_match
-                .as_str()
-                .trim_end_matches(&['.', ',', ')', ']'][..]);
+            let url = trim_url(url_match.as_str());
             if !seen_urls.contains(url) {
                 seen_urls.insert(url.to_string());
                 analysis.dependencies.push(Dependency {
@@ -1,8 +1,9 @@
 //! Skill analysis: token counting, dependencies, and optimization.
 //!
 //! This crate provides analysis of SKILL.md files:
-//! - Token estimation with section breakdown
+//! - Token counting with section breakdown (heuristic or exact BPE)
 //! - Dependency analysis (local files, external URLs)
+//! - Link checking (missing files, broken anchors, orphaned references)
 //! - Optimization suggestions
 //! - Quality scoring
 //!
@@ -30,8 +31,10 @@
 pub mod cold_window;
 pub mod deps;
 pub mod graph;
+pub mod links;
 pub mod optimize;
 pub mod resolve;
+pub mod tokenizer;
 pub mod tokens;
 
 pub use deps::{
@@ -39,6 +42,7 @@ pub use deps::{
     Warning, WarningKind, WarningLevel,
 };
 pub use graph::RelationshipGraph;
+pub use links::{check_links, check_urls};
 pub use optimize::{quality_score, suggest_optimizations, OptimizationType, Priority, Suggestion};
 pub use resolve::{
     DependencyGraph, DependencyResolver, GraphBuilder, InMemoryRegistry, ResolutionResult,
@@ -46,7 +50,10 @@ pub use resolve::{
 };
 // Re-export SkillSource for users of the resolve API
 pub use skrills_discovery::SkillSource;
-pub use tokens::{count_tokens, estimate_tokens, TokenBreakdown, TokenCategory};
+pub use tokenizer::{
+    BpeTokenizer, HeuristicTokenizer, Tokenizer, TokenizerConfig, TokenizerError, TokenizerSpec,
+};
+pub use tokens::{count_tokens, count_tokens_with, estimate_tokens, TokenBreakdown, TokenCategory};
 
 use serde::{Deserialize, Serialize};
 use std::path::Path;
@@ -70,6 +77,11 @@ pub struct SkillAnalysis {
 
 /// Analyze a single skill file.
 pub fn analyze_skill(path: &Path, content: &str) -> SkillAnalysis {
+    analyze_skill_with(path, content, &HeuristicTokenizer)
+}
+
+/// Analyze a single skill file, counting tokens with `tokenizer`.
+pub fn analyze_skill_with(path: &Path, content: &str, tokenizer: &dyn Tokenizer) -> SkillAnalysis {
     let name = path
         .file_stem()
         .and_then(|s| s.to_str())
@@ -86,7 +98,7 @@ pub fn analyze_skill(path: &Path, content: &str) -> SkillAnalysis {
         })
         .unwrap_or_else(|| "unknown".to_string());
 
-    let tokens = count_tokens(content);
+    let tokens = count_tokens_with(content, tokenizer);
     let category = TokenCategory::from_count(tokens.total);
     let dependencies = analyze_dependencies(path, content);
     let suggestions = suggest_optimizations(content, &tokens, &dependencies);
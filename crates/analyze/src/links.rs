@@ -0,0 +1,568 @@
+//! Link checking for skills and their module files.
+//!
+//! Checks `SKILL.md` and the markdown files under `modules/` and
+//! `references/` for:
+//! - Relative links to missing files
+//! - Anchors without a matching heading
+//! - References to skills that were not discovered
+//! - Files in `references/` that nothing links to or mentions
+//!
+//! External URLs are only probed on request through [`check_urls`], and
+//! unreachable ones are informational so the check stays usable offline.
+
+use crate::deps::{trim_url, Warning, WarningKind, WarningLevel, LINK_REGEX, URL_REGEX};
+use regex::Regex;
+use skrills_discovery::SkillMeta;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::{Component, Path, PathBuf};
+use std::sync::LazyLock;
+use walkdir::WalkDir;
+
+/// Skill subdirectories whose markdown files are checked with `SKILL.md`.
+const MODULE_DIRS: [&str; 2] = ["modules", "references"];
+
+/// Directory whose files must be reachable from `SKILL.md`.
+const REFERENCES_DIR: &str = "references";
+
+/// URI scheme used for skills in the MCP server and dependency graph.
+const SKILL_URI_PREFIX: &str = "skill://";
+
+// RATIONALE: compile-time constant pattern, see deps.rs.
+static SCHEME_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^[A-Za-z][A-Za-z0-9+.-]*:").expect("SCHEME_REGEX: compile-time constant")
+});
+
+struct Document {
+    path: PathBuf,
+    content: String,
+}
+
+/// `SKILL.md` followed by its module files; unreadable files become warnings.
+fn skill_documents(skill_path: &Path, content: &str) -> (Vec<Document>, Vec<Warning>) {
+    let mut docs = vec![Document {
+        path: skill_path.to_path_buf(),
+        content: content.to_string(),
+    }];
+    let mut warnings = Vec::new();
+    let skill_dir = skill_path.parent().unwrap_or(Path::new("."));
+    for dir in MODULE_DIRS {
+        for entry in WalkDir::new(skill_dir.join(dir))
+            .sort_by_file_name()
+            .into_iter()
+            .filter_map(Result::ok)
+            .filter(|e| e.file_type().is_file() && is_markdown(e.path()))
+        {
+            match std::fs::read_to_string(entry.path()) {
+                Ok(content) => docs.push(Document {
+                    path: entry.path().to_path_buf(),
+                    content,
+                }),
+                Err(e) => warnings.push(
+                    Warning::new(
+                        WarningLevel::Warning,
+                        WarningKind::FileReadFailed,
+                        format!("Could not read {}: {}", entry.path().display(), e),
+                    )
+                    .with_context(entry.path()),
+                ),
+            }
+        }
+    }
+    (docs, warnings)
+}
+
+fn is_markdown(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|e| e.eq_ignore_ascii_case("md") || e.eq_ignore_ascii_case("mdx"))
+}
+
+/// Lines that are markdown prose: frontmatter and fenced code blocks are
+/// skipped and inline code spans blanked. Yields 1-indexed line numbers.
+fn prose_lines(content: &str) -> impl Iterator<Item = (usize, String)> + '_ {
+    let mut in_frontmatter = content.starts_with("---");
+    let mut fence: Option<&str> = None;
+    content.lines().enumerate().filter_map(move |(i, line)| {
+        let trimmed = line.trim_start();
+        if in_frontmatter {
+            if i > 0 && line.trim_end() == "---" {
+                in_frontmatter = false;
+            }
+            return None;
+        }
+        if let Some(marker) = fence {
+            if trimmed.starts_with(marker) {
+                fence = None;
+            }
+            return None;
+        }
+        for marker in ["```", "~~~"] {
+            if trimmed.starts_with(marker) {
+                fence = Some(marker);
+                return None;
+            }
+        }
+        Some((i + 1, blank_inline_code(line)))
+    })
+}
+
+fn blank_inline_code(line: &str) -> String {
+    let mut in_code = false;
+    line.chars()
+        .map(|c| {
+            if c == '`' {
+                in_code = !in_code;
+                ' '
+            } else if in_code {
+                ' '
+            } else {
+                c
+            }
+        })
+        .collect()
+}
+
+/// GitHub-style heading anchor: lowercase, punctuation dropped, spaces
+/// turned into hyphens.
+fn slugify(heading: &str) -> String {
+    heading
+        .trim()
+        .to_lowercase()
+        .chars()
+        .filter_map(|c| match c {
+            ' ' => Some('-'),
+            c if c.is_alphanumeric() || c == '-' || c == '_' => Some(c),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Anchors of every heading in `content`, with `-1`, `-2`... suffixes for
+/// repeated headings.
+fn heading_anchors(content: &str) -> HashSet<String> {
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    let mut anchors = HashSet::new();
+    for (_, line) in prose_lines(content) {
+        let hashes = line.chars().take_while(|&c| c == '#').count();
+        if !(1..=6).contains(&hashes) || !line[hashes..].starts_with(' ') {
+            continue;
+        }
+        let slug = slugify(line[hashes..].trim().trim_end_matches('#'));
+        let seen = counts.entry(slug.clone()).or_default();
+        anchors.insert(if *seen == 0 {
+            slug.clone()
+        } else {
+            format!("{slug}-{seen}")
+        });
+        *seen += 1;
+    }
+    anchors
+}
+
+/// Splits a link destination into its path and anchor, dropping an
+/// optional title and angle brackets and decoding `%XX` escapes.
+fn split_destination(raw: &str) -> (String, Option<String>) {
+    let raw = raw.trim();
+    let raw = match raw.strip_prefix('<') {
+        Some(rest) => rest.split('>').next().unwrap_or(rest),
+        None => raw.split_whitespace().next().unwrap_or(raw),
+    };
+    let (path, anchor) = match raw.split_once('#') {
+        Some((path, anchor)) => (path, Some(anchor.to_string())),
+        None => (raw, None),
+    };
+    (percent_decode(path), anchor)
+}
+
+fn percent_decode(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = (bytes[i] == b'%')
+            .then(|| s.get(i + 1..i + 3))
+            .flatten()
+            .and_then(|h| u8::from_str_radix(h, 16).ok());
+        match hex {
+            Some(byte) => {
+                out.push(byte);
+                i += 3;
+            }
+            None => {
+                out.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+/// Lexically resolves `..` and `.` so paths can be compared without
+/// touching the filesystem.
+fn normalize(path: &Path) -> PathBuf {
+    let mut out = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if !out.pop() {
+                    out.push("..");
+                }
+            }
+            other => out.push(other),
+        }
+    }
+    out
+}
+
+fn canonical(path: &Path) -> PathBuf {
+    path.canonicalize().unwrap_or_else(|_| normalize(path))
+}
+
+fn is_skill_file(path: &Path) -> bool {
+    path.file_name()
+        .is_some_and(|n| n.to_string_lossy().eq_ignore_ascii_case("SKILL.md"))
+}
+
+fn skill_uri(meta: &SkillMeta) -> String {
+    format!("skill://skrills/{}/{}", meta.source.label(), meta.name)
+}
+
+fn link_warning(
+    level: WarningLevel,
+    kind: WarningKind,
+    message: String,
+    doc: &Path,
+    line: usize,
+) -> Warning {
+    Warning::new(level, kind, message)
+        .with_context(doc)
+        .with_line(line)
+}
+
+/// Checks the links of a skill and its module files.
+///
+/// `content` is the skill's `SKILL.md`; module files are read from disk.
+/// `skills` are the discovered skills that links to other skills' `SKILL.md`
+/// files and `skill://` URIs are resolved against. Missing files are errors;
+/// broken anchors, unknown skills and orphaned reference files are warnings.
+pub fn check_links(skill_path: &Path, content: &str, skills: &[SkillMeta]) -> Vec<Warning> {
+    let skill_dir = skill_path.parent().unwrap_or(Path::new("."));
+    let skill_file = canonical(skill_path);
+    let known_paths: HashSet<PathBuf> = skills.iter().map(|s| canonical(&s.path)).collect();
+    let known_uris: HashSet<String> = skills.iter().map(skill_uri).collect();
+
+    let (docs, mut warnings) = skill_documents(skill_path, content);
+    let references: Vec<PathBuf> = WalkDir::new(skill_dir.join(REFERENCES_DIR))
+        .sort_by_file_name()
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|e| e.file_type().is_file())
+        .map(|e| canonical(e.path()))
+        .collect();
+    let mut anchor_cache: HashMap<PathBuf, HashSet<String>> = HashMap::new();
+    // Files and directories each document links to or mentions.
+    let mut edges: HashMap<PathBuf, Vec<PathBuf>> = HashMap::new();
+
+    for doc in &docs {
+        let doc_path = canonical(&doc.path);
+        let doc_dir = doc.path.parent().unwrap_or(Path::new("."));
+        let mut targets = Vec::new();
+
+        for (line, text) in prose_lines(&doc.content) {
+            for cap in LINK_REGEX.captures_iter(&text) {
+                let destination = &cap[2];
+                if destination.starts_with(SKILL_URI_PREFIX) {
+                    let uri = destination.split_whitespace().next().unwrap_or_default();
+                    if !known_uris.contains(uri) {
+                        warnings.push(link_warning(
+                            WarningLevel::Warning,
+                            WarningKind::UnknownSkill,
+                            format!("References skill {uri}, which was not discovered"),
+                            &doc.path,
+                            line,
+                        ));
+                    }
+                    continue;
+                }
+                if SCHEME_REGEX.is_match(destination) {
+                    continue;
+                }
+
+                let (path, anchor) = split_destination(destination);
+                let target = if path.is_empty() {
+                    doc.path.clone()
+                } else {
+                    doc_dir.join(&path)
+                };
+                if !target.exists() {
+                    warnings.push(link_warning(
+                        WarningLevel::Error,
+                        WarningKind::BrokenLink,
+                        format!("Broken link to {path}: file not found"),
+                        &doc.path,
+                        line,
+                    ));
+                    continue;
+                }
+                let resolved = canonical(&target);
+                if is_skill_file(&target)
+                    && resolved != skill_file
+                    && !known_paths.contains(&resolved)
+                {
+                    warnings.push(link_warning(
+                        WarningLevel::Warning,
+                        WarningKind::UnknownSkill,
+                        format!("Links to skill {path}, which was not discovered"),
+                        &doc.path,
+                        line,
+                    ));
+                }
+                if let Some(anchor) = anchor.filter(|a| !a.is_empty()) {
+                    if target.is_file() && is_markdown(&target) {
+                        let anchors = anchor_cache.entry(resolved.clone()).or_insert_with(|| {
+                            let text = if resolved == doc_path {
+                                doc.content.clone()
+                            } else {
+                                std::fs::read_to_string(&target).unwrap_or_default()
+                            };
+                            heading_anchors(&text)
+                        });
+                        if !anchors.contains(&anchor.to_lowercase()) {
+                            let shown = if path.is_empty() { "" } else { path.as_str() };
+                            warnings.push(link_warning(
+                                WarningLevel::Warning,
+                                WarningKind::BrokenAnchor,
+                                format!("Broken anchor {shown}#{anchor}: no matching heading"),
+                                &doc.path,
+                                line,
+                            ));
+                        }
+                    }
+                }
+                targets.push(resolved);
+            }
+        }
+
+        for reference in &references {
+            let mentioned = [skill_dir, doc_dir].iter().any(|base| {
+                let relative = reference
+                    .strip_prefix(canonical(base))
+                    .map(|r| r.to_string_lossy().replace('\\', "/"));
+                relative.is_ok_and(|r| doc.content.contains(&r))
+            });
+            if mentioned {
+                targets.push(reference.clone());
+            }
+        }
+        edges.insert(doc_path, targets);
+    }
+
+    // Reference files are reachable from SKILL.md through any chain of
+    // documents linking to or mentioning each other.
+    let mut reached: HashSet<PathBuf> = HashSet::new();
+    let mut queue = VecDeque::from([skill_file.clone()]);
+    while let Some(doc) = queue.pop_front() {
+        if !reached.insert(doc.clone()) {
+            continue;
+        }
+        for target in edges.get(&doc).into_iter().flatten() {
+            queue.push_back(target.clone());
+        }
+    }
+    for reference in &references {
+        if !reached.iter().any(|r| reference.starts_with(r)) {
+            let relative = reference
+                .strip_prefix(canonical(skill_dir))
+                .unwrap_or(reference);
+            warnings.push(
+                Warning::new(
+                    WarningLevel::Warning,
+                    WarningKind::OrphanedReference,
+                    format!(
+                        "{} is not linked from {} or its module files",
+                        relative.display(),
+                        skill_path
+                            .file_name()
+                            .map(|n| n.to_string_lossy().into_owned())
+                            .unwrap_or_default()
+                    ),
+                )
+                .with_context(reference),
+            );
+        }
+    }
+
+    warnings
+}
+
+/// Probes the external URLs of a skill and its module files.
+///
+/// `probe` is called once per distinct URL and returns why it could not be
+/// reached. Every occurrence of an unreachable URL is reported at
+/// [`WarningLevel::Info`], since a failed probe may only mean the check ran
+/// offline. URLs in code blocks and inline code are skipped.
+pub fn check_urls(
+    skill_path: &Path,
+    content: &str,
+    mut probe: impl FnMut(&str) -> Result<(), String>,
+) -> Vec<Warning> {
+    let (docs, _) = skill_documents(skill_path, content);
+    let mut results: HashMap<String, Result<(), String>> = HashMap::new();
+    let mut warnings = Vec::new();
+    for doc in &docs {
+        for (line, text) in prose_lines(&doc.content) {
+            for url in URL_REGEX.find_iter(&text).map(|m| trim_url(m.as_str())) {
+                let result = results.entry(url.to_string()).or_insert_with(|| probe(url));
+                if let Err(reason) = result {
+                    warnings.push(link_warning(
+                        WarningLevel::Info,
+                        WarningKind::UnreachableUrl,
+                        format!("Could not reach {url}: {reason}"),
+                        &doc.path,
+                        line,
+                    ));
+                }
+            }
+        }
+    }
+    warnings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use skrills_discovery::SkillSource;
+    use std::fs;
+
+    fn meta(path: &Path, name: &str) -> SkillMeta {
+        SkillMeta {
+            name: name.to_string(),
+            path: path.to_path_buf(),
+            source: SkillSource::Extra(0),
+            root: path.parent().unwrap().parent().unwrap().to_path_buf(),
+            hash: String::new(),
+            description: None,
+            frontmatter_name: None,
+        }
+    }
+
+    fn kinds(warnings: &[Warning]) -> Vec<(WarningKind, Option<usize>)> {
+        warnings.iter().map(|w| (w.kind, w.line)).collect()
+    }
+
+    #[test]
+    fn slugs_follow_github_anchors() {
+        let anchors = heading_anchors(
+            "---\ntitle: x\n---\n# Getting Started!\n## API (v2) & more\n```\n# not a heading\n```\n## Usage\n## Usage\n",
+        );
+        let mut anchors: Vec<_> = anchors.into_iter().collect();
+        anchors.sort();
+        assert_eq!(
+            anchors,
+            ["api-v2--more", "getting-started", "usage", "usage-1"]
+        );
+    }
+
+    #[test]
+    fn destinations_drop_titles_and_decode_escapes() {
+        assert_eq!(
+            split_destination("my%20file.md#Intro \"Title\""),
+            ("my file.md".to_string(), Some("Intro".to_string()))
+        );
+        assert_eq!(split_destination("<a b.md>"), ("a b.md".to_string(), None));
+    }
+
+    #[test]
+    fn reports_missing_files_anchors_unknown_skills_and_orphans() {
+        let tmp = tempfile::tempdir().unwrap();
+        let skill_dir = tmp.path().join("base");
+        let other_dir = tmp.path().join("other");
+        fs::create_dir_all(skill_dir.join("references")).unwrap();
+        fs::create_dir_all(skill_dir.join("modules")).unwrap();
+        fs::create_dir_all(&other_dir).unwrap();
+        fs::write(other_dir.join("SKILL.md"), "# Other\n").unwrap();
+        fs::write(
+            skill_dir.join("modules/setup.md"),
+            "# Setup\nSee [guide](../references/guide.md#install).\n",
+        )
+        .unwrap();
+        fs::write(
+            skill_dir.join("references/guide.md"),
+            "# Guide\n## Install\nThen read `chained.md`.\n",
+        )
+        .unwrap();
+        fs::write(skill_dir.join("references/mentioned.txt"), "x").unwrap();
+        fs::write(skill_dir.join("references/chained.md"), "x").unwrap();
+        fs::write(
+            skill_dir.join("references/orphan.md"),
+            "Only links [back](../SKILL.md).\n",
+        )
+        .unwrap();
+
+        let content = "---\nname: base\n---\n# Base\n\
+See [setup](modules/setup.md) and [missing](modules/gone.md).\n\
+Jump to [usage](#usage) or [nowhere](#nowhere).\n\
+Read [other](../other/SKILL.md) and [known](skill://skrills/extra0/base/SKILL.md).\n\
+Ask [ghost](skill://skrills/claude/ghost/SKILL.md).\n\
+Also references/mentioned.txt and [site](https://example.com).\n\
+```\n[ignored](nope.md)\n```\n\
+## Usage\nDone.\n";
+        let skill_path = skill_dir.join("SKILL.md");
+        fs::write(&skill_path, content).unwrap();
+        let skills = [meta(&skill_path, "base/SKILL.md")];
+
+        let warnings = check_links(&skill_path, content, &skills);
+        assert_eq!(
+            kinds(&warnings),
+            [
+                (WarningKind::BrokenLink, Some(5)),
+                (WarningKind::BrokenAnchor, Some(6)),
+                (WarningKind::UnknownSkill, Some(7)),
+                (WarningKind::UnknownSkill, Some(8)),
+                (WarningKind::OrphanedReference, None),
+            ],
+            "{warnings:#?}"
+        );
+        assert_eq!(warnings[0].level, WarningLevel::Error);
+        assert!(warnings[1].message.contains("#nowhere"));
+        assert!(warnings[4].message.starts_with("references/orphan.md"));
+
+        let known = [
+            meta(&skill_path, "base/SKILL.md"),
+            meta(&other_dir.join("SKILL.md"), "other/SKILL.md"),
+        ];
+        let warnings = check_links(&skill_path, content, &known);
+        assert_eq!(
+            warnings
+                .iter()
+                .filter(|w| w.kind == WarningKind::UnknownSkill)
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn unreachable_urls_are_informational() {
+        let tmp = tempfile::tempdir().unwrap();
+        let skill_path = tmp.path().join("SKILL.md");
+        let content = "See https://down.example.\nAnd https://up.example and https://down.example.\n`https://code.example`\n";
+        let mut probed = Vec::new();
+        let warnings = check_urls(&skill_path, content, |url| {
+            probed.push(url.to_string());
+            if url.contains("down") {
+                Err("connection refused".to_string())
+            } else {
+                Ok(())
+            }
+        });
+        assert_eq!(probed, ["https://down.example", "https://up.example"]);
+        assert_eq!(
+            kinds(&warnings),
+            [
+                (WarningKind::UnreachableUrl, Some(1)),
+                (WarningKind::UnreachableUrl, Some(2)),
+            ]
+        );
+        assert!(warnings.iter().all(|w| w.level == WarningLevel::Info));
+    }
+}
@@ -0,0 +1,454 @@
+//! Pluggable tokenizers for token counting.
+//!
+//! [`count_tokens`](crate::tokens::count_tokens) and the cold-window
+//! collectors count through a [`Tokenizer`]. Two implementations ship:
+//!
+//! - [`HeuristicTokenizer`] (default): fixed chars-per-token ratios per
+//!   section (4.0 prose, 3.5 code, 3.2 YAML). Cheap and dependency-free,
+//!   but only an estimate.
+//! - [`BpeTokenizer`]: exact byte-level BPE (GPT-2 style) loaded from a
+//!   `vocab.json` + `merges.txt` pair on disk.
+//!
+//! Tokenizers are selected per target CLI with `~/.skrills/tokenizers.toml`:
+//!
+//! ```toml
+//! # Used for CLIs without their own entry (omit for the heuristic).
+//! [default]
+//! kind = "heuristic"
+//!
+//! [cli.codex]
+//! kind = "bpe"
+//! vocab = "tokenizers/codex/vocab.json"   # relative to the config file
+//! merges = "tokenizers/codex/merges.txt"
+//! ```
+
+use regex::Regex;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, LazyLock};
+use thiserror::Error;
+
+/// Approximate characters per token for prose/markdown content.
+/// English text averages ~4 characters per token.
+pub(crate) const PROSE_CHARS_PER_TOKEN: f64 = 4.0;
+
+/// Approximate characters per token for code content.
+/// Code averages ~3.5 chars/token due to short keywords, symbols, and identifiers.
+const CODE_CHARS_PER_TOKEN: f64 = 3.5;
+
+/// Approximate characters per token for YAML/frontmatter content.
+/// YAML has many short keys and values, averaging ~3.2 chars/token.
+const FRONTMATTER_CHARS_PER_TOKEN: f64 = 3.2;
+
+/// Kind of content being counted.
+///
+/// Exact tokenizers ignore it; the heuristic picks its ratio from it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Section {
+    /// YAML frontmatter.
+    Frontmatter,
+    /// Markdown prose.
+    Prose,
+    /// Fenced code blocks.
+    Code,
+}
+
+/// Counts tokens in text.
+///
+/// Kept object-safe so callers can hold `Arc<dyn Tokenizer>` chosen at
+/// runtime from [`TokenizerConfig`].
+pub trait Tokenizer: Send + Sync {
+    /// Short name for reports (`"heuristic"`, `"bpe"`).
+    fn name(&self) -> &str;
+
+    /// Number of tokens in `text`.
+    fn count(&self, text: &str, section: Section) -> usize;
+}
+
+/// Character-ratio estimate; the default tokenizer.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HeuristicTokenizer;
+
+impl HeuristicTokenizer {
+    fn chars_per_token(section: Section) -> f64 {
+        match section {
+            Section::Frontmatter => FRONTMATTER_CHARS_PER_TOKEN,
+            Section::Prose => PROSE_CHARS_PER_TOKEN,
+            Section::Code => CODE_CHARS_PER_TOKEN,
+        }
+    }
+}
+
+impl Tokenizer for HeuristicTokenizer {
+    fn name(&self) -> &str {
+        "heuristic"
+    }
+
+    fn count(&self, text: &str, section: Section) -> usize {
+        (text.len() as f64 / Self::chars_per_token(section)).ceil() as usize
+    }
+}
+
+/// Errors loading a tokenizer.
+#[derive(Debug, Error)]
+pub enum TokenizerError {
+    /// A tokenizer or config file could not be read.
+    #[error("Failed to read {path}: {source}")]
+    Io {
+        /// The file being read.
+        path: PathBuf,
+        /// The underlying I/O error.
+        source: std::io::Error,
+    },
+    /// A tokenizer or config file is malformed.
+    #[error("Invalid {path}: {message}")]
+    Parse {
+        /// The malformed file.
+        path: PathBuf,
+        /// What was wrong with it.
+        message: String,
+    },
+}
+
+/// GPT-2 pre-tokenization pattern without its `\s+(?!\S)` look-ahead,
+/// which `pieces` emulates.
+// RATIONALE: compile-time constant pattern; `.expect()` cannot fire.
+static PRETOKENIZE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"'s|'t|'re|'ve|'m|'ll|'d| ?\p{L}+| ?\p{N}+| ?[^\s\p{L}\p{N}]+|\s+")
+        .expect("PRETOKENIZE: compile-time constant")
+});
+
+/// Exact byte-level BPE tokenizer (GPT-2 `vocab.json` + `merges.txt`).
+#[derive(Debug, Clone)]
+pub struct BpeTokenizer {
+    vocab: HashMap<String, u32>,
+    ranks: HashMap<(String, String), usize>,
+    byte_chars: [char; 256],
+}
+
+impl BpeTokenizer {
+    /// Loads a tokenizer from a `vocab.json` and `merges.txt` pair.
+    pub fn from_files(vocab: &Path, merges: &Path) -> Result<Self, TokenizerError> {
+        let vocab_text = read(vocab)?;
+        let merges_text = read(merges)?;
+        Self::from_strs(&vocab_text, &merges_text).map_err(|message| TokenizerError::Parse {
+            path: if message.starts_with("vocab") {
+                vocab.to_path_buf()
+            } else {
+                merges.to_path_buf()
+            },
+            message,
+        })
+    }
+
+    /// Builds a tokenizer from `vocab.json` and `merges.txt` contents.
+    pub fn from_strs(vocab_json: &str, merges_txt: &str) -> Result<Self, String> {
+        let vocab: HashMap<String, u32> = serde_json::from_str(vocab_json)
+            .map_err(|e| format!("vocab is not a token map: {e}"))?;
+        if vocab.is_empty() {
+            return Err("vocab is empty".to_string());
+        }
+
+        let mut ranks = HashMap::new();
+        for (n, line) in merges_txt.lines().enumerate() {
+            let line = line.trim_end_matches('\r');
+            if line.is_empty() || (n == 0 && line.starts_with("#version")) {
+                continue;
+            }
+            let Some((a, b)) = line.split_once(' ') else {
+                return Err(format!("merges line {} is not a token pair", n + 1));
+            };
+            let rank = ranks.len();
+            ranks.entry((a.to_string(), b.to_string())).or_insert(rank);
+        }
+
+        Ok(Self {
+            vocab,
+            ranks,
+            byte_chars: bytes_to_unicode(),
+        })
+    }
+
+    /// Number of entries in the vocabulary.
+    pub fn vocab_size(&self) -> usize {
+        self.vocab.len()
+    }
+
+    /// Splits `text` into BPE tokens.
+    pub fn tokenize(&self, text: &str) -> Vec<String> {
+        let mut tokens = Vec::new();
+        for piece in pieces(text) {
+            let mapped: String = piece.bytes().map(|b| self.byte_chars[b as usize]).collect();
+            tokens.extend(self.merge(&mapped));
+        }
+        tokens
+    }
+
+    /// Token ids for `text`. Tokens missing from the vocabulary are skipped.
+    pub fn encode(&self, text: &str) -> Vec<u32> {
+        self.tokenize(text)
+            .iter()
+            .filter_map(|t| self.vocab.get(t).copied())
+            .collect()
+    }
+
+    /// Applies merges to one byte-mapped piece, lowest rank first.
+    fn merge(&self, piece: &str) -> Vec<String> {
+        let mut symbols: Vec<String> = piece.chars().map(String::from).collect();
+        while symbols.len() > 1 {
+            let best = symbols
+                .windows(2)
+                .enumerate()
+                .filter_map(|(i, w)| {
+                    self.ranks
+                        .get(&(w[0].clone(), w[1].clone()))
+                        .map(|r| (*r, i))
+                })
+                .min();
+            let Some((_, i)) = best else { break };
+            let (a, b) = (symbols[i].clone(), symbols[i + 1].clone());
+            let mut merged = Vec::with_capacity(symbols.len());
+            let mut j = 0;
+            while j < symbols.len() {
+                if j + 1 < symbols.len() && symbols[j] == a && symbols[j + 1] == b {
+                    merged.push(format!("{a}{b}"));
+                    j += 2;
+                } else {
+                    merged.push(std::mem::take(&mut symbols[j]));
+                    j += 1;
+                }
+            }
+            symbols = merged;
+        }
+        symbols
+    }
+}
+
+impl Tokenizer for BpeTokenizer {
+    fn name(&self) -> &str {
+        "bpe"
+    }
+
+    fn count(&self, text: &str, _section: Section) -> usize {
+        self.tokenize(text).len()
+    }
+}
+
+/// Splits text like GPT-2's pre-tokenizer: a whitespace run followed by a
+/// word leaves its last character to prefix that word.
+fn pieces(text: &str) -> Vec<&str> {
+    let mut out = Vec::new();
+    let mut pos = 0;
+    while let Some(m) = PRETOKENIZE.find_at(text, pos) {
+        let s = m.as_str();
+        let mut end = m.end();
+        if s.chars().all(char::is_whitespace) && end < text.len() {
+            if let Some((last, _)) = s.char_indices().last().filter(|(i, _)| *i > 0) {
+                end = m.start() + last;
+            }
+        }
+        out.push(&text[m.start()..end]);
+        pos = end;
+    }
+    out
+}
+
+/// GPT-2's reversible byte → printable character table.
+fn bytes_to_unicode() -> [char; 256] {
+    let mut table = ['\0'; 256];
+    let mut extra = 0u32;
+    for b in 0..=255u8 {
+        let printable = matches!(b, b'!'..=b'~' | 0xA1..=0xAC | 0xAE..=0xFF);
+        table[b as usize] = if printable {
+            char::from(b)
+        } else {
+            extra += 1;
+            char::from_u32(255 + extra).unwrap_or(char::REPLACEMENT_CHARACTER)
+        };
+    }
+    table
+}
+
+fn read(path: &Path) -> Result<String, TokenizerError> {
+    std::fs::read_to_string(path).map_err(|source| TokenizerError::Io {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+/// How to build one tokenizer.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum TokenizerSpec {
+    /// Character-ratio estimate.
+    #[default]
+    Heuristic,
+    /// Byte-level BPE from a vocabulary and merges file.
+    Bpe {
+        /// Path to `vocab.json`.
+        vocab: PathBuf,
+        /// Path to `merges.txt`.
+        merges: PathBuf,
+    },
+}
+
+/// Tokenizer selection per target CLI (`~/.skrills/tokenizers.toml`).
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TokenizerConfig {
+    /// Tokenizer for CLIs without an entry in `cli`.
+    #[serde(default)]
+    pub default: TokenizerSpec,
+    /// Tokenizers keyed by CLI name (`claude`, `codex`, ...).
+    #[serde(default)]
+    pub cli: HashMap<String, TokenizerSpec>,
+    /// Directory relative paths are resolved against.
+    #[serde(skip)]
+    pub base_dir: PathBuf,
+}
+
+impl TokenizerConfig {
+    /// Loads `path`; a missing file yields the heuristic-only default.
+    pub fn load(path: &Path) -> Result<Self, TokenizerError> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let text = read(path)?;
+        let mut config: Self = toml::from_str(&text).map_err(|e| TokenizerError::Parse {
+            path: path.to_path_buf(),
+            message: e.to_string(),
+        })?;
+        config.base_dir = path.parent().map(Path::to_path_buf).unwrap_or_default();
+        Ok(config)
+    }
+
+    /// The spec for `cli`, falling back to `default`.
+    pub fn spec_for(&self, cli: Option<&str>) -> &TokenizerSpec {
+        cli.and_then(|c| self.cli.get(&c.to_ascii_lowercase()))
+            .unwrap_or(&self.default)
+    }
+
+    /// Builds the tokenizer for `cli`, falling back to `default`.
+    pub fn tokenizer_for(&self, cli: Option<&str>) -> Result<Arc<dyn Tokenizer>, TokenizerError> {
+        match self.spec_for(cli) {
+            TokenizerSpec::Heuristic => Ok(Arc::new(HeuristicTokenizer)),
+            TokenizerSpec::Bpe { vocab, merges } => Ok(Arc::new(BpeTokenizer::from_files(
+                &self.base_dir.join(vocab),
+                &self.base_dir.join(merges),
+            )?)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    /// Vocabulary with every printable byte plus a few merged tokens.
+    fn tiny_bpe() -> BpeTokenizer {
+        let table = bytes_to_unicode();
+        let mut vocab: HashMap<String, u32> = table
+            .iter()
+            .enumerate()
+            .map(|(i, c)| (c.to_string(), i as u32))
+            .collect();
+        for (i, t) in ["he", "ll", "hell", "hello", "Ġw", "Ġwor"]
+            .iter()
+            .enumerate()
+        {
+            vocab.insert(t.to_string(), 256 + i as u32);
+        }
+        let merges = "#version: 0.2\nh e\nl l\nhe ll\nhell o\nĠ w\nĠw o\nĠwo r\n";
+        BpeTokenizer::from_strs(&serde_json::to_string(&vocab).unwrap(), merges).unwrap()
+    }
+
+    #[test]
+    fn heuristic_matches_section_ratios() {
+        let t = HeuristicTokenizer;
+        assert_eq!(t.count("abcdefgh", Section::Prose), 2);
+        assert_eq!(t.count("abcdefg", Section::Code), 2);
+        assert_eq!(t.count("abcdefghijklmnop", Section::Frontmatter), 5);
+        assert_eq!(t.count("", Section::Prose), 0);
+    }
+
+    #[test]
+    fn bpe_applies_merges_by_rank() {
+        let t = tiny_bpe();
+        assert_eq!(t.tokenize("hello"), vec!["hello"]);
+        // "Ġwor" merges (rank order) but there is no merge to absorb "ld".
+        assert_eq!(t.tokenize("hello world"), vec!["hello", "Ġwor", "l", "d"]);
+        assert_eq!(t.count("hello world", Section::Prose), 4);
+        assert_eq!(t.encode("hello").len(), 1);
+    }
+
+    #[test]
+    fn bpe_maps_bytes_so_unicode_is_never_lost() {
+        let t = tiny_bpe();
+        // 'é' is two UTF-8 bytes, each its own byte-level symbol.
+        assert_eq!(t.count("é", Section::Prose), 2);
+    }
+
+    #[test]
+    fn pre_tokenizer_gives_last_space_to_next_word() {
+        assert_eq!(pieces("a  b"), vec!["a", " ", " b"]);
+        assert_eq!(pieces("x\n\ny"), vec!["x", "\n", "\n", "y"]);
+        assert_eq!(pieces("it's 42!"), vec!["it", "'s", " 42", "!"]);
+        assert_eq!(pieces("end   "), vec!["end", "   "]);
+    }
+
+    #[test]
+    fn bpe_rejects_malformed_files() {
+        assert!(BpeTokenizer::from_strs("[]", "").is_err());
+        assert!(BpeTokenizer::from_strs("{\"a\": 0}", "nopair").is_err());
+    }
+
+    #[test]
+    fn config_selects_tokenizer_per_cli() {
+        let tmp = TempDir::new().unwrap();
+        let t = tiny_bpe();
+        std::fs::write(
+            tmp.path().join("vocab.json"),
+            serde_json::to_string(&t.vocab).unwrap(),
+        )
+        .unwrap();
+        std::fs::write(tmp.path().join("merges.txt"), "h e\nl l\nhe ll\nhell o\n").unwrap();
+        let path = tmp.path().join("tokenizers.toml");
+        std::fs::write(
+            &path,
+            "[cli.codex]\nkind = \"bpe\"\nvocab = \"vocab.json\"\nmerges = \"merges.txt\"\n",
+        )
+        .unwrap();
+
+        let config = TokenizerConfig::load(&path).unwrap();
+        let codex = config.tokenizer_for(Some("Codex")).unwrap();
+        assert_eq!(codex.name(), "bpe");
+        assert_eq!(codex.count("hello", Section::Prose), 1);
+        assert_eq!(
+            config.tokenizer_for(Some("claude")).unwrap().name(),
+            "heuristic"
+        );
+        assert_eq!(config.tokenizer_for(None).unwrap().name(), "heuristic");
+    }
+
+    #[test]
+    fn missing_config_is_heuristic_and_bad_paths_error() {
+        let tmp = TempDir::new().unwrap();
+        let config = TokenizerConfig::load(&tmp.path().join("absent.toml")).unwrap();
+        assert_eq!(config.spec_for(Some("codex")), &TokenizerSpec::Heuristic);
+
+        let path = tmp.path().join("tokenizers.toml");
+        std::fs::write(
+            &path,
+            "[default]\nkind = \"bpe\"\nvocab = \"nope.json\"\nmerges = \"nope.txt\"\n",
+        )
+        .unwrap();
+        let err = TokenizerConfig::load(&path)
+            .unwrap()
+            .tokenizer_for(None)
+            .err()
+            .unwrap();
+        assert!(matches!(err, TokenizerError::Io { .. }));
+    }
+}
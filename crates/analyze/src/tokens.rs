@@ -1,23 +1,14 @@
 //! Token estimation for skill files.
 //!
-//! Uses character-based heuristics for GPT-style tokenizers.
-//! Different ratios are used for prose vs code content since
-//! code tends to have shorter tokens due to symbols and keywords.
+//! Splits skills into frontmatter, prose, and code sections and counts
+//! each through a [`Tokenizer`]. The default [`HeuristicTokenizer`] uses
+//! character-based ratios for GPT-style tokenizers (code and YAML have
+//! shorter tokens than prose); a [`BpeTokenizer`](crate::tokenizer::BpeTokenizer)
+//! gives exact counts when a vocabulary is configured.
 
+use crate::tokenizer::{HeuristicTokenizer, Section, Tokenizer, PROSE_CHARS_PER_TOKEN};
 use serde::{Deserialize, Serialize};
 
-/// Approximate characters per token for prose/markdown content.
-/// English text averages ~4 characters per token.
-const PROSE_CHARS_PER_TOKEN: f64 = 4.0;
-
-/// Approximate characters per token for code content.
-/// Code averages ~3.5 chars/token due to short keywords, symbols, and identifiers.
-const CODE_CHARS_PER_TOKEN: f64 = 3.5;
-
-/// Approximate characters per token for YAML/frontmatter content.
-/// YAML has many short keys and values, averaging ~3.2 chars/token.
-const FRONTMATTER_CHARS_PER_TOKEN: f64 = 3.2;
-
 /// Token count breakdown by section.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct TokenBreakdown {
@@ -31,75 +22,77 @@ pub struct TokenBreakdown {
     pub total: usize,
 }
 
-/// Estimate token count from character count using prose ratio.
-/// For more accurate estimates, use `estimate_tokens_with_ratio`.
+/// Estimate token count from character count using the prose ratio.
+/// For section-aware estimates, use `count_tokens`.
 pub fn estimate_tokens(chars: usize) -> usize {
-    estimate_tokens_with_ratio(chars, PROSE_CHARS_PER_TOKEN)
+    (chars as f64 / PROSE_CHARS_PER_TOKEN).ceil() as usize
 }
 
-/// Estimate token count from character count using a specific chars-per-token ratio.
-fn estimate_tokens_with_ratio(chars: usize, chars_per_token: f64) -> usize {
-    (chars as f64 / chars_per_token).ceil() as usize
+/// Count tokens in a skill file with section breakdown, using the
+/// default [`HeuristicTokenizer`].
+pub fn count_tokens(content: &str) -> TokenBreakdown {
+    count_tokens_with(content, &HeuristicTokenizer)
 }
 
-/// Count tokens in a skill file with section breakdown.
-pub fn count_tokens(content: &str) -> TokenBreakdown {
+/// Count tokens in a skill file with section breakdown, using `tokenizer`.
+pub fn count_tokens_with(content: &str, tokenizer: &dyn Tokenizer) -> TokenBreakdown {
     let mut breakdown = TokenBreakdown::default();
 
     let mut in_frontmatter = false;
     let mut in_code_block = false;
-    let mut frontmatter_chars = 0usize;
-    let mut code_chars = 0usize;
-    let mut prose_chars = 0usize;
+    let mut frontmatter_text = String::new();
+    let mut code_text = String::new();
+    let mut prose_text = String::new();
 
     let mut frontmatter_started = false;
 
     for line in content.lines() {
-        let line_len = line.len() + 1; // +1 for newline
-
         // Handle frontmatter delimiters
         if line.trim() == "---" {
             if !frontmatter_started {
                 frontmatter_started = true;
                 in_frontmatter = true;
-                frontmatter_chars += line_len;
+                push_line(&mut frontmatter_text, line);
                 continue;
             } else if in_frontmatter {
                 in_frontmatter = false;
-                frontmatter_chars += line_len;
+                push_line(&mut frontmatter_text, line);
                 continue;
             }
         }
 
         if in_frontmatter {
-            frontmatter_chars += line_len;
+            push_line(&mut frontmatter_text, line);
             continue;
         }
 
         // Handle code blocks
         if line.trim().starts_with("```") {
             in_code_block = !in_code_block;
-            code_chars += line_len;
+            push_line(&mut code_text, line);
             continue;
         }
 
         if in_code_block {
-            code_chars += line_len;
+            push_line(&mut code_text, line);
         } else {
-            prose_chars += line_len;
+            push_line(&mut prose_text, line);
         }
     }
 
-    // Use content-specific ratios for more accurate token estimation
-    breakdown.frontmatter =
-        estimate_tokens_with_ratio(frontmatter_chars, FRONTMATTER_CHARS_PER_TOKEN);
-    breakdown.code = estimate_tokens_with_ratio(code_chars, CODE_CHARS_PER_TOKEN);
-    breakdown.prose = estimate_tokens_with_ratio(prose_chars, PROSE_CHARS_PER_TOKEN);
+    breakdown.frontmatter = tokenizer.count(&frontmatter_text, Section::Frontmatter);
+    breakdown.code = tokenizer.count(&code_text, Section::Code);
+    breakdown.prose = tokenizer.count(&prose_text, Section::Prose);
     breakdown.total = breakdown.frontmatter + breakdown.code + breakdown.prose;
 
     breakdown
 }
 
+fn push_line(section: &mut String, line: &str) {
+    section.push_str(line);
+    section.push('\n');
+}
+
 /// Token budget categories.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TokenCategory {
@@ -178,9 +171,9 @@ pub struct AttributedBreakdown {
 /// Count tokens for `content` and attribute the result to a named source.
 ///
 /// Existing `count_tokens()` API is unchanged; this is purely additive.
-/// The same character-heuristic limits apply (see crate-level docs in
-/// `tokens.rs`); accuracy on a known fixture is asserted at ≥95%
-/// in the cold-window test plan.
+/// Counts with the default [`HeuristicTokenizer`], so the same
+/// character-heuristic limits apply (see the module docs); use
+/// [`count_tokens_attributed_with`] for a configured tokenizer.
 ///
 /// # Example
 ///
@@ -200,11 +193,21 @@ pub fn count_tokens_attributed(
     content: &str,
     source: TokenSource,
     identifier: impl Into<String>,
+) -> AttributedBreakdown {
+    count_tokens_attributed_with(content, source, identifier, &HeuristicTokenizer)
+}
+
+/// [`count_tokens_attributed`] with an explicit tokenizer.
+pub fn count_tokens_attributed_with(
+    content: &str,
+    source: TokenSource,
+    identifier: impl Into<String>,
+    tokenizer: &dyn Tokenizer,
 ) -> AttributedBreakdown {
     AttributedBreakdown {
         source,
         identifier: identifier.into(),
-        breakdown: count_tokens(content),
+        breakdown: count_tokens_with(content, tokenizer),
     }
 }
 
@@ -339,4 +342,50 @@ More prose here.
             "Total should equal sum of parts"
         );
     }
+
+    /// Counts whitespace-separated words, tagging code sections so the
+    /// test can see which section each call came from.
+    struct WordTokenizer;
+
+    impl Tokenizer for WordTokenizer {
+        fn name(&self) -> &str {
+            "words"
+        }
+
+        fn count(&self, text: &str, section: Section) -> usize {
+            let words = text.split_whitespace().count();
+            if section == Section::Code {
+                words * 10
+            } else {
+                words
+            }
+        }
+    }
+
+    #[test]
+    fn test_count_tokens_with_routes_sections_through_tokenizer() {
+        let content = "---\nname: x\n---\nTwo words\n```\nlet a;\n```\n";
+        let breakdown = count_tokens_with(content, &WordTokenizer);
+
+        // Frontmatter: "---", "name:", "x", "---".
+        assert_eq!(breakdown.frontmatter, 4);
+        assert_eq!(breakdown.prose, 2);
+        // Code: "```", "let", "a;", "```" = 4 words, tagged x10.
+        assert_eq!(breakdown.code, 40);
+        assert_eq!(breakdown.total, 46);
+
+        let attributed =
+            count_tokens_attributed_with(content, TokenSource::Skill, "skill://x", &WordTokenizer);
+        assert_eq!(attributed.breakdown.total, 46);
+    }
+
+    #[test]
+    fn test_count_tokens_uses_heuristic_ratios() {
+        // 7 chars + newline = 8 prose chars at 4.0 chars/token.
+        assert_eq!(count_tokens("abcdefg").prose, 2);
+        assert_eq!(
+            count_tokens("abcdefg").total,
+            count_tokens_with("abcdefg", &HeuristicTokenizer).total
+        );
+    }
 }
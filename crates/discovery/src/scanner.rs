@@ -592,6 +592,29 @@ pub fn default_roots(home: &Path) -> Vec<SkillRoot> {
     roots
 }
 
+/// Returns the skill roots inside a repository, as synced by project-scoped
+/// `skrills sync`.
+pub fn project_roots(project: &Path) -> Vec<SkillRoot> {
+    vec![
+        SkillRoot {
+            root: project.join(".codex/skills"),
+            source: SkillSource::Codex,
+        },
+        SkillRoot {
+            root: project.join(".claude/skills"),
+            source: SkillSource::Claude,
+        },
+        SkillRoot {
+            root: project.join(".github/skills"),
+            source: SkillSource::Copilot,
+        },
+        SkillRoot {
+            root: project.join(".cursor/skills"),
+            source: SkillSource::Cursor,
+        },
+    ]
+}
+
 /// Builds skill roots from extra directories.
 pub fn extra_skill_roots(extra: &[PathBuf]) -> Vec<SkillRoot> {
     extra
@@ -790,6 +813,15 @@ mod tests {
         assert!(!refs.contains("rules"));
     }
 
+    #[test]
+    fn project_roots_point_into_repository() {
+        let tmp = tempdir().unwrap();
+        let roots = project_roots(tmp.path());
+        let labels: Vec<_> = roots.iter().map(|r| r.source.label()).collect();
+        assert_eq!(labels, vec!["codex", "claude", "copilot", "cursor"]);
+        assert!(roots.iter().all(|r| r.root.starts_with(tmp.path())));
+    }
+
     #[test]
     fn default_roots_use_priority_order() {
         let tmp = tempdir().unwrap();
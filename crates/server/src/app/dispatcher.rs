@@ -11,20 +11,26 @@
 //! line, the logic belongs in `crate::commands::foo` (or a new
 //! handler under `crate::commands`).
 
-use crate::cli::{CertAction, Cli, Commands, SyncSource};
+use crate::cli::{
+    CertAction, Cli, Commands, DepsAction, PluginAction, SyncPlatform, SyncScopeArg, SyncSource,
+};
 use crate::commands::{
     handle_agent_command, handle_analyze_command, handle_analyze_project_context_command,
     handle_cert_install_command, handle_cert_renew_command, handle_cert_status_command,
-    handle_create_skill_command, handle_export_analytics_command, handle_import_analytics_command,
-    handle_metrics_command, handle_mirror_command, handle_multi_cli_agent_command,
+    handle_create_skill_command, handle_deps_install_command, handle_deps_verify_command,
+    handle_export_analytics_command, handle_import_analytics_command,
+    handle_instructions_diff_command, handle_lint_rules_command, handle_metrics_command,
+    handle_mirror_command, handle_multi_cli_agent_command, handle_plugin_install_command,
+    handle_plugin_list_command, handle_plugin_uninstall_command, handle_plugin_upgrade_command,
     handle_pre_commit_validate_command, handle_recommend_command,
     handle_recommend_skills_smart_command, handle_resolve_dependencies_command,
     handle_search_skills_command, handle_search_skills_github_command, handle_serve_command,
     handle_setup_command, handle_skill_catalog_command, handle_skill_deprecate_command,
     handle_skill_diff_command, handle_skill_import_command, handle_skill_profile_command,
-    handle_skill_rollback_command, handle_skill_score_command, handle_skill_usage_report_command,
-    handle_suggest_new_skills_command, handle_sync_agents_command, handle_sync_command,
-    handle_sync_pull_command, handle_validate_command,
+    handle_skill_release_command, handle_skill_rollback_command, handle_skill_score_command,
+    handle_skill_usage_report_command, handle_suggest_new_skills_command,
+    handle_sync_agents_command, handle_sync_command, handle_sync_history_command,
+    handle_sync_pull_command, handle_sync_undo_command, handle_validate_command, ReleaseLevel,
 };
 use crate::discovery::merge_extra_dirs;
 use crate::doctor::doctor_report;
@@ -40,8 +46,8 @@ use skrills_state::home_dir;
 /// Re-exported under `#[cfg(test)]` from `app/mod.rs` so tests under
 /// `app/tests/sync.rs` can reach it via the `super::super::*` glob.
 pub(crate) fn run_sync_with_adapters(
-    from: SyncSource,
-    to: SyncSource,
+    from: &SyncPlatform,
+    to: &SyncPlatform,
     params: &skrills_sync::SyncParams,
 ) -> Result<skrills_sync::SyncReport> {
     // Same source and target - error
@@ -160,7 +166,18 @@ pub fn run() -> Result<()> {
         Commands::SyncAgents { path, skill_dirs } => handle_sync_agents_command(path, skill_dirs),
         Commands::Sync {
             include_marketplace,
-        } => handle_sync_command(include_marketplace),
+            strategy,
+            scope,
+            project_dir,
+            plan,
+            apply,
+        } => handle_sync_command(
+            include_marketplace,
+            strategy,
+            SyncScopeArg::resolve(scope, project_dir)?,
+            plan,
+            apply,
+        ),
         Commands::SyncCommands {
             from,
             to,
@@ -190,7 +207,7 @@ pub fn run() -> Result<()> {
                 ..Default::default()
             };
 
-            let report = run_sync_with_adapters(from, target, &params)?;
+            let report = run_sync_with_adapters(&from, &target, &params)?;
 
             tracing::info!(
                 "{}{}",
@@ -215,7 +232,12 @@ pub fn run() -> Result<()> {
             }
             Ok(())
         }
-        Commands::SyncMcpServers { from, to, dry_run } => {
+        Commands::SyncMcpServers {
+            from,
+            to,
+            dry_run,
+            redact_secrets,
+        } => {
             use skrills_sync::SyncParams;
 
             let target = to.unwrap_or_else(|| from.default_target());
@@ -227,10 +249,11 @@ pub fn run() -> Result<()> {
                 sync_mcp_servers: true,
                 sync_preferences: false,
                 sync_skills: false,
+                redact_secrets,
                 ..Default::default()
             };
 
-            let report = run_sync_with_adapters(from, target, &params)?;
+            let report = run_sync_with_adapters(&from, &target, &params)?;
 
             tracing::info!("{}", report.summary);
             if dry_run {
@@ -253,7 +276,7 @@ pub fn run() -> Result<()> {
                 ..Default::default()
             };
 
-            let report = run_sync_with_adapters(from, target, &params)?;
+            let report = run_sync_with_adapters(&from, &target, &params)?;
 
             tracing::info!("{}", report.summary);
             if dry_run {
@@ -270,12 +293,23 @@ pub fn run() -> Result<()> {
             exclude_plugins,
             validate: _validate,
             autofix: _autofix,
+            force,
+            strategy,
+            scope,
+            project_dir,
         } => {
             use skrills_sync::SyncParams;
 
-            // Determine targets: explicit --to or all other CLIs
-            let targets: Vec<SyncSource> = match to {
+            let scope = SyncScopeArg::resolve(scope, project_dir)?;
+            // Determine targets: explicit --to or all other CLIs. Only the
+            // built-in CLIs have a project layout.
+            let targets: Vec<SyncPlatform> = match to {
                 Some(t) => vec![t],
+                None if scope.is_project() => from
+                    .other_targets()
+                    .into_iter()
+                    .filter(|t| matches!(t, SyncPlatform::Builtin(_)))
+                    .collect(),
                 None => from.other_targets(),
             };
 
@@ -290,8 +324,16 @@ pub fn run() -> Result<()> {
                     );
                 }
 
-                // First sync skills using existing mechanism (only for claude→codex)
-                if from.is_claude() && target.is_codex() && !dry_run {
+                // First sync skills using existing mechanism (only for claude→codex).
+                // An explicit --strategy routes them through the orchestrator's
+                // conflict detection instead.
+                // Repositories have no plugin cache, so project scope always
+                // syncs skills through the orchestrator.
+                let mirror_skills = from.is_claude()
+                    && target.is_codex()
+                    && strategy.is_none()
+                    && !scope.is_project();
+                if mirror_skills && !dry_run {
                     let home = home_dir()?;
                     let claude_root = mirror_source_root(&home);
                     let codex_skills_root = home.join(".codex/skills");
@@ -324,11 +366,11 @@ pub fn run() -> Result<()> {
                 // Skip skills sync for Claude→Codex (handled above with special logic).
                 // For →Cursor: skip flat skills copy, Cursor discovers skills from
                 // its own plugins/cache/ which is synced via plugin_assets.
-                let sync_skills = !(target.is_cursor() || from.is_claude() && target.is_codex());
+                let sync_skills = scope.is_project() || !(target.is_cursor() || mirror_skills);
                 // Cursor needs a full plugin mirror (including skills and manifests)
                 // since it has its own plugin cache at ~/.cursor/plugins/cache/
-                let full_plugin_mirror = target.is_cursor();
-                let params = SyncParams {
+                let full_plugin_mirror = target.is_cursor() && !scope.is_project();
+                let mut params = SyncParams {
                     from: Some(from.as_str().to_string()),
                     dry_run,
                     sync_commands: true,
@@ -339,10 +381,16 @@ pub fn run() -> Result<()> {
                     include_marketplace,
                     exclude_plugins: exclude_plugins.clone(),
                     full_plugin_mirror,
+                    sync_plugin_assets: !scope.is_project(),
+                    force,
+                    scope: scope.clone(),
                     ..Default::default()
                 };
+                if let Some(strategy) = strategy {
+                    params.conflict_strategy = Some(strategy.into());
+                }
 
-                let report = run_sync_with_adapters(from, target, &params)?;
+                let report = run_sync_with_adapters(&from, &target, &params)?;
 
                 tracing::info!(
                     "{}{}",
@@ -369,12 +417,30 @@ pub fn run() -> Result<()> {
             }
             Ok(())
         }
-        Commands::SyncStatus { from, to } => {
+        #[cfg(feature = "watch")]
+        Commands::SyncWatch {
+            from,
+            to,
+            scope,
+            project_dir,
+            debounce_ms,
+        } => {
+            let scope = SyncScopeArg::resolve(scope, project_dir)?;
+            crate::commands::handle_sync_watch_command(from, to, scope, debounce_ms)
+        }
+        Commands::SyncStatus {
+            from,
+            to,
+            scope,
+            project_dir,
+            redact_secrets,
+        } => {
             use skrills_sync::SyncParams;
 
+            let scope = SyncScopeArg::resolve(scope, project_dir)?;
             let target = to.unwrap_or_else(|| from.default_target());
             // Only skip skills sync for Claude→Codex (it has special handling elsewhere)
-            let sync_skills = !(from.is_claude() && target.is_codex());
+            let sync_skills = scope.is_project() || !(from.is_claude() && target.is_codex());
 
             let params = SyncParams {
                 from: Some(from.as_str().to_string()),
@@ -383,6 +449,8 @@ pub fn run() -> Result<()> {
                 sync_mcp_servers: true,
                 sync_preferences: true,
                 sync_skills,
+                scope: scope.clone(),
+                redact_secrets,
                 ..Default::default()
             };
 
@@ -392,7 +460,7 @@ pub fn run() -> Result<()> {
                 "sync direction"
             );
 
-            let report = run_sync_with_adapters(from, target, &params)?;
+            let report = run_sync_with_adapters(&from, &target, &params)?;
 
             tracing::info!(
                 commands = report.commands.written,
@@ -400,26 +468,58 @@ pub fn run() -> Result<()> {
                 preferences = report.preferences.written,
                 "pending changes"
             );
+            for unmapped in &report.unmapped_models {
+                tracing::warn!(
+                    item = %unmapped.item,
+                    model = %unmapped.model,
+                    policy = %unmapped.policy,
+                    "no model mapping for target; add a rule to ~/.skrills/models.toml"
+                );
+            }
+            for secret in &report.mcp_secrets {
+                tracing::warn!(
+                    server = %secret.server,
+                    field = %secret.field,
+                    variable = %secret.variable,
+                    action = %secret.action,
+                    length = ?secret.length,
+                    "MCP secret in server config"
+                );
+            }
+            for reason in report.policy_exclusions() {
+                tracing::info!("{}", reason.description());
+            }
 
             // Count skills
             let home = home_dir()?;
-            let source_root = match from {
-                SyncSource::Claude => mirror_source_root(&home),
-                SyncSource::Codex => home.join(".codex/skills"),
-                SyncSource::Copilot => {
+            let source_root = match &from {
+                _ if scope.is_project() => {
+                    use skrills_sync::adapters::traits::AgentAdapter;
+                    skrills_sync::create_adapter_in(from.as_str(), &scope)?
+                        .config_root()
+                        .join("skills")
+                }
+                SyncPlatform::Builtin(SyncSource::Claude) => mirror_source_root(&home),
+                SyncPlatform::Builtin(SyncSource::Codex) => home.join(".codex/skills"),
+                SyncPlatform::Builtin(SyncSource::Copilot) => {
                     use skrills_sync::adapters::traits::AgentAdapter;
                     use skrills_sync::CopilotAdapter;
                     CopilotAdapter::new()
                         .map(|a| a.config_root().join("skills"))
                         .unwrap_or_else(|_| home.join(".copilot/skills"))
                 }
-                SyncSource::Cursor => {
+                SyncPlatform::Builtin(SyncSource::Cursor) => {
                     use skrills_sync::adapters::traits::AgentAdapter;
                     use skrills_sync::CursorAdapter;
                     CursorAdapter::new()
                         .map(|a| a.config_root().join("skills"))
                         .unwrap_or_else(|_| home.join(".cursor/skills"))
                 }
+                SyncPlatform::External(name) => {
+                    skrills_sync::adapters::external::find_external_config(name)?
+                        .and_then(|config| config.skills_path())
+                        .unwrap_or_default()
+                }
             };
             if source_root.exists() {
                 // Surface walkdir errors instead of
@@ -462,6 +562,8 @@ pub fn run() -> Result<()> {
 
             Ok(())
         }
+        Commands::SyncUndo { id, format } => handle_sync_undo_command(id, format),
+        Commands::SyncHistory { limit, format } => handle_sync_history_command(limit, format),
         Commands::Doctor => doctor_report(),
         Commands::Tui { skill_dirs } => tui_flow(&merge_extra_dirs(&skill_dirs)),
         #[cfg(feature = "dashboard")]
@@ -504,6 +606,8 @@ pub fn run() -> Result<()> {
             backup,
             format,
             errors_only,
+            check_links,
+            check_urls,
             #[cfg(feature = "watch")]
                 watch: _watch,
             #[cfg(feature = "watch")]
@@ -511,14 +615,34 @@ pub fn run() -> Result<()> {
         } => {
             // TODO(#208): when watch feature is enabled, dispatch to watch loop
             // if _watch { return handle_validate_watch(...); }
-            handle_validate_command(skill_dirs, target, autofix, backup, format, errors_only)
+            handle_validate_command(
+                skill_dirs,
+                target,
+                autofix,
+                backup,
+                format,
+                errors_only,
+                check_links,
+                check_urls,
+            )
         }
         Commands::Analyze {
             skill_dirs,
             format,
             min_tokens,
             suggestions,
-        } => handle_analyze_command(skill_dirs, format, min_tokens, suggestions),
+            target,
+            check_links,
+            check_urls,
+        } => handle_analyze_command(
+            skill_dirs,
+            format,
+            min_tokens,
+            suggestions,
+            target,
+            check_links,
+            check_urls,
+        ),
         Commands::Metrics {
             skill_dirs,
             format,
@@ -620,7 +744,17 @@ pub fn run() -> Result<()> {
             name,
             format,
             context,
-        } => handle_skill_diff_command(name, format, context),
+            scope,
+            project_dir,
+            ..
+        } => {
+            let scope = SyncScopeArg::resolve(scope, project_dir)?;
+            // clap requires a name unless --instructions is given.
+            match name {
+                Some(name) => handle_skill_diff_command(name, format, context, scope),
+                None => handle_instructions_diff_command(format, context, scope),
+            }
+        }
         Commands::SkillDeprecate {
             name,
             message,
@@ -634,13 +768,31 @@ pub fn run() -> Result<()> {
             skill_dirs,
             format,
         } => handle_skill_rollback_command(name, version, skill_dirs, format),
+        Commands::SkillRelease {
+            name,
+            major,
+            minor,
+            patch,
+            dry_run,
+            no_tag,
+            skill_dirs,
+            format,
+        } => handle_skill_release_command(
+            name,
+            ReleaseLevel::from_flags(major, minor, patch),
+            dry_run,
+            no_tag,
+            skill_dirs,
+            format,
+        ),
         Commands::SyncPull {
             source,
             skill,
             target,
             dry_run,
+            force,
             format,
-        } => handle_sync_pull_command(source, skill, target, dry_run, format),
+        } => handle_sync_pull_command(source, skill, target, dry_run, force, format),
         Commands::SkillProfile {
             name,
             period,
@@ -654,18 +806,22 @@ pub fn run() -> Result<()> {
             skill_dirs,
             format,
         } => handle_skill_catalog_command(search, source, category, limit, skill_dirs, format),
+        Commands::LintRules { format } => handle_lint_rules_command(format),
         Commands::PreCommitValidate {
             staged,
             target,
             skill_dirs,
-        } => handle_pre_commit_validate_command(staged, target, skill_dirs),
+            format,
+        } => handle_pre_commit_validate_command(staged, target, skill_dirs, format),
         Commands::SkillImport {
             source,
+            git_ref,
+            subdir,
             target,
             force,
             dry_run,
             format,
-        } => handle_skill_import_command(source, target, force, dry_run, format),
+        } => handle_skill_import_command(source, git_ref, subdir, target, force, dry_run, format),
         Commands::SkillUsageReport {
             period,
             format,
@@ -678,6 +834,32 @@ pub fn run() -> Result<()> {
             format,
             below_threshold,
         } => handle_skill_score_command(name, skill_dirs, format, below_threshold),
+        Commands::Plugin(action) => match action {
+            PluginAction::List { format } => handle_plugin_list_command(format),
+            PluginAction::Install { name, to } => handle_plugin_install_command(name, to),
+            PluginAction::Uninstall { name, from } => handle_plugin_uninstall_command(name, from),
+            PluginAction::Upgrade { name, to } => handle_plugin_upgrade_command(name, to),
+        },
+        Commands::Deps(action) => match action {
+            DepsAction::Install {
+                skills,
+                registries,
+                target,
+                lock,
+                skill_dirs,
+                dry_run,
+                force,
+                format,
+            } => handle_deps_install_command(
+                skills, registries, target, lock, skill_dirs, dry_run, force, format,
+            ),
+            DepsAction::Verify {
+                target,
+                lock,
+                skill_dirs,
+                format,
+            } => handle_deps_verify_command(target, lock, skill_dirs, format),
+        },
         Commands::Cert(action) => match action {
             CertAction::Status { format } => handle_cert_status_command(format),
             CertAction::Renew { force } => handle_cert_renew_command(force),
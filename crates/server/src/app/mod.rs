@@ -15,12 +15,15 @@
 mod dispatcher;
 mod intelligence;
 mod mcp_registry;
+mod prompts;
 mod research;
 mod skill_recommendations;
+mod subscriptions;
 mod tools;
 
 pub use dispatcher::run;
 use mcp_registry::build_mcp_registry;
+pub(crate) use subscriptions::ResourceSubscriptions;
 
 #[cfg(test)]
 pub(crate) use dispatcher::run_sync_with_adapters;
@@ -74,25 +77,38 @@ pub struct SkillService {
     pub(crate) mcp_registry: Arc<Mutex<McpToolRegistry>>,
     /// Context usage statistics for tracking token savings.
     pub(crate) context_stats: Arc<ContextStats>,
+    /// Connected client and its resource subscriptions, for change notifications.
+    pub(crate) subscriptions: Arc<ResourceSubscriptions>,
 }
 
+/// Resource URI of a discovered skill.
+pub(crate) fn skill_uri(meta: &SkillMeta) -> String {
+    format!("skill://skrills/{}/{}", meta.source.label(), meta.name)
+}
+
+/// Debounce window for bursts of filesystem events (e.g. editor save sequences).
+#[cfg(feature = "watch")]
+const WATCH_DEBOUNCE: Duration = Duration::from_millis(300);
+
 /// Starts a filesystem watcher to invalidate caches on changes.
+///
+/// Each debounced batch of events rescans the skill roots and notifies the
+/// connected client (via `runtime`) of updated subscribed resources and of
+/// changes to the skill list.
 #[cfg(feature = "watch")]
-pub(crate) fn start_fs_watcher(service: &SkillService) -> Result<RecommendedWatcher> {
+pub(crate) fn start_fs_watcher(
+    service: &SkillService,
+    runtime: tokio::runtime::Handle,
+) -> Result<RecommendedWatcher> {
     let cache = service.cache.clone();
+    let subscriptions = service.subscriptions.clone();
     let roots = {
         let guard = cache.lock();
         guard.watched_roots()
     };
 
-    let mut watcher = RecommendedWatcher::new(
-        move |event: notify::Result<notify::Event>| {
-            if event.is_ok() {
-                cache.lock().invalidate();
-            }
-        },
-        NotifyConfig::default(),
-    )?;
+    let (tx, rx) = std::sync::mpsc::channel();
+    let mut watcher = RecommendedWatcher::new(tx, NotifyConfig::default())?;
 
     for root in roots {
         if root.exists() {
@@ -100,6 +116,23 @@ pub(crate) fn start_fs_watcher(service: &SkillService) -> Result<RecommendedWatc
         }
     }
 
+    // The thread exits when the watcher (and with it the sender) is dropped.
+    let mut known = subscriptions::skill_hashes(&cache);
+    std::thread::Builder::new()
+        .name("skrills-watch".into())
+        .spawn(move || {
+            while skrills_validate::watch::collect_debounced_paths(&rx, WATCH_DEBOUNCE).is_some() {
+                cache.lock().invalidate();
+                let current = subscriptions::skill_hashes(&cache);
+                let changes = subscriptions::SkillChanges::between(&known, &current);
+                known = current;
+                if !changes.is_empty() {
+                    runtime.block_on(subscriptions.notify(&changes));
+                }
+            }
+        })?;
+    service.subscriptions.set_watching();
+
     Ok(watcher)
 }
 
@@ -107,7 +140,10 @@ pub(crate) fn start_fs_watcher(service: &SkillService) -> Result<RecommendedWatc
 ///
 /// Returns an error if called.
 #[cfg(not(feature = "watch"))]
-pub(crate) fn start_fs_watcher(_service: &SkillService) -> Result<()> {
+pub(crate) fn start_fs_watcher(
+    _service: &SkillService,
+    _runtime: tokio::runtime::Handle,
+) -> Result<()> {
     Err(anyhow!(
         "watch feature is disabled; rebuild with --features watch"
     ))
@@ -138,6 +174,7 @@ impl SkillService {
             subagents: Some(SubagentService::new()?),
             mcp_registry,
             context_stats,
+            subscriptions: Arc::new(ResourceSubscriptions::default()),
         })
     }
 
@@ -165,6 +202,7 @@ impl SkillService {
             subagents: Some(SubagentService::new()?),
             mcp_registry,
             context_stats,
+            subscriptions: Arc::new(ResourceSubscriptions::default()),
         })
     }
 
@@ -362,7 +400,7 @@ impl SkillService {
         let mut resources: Vec<Resource> = skills
             .into_iter()
             .map(|s| {
-                let uri = format!("skill://skrills/{}/{}", s.source.label(), s.name);
+                let uri = skill_uri(&s);
                 let mut raw = RawResource::new(uri, s.name.clone());
                 raw.description = Some(format!(
                     "Skill from {} [location: {}]",
@@ -0,0 +1,394 @@
+//! MCP prompts backed by skills and slash commands.
+//!
+//! Every Claude and Codex slash command is listed under its own name and
+//! every discovered skill as `skill-<name>`, so any MCP client that surfaces
+//! prompts gets the shared command library without file syncing.
+//!
+//! Arguments come from the `arguments` frontmatter list (plain names or
+//! `{name, description, required}` maps). A template without that list but
+//! using `$ARGUMENTS` or declaring an `argument-hint` gets a single optional
+//! `arguments` argument. Rendering follows Claude Code's command syntax:
+//! `$ARGUMENTS` expands to all values, `$1`..`$9` to positional values, and
+//! `$name`/`${name}` to a declared argument.
+
+use super::SkillService;
+use anyhow::{anyhow, bail, Result};
+use rmcp::model::{GetPromptResult, Prompt, PromptArgument, PromptMessage, PromptMessageRole};
+use serde_json::{Map as JsonMap, Value};
+use skrills_sync::adapters::AgentAdapter as _;
+use skrills_sync::{ClaudeAdapter, CodexAdapter};
+use skrills_validate::frontmatter::split_frontmatter;
+use std::collections::HashSet;
+
+/// Prefix distinguishing skill prompts from slash command prompts.
+pub(crate) const SKILL_PROMPT_PREFIX: &str = "skill-";
+
+/// Name of the implicit argument for templates that declare none.
+const IMPLICIT_ARGUMENT: &str = "arguments";
+
+/// A prompt template parsed from a command or skill file.
+#[derive(Debug, Clone)]
+struct PromptTemplate {
+    name: String,
+    description: String,
+    arguments: Vec<PromptArgument>,
+    /// True when `arguments` is the implicit single argument.
+    implicit: bool,
+    body: String,
+}
+
+impl PromptTemplate {
+    fn parse(name: String, content: &str, fallback_description: String) -> Self {
+        let (yaml, body, _) = split_frontmatter(content);
+        let frontmatter = yaml
+            .and_then(|y| serde_yaml::from_str::<serde_yaml::Value>(&y).ok())
+            .unwrap_or(serde_yaml::Value::Null);
+        let text = |key: &str| {
+            frontmatter
+                .get(key)
+                .and_then(|v| v.as_str())
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+        };
+
+        let mut arguments: Vec<PromptArgument> = frontmatter
+            .get("arguments")
+            .and_then(|v| v.as_sequence())
+            .map(|seq| seq.iter().filter_map(declared_argument).collect())
+            .unwrap_or_default();
+        let hint = argument_hint(&frontmatter);
+        let implicit = arguments.is_empty() && (hint.is_some() || body.contains("$ARGUMENTS"));
+        if implicit {
+            arguments.push(PromptArgument {
+                name: IMPLICIT_ARGUMENT.to_string(),
+                title: None,
+                description: Some(hint.unwrap_or_else(|| "Text for $ARGUMENTS".to_string())),
+                required: Some(false),
+            });
+        }
+
+        Self {
+            name,
+            description: text("description").unwrap_or(fallback_description),
+            arguments,
+            implicit,
+            body,
+        }
+    }
+
+    fn to_prompt(&self) -> Prompt {
+        Prompt::new(
+            self.name.clone(),
+            Some(self.description.clone()),
+            (!self.arguments.is_empty()).then(|| self.arguments.clone()),
+        )
+    }
+
+    /// Renders the template with `args`, failing on missing required arguments.
+    fn render(&self, args: Option<&JsonMap<String, Value>>) -> Result<String> {
+        if self.arguments.is_empty() {
+            // Without arguments a `$` is just text (e.g. a price in a skill).
+            return Ok(self.body.clone());
+        }
+        let value_of = |name: &str| -> Option<String> {
+            match args?.get(name)? {
+                Value::Null => None,
+                Value::String(s) => Some(s.clone()),
+                other => Some(other.to_string()),
+            }
+        };
+
+        let mut named = Vec::with_capacity(self.arguments.len());
+        for arg in &self.arguments {
+            let value = value_of(&arg.name);
+            if value.is_none() && arg.required == Some(true) {
+                bail!(
+                    "missing required argument '{}' for prompt {}",
+                    arg.name,
+                    self.name
+                );
+            }
+            named.push((arg.name.as_str(), value));
+        }
+
+        let all = named
+            .iter()
+            .filter_map(|(_, v)| v.as_deref())
+            .collect::<Vec<_>>()
+            .join(" ");
+        let positional: Vec<String> = if self.implicit {
+            all.split_whitespace().map(str::to_string).collect()
+        } else {
+            named
+                .iter()
+                .map(|(_, v)| v.clone().unwrap_or_default())
+                .collect()
+        };
+
+        Ok(substitute(&self.body, |key| {
+            if key == "ARGUMENTS" {
+                return Some(all.clone());
+            }
+            if let Ok(index) = key.parse::<usize>() {
+                return (index > 0).then(|| positional.get(index - 1).cloned().unwrap_or_default());
+            }
+            named
+                .iter()
+                .find(|(name, _)| *name == key)
+                .map(|(_, v)| v.clone().unwrap_or_default())
+        }))
+    }
+}
+
+/// Reads `argument-hint`, which YAML parses as a list when written unquoted
+/// as `[message] [scope]`.
+fn argument_hint(frontmatter: &serde_yaml::Value) -> Option<String> {
+    let hint = match frontmatter.get("argument-hint")? {
+        serde_yaml::Value::String(s) => s.trim().to_string(),
+        serde_yaml::Value::Sequence(items) => items
+            .iter()
+            .filter_map(|i| i.as_str())
+            .map(|i| format!("[{i}]"))
+            .collect::<Vec<_>>()
+            .join(" "),
+        _ => return None,
+    };
+    (!hint.is_empty()).then_some(hint)
+}
+
+/// Parses one entry of the `arguments` frontmatter list.
+fn declared_argument(value: &serde_yaml::Value) -> Option<PromptArgument> {
+    let (name, description, required) = match value {
+        serde_yaml::Value::String(name) => (name.clone(), None, false),
+        serde_yaml::Value::Mapping(_) => (
+            value.get("name")?.as_str()?.to_string(),
+            value
+                .get("description")
+                .and_then(|d| d.as_str())
+                .map(str::to_string),
+            value
+                .get("required")
+                .and_then(|r| r.as_bool())
+                .unwrap_or(false),
+        ),
+        _ => return None,
+    };
+    let name = name.trim().to_string();
+    if name.is_empty() {
+        return None;
+    }
+    Some(PromptArgument {
+        name,
+        title: None,
+        description,
+        required: Some(required),
+    })
+}
+
+/// Replaces `$key` and `${key}` placeholders for which `lookup` returns a value.
+///
+/// Keys are ASCII alphanumerics and underscores; a bare `$` followed by digits
+/// takes only the digits (`$1st` is `$1` + `st`). Unknown keys are left as-is.
+fn substitute(body: &str, mut lookup: impl FnMut(&str) -> Option<String>) -> String {
+    let mut out = String::with_capacity(body.len());
+    let mut rest = body;
+    while let Some(pos) = rest.find('$') {
+        out.push_str(&rest[..pos]);
+        let after = &rest[pos + 1..];
+        let (key, consumed) = if let Some(braced) = after.strip_prefix('{') {
+            match braced.find('}') {
+                Some(end) if is_key(&braced[..end]) => (&braced[..end], end + 2),
+                _ => ("", 0),
+            }
+        } else {
+            let digits = after.bytes().take_while(u8::is_ascii_digit).count();
+            let len = if digits > 0 {
+                digits
+            } else {
+                after
+                    .bytes()
+                    .take_while(|b| b.is_ascii_alphanumeric() || *b == b'_')
+                    .count()
+            };
+            (&after[..len], len)
+        };
+        match (!key.is_empty()).then(|| lookup(key)).flatten() {
+            Some(value) => {
+                out.push_str(&value);
+                rest = &after[consumed..];
+            }
+            None => {
+                out.push('$');
+                rest = after;
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+fn is_key(s: &str) -> bool {
+    !s.is_empty() && s.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'_')
+}
+
+impl SkillService {
+    /// Collects prompt templates: Claude commands, then Codex commands, then skills.
+    ///
+    /// The first template with a given name wins; later duplicates are logged
+    /// and skipped.
+    fn prompt_templates(&self) -> Result<Vec<PromptTemplate>> {
+        let mut templates = Vec::new();
+
+        let claude = ClaudeAdapter::new().and_then(|a| a.read_commands(false));
+        let codex = CodexAdapter::new().and_then(|a| a.read_commands(false));
+        for (label, commands) in [("claude", claude), ("codex", codex)] {
+            let commands = match commands {
+                Ok(commands) => commands,
+                Err(e) => {
+                    tracing::warn!("skipping {label} commands for prompts: {e}");
+                    continue;
+                }
+            };
+            for command in commands {
+                let content = String::from_utf8_lossy(&command.content);
+                let fallback = format!("/{} command from {}", command.name, label);
+                templates.push(PromptTemplate::parse(command.name, &content, fallback));
+            }
+        }
+
+        let (skills, _) = self.current_skills_with_dups()?;
+        for skill in skills {
+            let content = match self.read_skill_cached(&skill) {
+                Ok(content) => content,
+                Err(e) => {
+                    tracing::warn!("skipping skill {} for prompts: {e}", skill.name);
+                    continue;
+                }
+            };
+            let fallback = skill
+                .description
+                .clone()
+                .unwrap_or_else(|| format!("Skill {} from {}", skill.name, skill.source.label()));
+            // Discovery names skills by relative path ("demo/SKILL.md").
+            let name = skill.name.trim_end_matches("/SKILL.md");
+            templates.push(PromptTemplate::parse(
+                format!("{SKILL_PROMPT_PREFIX}{name}"),
+                &content,
+                fallback,
+            ));
+        }
+
+        let mut seen = HashSet::new();
+        templates.retain(|t| {
+            let first = seen.insert(t.name.clone());
+            if !first {
+                tracing::warn!("duplicate prompt {} skipped", t.name);
+            }
+            first
+        });
+        Ok(templates)
+    }
+
+    /// Lists every skill and slash command as an MCP prompt.
+    pub(crate) fn list_prompts_payload(&self) -> Result<Vec<Prompt>> {
+        Ok(self
+            .prompt_templates()?
+            .iter()
+            .map(PromptTemplate::to_prompt)
+            .collect())
+    }
+
+    /// Renders the prompt `name` with `arguments`.
+    pub(crate) fn get_prompt_payload(
+        &self,
+        name: &str,
+        arguments: Option<&JsonMap<String, Value>>,
+    ) -> Result<GetPromptResult> {
+        let template = self
+            .prompt_templates()?
+            .into_iter()
+            .find(|t| t.name == name)
+            .ok_or_else(|| anyhow!("prompt not found: {name}"))?;
+        let text = template.render(arguments)?;
+        Ok(GetPromptResult {
+            description: Some(template.description),
+            messages: vec![PromptMessage::new_text(PromptMessageRole::User, text)],
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn template(content: &str) -> PromptTemplate {
+        PromptTemplate::parse("demo".into(), content, "fallback".into())
+    }
+
+    fn args(value: Value) -> JsonMap<String, Value> {
+        value.as_object().cloned().unwrap()
+    }
+
+    #[test]
+    fn plain_template_has_no_arguments() {
+        let t = template("# Review\nCosts $5 of $HOME time.");
+        assert!(t.arguments.is_empty());
+        assert_eq!(t.description, "fallback");
+        assert_eq!(t.render(None).unwrap(), "# Review\nCosts $5 of $HOME time.");
+    }
+
+    #[test]
+    fn arguments_placeholder_gets_implicit_argument() {
+        let t = template(
+            "---\ndescription: Write a commit\nargument-hint: [message]\n---\nCommit: $ARGUMENTS (first word: $1)",
+        );
+        assert_eq!(t.description, "Write a commit");
+        assert_eq!(t.arguments.len(), 1);
+        assert_eq!(t.arguments[0].name, IMPLICIT_ARGUMENT);
+        assert_eq!(t.arguments[0].description.as_deref(), Some("[message]"));
+
+        let rendered = t
+            .render(Some(&args(json!({"arguments": "fix parser bug"}))))
+            .unwrap();
+        assert_eq!(rendered, "Commit: fix parser bug (first word: fix)");
+        assert_eq!(t.render(None).unwrap(), "Commit:  (first word: )");
+    }
+
+    #[test]
+    fn declared_arguments_map_to_prompt_arguments() {
+        let t = template(
+            "---\narguments:\n  - name: pr\n    description: Pull request number\n    required: true\n  - priority\n---\nReview PR ${pr} at $priority priority ($ARGUMENTS, $2). Cost: $5 flat, $HOME kept.",
+        );
+        let prompt = t.to_prompt();
+        let declared = prompt.arguments.unwrap();
+        assert_eq!(declared.len(), 2);
+        assert_eq!(declared[0].name, "pr");
+        assert_eq!(declared[0].required, Some(true));
+        assert_eq!(declared[1].name, "priority");
+        assert_eq!(declared[1].required, Some(false));
+
+        let rendered = t
+            .render(Some(&args(json!({"pr": 42, "priority": "high"}))))
+            .unwrap();
+        assert_eq!(
+            rendered,
+            "Review PR 42 at high priority (42 high, high). Cost:  flat, $HOME kept."
+        );
+    }
+
+    #[test]
+    fn missing_required_argument_errors() {
+        let t = template("---\narguments:\n  - name: pr\n    required: true\n---\nPR $pr");
+        let err = t.render(Some(&args(json!({})))).unwrap_err();
+        assert!(err.to_string().contains("missing required argument 'pr'"));
+    }
+
+    #[test]
+    fn substitute_leaves_unknown_and_malformed_placeholders() {
+        let out = substitute("a $x ${y} ${ bad } $ end $", |k| {
+            (k == "x").then(|| "X".to_string())
+        });
+        assert_eq!(out, "a X ${y} ${ bad } $ end $");
+    }
+}
@@ -0,0 +1,170 @@
+//! Resource subscriptions and change notifications.
+//!
+//! Clients subscribe to skill URIs with `resources/subscribe`. When the
+//! filesystem watcher sees skills change, it diffs the skill set against the
+//! previous scan and sends `notifications/resources/updated` for subscribed
+//! URIs whose content changed, plus `resources/list_changed`,
+//! `tools/list_changed` and `prompts/list_changed` when skills were added or
+//! removed, so long-running sessions pick up edits without reconnecting.
+
+// The diffing and notification side is only driven by the `watch` feature.
+#![cfg_attr(not(feature = "watch"), allow(dead_code))]
+
+use crate::cache::SkillCache;
+use parking_lot::Mutex;
+use rmcp::model::ResourceUpdatedNotificationParam;
+use rmcp::service::Peer;
+use rmcp::RoleServer;
+use std::collections::{BTreeMap, HashSet};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Skill resource URIs mapped to content hashes.
+pub(crate) type SkillHashes = BTreeMap<String, String>;
+
+/// Current skills of `cache` keyed by resource URI.
+pub(crate) fn skill_hashes(cache: &Mutex<SkillCache>) -> SkillHashes {
+    match cache.lock().skills_with_dups() {
+        Ok((skills, _)) => skills
+            .iter()
+            .map(|s| (super::skill_uri(s), s.hash.clone()))
+            .collect(),
+        Err(e) => {
+            tracing::warn!(target: "skrills::watch", "skill rescan failed: {e}");
+            SkillHashes::new()
+        }
+    }
+}
+
+/// Differences between two skill scans.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(crate) struct SkillChanges {
+    /// URIs present in both scans whose content changed.
+    pub updated: Vec<String>,
+    /// True when skills were added or removed.
+    pub list_changed: bool,
+}
+
+impl SkillChanges {
+    pub(crate) fn between(before: &SkillHashes, after: &SkillHashes) -> Self {
+        let updated = after
+            .iter()
+            .filter(|(uri, hash)| before.get(*uri).is_some_and(|old| old != *hash))
+            .map(|(uri, _)| uri.clone())
+            .collect();
+        let list_changed =
+            before.len() != after.len() || before.keys().any(|k| !after.contains_key(k));
+        Self {
+            updated,
+            list_changed,
+        }
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.updated.is_empty() && !self.list_changed
+    }
+}
+
+/// The connected client and the resource URIs it subscribed to.
+#[derive(Default)]
+pub(crate) struct ResourceSubscriptions {
+    peer: Mutex<Option<Peer<RoleServer>>>,
+    uris: Mutex<HashSet<String>>,
+    watching: AtomicBool,
+}
+
+impl ResourceSubscriptions {
+    /// Records that a filesystem watcher is sending notifications.
+    pub(crate) fn set_watching(&self) {
+        self.watching.store(true, Ordering::Relaxed);
+    }
+
+    /// True when change notifications will actually be sent.
+    pub(crate) fn is_watching(&self) -> bool {
+        self.watching.load(Ordering::Relaxed)
+    }
+
+    /// Remembers the client to notify.
+    pub(crate) fn attach(&self, peer: Peer<RoleServer>) {
+        *self.peer.lock() = Some(peer);
+    }
+
+    pub(crate) fn subscribe(&self, uri: String) {
+        self.uris.lock().insert(uri);
+    }
+
+    pub(crate) fn unsubscribe(&self, uri: &str) {
+        self.uris.lock().remove(uri);
+    }
+
+    pub(crate) fn is_subscribed(&self, uri: &str) -> bool {
+        self.uris.lock().contains(uri)
+    }
+
+    /// Sends the notifications for `changes` to the attached client, if any.
+    pub(crate) async fn notify(&self, changes: &SkillChanges) {
+        let Some(peer) = self.peer.lock().clone() else {
+            return;
+        };
+        for uri in changes.updated.iter().filter(|u| self.is_subscribed(u)) {
+            let param = ResourceUpdatedNotificationParam { uri: uri.clone() };
+            if let Err(e) = peer.notify_resource_updated(param).await {
+                tracing::debug!(target: "skrills::watch", "resources/updated not sent: {e}");
+            }
+        }
+        if changes.list_changed {
+            let sent = async {
+                peer.notify_resource_list_changed().await?;
+                peer.notify_tool_list_changed().await?;
+                peer.notify_prompt_list_changed().await
+            };
+            if let Err(e) = sent.await {
+                tracing::debug!(target: "skrills::watch", "list_changed not sent: {e}");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hashes(entries: &[(&str, &str)]) -> SkillHashes {
+        entries
+            .iter()
+            .map(|(u, h)| (u.to_string(), h.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn edits_are_updates_without_list_change() {
+        let before = hashes(&[
+            ("skill://skrills/claude/a", "1"),
+            ("skill://skrills/claude/b", "1"),
+        ]);
+        let after = hashes(&[
+            ("skill://skrills/claude/a", "2"),
+            ("skill://skrills/claude/b", "1"),
+        ]);
+        let changes = SkillChanges::between(&before, &after);
+        assert_eq!(changes.updated, vec!["skill://skrills/claude/a"]);
+        assert!(!changes.list_changed);
+    }
+
+    #[test]
+    fn added_or_removed_skills_change_the_list() {
+        let before = hashes(&[("skill://skrills/claude/a", "1")]);
+        let renamed = hashes(&[("skill://skrills/claude/b", "1")]);
+        assert!(SkillChanges::between(&before, &renamed).list_changed);
+        assert!(SkillChanges::between(&before, &SkillHashes::new()).list_changed);
+        assert!(SkillChanges::between(&before, &before).is_empty());
+    }
+
+    #[test]
+    fn subscribe_and_unsubscribe_track_uris() {
+        let subs = ResourceSubscriptions::default();
+        subs.subscribe("skill://skrills/claude/a".into());
+        assert!(subs.is_subscribed("skill://skrills/claude/a"));
+        subs.unsubscribe("skill://skrills/claude/a");
+        assert!(!subs.is_subscribed("skill://skrills/claude/a"));
+    }
+}
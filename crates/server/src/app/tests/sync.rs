@@ -1,7 +1,7 @@
 //! Sync tests - sync error paths and parameter handling
 
 use super::super::*;
-use crate::cli::SyncSource;
+use crate::cli::{SyncPlatform, SyncSource};
 use std::time::Duration;
 
 /// T1: run_sync_with_adapters rejects same source and target.
@@ -16,7 +16,8 @@ fn run_sync_with_adapters_same_source_returns_error() {
         ..Default::default()
     };
 
-    let result = run_sync_with_adapters(SyncSource::Claude, SyncSource::Claude, &params);
+    let claude = SyncPlatform::Builtin(SyncSource::Claude);
+    let result = run_sync_with_adapters(&claude, &claude, &params);
     assert!(result.is_err(), "Expected error when from == to");
     let msg = result.unwrap_err().to_string();
     assert!(
@@ -39,7 +40,8 @@ fn run_sync_with_adapters_same_source_all_variants() {
         SyncSource::Copilot,
         SyncSource::Cursor,
     ] {
-        let result = run_sync_with_adapters(source, source, &params);
+        let platform = SyncPlatform::from(source);
+        let result = run_sync_with_adapters(&platform, &platform, &params);
         assert!(
             result.is_err(),
             "Expected error for {:?} -> {:?}",
@@ -98,3 +100,43 @@ fn sync_skills_tool_uses_default_target_when_to_omitted() {
         result.err()
     );
 }
+
+/// Project scope points the explicit sync tools at the repository.
+///
+/// Given: a repository with one command under `.claude/commands`
+/// When: sync_to_cursor_tool runs with `project_dir` as a dry run
+/// Then: The repository command is previewed and nothing is written
+#[test]
+fn sync_to_cursor_tool_project_scope_reads_repository() {
+    let repo = tempfile::tempdir().expect("tempdir");
+    std::fs::create_dir_all(repo.path().join(".claude/commands")).unwrap();
+    std::fs::write(repo.path().join(".claude/commands/review.md"), "# Review").unwrap();
+
+    let service =
+        SkillService::new_with_ttl(Vec::new(), Duration::from_secs(1)).expect("create service");
+    let mut args = serde_json::Map::new();
+    args.insert("from".into(), serde_json::json!("claude"));
+    args.insert(
+        "project_dir".into(),
+        serde_json::json!(repo.path().to_string_lossy()),
+    );
+    args.insert("dry_run".into(), serde_json::json!(true));
+
+    let result = service.sync_to_cursor_tool(args).expect("project sync");
+    let report = &result.structured_content.expect("structured")["report"];
+    assert_eq!(report["commands"]["written"], 1);
+    assert!(!repo.path().join(".cursor").exists());
+}
+
+/// An unknown `scope` is rejected instead of silently syncing user files.
+#[test]
+fn sync_tools_reject_unknown_scope() {
+    let service =
+        SkillService::new_with_ttl(Vec::new(), Duration::from_secs(1)).expect("create service");
+    let mut args = serde_json::Map::new();
+    args.insert("scope".into(), serde_json::json!("team"));
+    args.insert("dry_run".into(), serde_json::json!(true));
+
+    let msg = service.sync_skills_tool(args).unwrap_err().to_string();
+    assert!(msg.contains("Unknown scope"), "got: {msg}");
+}
@@ -41,6 +41,53 @@ fn validate_skills_tool_autofix_adds_frontmatter() {
     );
 }
 
+#[test]
+fn validate_skills_tool_cursor_target_reports_and_fixes_rules() {
+    let _guard = crate::test_support::env_guard();
+    let temp = tempdir().expect("create temp directory");
+    let skill_dir = temp.path().join("skills").join("globbed");
+    std::fs::create_dir_all(&skill_dir).expect("create skill directory");
+    let skill_path = skill_dir.join("SKILL.md");
+    std::fs::write(
+        &skill_path,
+        "---\nname: globbed\nalwaysApply: \"false\"\nglobs: src/**/*.rs,\n---\n# Rust rules\n",
+    )
+    .expect("write test skill file");
+
+    let _home_guard =
+        crate::test_support::set_env_var("HOME", Some(temp.path().to_str().expect("temp path")));
+
+    let service =
+        SkillService::new_with_ttl(vec![temp.path().join("skills")], Duration::from_secs(1))
+            .expect("create skill service");
+    let args = |autofix: bool| {
+        json!({"target": "cursor", "autofix": autofix})
+            .as_object()
+            .cloned()
+            .expect("create json args")
+    };
+
+    let report = service
+        .validate_skills_tool(args(false))
+        .expect("validate skills")
+        .structured_content
+        .expect("structured content");
+    let skill = &report["results"][0];
+    assert_eq!(skill["cursor_valid"], json!(false));
+    assert_eq!(skill["claude_valid"], json!(true));
+
+    let fixed = service
+        .validate_skills_tool(args(true))
+        .expect("validate skills")
+        .structured_content
+        .expect("structured content");
+    assert_eq!(fixed["autofixed"], json!(1));
+    assert_eq!(fixed["results"][0]["cursor_valid"], json!(true));
+    let content = std::fs::read_to_string(&skill_path).expect("read skill file");
+    assert!(content.contains("alwaysApply: false"));
+    assert!(content.contains("globs: src/**/*.rs\n"));
+}
+
 #[test]
 fn create_skill_rejects_path_like_names() {
     let service =
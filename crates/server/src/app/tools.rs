@@ -9,6 +9,7 @@ use anyhow::Result;
 use rmcp::model::{CallToolResult, Content};
 use serde_json::{json, Map as JsonMap, Value};
 use skrills_state::home_dir;
+use skrills_sync::SyncScope;
 use std::fs;
 use std::time::{SystemTime, UNIX_EPOCH};
 
@@ -30,12 +31,45 @@ impl SkillService {
         }
     }
 
+    /// Parse the sync scope from the `scope` and `project_dir` tool arguments.
+    pub(crate) fn parse_sync_scope(args: &JsonMap<String, Value>) -> Result<SyncScope> {
+        SyncScope::resolve(
+            args.get("scope").and_then(|v| v.as_str()),
+            args.get("project_dir")
+                .and_then(|v| v.as_str())
+                .map(std::path::PathBuf::from),
+        )
+    }
+
+    /// Syncs `from` → `to` in `scope` for the explicit Copilot and Cursor
+    /// tools, with snapshots, the user's model map and sync policy but no
+    /// ledger.
+    fn sync_pair(
+        from: &str,
+        to: &str,
+        scope: &SyncScope,
+        params: &skrills_sync::SyncParams,
+    ) -> Result<skrills_sync::SyncReport> {
+        use skrills_sync::{
+            create_adapter_in, ModelMap, SnapshotConfig, SyncOrchestrator, SyncPolicy,
+        };
+
+        SyncOrchestrator::new(
+            create_adapter_in(from, scope)?,
+            create_adapter_in(to, scope)?,
+        )
+        .with_snapshots(SnapshotConfig::default())
+        .with_model_map(ModelMap::load_default()?)
+        .with_policy(SyncPolicy::load_default(scope)?)
+        .sync(params)
+    }
+
     /// Validates all discovered skills and optionally autofixes issues.
     ///
     /// # Arguments
     ///
     /// The `args` map accepts the following JSON keys:
-    /// - `target`: `"claude"`, `"codex"`, or `"both"` (default: `"both"`) - which clients to validate for
+    /// - `target`: `"claude"`, `"codex"`, `"copilot"`, `"cursor"`, `"both"`, or `"all"` (default: `"both"`) - which clients to validate for
     /// - `errors_only`: `bool` (default: `false`) - only return skills with errors
     /// - `autofix`: `bool` (default: `false`) - attempt to autofix frontmatter issues
     /// - `check_dependencies`: `bool` (default: `false`) - validate skill dependencies exist
@@ -50,7 +84,8 @@ impl SkillService {
         args: JsonMap<String, Value>,
     ) -> Result<CallToolResult> {
         use skrills_validate::{
-            autofix_frontmatter, validate_skill, AutofixOptions, ValidationTarget as VT,
+            autofix_cursor, autofix_frontmatter, needs_autofix, validate_skill, AutofixOptions,
+            ValidationTarget as VT,
         };
 
         let target_str = args
@@ -73,6 +108,9 @@ impl SkillService {
         let validation_target = match target_str {
             "claude" => VT::Claude,
             "codex" => VT::Codex,
+            "copilot" => VT::Copilot,
+            "cursor" => VT::Cursor,
+            "all" => VT::All,
             _ => VT::Both,
         };
 
@@ -99,14 +137,20 @@ impl SkillService {
             let mut result = validate_skill(&meta.path, &content, validation_target);
             let mut autofixed_skill = false;
 
-            if autofix && !result.codex_valid && validation_target != VT::Claude {
+            let needs_fix = needs_autofix(&result, validation_target);
+            if autofix && needs_fix {
                 let opts = AutofixOptions {
                     create_backup: false,
                     write_changes: true,
                     suggested_name: Some(meta.name.clone()),
                     suggested_description: None,
                 };
-                match autofix_frontmatter(&meta.path, &content, &opts) {
+                let fixed = if validation_target == VT::Cursor {
+                    autofix_cursor(&meta.path, &content, &opts)
+                } else {
+                    autofix_frontmatter(&meta.path, &content, &opts)
+                };
+                match fixed {
                     Ok(fix_result) => {
                         if fix_result.modified {
                             autofixed += 1;
@@ -176,6 +220,8 @@ impl SkillService {
                     "path": meta.path.display().to_string(),
                     "claude_valid": result.claude_valid,
                     "codex_valid": result.codex_valid,
+                    "copilot_valid": result.copilot_valid,
+                    "cursor_valid": result.cursor_valid,
                     "errors": result.error_count(),
                     "warnings": result.warning_count(),
                     "autofixed": autofixed_skill,
@@ -566,6 +612,7 @@ impl SkillService {
     /// - `dry_run`: `bool` (default: `false`) - preview changes without writing
     /// - `include_marketplace`: `bool` (default: `false`) - include marketplace skills in sync
     /// - `skip_existing_commands`: `bool` (default: `false`) - skip commands that already exist at destination
+    /// - `scope`: `"user"` or `"project"` (default: `"user"`), with optional `project_dir` - which tool directories to sync
     ///
     /// # Returns
     ///
@@ -592,7 +639,11 @@ impl SkillService {
             .and_then(|v| v.as_bool())
             .unwrap_or(false);
 
-        let is_claude_to_codex = from == "claude" && to == "codex";
+        let scope = Self::parse_sync_scope(&args)?;
+
+        // Project scope has no plugin cache to mirror from, so the
+        // orchestrator syncs skills in every direction.
+        let is_claude_to_codex = from == "claude" && to == "codex" && !scope.is_project();
 
         // Sync skills first (Codex discovery root) for Claude→Codex only.
         let skill_report = if is_claude_to_codex && !dry_run {
@@ -619,16 +670,19 @@ impl SkillService {
             .get("skip_existing_commands")
             .and_then(|v| v.as_bool())
             .unwrap_or(false);
+        let force = args.get("force").and_then(|v| v.as_bool()).unwrap_or(false);
 
         let params = SyncParams {
             from: Some(from.to_string()),
             dry_run,
+            force,
             sync_commands: true,
             skip_existing_commands,
             sync_mcp_servers: true,
             sync_preferences: true,
             sync_skills: sync_skills_via_orchestrator,
             include_marketplace,
+            scope,
             ..Default::default()
         };
 
@@ -653,6 +707,60 @@ impl SkillService {
         })
     }
 
+    /// Undoes a sync by restoring the snapshot taken before it wrote.
+    ///
+    /// # Arguments
+    ///
+    /// The `args` map accepts the following JSON keys:
+    /// - `id`: snapshot id from `sync-history` (default: the most recent)
+    ///
+    /// # Returns
+    ///
+    /// A `CallToolResult` with the restore summary and structured result.
+    pub(crate) fn sync_undo_tool(&self, args: JsonMap<String, Value>) -> Result<CallToolResult> {
+        use skrills_sync::snapshot::undo_sync;
+        use skrills_sync::{SnapshotConfig, SyncStateStore};
+
+        let id = args.get("id").and_then(|v| v.as_str());
+        let mut ledger = SyncStateStore::load_default()?;
+        let result = undo_sync(&SnapshotConfig::default(), id, Some(&mut ledger))?;
+
+        Ok(CallToolResult {
+            content: vec![Content::text(result.format_summary())],
+            is_error: Some(result.failed > 0),
+            structured_content: Some(json!(result)),
+            meta: None,
+        })
+    }
+
+    /// Lists sync snapshots with per-file before/after hashes.
+    ///
+    /// # Arguments
+    ///
+    /// The `args` map accepts the following JSON keys:
+    /// - `limit`: maximum number of snapshots to return, newest first
+    ///
+    /// # Returns
+    ///
+    /// A `CallToolResult` with the formatted history and the snapshot records.
+    pub(crate) fn sync_history_tool(&self, args: JsonMap<String, Value>) -> Result<CallToolResult> {
+        use skrills_sync::snapshot::{format_history, snapshot_history};
+        use skrills_sync::SnapshotConfig;
+
+        let limit = args
+            .get("limit")
+            .and_then(|v| v.as_u64())
+            .map(|v| usize::try_from(v).unwrap_or(usize::MAX));
+        let records = snapshot_history(&SnapshotConfig::default(), limit)?;
+
+        Ok(CallToolResult {
+            content: vec![Content::text(format_history(&records))],
+            is_error: Some(false),
+            structured_content: Some(json!({ "snapshots": records })),
+            meta: None,
+        })
+    }
+
     /// Gets skill loading status for observability and debugging.
     ///
     /// Reports on discovered skill files, instrumentation markers, and trace installation state.
@@ -874,6 +982,7 @@ impl SkillService {
     /// The `args` map accepts the following JSON keys:
     /// - `to`: `"claude"` or `"codex"` (default: `"claude"`) - target CLI
     /// - `dry_run`: `bool` (default: `false`) - preview changes without writing
+    /// - `scope`: `"user"` or `"project"` (default: `"user"`), with optional `project_dir` - which tool directories to sync
     ///
     /// # Returns
     ///
@@ -882,13 +991,17 @@ impl SkillService {
         &self,
         args: JsonMap<String, Value>,
     ) -> Result<CallToolResult> {
-        use skrills_sync::{ClaudeAdapter, CopilotAdapter, SyncOrchestrator, SyncParams};
+        use skrills_sync::SyncParams;
 
-        let to = args.get("to").and_then(|v| v.as_str()).unwrap_or("claude");
+        let to = match args.get("to").and_then(|v| v.as_str()) {
+            Some("codex") => "codex",
+            _ => "claude",
+        };
         let dry_run = args
             .get("dry_run")
             .and_then(|v| v.as_bool())
             .unwrap_or(false);
+        let scope = Self::parse_sync_scope(&args)?;
 
         let params = SyncParams {
             from: Some("copilot".to_string()),
@@ -899,18 +1012,11 @@ impl SkillService {
             sync_skills: true,
             sync_agents: true,
             sync_instructions: true,
+            scope: scope.clone(),
             ..Default::default()
         };
 
-        let source = CopilotAdapter::new()?;
-        let report = if to == "codex" {
-            use skrills_sync::CodexAdapter;
-            let target = CodexAdapter::new()?;
-            SyncOrchestrator::new(source, target).sync(&params)?
-        } else {
-            let target = ClaudeAdapter::new()?;
-            SyncOrchestrator::new(source, target).sync(&params)?
-        };
+        let report = Self::sync_pair("copilot", to, &scope, &params)?;
 
         Ok(CallToolResult {
             content: vec![Content::text(report.summary.clone())],
@@ -932,6 +1038,7 @@ impl SkillService {
     /// The `args` map accepts the following JSON keys:
     /// - `from`: `"claude"`, `"codex"`, or `"cursor"` (default: `"claude"`) - source CLI
     /// - `dry_run`: `bool` (default: `false`) - preview changes without writing
+    /// - `scope`: `"user"` or `"project"` (default: `"user"`), with optional `project_dir` - which tool directories to sync
     ///
     /// # Returns
     ///
@@ -940,16 +1047,18 @@ impl SkillService {
         &self,
         args: JsonMap<String, Value>,
     ) -> Result<CallToolResult> {
-        use skrills_sync::{ClaudeAdapter, CopilotAdapter, SyncOrchestrator, SyncParams};
+        use skrills_sync::SyncParams;
 
         let from = args
             .get("from")
             .and_then(|v| v.as_str())
+            .filter(|from| matches!(*from, "codex" | "cursor"))
             .unwrap_or("claude");
         let dry_run = args
             .get("dry_run")
             .and_then(|v| v.as_bool())
             .unwrap_or(false);
+        let scope = Self::parse_sync_scope(&args)?;
 
         let params = SyncParams {
             from: Some(from.to_string()),
@@ -960,22 +1069,11 @@ impl SkillService {
             sync_skills: true,
             sync_agents: true,
             sync_instructions: true,
+            scope: scope.clone(),
             ..Default::default()
         };
 
-        let target = CopilotAdapter::new()?;
-        let report = if from == "codex" {
-            use skrills_sync::CodexAdapter;
-            let source = CodexAdapter::new()?;
-            SyncOrchestrator::new(source, target).sync(&params)?
-        } else if from == "cursor" {
-            use skrills_sync::CursorAdapter;
-            let source = CursorAdapter::new()?;
-            SyncOrchestrator::new(source, target).sync(&params)?
-        } else {
-            let source = ClaudeAdapter::new()?;
-            SyncOrchestrator::new(source, target).sync(&params)?
-        };
+        let report = Self::sync_pair(from, "copilot", &scope, &params)?;
 
         Ok(CallToolResult {
             content: vec![Content::text(report.summary.clone())],
@@ -995,16 +1093,18 @@ impl SkillService {
         &self,
         args: JsonMap<String, Value>,
     ) -> Result<CallToolResult> {
-        use skrills_sync::{
-            ClaudeAdapter, CodexAdapter, CopilotAdapter, CursorAdapter, SyncOrchestrator,
-            SyncParams,
-        };
+        use skrills_sync::SyncParams;
 
-        let to = args.get("to").and_then(|v| v.as_str()).unwrap_or("claude");
+        let to = args
+            .get("to")
+            .and_then(|v| v.as_str())
+            .filter(|to| matches!(*to, "codex" | "copilot"))
+            .unwrap_or("claude");
         let dry_run = args
             .get("dry_run")
             .and_then(|v| v.as_bool())
             .unwrap_or(false);
+        let scope = Self::parse_sync_scope(&args)?;
 
         let params = SyncParams {
             from: Some("cursor".to_string()),
@@ -1015,24 +1115,11 @@ impl SkillService {
             sync_skills: true,
             sync_agents: true,
             sync_instructions: true,
+            scope: scope.clone(),
             ..Default::default()
         };
 
-        let source = CursorAdapter::new()?;
-        let report = match to {
-            "codex" => {
-                let target = CodexAdapter::new()?;
-                SyncOrchestrator::new(source, target).sync(&params)?
-            }
-            "copilot" => {
-                let target = CopilotAdapter::new()?;
-                SyncOrchestrator::new(source, target).sync(&params)?
-            }
-            _ => {
-                let target = ClaudeAdapter::new()?;
-                SyncOrchestrator::new(source, target).sync(&params)?
-            }
-        };
+        let report = Self::sync_pair("cursor", to, &scope, &params)?;
 
         Ok(CallToolResult {
             content: vec![Content::text(report.summary.clone())],
@@ -1052,51 +1139,39 @@ impl SkillService {
         &self,
         args: JsonMap<String, Value>,
     ) -> Result<CallToolResult> {
-        use skrills_sync::{
-            ClaudeAdapter, CodexAdapter, CopilotAdapter, CursorAdapter, SyncOrchestrator,
-            SyncParams,
-        };
+        use skrills_sync::SyncParams;
 
         let from = args
             .get("from")
             .and_then(|v| v.as_str())
+            .filter(|from| matches!(*from, "codex" | "copilot"))
             .unwrap_or("claude");
         let dry_run = args
             .get("dry_run")
             .and_then(|v| v.as_bool())
             .unwrap_or(false);
+        let scope = Self::parse_sync_scope(&args)?;
 
         // Skip flat skills copy, Cursor discovers skills from its own
         // plugins/cache/ which is populated by the plugin_assets sync.
         // Copying skills separately creates duplicates that inflate context.
+        // A repository has no plugin cache, so project scope copies skills.
         let params = SyncParams {
             from: Some(from.to_string()),
             dry_run,
             sync_commands: true,
             sync_mcp_servers: true,
             sync_preferences: false, // Cursor preferences are not yet mapped
-            sync_skills: false,
+            sync_skills: scope.is_project(),
             sync_agents: true,
             sync_instructions: true,
-            full_plugin_mirror: true, // Cursor needs complete plugin cache
+            sync_plugin_assets: !scope.is_project(),
+            full_plugin_mirror: !scope.is_project(), // Cursor needs complete plugin cache
+            scope: scope.clone(),
             ..Default::default()
         };
 
-        let target = CursorAdapter::new()?;
-        let report = match from {
-            "codex" => {
-                let source = CodexAdapter::new()?;
-                SyncOrchestrator::new(source, target).sync(&params)?
-            }
-            "copilot" => {
-                let source = CopilotAdapter::new()?;
-                SyncOrchestrator::new(source, target).sync(&params)?
-            }
-            _ => {
-                let source = ClaudeAdapter::new()?;
-                SyncOrchestrator::new(source, target).sync(&params)?
-            }
-        };
+        let report = Self::sync_pair(from, "cursor", &scope, &params)?;
 
         Ok(CallToolResult {
             content: vec![Content::text(report.summary.clone())],
@@ -1120,15 +1195,13 @@ impl SkillService {
     /// - `to`: `"claude"`, `"codex"`, `"copilot"`, or `"cursor"` (default based on from) - target CLI
     /// - `dry_run`: `bool` (default: `false`) - preview changes without writing
     /// - `include_marketplace`: `bool` (default: `false`) - include marketplace skills
+    /// - `scope`: `"user"` or `"project"` (default: `"user"`), with optional `project_dir` - which tool directories to sync
     ///
     /// # Returns
     ///
     /// A `CallToolResult` with sync summary and structured report.
     pub(crate) fn sync_skills_tool(&self, args: JsonMap<String, Value>) -> Result<CallToolResult> {
-        use skrills_sync::{
-            ClaudeAdapter, CodexAdapter, CopilotAdapter, CursorAdapter, SyncOrchestrator,
-            SyncParams,
-        };
+        use skrills_sync::{sync_between, SyncParams};
 
         let from = args
             .get("from")
@@ -1165,79 +1238,12 @@ impl SkillService {
             sync_preferences: false,
             sync_skills: true,
             include_marketplace,
+            scope: Self::parse_sync_scope(&args)?,
             ..Default::default()
         };
 
-        // Create adapters and run sync based on from/to combination
-        let report = match (from, to) {
-            ("claude", "codex") => {
-                let source = ClaudeAdapter::new()?;
-                let target = CodexAdapter::new()?;
-                SyncOrchestrator::new(source, target).sync(&params)?
-            }
-            ("claude", "copilot") => {
-                let source = ClaudeAdapter::new()?;
-                let target = CopilotAdapter::new()?;
-                SyncOrchestrator::new(source, target).sync(&params)?
-            }
-            ("codex", "claude") => {
-                let source = CodexAdapter::new()?;
-                let target = ClaudeAdapter::new()?;
-                SyncOrchestrator::new(source, target).sync(&params)?
-            }
-            ("codex", "copilot") => {
-                let source = CodexAdapter::new()?;
-                let target = CopilotAdapter::new()?;
-                SyncOrchestrator::new(source, target).sync(&params)?
-            }
-            ("copilot", "claude") => {
-                let source = CopilotAdapter::new()?;
-                let target = ClaudeAdapter::new()?;
-                SyncOrchestrator::new(source, target).sync(&params)?
-            }
-            ("copilot", "codex") => {
-                let source = CopilotAdapter::new()?;
-                let target = CodexAdapter::new()?;
-                SyncOrchestrator::new(source, target).sync(&params)?
-            }
-            ("cursor", "claude") => {
-                let source = CursorAdapter::new()?;
-                let target = ClaudeAdapter::new()?;
-                SyncOrchestrator::new(source, target).sync(&params)?
-            }
-            ("cursor", "codex") => {
-                let source = CursorAdapter::new()?;
-                let target = CodexAdapter::new()?;
-                SyncOrchestrator::new(source, target).sync(&params)?
-            }
-            ("cursor", "copilot") => {
-                let source = CursorAdapter::new()?;
-                let target = CopilotAdapter::new()?;
-                SyncOrchestrator::new(source, target).sync(&params)?
-            }
-            ("claude", "cursor") => {
-                let source = ClaudeAdapter::new()?;
-                let target = CursorAdapter::new()?;
-                SyncOrchestrator::new(source, target).sync(&params)?
-            }
-            ("codex", "cursor") => {
-                let source = CodexAdapter::new()?;
-                let target = CursorAdapter::new()?;
-                SyncOrchestrator::new(source, target).sync(&params)?
-            }
-            ("copilot", "cursor") => {
-                let source = CopilotAdapter::new()?;
-                let target = CursorAdapter::new()?;
-                SyncOrchestrator::new(source, target).sync(&params)?
-            }
-            _ => {
-                return Err(anyhow::anyhow!(
-                    "Invalid sync direction: {} -> {}. Valid options are: claude, codex, copilot, cursor",
-                    from,
-                    to
-                ));
-            }
-        };
+        // Adapter creation handles built-in and external (adapters.toml) platforms.
+        let report = sync_between(from, to, &params)?;
 
         Ok(CallToolResult {
             content: vec![Content::text(report.summary.clone())],
@@ -19,7 +19,9 @@ pub enum ValidationTarget {
     Codex,
     /// Validate for GitHub Copilot CLI (strict).
     Copilot,
-    /// Validate for all targets (Claude, Codex, and Copilot).
+    /// Validate as Cursor rules (MDC frontmatter, globs, rule size).
+    Cursor,
+    /// Validate for all targets (Claude, Codex, Copilot, and Cursor).
     #[default]
     All,
     /// Validate for Claude and Codex (legacy, use 'all' for new code).
@@ -92,6 +94,161 @@ impl SyncSource {
     }
 }
 
+/// Source/target for cross-CLI sync commands.
+///
+/// Accepts the built-in CLIs plus any external adapter registered in
+/// `~/.skrills/adapters.toml` (e.g. `windsurf`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SyncPlatform {
+    /// One of the built-in CLIs.
+    Builtin(SyncSource),
+    /// An external adapter, by its configured name.
+    External(String),
+}
+
+impl Default for SyncPlatform {
+    fn default() -> Self {
+        Self::Builtin(SyncSource::default())
+    }
+}
+
+impl From<SyncSource> for SyncPlatform {
+    fn from(source: SyncSource) -> Self {
+        Self::Builtin(source)
+    }
+}
+
+impl std::str::FromStr for SyncPlatform {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(source) = SyncSource::from_str(s, true) {
+            return Ok(Self::Builtin(source));
+        }
+        if skrills_sync::is_valid_platform(s) {
+            return Ok(Self::External(s.to_lowercase()));
+        }
+        Err(format!(
+            "unknown platform '{s}' (available: {})",
+            skrills_sync::available_platforms().join(", ")
+        ))
+    }
+}
+
+impl std::fmt::Display for SyncPlatform {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl SyncPlatform {
+    /// Returns the default target for this source.
+    /// Claude → Codex, everything else → Claude.
+    pub fn default_target(&self) -> Self {
+        match self {
+            Self::Builtin(source) => Self::Builtin(source.default_target()),
+            Self::External(_) => Self::Builtin(SyncSource::Claude),
+        }
+    }
+
+    /// Returns the built-in CLI, if this is not an external adapter.
+    pub fn builtin(&self) -> Option<SyncSource> {
+        match self {
+            Self::Builtin(source) => Some(*source),
+            Self::External(_) => None,
+        }
+    }
+
+    /// Returns true if this platform is Claude.
+    pub fn is_claude(&self) -> bool {
+        self.builtin().is_some_and(SyncSource::is_claude)
+    }
+
+    /// Returns true if this platform is Codex.
+    pub fn is_codex(&self) -> bool {
+        self.builtin().is_some_and(SyncSource::is_codex)
+    }
+
+    /// Returns true if this platform is Cursor.
+    pub fn is_cursor(&self) -> bool {
+        self.builtin().is_some_and(SyncSource::is_cursor)
+    }
+
+    /// Returns the platform name as understood by `skrills_sync::create_adapter`.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Builtin(source) => source.as_str(),
+            Self::External(name) => name,
+        }
+    }
+
+    /// Returns all other platforms, including external adapters.
+    /// Used when `--to` is not specified to sync to every other CLI.
+    pub fn other_targets(&self) -> Vec<Self> {
+        skrills_sync::available_platforms()
+            .into_iter()
+            .filter(|name| name != self.as_str())
+            .map(|name| match SyncSource::from_str(&name, true) {
+                Ok(source) => Self::Builtin(source),
+                Err(_) => Self::External(name),
+            })
+            .collect()
+    }
+}
+
+/// How `sync`/`sync-all` resolve items changed on both sides since the
+/// last sync.
+#[derive(Debug, Clone, Copy, ValueEnum, PartialEq, Eq)]
+pub enum SyncStrategy {
+    /// Leave both copies alone and report the conflict.
+    Skip,
+    /// Overwrite the target with the source.
+    Source,
+    /// Keep the target copy.
+    Target,
+    /// Ask for each conflict (falls back to skip without a TTY).
+    Prompt,
+    /// Three-way merge against the last-synced content; overlapping edits
+    /// get conflict markers in the target.
+    Merge,
+}
+
+impl From<SyncStrategy> for skrills_sync::ConflictStrategy {
+    fn from(strategy: SyncStrategy) -> Self {
+        match strategy {
+            SyncStrategy::Skip => Self::SkipAll,
+            SyncStrategy::Source => Self::ForceSource,
+            SyncStrategy::Target => Self::ForceTarget,
+            SyncStrategy::Prompt => Self::Prompt,
+            SyncStrategy::Merge => Self::Merge,
+        }
+    }
+}
+
+/// Which copy of each tool's configuration the sync commands operate on.
+#[derive(Debug, Clone, Copy, ValueEnum, PartialEq, Eq)]
+pub enum SyncScopeArg {
+    /// User-level directories (`~/.claude`, `~/.codex`, ...).
+    User,
+    /// A repository's `.claude/`, `.codex/`, `.cursor/`, `.github/` and
+    /// `AGENTS.md` (see `--project-dir`).
+    Project,
+}
+
+impl SyncScopeArg {
+    /// Resolves `--scope` and `--project-dir` into a sync scope.
+    pub fn resolve(
+        scope: Option<Self>,
+        project_dir: Option<PathBuf>,
+    ) -> anyhow::Result<skrills_sync::SyncScope> {
+        let scope = scope.map(|s| match s {
+            Self::User => "user",
+            Self::Project => "project",
+        });
+        skrills_sync::SyncScope::resolve(scope, project_dir)
+    }
+}
+
 /// Dependency traversal direction.
 #[derive(Debug, Clone, Copy, ValueEnum)]
 pub enum DependencyDirection {
@@ -202,6 +359,109 @@ pub enum CertAction {
     },
 }
 
+/// CLI a Claude plugin can be installed into with `skrills plugin`.
+#[derive(Debug, Clone, Copy, ValueEnum, PartialEq, Eq)]
+pub enum PluginTarget {
+    Codex,
+    Copilot,
+    Cursor,
+}
+
+impl PluginTarget {
+    /// Platform name, as used by `skrills_sync`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Codex => "codex",
+            Self::Copilot => "copilot",
+            Self::Cursor => "cursor",
+        }
+    }
+}
+
+/// Plugin management action.
+#[derive(Debug, Clone, Subcommand)]
+pub enum PluginAction {
+    /// List Claude's cached plugins and where each is installed.
+    List {
+        /// Output format: text or json.
+        #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+        format: OutputFormat,
+    },
+    /// Install the latest cached version of a plugin into other CLIs.
+    Install {
+        /// Plugin name, or `publisher/plugin` when several marketplaces ship it.
+        name: String,
+        /// Target CLI (repeatable or comma-separated).
+        #[arg(long = "to", value_enum, value_delimiter = ',', required = true)]
+        to: Vec<PluginTarget>,
+    },
+    /// Remove a plugin's files from other CLIs.
+    Uninstall {
+        /// Plugin name, or `publisher/plugin`.
+        name: String,
+        /// Target CLI (repeatable; default: every CLI it is installed into).
+        #[arg(long = "from", value_enum, value_delimiter = ',')]
+        from: Vec<PluginTarget>,
+    },
+    /// Move installs to the latest cached version of their plugin.
+    Upgrade {
+        /// Plugin name, or `publisher/plugin` (default: every installed plugin).
+        name: Option<String>,
+        /// Target CLI (repeatable; default: every CLI it is installed into).
+        #[arg(long = "to", value_enum, value_delimiter = ',')]
+        to: Vec<PluginTarget>,
+    },
+}
+
+/// Skill dependency action.
+#[derive(Debug, Clone, Subcommand)]
+pub enum DepsAction {
+    /// Install the dependencies of skills and pin them in a lockfile.
+    ///
+    /// Without skills, installs exactly what the lockfile pins.
+    Install {
+        /// Skills to resolve; added to the lockfile's roots.
+        skills: Vec<String>,
+        /// Registry to search for missing dependencies (repeatable; searched
+        /// before `[deps] registries` in `~/.skrills/config.toml`).
+        #[arg(long = "registry", value_name = "URL")]
+        registries: Vec<String>,
+        /// Target CLI to install registry skills into.
+        #[arg(long, value_enum, default_value_t = SyncSource::Claude)]
+        target: SyncSource,
+        /// Lockfile to read and write.
+        #[arg(long, value_name = "FILE", default_value = crate::deps::LOCK_FILE)]
+        lock: PathBuf,
+        /// Additional skill directory to resolve from (repeatable).
+        #[arg(long = "skill-dir", value_name = "DIR")]
+        skill_dirs: Vec<PathBuf>,
+        /// Show what would be installed without writing anything.
+        #[arg(long)]
+        dry_run: bool,
+        /// Replace local copies that were not installed from a registry.
+        #[arg(long)]
+        force: bool,
+        /// Output format: text or json.
+        #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+        format: OutputFormat,
+    },
+    /// Fail when installed skills diverge from the lockfile.
+    Verify {
+        /// Target CLI registry skills were installed into.
+        #[arg(long, value_enum, default_value_t = SyncSource::Claude)]
+        target: SyncSource,
+        /// Lockfile to check against.
+        #[arg(long, value_name = "FILE", default_value = crate::deps::LOCK_FILE)]
+        lock: PathBuf,
+        /// Additional skill directory to resolve from (repeatable).
+        #[arg(long = "skill-dir", value_name = "DIR")]
+        skill_dirs: Vec<PathBuf>,
+        /// Output format: text or json.
+        #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+        format: OutputFormat,
+    },
+}
+
 /// Output format for command results.
 #[derive(Debug, Clone, Copy, ValueEnum, Default, PartialEq, Eq)]
 pub enum OutputFormat {
@@ -218,3 +478,32 @@ impl OutputFormat {
         matches!(self, Self::Json)
     }
 }
+
+/// Output format for commands that report skill problems.
+///
+/// Adds SARIF 2.1.0 (code-scanning annotations) and JUnit XML (CI test
+/// reports) to the text and JSON formats.
+#[derive(Debug, Clone, Copy, ValueEnum, Default, PartialEq, Eq)]
+pub enum ReportFormat {
+    /// Human-readable text output.
+    #[default]
+    Text,
+    /// JSON output for machine parsing.
+    Json,
+    /// SARIF 2.1.0 log for code-scanning tools.
+    Sarif,
+    /// JUnit XML report for CI test dashboards.
+    Junit,
+}
+
+impl ReportFormat {
+    /// Check if this format is JSON.
+    pub fn is_json(&self) -> bool {
+        matches!(self, Self::Json)
+    }
+
+    /// Check if this format is human-readable text.
+    pub fn is_text(&self) -> bool {
+        matches!(self, Self::Text)
+    }
+}
@@ -4,7 +4,8 @@ use std::path::PathBuf;
 mod enums;
 
 pub use enums::{
-    AgentBackend, CertAction, CreateSkillMethod, DependencyDirection, OutputFormat, SyncSource,
+    AgentBackend, CertAction, CreateSkillMethod, DependencyDirection, DepsAction, OutputFormat,
+    PluginAction, PluginTarget, ReportFormat, SyncPlatform, SyncScopeArg, SyncSource, SyncStrategy,
     ValidationTarget,
 };
 
@@ -146,15 +147,32 @@ pub enum Commands {
         /// Include marketplace content (uninstalled plugins).
         #[arg(long, env = "SKRILLS_INCLUDE_MARKETPLACE", default_value_t = false)]
         include_marketplace: bool,
+        /// Sync through the conflict-aware ledger, resolving skills changed on
+        /// both sides with this strategy (default: plain copy).
+        #[arg(long, value_enum)]
+        strategy: Option<SyncStrategy>,
+        /// Sync user-level directories or a repository's tool directories.
+        #[arg(long, value_enum)]
+        scope: Option<SyncScopeArg>,
+        /// Repository root for `--scope project` (default: current directory).
+        #[arg(long, value_name = "DIR")]
+        project_dir: Option<PathBuf>,
+        /// Write the planned changes to FILE as JSON instead of syncing.
+        #[arg(long, value_name = "FILE", conflicts_with = "apply")]
+        plan: Option<PathBuf>,
+        /// Apply a plan written by `--plan`, refusing if any target file
+        /// changed since.
+        #[arg(long, value_name = "FILE")]
+        apply: Option<PathBuf>,
     },
     /// Syncs slash commands between AI CLI tools (Claude, Codex, Copilot, Cursor).
     SyncCommands {
-        /// Source CLI: claude, codex, copilot, or cursor.
-        #[arg(long, value_enum, default_value_t = SyncSource::Claude)]
-        from: SyncSource,
-        /// Target CLI: claude, codex, copilot, or cursor. If not specified, defaults to codex (for claude source) or claude (for others).
-        #[arg(long, value_enum)]
-        to: Option<SyncSource>,
+        /// Source CLI: claude, codex, copilot, cursor, or an external adapter.
+        #[arg(long, default_value = "claude")]
+        from: SyncPlatform,
+        /// Target CLI: claude, codex, copilot, cursor, or an external adapter. If not specified, defaults to codex (for claude source) or claude (for others).
+        #[arg(long)]
+        to: Option<SyncPlatform>,
         /// Preview changes without writing.
         #[arg(long)]
         dry_run: bool,
@@ -167,36 +185,40 @@ pub enum Commands {
     },
     /// Syncs MCP server configurations between AI CLI tools.
     SyncMcpServers {
-        /// Source CLI: claude, codex, copilot, or cursor.
-        #[arg(long, value_enum, default_value_t = SyncSource::Claude)]
-        from: SyncSource,
-        /// Target CLI: claude, codex, copilot, or cursor.
-        #[arg(long, value_enum)]
-        to: Option<SyncSource>,
+        /// Source CLI: claude, codex, copilot, cursor, or an external adapter.
+        #[arg(long, default_value = "claude")]
+        from: SyncPlatform,
+        /// Target CLI: claude, codex, copilot, cursor, or an external adapter.
+        #[arg(long)]
+        to: Option<SyncPlatform>,
         /// Preview changes without writing.
         #[arg(long)]
         dry_run: bool,
+        /// Never write secret values in plaintext and keep them out of the output.
+        #[arg(long)]
+        redact_secrets: bool,
     },
     /// Syncs preferences between AI CLI tools.
     SyncPreferences {
-        /// Source CLI: claude, codex, copilot, or cursor.
-        #[arg(long, value_enum, default_value_t = SyncSource::Claude)]
-        from: SyncSource,
-        /// Target CLI: claude, codex, copilot, or cursor.
-        #[arg(long, value_enum)]
-        to: Option<SyncSource>,
+        /// Source CLI: claude, codex, copilot, cursor, or an external adapter.
+        #[arg(long, default_value = "claude")]
+        from: SyncPlatform,
+        /// Target CLI: claude, codex, copilot, cursor, or an external adapter.
+        #[arg(long)]
+        to: Option<SyncPlatform>,
         /// Preview changes without writing.
         #[arg(long)]
         dry_run: bool,
     },
     /// Syncs all configurations (commands, MCP servers, preferences, skills).
     SyncAll {
-        /// Source CLI: claude, codex, copilot, or cursor.
-        #[arg(long, value_enum, default_value_t = SyncSource::Claude)]
-        from: SyncSource,
-        /// Target CLI: claude, codex, copilot, or cursor. If omitted, syncs to ALL other CLIs.
-        #[arg(long, value_enum)]
-        to: Option<SyncSource>,
+        /// Source CLI: claude, codex, copilot, cursor, or an external adapter.
+        #[arg(long, default_value = "claude")]
+        from: SyncPlatform,
+        /// Target CLI: claude, codex, copilot, cursor, or an external adapter. If omitted, syncs to ALL other CLIs,
+        /// including external adapters from adapters.toml.
+        #[arg(long)]
+        to: Option<SyncPlatform>,
         /// Preview changes without writing.
         #[arg(long)]
         dry_run: bool,
@@ -216,15 +238,75 @@ pub enum Commands {
         /// Automatically fix validation issues (add frontmatter).
         #[arg(long)]
         autofix: bool,
+        /// Overwrite target edits and conflicts detected since the last sync.
+        #[arg(long)]
+        force: bool,
+        /// How to resolve items changed on both sides since the last sync
+        /// (default: skip). With a strategy, Claude→Codex skills also go
+        /// through conflict detection instead of a plain copy.
+        #[arg(long, value_enum)]
+        strategy: Option<SyncStrategy>,
+        /// Sync user-level directories or a repository's tool directories.
+        #[arg(long, value_enum)]
+        scope: Option<SyncScopeArg>,
+        /// Repository root for `--scope project` (default: current directory).
+        #[arg(long, value_name = "DIR")]
+        project_dir: Option<PathBuf>,
+    },
+    /// Watches the source CLI and syncs changed artifacts as they are edited.
+    #[cfg(feature = "watch")]
+    SyncWatch {
+        /// Source CLI: claude, codex, copilot, cursor, or an external adapter.
+        #[arg(long, default_value = "claude")]
+        from: SyncPlatform,
+        /// Target CLI (repeatable). If omitted, syncs to ALL other CLIs.
+        #[arg(long)]
+        to: Vec<SyncPlatform>,
+        /// Sync user-level directories or a repository's tool directories.
+        #[arg(long, value_enum)]
+        scope: Option<SyncScopeArg>,
+        /// Repository root for `--scope project` (default: current directory).
+        #[arg(long, value_name = "DIR")]
+        project_dir: Option<PathBuf>,
+        /// Debounce interval in milliseconds.
+        #[arg(long, default_value_t = 300)]
+        debounce_ms: u64,
     },
     /// Shows sync status and configuration differences.
     SyncStatus {
-        /// Source CLI: claude, codex, copilot, or cursor.
-        #[arg(long, value_enum, default_value_t = SyncSource::Claude)]
-        from: SyncSource,
-        /// Target CLI: claude, codex, copilot, or cursor.
+        /// Source CLI: claude, codex, copilot, cursor, or an external adapter.
+        #[arg(long, default_value = "claude")]
+        from: SyncPlatform,
+        /// Target CLI: claude, codex, copilot, cursor, or an external adapter.
+        #[arg(long)]
+        to: Option<SyncPlatform>,
+        /// Sync user-level directories or a repository's tool directories.
         #[arg(long, value_enum)]
-        to: Option<SyncSource>,
+        scope: Option<SyncScopeArg>,
+        /// Repository root for `--scope project` (default: current directory).
+        #[arg(long, value_name = "DIR")]
+        project_dir: Option<PathBuf>,
+        /// Never write secret values in plaintext and keep them out of the output.
+        #[arg(long)]
+        redact_secrets: bool,
+    },
+    /// Undoes a sync by restoring the snapshot taken before it wrote.
+    SyncUndo {
+        /// Snapshot id from `sync-history` (default: the most recent).
+        #[arg(long)]
+        id: Option<String>,
+        /// Output format: text or json.
+        #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+        format: OutputFormat,
+    },
+    /// Lists sync snapshots with per-file before/after hashes.
+    SyncHistory {
+        /// Show at most this many snapshots, newest first.
+        #[arg(long)]
+        limit: Option<usize>,
+        /// Output format: text or json.
+        #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+        format: OutputFormat,
     },
     /// Diagnoses Codex MCP configuration for this server.
     Doctor,
@@ -233,7 +315,7 @@ pub enum Commands {
         /// Skills directory to validate (default: all discovered skills).
         #[arg(long = "skill-dir", value_name = "DIR")]
         skill_dirs: Vec<PathBuf>,
-        /// Validation target: claude, codex, or both.
+        /// Validation target: claude, codex, copilot, cursor, all, or both.
         #[arg(long, value_enum, default_value = "both")]
         target: ValidationTarget,
         /// Automatically fix validation issues (add frontmatter).
@@ -242,12 +324,20 @@ pub enum Commands {
         /// Create backup files before autofix.
         #[arg(long)]
         backup: bool,
-        /// Output format: text or json.
-        #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
-        format: OutputFormat,
+        /// Output format: text, json, sarif or junit.
+        #[arg(long, value_enum, default_value_t = ReportFormat::Text)]
+        format: ReportFormat,
         /// Only show skills with errors.
         #[arg(long)]
         errors_only: bool,
+        /// Check links in skills and their module files: missing files,
+        /// broken anchors, undiscovered skills and orphaned `references/` files.
+        #[arg(long)]
+        check_links: bool,
+        /// With --check-links, also probe external URLs. Unreachable URLs are
+        /// reported as info, so the check is safe to run offline.
+        #[arg(long, requires = "check_links")]
+        check_urls: bool,
         #[cfg(feature = "watch")]
         /// Watch skill directories for changes and auto-revalidate.
         #[arg(long, default_value_t = false)]
@@ -257,20 +347,40 @@ pub enum Commands {
         #[arg(long, default_value_t = 300)]
         debounce_ms: u64,
     },
+    /// Lists the lint rules `validate` runs, with their severities and options.
+    ///
+    /// Shows both the default severity and the effective one after
+    /// ~/.skrills/lint.toml and the project's .skrills/lint.toml are applied.
+    LintRules {
+        /// Output format: text or json.
+        #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+        format: OutputFormat,
+    },
     /// Analyzes skills for token usage, dependencies, and optimization suggestions.
     Analyze {
         /// Skills directory to analyze (default: all discovered skills).
         #[arg(long = "skill-dir", value_name = "DIR")]
         skill_dirs: Vec<PathBuf>,
-        /// Output format: text or json.
-        #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
-        format: OutputFormat,
+        /// Output format: text, json, sarif or junit.
+        #[arg(long, value_enum, default_value_t = ReportFormat::Text)]
+        format: ReportFormat,
         /// Only show skills exceeding this token count.
         #[arg(long)]
         min_tokens: Option<usize>,
         /// Include optimization suggestions.
         #[arg(long, default_value_t = true)]
         suggestions: bool,
+        /// Target CLI whose tokenizer counts tokens (per `~/.skrills/tokenizers.toml`).
+        #[arg(long, value_name = "CLI")]
+        target: Option<String>,
+        /// Check links in skills and their module files: missing files,
+        /// broken anchors, undiscovered skills and orphaned `references/` files.
+        #[arg(long)]
+        check_links: bool,
+        /// With --check-links, also probe external URLs. Unreachable URLs are
+        /// reported as info, so the check is safe to run offline.
+        #[arg(long, requires = "check_links")]
+        check_urls: bool,
     },
     /// Shows aggregate statistics about discovered skills.
     Metrics {
@@ -475,14 +585,24 @@ pub enum Commands {
     /// Compare a skill across Claude, Codex, Copilot, and Cursor to show differences.
     SkillDiff {
         /// Skill name to compare (e.g., "commit", "review-pr").
-        #[arg(required = true)]
-        name: String,
+        #[arg(required_unless_present = "instructions")]
+        name: Option<String>,
+        /// Compare the repository's CLAUDE.md and AGENTS.md section by
+        /// section instead of a skill.
+        #[arg(long, conflicts_with = "name")]
+        instructions: bool,
         /// Output format: text (unified diff), json, or summary.
         #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
         format: OutputFormat,
         /// Show context lines around differences.
         #[arg(long, short = 'C', default_value = "3")]
         context: usize,
+        /// Compare user-level skills or a repository's skills.
+        #[arg(long, value_enum)]
+        scope: Option<SyncScopeArg>,
+        /// Repository root for `--scope project` (default: current directory).
+        #[arg(long, value_name = "DIR")]
+        project_dir: Option<PathBuf>,
     },
     /// Sets up skrills for Claude Code, Codex, Copilot, or Cursor (hooks, MCP, directories).
     Setup {
@@ -544,9 +664,45 @@ pub enum Commands {
         #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
         format: OutputFormat,
     },
-    /// Pull skill updates from remote sources.
+    /// Release a new version of a skill.
+    ///
+    /// Diffs the skill against its last `<name>-v<version>` git tag and
+    /// classifies the change: a removed section, file or frontmatter field,
+    /// or a changed frontmatter field other than `description`, is major;
+    /// additions are minor; other edits are patches. Bumps `version`,
+    /// prepends a section to CHANGELOG.md in the skill directory, commits the
+    /// directory and tags the release. Lists dependents whose `depends`
+    /// constraints the new version breaks.
+    SkillRelease {
+        /// Skill name to release.
+        #[arg(required = true)]
+        name: String,
+        /// Force a major bump.
+        #[arg(long, conflicts_with_all = ["minor", "patch"])]
+        major: bool,
+        /// Force a minor bump.
+        #[arg(long, conflicts_with = "patch")]
+        minor: bool,
+        /// Force a patch bump.
+        #[arg(long)]
+        patch: bool,
+        /// Show the release without writing, committing or tagging.
+        #[arg(long)]
+        dry_run: bool,
+        /// Update the version and changelog without committing or tagging.
+        #[arg(long)]
+        no_tag: bool,
+        /// Skills directory to search (default: all discovered skills).
+        #[arg(long = "skill-dir", value_name = "DIR")]
+        skill_dirs: Vec<PathBuf>,
+        /// Output format: text or json.
+        #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+        format: OutputFormat,
+    },
+    /// Pull skills from a remote skill registry.
     SyncPull {
-        /// Remote source URL (git repo, HTTP endpoint, or registry).
+        /// Registry to pull from: an http(s):// URL or a file:// directory
+        /// containing `index.json` (or the URL of the index itself).
         #[arg(long)]
         source: Option<String>,
         /// Specific skill name to pull (if omitted, pulls all from source).
@@ -558,6 +714,9 @@ pub enum Commands {
         /// Preview changes without applying.
         #[arg(long)]
         dry_run: bool,
+        /// Overwrite local copies that were not pulled or were edited since.
+        #[arg(long)]
+        force: bool,
         /// Output format: text or json.
         #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
         format: OutputFormat,
@@ -599,18 +758,28 @@ pub enum Commands {
         /// Only validate staged files (auto-detects via git status).
         #[arg(long)]
         staged: bool,
-        /// Validation target: claude, codex, copilot, or all.
+        /// Validation target: claude, codex, copilot, cursor, or all.
         #[arg(long, value_enum, default_value = "all")]
         target: ValidationTarget,
         /// Skills directory to validate (default: all discovered skills).
         #[arg(long = "skill-dir", value_name = "DIR")]
         skill_dirs: Vec<PathBuf>,
+        /// Output format: text, json, sarif or junit. Problems in text format
+        /// go to stderr.
+        #[arg(long, value_enum, default_value_t = ReportFormat::Text)]
+        format: ReportFormat,
     },
     /// Import skills from external sources (URLs, git repos, local paths).
     SkillImport {
-        /// Source to import from (URL, git URL, or local path).
+        /// Source to import from (git URL, local repository, or local file).
         #[arg(required = true)]
         source: String,
+        /// Git branch, tag, or commit to import (default: the remote HEAD).
+        #[arg(long = "ref", value_name = "REF")]
+        git_ref: Option<String>,
+        /// Only discover skills below this repository subdirectory.
+        #[arg(long, value_name = "DIR")]
+        subdir: Option<String>,
         /// Target CLI to import skill into.
         #[arg(long, value_enum, default_value_t = SyncSource::Claude)]
         target: SyncSource,
@@ -646,9 +815,9 @@ pub enum Commands {
         /// Skills directory to include (default: all discovered skills).
         #[arg(long = "skill-dir", value_name = "DIR")]
         skill_dirs: Vec<PathBuf>,
-        /// Output format: text or json.
-        #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
-        format: OutputFormat,
+        /// Output format: text, json, sarif or junit.
+        #[arg(long, value_enum, default_value_t = ReportFormat::Text)]
+        format: ReportFormat,
         /// Only show skills below this score threshold.
         #[arg(long)]
         below_threshold: Option<u8>,
@@ -656,6 +825,12 @@ pub enum Commands {
     /// Manage TLS certificates for HTTPS transport.
     #[command(subcommand)]
     Cert(CertAction),
+    /// Install Claude marketplace plugins into other CLIs as versioned units.
+    #[command(subcommand)]
+    Plugin(PluginAction),
+    /// Install skill dependencies declared in `depends` and pin them in `skills.lock`.
+    #[command(subcommand)]
+    Deps(DepsAction),
     /// Run the cold-window real-time analysis surface (TUI library and browser).
     ///
     /// Continuously re-reads authoritative state on each tick, runs a
@@ -859,6 +1034,7 @@ mod tests {
         match cli.command {
             Some(Commands::Sync {
                 include_marketplace,
+                ..
             }) => assert!(include_marketplace),
             _ => unreachable!("expected Sync command"),
         }
@@ -875,7 +1051,12 @@ mod tests {
         match cli.command {
             Some(Commands::Sync {
                 include_marketplace,
-            }) => assert!(!include_marketplace),
+                strategy,
+                ..
+            }) => {
+                assert!(!include_marketplace);
+                assert!(strategy.is_none());
+            }
             _ => unreachable!("expected Sync command"),
         }
     }
@@ -1249,20 +1430,189 @@ mod tests {
                 exclude_plugins,
                 validate,
                 autofix,
+                force,
+                strategy,
+                scope,
+                project_dir,
             }) => {
-                assert!(matches!(from, SyncSource::Codex));
-                assert_eq!(to, Some(SyncSource::Copilot));
+                assert_eq!(from, SyncPlatform::Builtin(SyncSource::Codex));
+                assert_eq!(to, Some(SyncPlatform::Builtin(SyncSource::Copilot)));
                 assert!(dry_run);
                 assert!(skip_existing_commands);
                 assert!(!include_marketplace);
                 assert!(exclude_plugins.is_empty());
                 assert!(validate);
                 assert!(autofix);
+                assert!(!force);
+                assert!(strategy.is_none());
+                assert!(scope.is_none());
+                assert!(project_dir.is_none());
             }
             _ => unreachable!("expected SyncAll command"),
         }
     }
 
+    #[test]
+    fn parse_project_scope_arguments() {
+        let cli = Cli::try_parse_from([
+            "skrills",
+            "sync-status",
+            "--scope",
+            "project",
+            "--project-dir",
+            "/tmp/repo",
+        ])
+        .expect("sync-status --scope project should parse");
+        match cli.command {
+            Some(Commands::SyncStatus {
+                scope, project_dir, ..
+            }) => {
+                assert_eq!(scope, Some(SyncScopeArg::Project));
+                assert_eq!(project_dir, Some(PathBuf::from("/tmp/repo")));
+            }
+            _ => unreachable!("expected SyncStatus command"),
+        }
+
+        for command in ["sync", "sync-all"] {
+            assert!(Cli::try_parse_from(["skrills", command, "--scope", "project"]).is_ok());
+        }
+        assert!(
+            Cli::try_parse_from(["skrills", "skill-diff", "commit", "--scope", "project"]).is_ok()
+        );
+        assert!(Cli::try_parse_from(["skrills", "sync", "--scope", "team"]).is_err());
+    }
+
+    #[cfg(feature = "watch")]
+    #[test]
+    fn parse_sync_watch() {
+        let cli = Cli::try_parse_from([
+            "skrills",
+            "sync-watch",
+            "--to",
+            "codex",
+            "--to",
+            "cursor",
+            "--debounce-ms",
+            "500",
+        ])
+        .expect("sync-watch should parse");
+        match cli.command {
+            Some(Commands::SyncWatch {
+                from,
+                to,
+                debounce_ms,
+                ..
+            }) => {
+                assert!(from.is_claude());
+                assert_eq!(to.len(), 2);
+                assert!(to[0].is_codex() && to[1].is_cursor());
+                assert_eq!(debounce_ms, 500);
+            }
+            _ => unreachable!("expected SyncWatch command"),
+        }
+    }
+
+    #[test]
+    fn parse_redact_secrets() {
+        let cli = Cli::try_parse_from(["skrills", "sync-mcp-servers", "--redact-secrets"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Some(Commands::SyncMcpServers {
+                redact_secrets: true,
+                ..
+            })
+        ));
+        let cli = Cli::try_parse_from(["skrills", "sync-status", "--redact-secrets"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Some(Commands::SyncStatus {
+                redact_secrets: true,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn parse_sync_undo_and_history() {
+        let cli = Cli::try_parse_from(["skrills", "sync-undo", "--id", "2026-10-17T10-00-00Z"])
+            .expect("sync-undo --id should parse");
+        match cli.command {
+            Some(Commands::SyncUndo { id, format }) => {
+                assert_eq!(id.as_deref(), Some("2026-10-17T10-00-00Z"));
+                assert!(!format.is_json());
+            }
+            _ => unreachable!("expected SyncUndo command"),
+        }
+
+        let cli = Cli::try_parse_from([
+            "skrills",
+            "sync-history",
+            "--limit",
+            "5",
+            "--format",
+            "json",
+        ])
+        .expect("sync-history should parse");
+        match cli.command {
+            Some(Commands::SyncHistory { limit, format }) => {
+                assert_eq!(limit, Some(5));
+                assert!(format.is_json());
+            }
+            _ => unreachable!("expected SyncHistory command"),
+        }
+    }
+
+    #[test]
+    fn parse_sync_strategy_merge() {
+        let cli = Cli::try_parse_from(["skrills", "sync-all", "--strategy", "merge"])
+            .expect("sync-all --strategy merge should parse");
+        match cli.command {
+            Some(Commands::SyncAll { strategy, .. }) => {
+                assert_eq!(strategy, Some(SyncStrategy::Merge));
+                assert_eq!(
+                    skrills_sync::ConflictStrategy::from(SyncStrategy::Merge),
+                    skrills_sync::ConflictStrategy::Merge
+                );
+            }
+            _ => unreachable!("expected SyncAll command"),
+        }
+
+        let cli = Cli::try_parse_from(["skrills", "sync", "--strategy", "merge"])
+            .expect("sync --strategy merge should parse");
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Sync {
+                strategy: Some(SyncStrategy::Merge),
+                ..
+            })
+        ));
+        assert!(Cli::try_parse_from(["skrills", "sync", "--strategy", "rebase"]).is_err());
+    }
+
+    #[test]
+    fn parse_sync_plan_and_apply() {
+        let cli = Cli::try_parse_from(["skrills", "sync", "--plan", "out.json"])
+            .expect("sync --plan should parse");
+        match cli.command {
+            Some(Commands::Sync { plan, apply, .. }) => {
+                assert_eq!(plan, Some(PathBuf::from("out.json")));
+                assert!(apply.is_none());
+            }
+            _ => unreachable!("expected Sync command"),
+        }
+
+        let cli = Cli::try_parse_from(["skrills", "sync", "--apply", "out.json"])
+            .expect("sync --apply should parse");
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Sync { apply: Some(_), .. })
+        ));
+        assert!(
+            Cli::try_parse_from(["skrills", "sync", "--plan", "a.json", "--apply", "b.json"])
+                .is_err()
+        );
+    }
+
     #[test]
     fn parse_sync_commands_defaults() {
         let cli = Cli::try_parse_from(["skrills", "sync-commands"])
@@ -1272,7 +1622,7 @@ mod tests {
             Some(Commands::SyncCommands {
                 from, to, dry_run, ..
             }) => {
-                assert!(matches!(from, SyncSource::Claude));
+                assert_eq!(from, SyncPlatform::Builtin(SyncSource::Claude));
                 assert!(to.is_none());
                 assert!(!dry_run);
             }
@@ -1290,6 +1640,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_link_check_flags() {
+        let cli = Cli::try_parse_from(["skrills", "analyze", "--check-links", "--check-urls"])
+            .expect("analyze link check should parse");
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Analyze {
+                check_links: true,
+                check_urls: true,
+                ..
+            })
+        ));
+
+        assert!(
+            Cli::try_parse_from(["skrills", "validate", "--check-urls"]).is_err(),
+            "--check-urls requires --check-links"
+        );
+    }
+
     #[test]
     fn parse_validate_all_flags() {
         let cli = Cli::try_parse_from([
@@ -1321,7 +1690,7 @@ mod tests {
                 assert!(matches!(target, ValidationTarget::Codex));
                 assert!(autofix);
                 assert!(backup);
-                assert_eq!(format, OutputFormat::Json);
+                assert_eq!(format, ReportFormat::Json);
                 assert!(errors_only);
             }
             _ => unreachable!("expected Validate command"),
@@ -1421,6 +1790,133 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_plugin_actions() {
+        let cli = Cli::try_parse_from([
+            "skrills",
+            "plugin",
+            "install",
+            "tidy",
+            "--to",
+            "codex,cursor",
+        ])
+        .expect("plugin install should parse");
+        match cli.command {
+            Some(Commands::Plugin(PluginAction::Install { name, to })) => {
+                assert_eq!(name, "tidy");
+                assert_eq!(to, [PluginTarget::Codex, PluginTarget::Cursor]);
+            }
+            _ => unreachable!("expected Plugin Install command"),
+        }
+        assert!(Cli::try_parse_from(["skrills", "plugin", "install", "tidy"]).is_err());
+        assert!(
+            Cli::try_parse_from(["skrills", "plugin", "install", "tidy", "--to", "claude"])
+                .is_err()
+        );
+
+        let cli = Cli::try_parse_from(["skrills", "plugin", "upgrade"])
+            .expect("plugin upgrade should parse");
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Plugin(PluginAction::Upgrade { name: None, .. }))
+        ));
+        let cli = Cli::try_parse_from(["skrills", "plugin", "uninstall", "market/tidy"])
+            .expect("plugin uninstall should parse");
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Plugin(PluginAction::Uninstall { from, .. })) if from.is_empty()
+        ));
+    }
+
+    #[test]
+    fn parse_deps_actions() {
+        let cli = Cli::try_parse_from([
+            "skrills",
+            "deps",
+            "install",
+            "release-notes",
+            "--registry",
+            "file:///srv/skills",
+            "--dry-run",
+        ])
+        .expect("deps install should parse");
+        match cli.command {
+            Some(Commands::Deps(DepsAction::Install {
+                skills,
+                registries,
+                lock,
+                dry_run,
+                ..
+            })) => {
+                assert_eq!(skills, ["release-notes"]);
+                assert_eq!(registries, ["file:///srv/skills"]);
+                assert_eq!(lock, PathBuf::from("skills.lock"));
+                assert!(dry_run);
+            }
+            _ => unreachable!("expected Deps Install command"),
+        }
+
+        let cli = Cli::try_parse_from(["skrills", "deps", "verify", "--lock", "team.lock"])
+            .expect("deps verify should parse");
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Deps(DepsAction::Verify { lock, .. })) if lock == std::path::Path::new("team.lock")
+        ));
+    }
+
+    #[test]
+    fn parse_report_formats() {
+        let cli = Cli::try_parse_from(["skrills", "validate", "--format", "sarif"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Validate {
+                format: ReportFormat::Sarif,
+                ..
+            })
+        ));
+        let cli =
+            Cli::try_parse_from(["skrills", "pre-commit-validate", "--format", "junit"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Some(Commands::PreCommitValidate {
+                format: ReportFormat::Junit,
+                ..
+            })
+        ));
+        for command in ["analyze", "skill-score"] {
+            assert!(Cli::try_parse_from(["skrills", command, "--format", "junit"]).is_ok());
+        }
+        assert!(Cli::try_parse_from(["skrills", "sync-history", "--format", "sarif"]).is_err());
+    }
+
+    #[test]
+    fn parse_lint_rules_format() {
+        let cli = Cli::try_parse_from(["skrills", "lint-rules", "--format", "json"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Some(Commands::LintRules {
+                format: OutputFormat::Json
+            })
+        ));
+    }
+
+    #[test]
+    fn parse_skill_release_flags() {
+        let cli = Cli::try_parse_from(["skrills", "skill-release", "base", "--minor", "--dry-run"])
+            .expect("skill-release should parse");
+        assert!(matches!(
+            cli.command,
+            Some(Commands::SkillRelease { name, major: false, minor: true, patch: false, dry_run: true, no_tag: false, .. })
+                if name == "base"
+        ));
+
+        assert!(
+            Cli::try_parse_from(["skrills", "skill-release", "base", "--major", "--patch"])
+                .is_err(),
+            "bump levels should conflict"
+        );
+    }
+
     #[test]
     fn parse_setup_arguments() {
         let cli = Cli::try_parse_from([
@@ -1466,6 +1962,10 @@ mod tests {
             "skrills",
             "skill-import",
             "https://github.com/example/skill.git",
+            "--ref",
+            "v1.2.0",
+            "--subdir",
+            "skills",
             "--target",
             "codex",
             "--force",
@@ -1478,12 +1978,16 @@ mod tests {
         match cli.command {
             Some(Commands::SkillImport {
                 source,
+                git_ref,
+                subdir,
                 target,
                 force,
                 dry_run,
                 format,
             }) => {
                 assert_eq!(source, "https://github.com/example/skill.git");
+                assert_eq!(git_ref.as_deref(), Some("v1.2.0"));
+                assert_eq!(subdir.as_deref(), Some("skills"));
                 assert!(matches!(target, SyncSource::Codex));
                 assert!(force);
                 assert!(dry_run);
@@ -1511,13 +2015,29 @@ mod tests {
                 name,
                 format,
                 context,
+                ..
             }) => {
-                assert_eq!(name, "commit");
+                assert_eq!(name.as_deref(), Some("commit"));
                 assert_eq!(format, OutputFormat::Json);
                 assert_eq!(context, 5);
             }
             _ => unreachable!("expected SkillDiff command"),
         }
+
+        let cli = Cli::try_parse_from(["skrills", "skill-diff", "--instructions"])
+            .expect("skill-diff --instructions should parse");
+        assert!(matches!(
+            cli.command,
+            Some(Commands::SkillDiff {
+                name: None,
+                instructions: true,
+                ..
+            })
+        ));
+        assert!(Cli::try_parse_from(["skrills", "skill-diff"]).is_err());
+        assert!(
+            Cli::try_parse_from(["skrills", "skill-diff", "commit", "--instructions"]).is_err()
+        );
     }
 
     #[test]
@@ -1683,8 +2203,8 @@ mod tests {
             Some(Commands::SyncAll {
                 from, to, dry_run, ..
             }) => {
-                assert!(matches!(from, SyncSource::Claude));
-                assert_eq!(to, Some(SyncSource::Cursor));
+                assert_eq!(from, SyncPlatform::Builtin(SyncSource::Claude));
+                assert_eq!(to, Some(SyncPlatform::Builtin(SyncSource::Cursor)));
                 assert!(dry_run);
             }
             _ => unreachable!("expected SyncAll command"),
@@ -1754,6 +2274,30 @@ mod tests {
         }
     }
 
+    #[test]
+    fn sync_platform_parses_builtins_case_insensitively() {
+        assert_eq!(
+            "Cursor".parse::<SyncPlatform>().unwrap(),
+            SyncPlatform::Builtin(SyncSource::Cursor)
+        );
+        assert!("definitely-not-a-cli".parse::<SyncPlatform>().is_err());
+    }
+
+    #[test]
+    fn sync_platform_external_routes_like_non_claude_source() {
+        let windsurf = SyncPlatform::External("windsurf".to_string());
+        assert_eq!(windsurf.as_str(), "windsurf");
+        assert!(windsurf.builtin().is_none());
+        assert!(!windsurf.is_claude() && !windsurf.is_codex() && !windsurf.is_cursor());
+        assert_eq!(
+            windsurf.default_target(),
+            SyncPlatform::Builtin(SyncSource::Claude)
+        );
+        assert!(windsurf
+            .other_targets()
+            .contains(&SyncPlatform::Builtin(SyncSource::Claude)));
+    }
+
     // --- AgentBackend enum method tests ---
 
     #[test]
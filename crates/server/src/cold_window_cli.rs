@@ -87,11 +87,16 @@ pub struct ColdWindowArgs {
     /// Additional skill directories the producer walks each tick to
     /// build the snapshot's per-skill token attribution. Combined with
     /// any directories supplied via `SKRILLS_EXTRA_SKILL_DIRS` and the
-    /// default skill roots. Token totals are estimated from file size
-    /// (`bytes / 4`); a real BPE tokenizer is a follow-up.
+    /// default skill roots. Token totals come from the tokenizer
+    /// selected by `--target`.
     #[arg(long = "skill-dir", value_name = "DIR")]
     pub skill_dirs: Vec<PathBuf>,
 
+    /// Target CLI whose tokenizer (per `~/.skrills/tokenizers.toml`)
+    /// counts skill tokens. Without it the section heuristic is used.
+    #[arg(long, value_name = "CLI")]
+    pub target: Option<String>,
+
     /// Plugins root directory whose `<plugin>/health.toml` files
     /// participate in each tick. Defaults to `./plugins`
     /// relative to the current working directory; missing or
@@ -157,8 +162,11 @@ pub async fn run(args: ColdWindowArgs) -> Result<()> {
     // adapters consult it before mutating I/O.
     let kill_switch = KillSwitch::new();
 
+    let tokenizer = crate::config::load_tokenizer(args.target.as_deref())?;
     let engine = Arc::new(
-        ColdWindowEngine::with_defaults(args.alert_budget).with_kill_switch(kill_switch.clone()),
+        ColdWindowEngine::with_defaults(args.alert_budget)
+            .with_kill_switch(kill_switch.clone())
+            .with_tokenizer(tokenizer),
     );
     let bus = engine.bus_sender();
 
@@ -300,7 +308,8 @@ async fn producer_loop(
     // When the operator supplied no dirs the collector short-circuits
     // to empty; the producer keeps the synthetic demo ledger so the
     // dashboard stays interactive in that mode.
-    let skill_collector = Arc::new(SkillCollector::new(skill_dirs));
+    let skill_collector =
+        Arc::new(SkillCollector::new(skill_dirs).with_tokenizer(engine.tokenizer()));
     loop {
         tokio::select! {
             biased;
@@ -1,31 +1,54 @@
-use crate::cli::OutputFormat;
+use crate::cli::ReportFormat;
+use crate::config::load_tokenizer;
 use crate::discovery::merge_extra_dirs;
 use anyhow::Result;
 use skrills_discovery::{discover_skills, extra_skill_roots};
 
+use super::links::{known_skills, link_warnings, warning_location, UrlProbe};
+use super::report::{rule_id, Finding, Level, Report, ReportKind};
+
 /// Handle the `analyze` command.
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn handle_analyze_command(
     skill_dirs: Vec<std::path::PathBuf>,
-    format: OutputFormat,
+    format: ReportFormat,
     min_tokens: Option<usize>,
     suggestions: bool,
+    target: Option<String>,
+    check_links: bool,
+    check_urls: bool,
 ) -> Result<()> {
-    use skrills_analyze::{analyze_skill, AnalysisSummary, Priority};
+    use skrills_analyze::{analyze_skill_with, AnalysisSummary, Priority};
+
+    let tokenizer = load_tokenizer(target.as_deref())?;
 
     let extra_dirs = merge_extra_dirs(&skill_dirs);
     let roots = extra_skill_roots(&extra_dirs);
     let skills = discover_skills(&roots, None)?;
 
     if skills.is_empty() {
-        if format.is_json() {
-            println!("[]");
-        } else {
-            println!("No skills found to analyze.");
+        match ReportKind::of(format) {
+            Some(kind) => Report::new("analyze").print(kind)?,
+            None if format.is_json() => println!("[]"),
+            None => println!("No skills found to analyze."),
         }
         return Ok(());
     }
 
+    let known = if check_links {
+        known_skills(&extra_dirs)?
+    } else {
+        Vec::new()
+    };
+    let probe = if check_urls {
+        Some(UrlProbe::new()?)
+    } else {
+        None
+    };
+
     let mut analyses = Vec::new();
+    let mut paths = Vec::new();
+    let mut link_problems = Vec::new();
 
     for meta in skills.iter() {
         let content = match std::fs::read_to_string(&meta.path) {
@@ -33,7 +56,7 @@ pub(crate) fn handle_analyze_command(
             Err(_) => continue,
         };
 
-        let analysis = analyze_skill(&meta.path, &content);
+        let mut analysis = analyze_skill_with(&meta.path, &content, tokenizer.as_ref());
 
         if let Some(min) = min_tokens {
             if analysis.tokens.total < min {
@@ -41,10 +64,64 @@ pub(crate) fn handle_analyze_command(
             }
         }
 
+        if check_links {
+            let warnings = link_warnings(&meta.path, &content, &known, probe.as_ref());
+            for warning in &warnings {
+                link_problems.push(format!(
+                    "  {} ({}): [{}] {}",
+                    analysis.name,
+                    warning_location(&meta.path, warning),
+                    warning.level,
+                    warning.message
+                ));
+            }
+            analysis.dependencies.warnings.extend(warnings);
+        }
+
         analyses.push(analysis);
+        paths.push(meta.path.clone());
     }
 
-    if format.is_json() {
+    if let Some(kind) = ReportKind::of(format) {
+        let mut report = Report::new("analyze");
+        for (analysis, path) in analyses.iter().zip(&paths) {
+            let case = report.add_case(&analysis.name, path);
+            for warning in &analysis.dependencies.warnings {
+                let mut finding = Finding::new(
+                    rule_id(warning.kind),
+                    warning.level.into(),
+                    &warning.message,
+                    warning.context.as_deref().unwrap_or(path),
+                );
+                finding.line = warning.line;
+                case.findings.push(finding);
+            }
+            if suggestions {
+                for suggestion in &analysis.suggestions {
+                    let level = match suggestion.priority {
+                        Priority::High => Level::Warning,
+                        _ => Level::Note,
+                    };
+                    let mut finding = Finding::new(
+                        optimization_rule(suggestion.opt_type),
+                        level,
+                        &suggestion.message,
+                        path,
+                    );
+                    finding.suggestion = suggestion.action.clone();
+                    case.findings.push(finding);
+                }
+            }
+        }
+        report.describe("reduce-size", "Skill could use fewer tokens");
+        report.describe("improve-structure", "Skill structure could be improved");
+        report.describe("fix-issue", "Skill has a likely content problem");
+        report.describe(
+            "improve-compatibility",
+            "Skill compatibility could be improved",
+        );
+        report.print(kind)?;
+    } else if format.is_json() {
         println!("{}", serde_json::to_string_pretty(&analyses)?);
     } else {
         let summary = AnalysisSummary::from_analyses(&analyses);
@@ -74,7 +151,29 @@ pub(crate) fn handle_analyze_command(
                 }
             }
         }
+
+        if check_links {
+            if link_problems.is_empty() {
+                println!("\nLink check: no problems found");
+            } else {
+                println!("\nLink problems ({}):", link_problems.len());
+                for problem in &link_problems {
+                    println!("{}", problem);
+                }
+            }
+        }
     }
 
     Ok(())
 }
+
+/// Rule ID for optimization suggestions of `opt_type`.
+fn optimization_rule(opt_type: skrills_analyze::OptimizationType) -> &'static str {
+    use skrills_analyze::OptimizationType;
+    match opt_type {
+        OptimizationType::ReduceSize => "reduce-size",
+        OptimizationType::ImproveStructure => "improve-structure",
+        OptimizationType::FixIssue => "fix-issue",
+        OptimizationType::Compatibility => "improve-compatibility",
+    }
+}
@@ -0,0 +1,189 @@
+//! Skill dependency commands.
+//!
+//! Resolves `depends` frontmatter into installed skills and a `skills.lock`
+//! through [`crate::deps`].
+
+use crate::cli::{OutputFormat, SyncSource};
+use crate::config::configured_registries;
+use crate::deps::{self, DepAction, DivergenceKind, InstallOptions, RegistrySet, SkillsLock};
+use crate::discovery::{merge_extra_dirs, skill_roots};
+use crate::registry::RegistryLock;
+use anyhow::{bail, Context, Result};
+use serde_json::json;
+use skrills_discovery::{discover_skills, SkillMeta, SkillRoot};
+use std::path::{Path, PathBuf};
+
+use super::skill::target_skills_dir;
+
+/// Discovered skills plus the target's skills directory, where registry
+/// dependencies are installed.
+fn local_skills(
+    skill_dirs: &[PathBuf],
+    skills_dir: &Path,
+    target: SyncSource,
+) -> Result<Vec<SkillMeta>> {
+    let mut roots = skill_roots(&merge_extra_dirs(skill_dirs))?;
+    if !roots.iter().any(|r| r.root == skills_dir) {
+        roots.push(SkillRoot {
+            root: skills_dir.to_path_buf(),
+            source: deps::discovery_source(target),
+        });
+    }
+    discover_skills(&roots, None)
+}
+
+/// Handle `deps install`.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn handle_deps_install_command(
+    skills: Vec<String>,
+    registries: Vec<String>,
+    target: SyncSource,
+    lock_path: PathBuf,
+    skill_dirs: Vec<PathBuf>,
+    dry_run: bool,
+    force: bool,
+    format: OutputFormat,
+) -> Result<()> {
+    let home = dirs::home_dir().context("Could not determine home directory")?;
+    let skills_dir = target_skills_dir(&home, target);
+    let registry_lock = RegistryLock::default_path()?;
+    let opts = InstallOptions {
+        target,
+        skills_dir: &skills_dir,
+        registry_lock: &registry_lock,
+        dry_run,
+        force,
+    };
+
+    let mut sources = registries;
+    for source in configured_registries()? {
+        if !sources.contains(&source) {
+            sources.push(source);
+        }
+    }
+    let registries = RegistrySet::new(sources);
+    let local = local_skills(&skill_dirs, &skills_dir, target)?;
+    let existing = SkillsLock::load(&lock_path)?;
+
+    let report = if skills.is_empty() {
+        if !lock_path.exists() {
+            bail!(
+                "{} not found; run `skrills deps install <skill>` to create it",
+                lock_path.display()
+            );
+        }
+        deps::install_locked(&existing, &local, &registries, &opts)?
+    } else {
+        let mut roots = existing.roots;
+        for skill in skills {
+            if !roots.contains(&skill) {
+                roots.push(skill);
+            }
+        }
+        let resolution = deps::resolve(&roots, &local, &registries, target)?;
+        let (lock, report) = deps::install(resolution, roots, &opts)?;
+        if !dry_run {
+            lock.save(&lock_path)?;
+        }
+        report
+    };
+
+    if format.is_json() {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+        return Ok(());
+    }
+    for s in &report.skills {
+        let registry = s.registry.as_deref().unwrap_or("registry");
+        let label = match s.action {
+            DepAction::Present => "present".to_string(),
+            DepAction::Installed => format!("installed from {registry}"),
+            DepAction::WouldInstall => format!("would install from {registry}"),
+            DepAction::Skipped => "skipped".to_string(),
+        };
+        println!(
+            "  {}{} - {}{}",
+            s.name,
+            s.version
+                .as_ref()
+                .map(|v| format!(" {v}"))
+                .unwrap_or_default(),
+            label,
+            if s.optional { " (optional)" } else { "" }
+        );
+    }
+    for warning in &report.warnings {
+        println!("  ⚠ {}", warning);
+    }
+    let installed = report
+        .skills
+        .iter()
+        .filter(|s| matches!(s.action, DepAction::Installed | DepAction::WouldInstall))
+        .count();
+    if dry_run {
+        println!(
+            "[dry-run] Would install {} skill(s); {} not written",
+            installed,
+            lock_path.display()
+        );
+    } else {
+        println!(
+            "Installed {} skill(s); {} pins {} skill(s)",
+            installed,
+            lock_path.display(),
+            report.skills.len()
+        );
+    }
+    Ok(())
+}
+
+/// Handle `deps verify`.
+pub(crate) fn handle_deps_verify_command(
+    target: SyncSource,
+    lock_path: PathBuf,
+    skill_dirs: Vec<PathBuf>,
+    format: OutputFormat,
+) -> Result<()> {
+    if !lock_path.exists() {
+        bail!("{} not found", lock_path.display());
+    }
+    let lock = SkillsLock::load(&lock_path)?;
+    let home = dirs::home_dir().context("Could not determine home directory")?;
+    let local = local_skills(&skill_dirs, &target_skills_dir(&home, target), target)?;
+    let divergences = deps::verify(&lock, &local)?;
+
+    if format.is_json() {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&json!({
+                "lock": lock_path,
+                "skills": lock.skills.len(),
+                "in_sync": divergences.is_empty(),
+                "divergences": divergences,
+            }))?
+        );
+    } else if divergences.is_empty() {
+        println!(
+            "✓ {} skill(s) match {}",
+            lock.skills.len(),
+            lock_path.display()
+        );
+    } else {
+        for d in &divergences {
+            let kind = match d.kind {
+                DivergenceKind::Missing => "missing",
+                DivergenceKind::Modified => "modified",
+                DivergenceKind::Unlocked => "unlocked",
+            };
+            println!("✗ {} ({}): {}", d.name, kind, d.detail);
+        }
+    }
+
+    if !divergences.is_empty() {
+        bail!(
+            "Installed skills diverge from {} ({} difference(s))",
+            lock_path.display(),
+            divergences.len()
+        );
+    }
+    Ok(())
+}
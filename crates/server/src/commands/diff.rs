@@ -3,26 +3,37 @@
 use crate::cli::OutputFormat;
 use anyhow::{anyhow, Result};
 use serde_json::json;
-use skrills_discovery::{default_roots, discover_skills, SkillRoot, SkillSource};
+use skrills_discovery::{default_roots, discover_skills, project_roots, SkillRoot, SkillSource};
 use skrills_state::home_dir;
+use skrills_sync::sections::{diff_sections, SectionDrift, SectionStatus};
+use skrills_sync::SyncScope;
 use std::collections::HashMap;
 use std::path::PathBuf;
 
 /// Handle the `skill-diff` command.
+///
+/// In project scope the repository's own skill directories are compared
+/// instead of the user-level ones.
 pub(crate) fn handle_skill_diff_command(
     name: String,
     format: OutputFormat,
     context_lines: usize,
+    scope: SyncScope,
 ) -> Result<()> {
     // Discover skills from each source separately to avoid deduplication.
     // This ensures we find ALL versions of a skill across different CLIs.
     // Use default_roots which includes all CLIs (Codex, Claude, Copilot).
-    let home = home_dir()?;
-    let mut roots = default_roots(&home);
-    // Add Cursor rules from current working directory (project-local)
-    let cursor_rules = std::env::current_dir()
-        .unwrap_or_default()
-        .join(".cursor/rules");
+    let project = match scope.project_dir() {
+        Some(dir) => dir.to_path_buf(),
+        None => std::env::current_dir().unwrap_or_default(),
+    };
+    let mut roots = if scope.is_project() {
+        project_roots(&project)
+    } else {
+        default_roots(&home_dir()?)
+    };
+    // Add Cursor rules from the project (project-local)
+    let cursor_rules = project.join(".cursor/rules");
     if cursor_rules.exists() {
         roots.push(SkillRoot {
             root: cursor_rules,
@@ -141,6 +152,101 @@ pub(crate) fn handle_skill_diff_command(
     Ok(())
 }
 
+/// Handle `skill-diff --instructions`.
+///
+/// Compares the repository's `CLAUDE.md` and `AGENTS.md` section by section
+/// (see [`skrills_sync::sections`]), ignoring skrills' managed-region
+/// markers, and shows a diff for each section that drifted.
+pub(crate) fn handle_instructions_diff_command(
+    format: OutputFormat,
+    context_lines: usize,
+    scope: SyncScope,
+) -> Result<()> {
+    let project = match scope.project_dir() {
+        Some(dir) => dir.to_path_buf(),
+        None => std::env::current_dir().unwrap_or_default(),
+    };
+    let claude_path = project.join("CLAUDE.md");
+    let agents_path = project.join("AGENTS.md");
+    let read = |path: &PathBuf| match std::fs::read_to_string(path) {
+        Ok(content) => Ok(content),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(String::new()),
+        Err(e) => Err(anyhow!("Failed to read {}: {}", path.display(), e)),
+    };
+    if !claude_path.exists() && !agents_path.exists() {
+        return Err(anyhow!(
+            "Neither CLAUDE.md nor AGENTS.md found in {}",
+            project.display()
+        ));
+    }
+
+    let drift = diff_sections(&read(&claude_path)?, &read(&agents_path)?);
+    let drifted: Vec<&SectionDrift> = drift
+        .iter()
+        .filter(|d| d.status != SectionStatus::Identical)
+        .collect();
+
+    if format.is_json() {
+        let sections: Vec<_> = drift
+            .iter()
+            .map(|d| {
+                json!({
+                    "key": d.key,
+                    "heading": d.heading,
+                    "status": d.status,
+                    "diff": (d.status == SectionStatus::Changed).then(|| unified_diff(
+                        d.left.as_deref().unwrap_or_default().trim_end(),
+                        d.right.as_deref().unwrap_or_default().trim_end(),
+                        context_lines,
+                    )),
+                })
+            })
+            .collect();
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&json!({
+                "left": claude_path.to_string_lossy(),
+                "right": agents_path.to_string_lossy(),
+                "identical": drifted.is_empty(),
+                "sections": sections,
+            }))?
+        );
+        return Ok(());
+    }
+
+    for d in &drifted {
+        let title = d.heading.as_deref().unwrap_or("(preamble)");
+        match d.status {
+            SectionStatus::OnlyInLeft => println!("- {}: only in CLAUDE.md", title),
+            SectionStatus::OnlyInRight => println!("+ {}: only in AGENTS.md", title),
+            _ => {
+                println!("~ {}: changed", title);
+                println!(
+                    "{}",
+                    unified_diff(
+                        d.left.as_deref().unwrap_or_default().trim_end(),
+                        d.right.as_deref().unwrap_or_default().trim_end(),
+                        context_lines,
+                    )
+                );
+            }
+        }
+    }
+    if drifted.is_empty() {
+        println!(
+            "CLAUDE.md and AGENTS.md agree on all {} section(s)",
+            drift.len()
+        );
+    } else {
+        println!(
+            "\nSummary: {} of {} section(s) differ between CLAUDE.md and AGENTS.md",
+            drifted.len(),
+            drift.len()
+        );
+    }
+    Ok(())
+}
+
 /// Generate a unified diff between two strings.
 fn unified_diff(a: &str, b: &str, context: usize) -> String {
     use std::fmt::Write;
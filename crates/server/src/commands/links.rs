@@ -0,0 +1,89 @@
+//! Link checking shared by `analyze` and `validate`.
+//!
+//! Runs [`skrills_analyze::check_links`] and, on request, probes external
+//! URLs through [`skrills_analyze::check_urls`].
+
+use crate::discovery::skill_roots;
+use anyhow::Result;
+use skrills_analyze::{check_links, check_urls, Warning};
+use skrills_discovery::{discover_skills, SkillMeta};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Time allowed for each URL probe.
+const URL_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Skills that links to other skills are resolved against: the default
+/// roots plus any extra directories.
+pub(super) fn known_skills(extra_dirs: &[PathBuf]) -> Result<Vec<SkillMeta>> {
+    discover_skills(&skill_roots(extra_dirs)?, None)
+}
+
+/// Probes external URLs with a `HEAD` request, falling back to `GET` for
+/// servers that reject `HEAD`.
+pub(super) struct UrlProbe {
+    client: reqwest::Client,
+    runtime: tokio::runtime::Runtime,
+}
+
+impl UrlProbe {
+    pub(super) fn new() -> Result<Self> {
+        Ok(Self {
+            client: reqwest::Client::builder().timeout(URL_TIMEOUT).build()?,
+            runtime: tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()?,
+        })
+    }
+
+    fn probe(&self, url: &str) -> Result<(), String> {
+        self.runtime.block_on(async {
+            let describe = |e: reqwest::Error| {
+                if e.is_timeout() {
+                    "timed out".to_string()
+                } else if e.is_connect() {
+                    "connection failed".to_string()
+                } else {
+                    e.without_url().to_string()
+                }
+            };
+            let mut resp = self.client.head(url).send().await.map_err(describe)?;
+            if matches!(resp.status().as_u16(), 403 | 405 | 501) {
+                resp = self.client.get(url).send().await.map_err(describe)?;
+            }
+            let status = resp.status();
+            if status.is_client_error() || status.is_server_error() {
+                return Err(format!("HTTP {}", status.as_u16()));
+            }
+            Ok(())
+        })
+    }
+}
+
+/// Link problems of one skill, including unreachable URLs when `urls` is
+/// given.
+pub(super) fn link_warnings(
+    path: &Path,
+    content: &str,
+    skills: &[SkillMeta],
+    urls: Option<&UrlProbe>,
+) -> Vec<Warning> {
+    let mut warnings = check_links(path, content, skills);
+    if let Some(urls) = urls {
+        warnings.extend(check_urls(path, content, |url| urls.probe(url)));
+    }
+    warnings
+}
+
+/// `path:line` of a warning, relative to the skill directory when possible.
+pub(super) fn warning_location(skill_path: &Path, warning: &Warning) -> String {
+    let context = warning.context.as_deref().unwrap_or(skill_path);
+    let shown = skill_path
+        .parent()
+        .and_then(|dir| context.strip_prefix(dir).ok())
+        .unwrap_or(context);
+    match warning.line {
+        Some(line) => format!("{}:{}", shown.display(), line),
+        None => shown.display().to_string(),
+    }
+}
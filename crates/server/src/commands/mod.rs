@@ -3,16 +3,22 @@
 mod agent;
 mod analyze;
 mod cert;
+mod deps;
 mod diff;
 mod intelligence;
+mod links;
 mod metrics;
 mod multi_cli_agent;
+mod plugin;
 mod recommend;
+mod report;
 mod resolve;
 mod serve;
 mod setup;
 mod skill;
 mod sync;
+#[cfg(feature = "watch")]
+mod sync_watch;
 mod validate;
 
 pub(crate) use agent::handle_agent_command;
@@ -21,7 +27,8 @@ pub(crate) use cert::{
     get_cert_status_summary, handle_cert_install_command, handle_cert_renew_command,
     handle_cert_status_command,
 };
-pub(crate) use diff::handle_skill_diff_command;
+pub(crate) use deps::{handle_deps_install_command, handle_deps_verify_command};
+pub(crate) use diff::{handle_instructions_diff_command, handle_skill_diff_command};
 pub(crate) use intelligence::{
     handle_analyze_project_context_command, handle_create_skill_command,
     handle_export_analytics_command, handle_import_analytics_command,
@@ -30,6 +37,10 @@ pub(crate) use intelligence::{
 };
 pub(crate) use metrics::handle_metrics_command;
 pub(crate) use multi_cli_agent::handle_multi_cli_agent_command;
+pub(crate) use plugin::{
+    handle_plugin_install_command, handle_plugin_list_command, handle_plugin_uninstall_command,
+    handle_plugin_upgrade_command,
+};
 pub(crate) use recommend::handle_recommend_command;
 pub(crate) use resolve::handle_resolve_dependencies_command;
 pub(crate) use serve::handle_serve_command;
@@ -37,8 +48,13 @@ pub(crate) use setup::handle_setup_command;
 pub(crate) use skill::{
     handle_pre_commit_validate_command, handle_skill_catalog_command,
     handle_skill_deprecate_command, handle_skill_import_command, handle_skill_profile_command,
-    handle_skill_rollback_command, handle_skill_score_command, handle_skill_usage_report_command,
-    handle_sync_pull_command,
+    handle_skill_release_command, handle_skill_rollback_command, handle_skill_score_command,
+    handle_skill_usage_report_command, handle_sync_pull_command, ReleaseLevel,
+};
+pub(crate) use sync::{
+    handle_mirror_command, handle_sync_agents_command, handle_sync_command,
+    handle_sync_history_command, handle_sync_undo_command,
 };
-pub(crate) use sync::{handle_mirror_command, handle_sync_agents_command, handle_sync_command};
-pub(crate) use validate::handle_validate_command;
+#[cfg(feature = "watch")]
+pub(crate) use sync_watch::handle_sync_watch_command;
+pub(crate) use validate::{handle_lint_rules_command, handle_validate_command};
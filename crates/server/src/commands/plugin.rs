@@ -0,0 +1,177 @@
+//! Plugin management commands.
+//!
+//! Installs plugins from Claude's cache into other CLIs as versioned units
+//! through [`skrills_sync::plugins`].
+
+use crate::cli::{OutputFormat, PluginTarget};
+use anyhow::{bail, Result};
+use serde_json::json;
+use skrills_sync::plugins::{self, InstallReport, PluginRegistry, UninstallReport};
+use skrills_sync::{AgentAdapter, ClaudeAdapter};
+use std::path::PathBuf;
+
+fn claude_root() -> Result<PathBuf> {
+    Ok(ClaudeAdapter::new()?.config_root())
+}
+
+/// Handle `plugin list`.
+pub(crate) fn handle_plugin_list_command(format: OutputFormat) -> Result<()> {
+    let bundles = plugins::list_plugins(&claude_root()?)?;
+    let registry = PluginRegistry::load_default()?;
+
+    if format.is_json() {
+        let entries: Vec<_> = bundles
+            .iter()
+            .map(|b| {
+                json!({
+                    "id": b.id(),
+                    "version": b.origin.version,
+                    "path": b.path,
+                    "skills": b.skills.len(),
+                    "commands": b.commands.len(),
+                    "agents": b.agents.len(),
+                    "hooks": b.hooks.len(),
+                    "installed": registry
+                        .installs()
+                        .iter()
+                        .filter(|i| i.id() == b.id())
+                        .map(|i| json!({"target": i.target, "version": i.version, "files": i.files.len()}))
+                        .collect::<Vec<_>>(),
+                })
+            })
+            .collect();
+        println!("{}", serde_json::to_string_pretty(&entries)?);
+        return Ok(());
+    }
+
+    if bundles.is_empty() {
+        println!("No plugins in Claude's plugin cache");
+        return Ok(());
+    }
+    for b in &bundles {
+        println!(
+            "{} {} (skills {}, commands {}, agents {}, hooks {})",
+            b.id(),
+            b.origin.version,
+            b.skills.len(),
+            b.commands.len(),
+            b.agents.len(),
+            b.hooks.len()
+        );
+        for install in registry.installs().iter().filter(|i| i.id() == b.id()) {
+            let note = if install.version == b.origin.version {
+                String::new()
+            } else {
+                format!(" (upgrade available: {})", b.origin.version)
+            };
+            println!(
+                "  installed in {} at {}{}",
+                install.target, install.version, note
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Handle `plugin install`.
+pub(crate) fn handle_plugin_install_command(name: String, to: Vec<PluginTarget>) -> Result<()> {
+    let bundle = plugins::find_plugin(&claude_root()?, &name)?;
+    let mut registry = PluginRegistry::load_default()?;
+    for target in to {
+        let report = plugins::install(&bundle, target.as_str(), &mut registry)?;
+        print_install(&report);
+    }
+    Ok(())
+}
+
+/// Handle `plugin uninstall`.
+pub(crate) fn handle_plugin_uninstall_command(name: String, from: Vec<PluginTarget>) -> Result<()> {
+    let mut registry = PluginRegistry::load_default()?;
+    let targets = installed_targets(&registry, &name, &from);
+    if targets.is_empty() {
+        bail!("Plugin '{}' is not installed into any CLI", name);
+    }
+    for target in targets {
+        let report = plugins::uninstall(&name, &target, &mut registry)?;
+        print_uninstall(&name, &target, &report);
+    }
+    Ok(())
+}
+
+/// Handle `plugin upgrade`.
+pub(crate) fn handle_plugin_upgrade_command(
+    name: Option<String>,
+    to: Vec<PluginTarget>,
+) -> Result<()> {
+    let root = claude_root()?;
+    let mut registry = PluginRegistry::load_default()?;
+    let mut names: Vec<String> = match name {
+        Some(name) => vec![name],
+        None => registry.installs().iter().map(|i| i.id()).collect(),
+    };
+    names.sort();
+    names.dedup();
+    if names.is_empty() {
+        println!("No plugins installed");
+        return Ok(());
+    }
+
+    for name in names {
+        let bundle = plugins::find_plugin(&root, &name)?;
+        for target in installed_targets(&registry, &bundle.id(), &to) {
+            match plugins::upgrade(&bundle, &target, &mut registry)? {
+                None => println!(
+                    "{} is up to date in {} ({})",
+                    bundle.id(),
+                    target,
+                    bundle.origin.version
+                ),
+                Some((removed, installed)) => {
+                    print_uninstall(&bundle.id(), &target, &removed);
+                    print_install(&installed);
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Targets `name` is installed into, limited to `only` when given.
+fn installed_targets(registry: &PluginRegistry, name: &str, only: &[PluginTarget]) -> Vec<String> {
+    plugins::PLUGIN_TARGETS
+        .iter()
+        .filter(|t| only.is_empty() || only.iter().any(|o| o.as_str() == **t))
+        .filter(|t| registry.get(name, t).is_some())
+        .map(|t| t.to_string())
+        .collect()
+}
+
+fn print_install(report: &InstallReport) {
+    let install = &report.install;
+    println!(
+        "Installed {} {} into {} ({} file(s))",
+        install.id(),
+        install.version,
+        install.target,
+        install.files.len()
+    );
+    for reason in &report.skipped {
+        println!("  skipped: {}", reason.description());
+    }
+    for warning in &report.warnings {
+        println!("  ⚠ {}", warning);
+    }
+}
+
+fn print_uninstall(name: &str, target: &str, report: &UninstallReport) {
+    println!(
+        "Uninstalled {} from {}: {} removed, {} restored",
+        name, target, report.removed, report.restored
+    );
+    for path in &report.kept {
+        println!(
+            "  kept {} (changed since install; remove it by hand if unwanted)",
+            path.display()
+        );
+    }
+}
@@ -0,0 +1,638 @@
+//! SARIF and JUnit reports shared by `validate`, `analyze`, `skill-score`
+//! and `pre-commit-validate`.
+//!
+//! Commands collect one [`Case`] per skill with its [`Finding`]s. SARIF
+//! emits every finding as a result with its rule ID, level and location;
+//! JUnit emits each skill as a test case that fails when it has an error.
+
+use crate::cli::ReportFormat;
+use anyhow::Result;
+use serde_json::{json, Value};
+use skrills_validate::{ValidationIssue, ValidationResult, ValidationTarget};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// A format [`Report`] renders; text and JSON are printed by the commands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum ReportKind {
+    Sarif,
+    Junit,
+}
+
+impl ReportKind {
+    /// The report kind of `format`, or `None` for text and JSON.
+    pub(super) fn of(format: ReportFormat) -> Option<Self> {
+        match format {
+            ReportFormat::Sarif => Some(Self::Sarif),
+            ReportFormat::Junit => Some(Self::Junit),
+            ReportFormat::Text | ReportFormat::Json => None,
+        }
+    }
+}
+
+/// Severity of a finding, using SARIF's level names.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Level {
+    Error,
+    Warning,
+    Note,
+}
+
+impl Level {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Error => "error",
+            Self::Warning => "warning",
+            Self::Note => "note",
+        }
+    }
+}
+
+impl From<skrills_validate::Severity> for Level {
+    fn from(severity: skrills_validate::Severity) -> Self {
+        match severity {
+            skrills_validate::Severity::Error => Self::Error,
+            skrills_validate::Severity::Warning => Self::Warning,
+            _ => Self::Note,
+        }
+    }
+}
+
+impl From<skrills_analyze::WarningLevel> for Level {
+    fn from(level: skrills_analyze::WarningLevel) -> Self {
+        match level {
+            skrills_analyze::WarningLevel::Error => Self::Error,
+            skrills_analyze::WarningLevel::Warning => Self::Warning,
+            skrills_analyze::WarningLevel::Info => Self::Note,
+        }
+    }
+}
+
+/// A rewrite of a whole file that resolves one or more findings.
+#[derive(Debug, Clone)]
+pub(super) struct Fix {
+    pub(super) description: String,
+    pub(super) original: String,
+    pub(super) fixed: String,
+}
+
+/// A fix together with the validation issues it resolves, identified by
+/// target and message.
+#[derive(Debug, Clone)]
+pub(super) struct SuggestedFix {
+    pub(super) fix: Fix,
+    pub(super) resolves: Vec<(ValidationTarget, String)>,
+}
+
+impl SuggestedFix {
+    fn resolves(&self, issue: &ValidationIssue) -> bool {
+        self.resolves
+            .iter()
+            .any(|(target, message)| *target == issue.target && *message == issue.message)
+    }
+}
+
+/// A problem found in a file.
+#[derive(Debug, Clone)]
+pub(super) struct Finding {
+    /// Rule ID; `None` for validation issues raised without a rule.
+    pub(super) rule: Option<String>,
+    pub(super) level: Level,
+    pub(super) message: String,
+    pub(super) path: PathBuf,
+    pub(super) line: Option<usize>,
+    pub(super) suggestion: Option<String>,
+    pub(super) fix: Option<Fix>,
+}
+
+impl Finding {
+    pub(super) fn new(
+        rule: impl Into<String>,
+        level: Level,
+        message: impl Into<String>,
+        path: &Path,
+    ) -> Self {
+        Self {
+            rule: Some(rule.into()),
+            level,
+            message: message.into(),
+            path: path.to_path_buf(),
+            line: None,
+            suggestion: None,
+            fix: None,
+        }
+    }
+}
+
+/// The findings of one skill; a JUnit test case.
+#[derive(Debug)]
+pub(super) struct Case {
+    pub(super) name: String,
+    pub(super) path: PathBuf,
+    pub(super) findings: Vec<Finding>,
+}
+
+impl Case {
+    fn failed(&self) -> bool {
+        self.findings.iter().any(|f| f.level == Level::Error)
+    }
+}
+
+/// Findings of one command run.
+#[derive(Debug)]
+pub(super) struct Report {
+    command: &'static str,
+    descriptions: BTreeMap<String, String>,
+    base: Option<PathBuf>,
+    pub(super) cases: Vec<Case>,
+}
+
+impl Report {
+    /// Creates an empty report for `skrills <command>`, with locations made
+    /// relative to the working directory where possible.
+    pub(super) fn new(command: &'static str) -> Self {
+        let mut report = Self {
+            command,
+            descriptions: BTreeMap::new(),
+            base: std::env::current_dir().ok(),
+            cases: Vec::new(),
+        };
+        report.describe_validation_rules();
+        for (rule, description) in [
+            ("broken-link", "Relative link points to a missing file"),
+            ("broken-anchor", "Link anchor has no matching heading"),
+            (
+                "unknown-skill",
+                "Link refers to a skill that was not discovered",
+            ),
+            (
+                "orphaned-reference",
+                "File in references/ is never linked or mentioned",
+            ),
+            ("unreachable-url", "External URL could not be reached"),
+        ] {
+            report.describe(rule, description);
+        }
+        report
+    }
+
+    /// Records the description SARIF shows for `rule`.
+    pub(super) fn describe(&mut self, rule: impl Into<String>, description: impl Into<String>) {
+        self.descriptions.insert(rule.into(), description.into());
+    }
+
+    /// Adds a test case for the skill at `path`.
+    pub(super) fn add_case(&mut self, name: impl Into<String>, path: &Path) -> &mut Case {
+        self.cases.push(Case {
+            name: name.into(),
+            path: path.to_path_buf(),
+            findings: Vec::new(),
+        });
+        self.cases.last_mut().expect("case was just pushed")
+    }
+
+    /// Describes the lint rules that validation issues map to.
+    fn describe_validation_rules(&mut self) {
+        for rule in skrills_validate::LINT_RULES {
+            self.describe(rule.id, rule.description);
+        }
+    }
+
+    /// Adds a test case with the issues of a validation result, attaching
+    /// `fix` to the issues it resolves.
+    pub(super) fn add_validation(
+        &mut self,
+        result: &ValidationResult,
+        fix: Option<&SuggestedFix>,
+    ) -> &mut Case {
+        let case = self.add_case(&result.name, &result.path);
+        for issue in &result.issues {
+            case.findings.push(Finding {
+                rule: issue.rule.clone(),
+                level: issue.severity.into(),
+                message: issue.message.clone(),
+                path: result.path.clone(),
+                line: issue.line,
+                suggestion: issue.suggestion.clone(),
+                fix: fix.filter(|f| f.resolves(issue)).map(|f| f.fix.clone()),
+            });
+        }
+        case
+    }
+
+    /// Prints the report as `kind`.
+    pub(super) fn print(&self, kind: ReportKind) -> Result<()> {
+        match kind {
+            ReportKind::Sarif => println!("{}", serde_json::to_string_pretty(&self.to_sarif())?),
+            ReportKind::Junit => print!("{}", self.to_junit()),
+        }
+        Ok(())
+    }
+
+    fn relative<'a>(&self, path: &'a Path) -> Option<&'a Path> {
+        self.base
+            .as_deref()
+            .and_then(|base| path.strip_prefix(base).ok())
+            .or_else(|| path.is_relative().then_some(path))
+    }
+
+    fn display(&self, path: &Path) -> String {
+        self.relative(path).unwrap_or(path).display().to_string()
+    }
+
+    fn artifact_location(&self, path: &Path) -> Value {
+        match self.relative(path) {
+            Some(rel) => json!({ "uri": encode_uri(&slashed(rel)), "uriBaseId": "%SRCROOT%" }),
+            None => json!({ "uri": format!("file://{}", encode_uri(&slashed(path))) }),
+        }
+    }
+
+    /// Renders a SARIF 2.1.0 log with one run.
+    pub(super) fn to_sarif(&self) -> Value {
+        let findings: Vec<&Finding> = self.cases.iter().flat_map(|c| &c.findings).collect();
+        let mut rule_ids: Vec<&str> = findings.iter().filter_map(|f| f.rule.as_deref()).collect();
+        rule_ids.sort_unstable();
+        rule_ids.dedup();
+
+        let rules: Vec<Value> = rule_ids
+            .iter()
+            .map(|id| {
+                let description = self.descriptions.get(*id).map_or(*id, String::as_str);
+                json!({ "id": id, "shortDescription": { "text": description } })
+            })
+            .collect();
+
+        let results: Vec<Value> = findings
+            .iter()
+            .map(|finding| {
+                let mut text = finding.message.clone();
+                if let Some(suggestion) = &finding.suggestion {
+                    text.push_str("\nSuggestion: ");
+                    text.push_str(suggestion);
+                }
+                let mut location =
+                    json!({ "artifactLocation": self.artifact_location(&finding.path) });
+                if let Some(line) = finding.line {
+                    location["region"] = json!({ "startLine": line });
+                }
+                let mut result = json!({
+                    "level": finding.level.as_str(),
+                    "message": { "text": text },
+                    "locations": [{ "physicalLocation": location }],
+                });
+                if let Some(rule) = &finding.rule {
+                    result["ruleId"] = json!(rule);
+                    result["ruleIndex"] =
+                        json!(rule_ids.binary_search(&rule.as_str()).unwrap_or_default());
+                }
+                if let Some(fix) = &finding.fix {
+                    if let Some(replacement) = replacement(&fix.original, &fix.fixed) {
+                        result["fixes"] = json!([{
+                            "description": { "text": fix.description },
+                            "artifactChanges": [{
+                                "artifactLocation": self.artifact_location(&finding.path),
+                                "replacements": [replacement],
+                            }],
+                        }]);
+                    }
+                }
+                result
+            })
+            .collect();
+
+        let mut run = json!({
+            "tool": {
+                "driver": {
+                    "name": "skrills",
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "informationUri": env!("CARGO_PKG_REPOSITORY"),
+                    "rules": rules,
+                }
+            },
+            "columnKind": "unicodeCodePoints",
+            "results": results,
+        });
+        if let Some(base) = &self.base {
+            let uri = format!(
+                "file://{}/",
+                encode_uri(slashed(base).trim_end_matches('/'))
+            );
+            run["originalUriBaseIds"] = json!({ "%SRCROOT%": { "uri": uri } });
+        }
+        json!({ "$schema": SARIF_SCHEMA, "version": "2.1.0", "runs": [run] })
+    }
+
+    /// Renders a JUnit XML report with one test case per skill.
+    pub(super) fn to_junit(&self) -> String {
+        let suite = format!("skrills {}", self.command);
+        let classname = format!("skrills.{}", self.command);
+        let failures = self.cases.iter().filter(|c| c.failed()).count();
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        xml.push_str(&format!(
+            "<testsuites name=\"{}\" tests=\"{}\" failures=\"{failures}\" errors=\"0\">\n",
+            xml_escape(&suite),
+            self.cases.len()
+        ));
+        xml.push_str(&format!(
+            "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{failures}\" errors=\"0\" skipped=\"0\">\n",
+            xml_escape(&suite),
+            self.cases.len()
+        ));
+        for case in &self.cases {
+            xml.push_str(&format!(
+                "    <testcase name=\"{}\" classname=\"{}\" file=\"{}\"",
+                xml_escape(&case.name),
+                xml_escape(&classname),
+                xml_escape(&self.display(&case.path))
+            ));
+            if case.findings.is_empty() {
+                xml.push_str("/>\n");
+                continue;
+            }
+            xml.push_str(">\n");
+            let (errors, others): (Vec<&Finding>, Vec<&Finding>) =
+                case.findings.iter().partition(|f| f.level == Level::Error);
+            if let Some(first) = errors.first() {
+                let body: Vec<String> = errors.iter().map(|f| self.describe_finding(f)).collect();
+                xml.push_str(&format!(
+                    "      <failure type=\"{}\" message=\"{}\">{}</failure>\n",
+                    xml_escape(first.rule.as_deref().unwrap_or(first.level.as_str())),
+                    xml_escape(&first.message),
+                    xml_escape(&body.join("\n"))
+                ));
+            }
+            if !others.is_empty() {
+                let body: Vec<String> = others.iter().map(|f| self.describe_finding(f)).collect();
+                xml.push_str(&format!(
+                    "      <system-out>{}</system-out>\n",
+                    xml_escape(&body.join("\n"))
+                ));
+            }
+            xml.push_str("    </testcase>\n");
+        }
+        xml.push_str("  </testsuite>\n</testsuites>\n");
+        xml
+    }
+
+    fn describe_finding(&self, finding: &Finding) -> String {
+        let location = match finding.line {
+            Some(line) => format!("{}:{}", self.display(&finding.path), line),
+            None => self.display(&finding.path),
+        };
+        let mut text = format!("{location}: {}", finding.level.as_str());
+        if let Some(rule) = &finding.rule {
+            text.push_str(&format!(" [{rule}]"));
+        }
+        text.push_str(&format!(" {}", finding.message));
+        if let Some(suggestion) = &finding.suggestion {
+            text.push_str(&format!("\n  Suggestion: {suggestion}"));
+        }
+        text
+    }
+}
+
+/// Converts a `snake_case` kind name to a kebab-case rule ID.
+pub(super) fn rule_id(kind: impl std::fmt::Display) -> String {
+    kind.to_string().replace('_', "-")
+}
+
+fn slashed(path: &Path) -> String {
+    path.to_string_lossy().replace('\\', "/")
+}
+
+/// Percent-encodes the characters that are not allowed in a URI path.
+fn encode_uri(path: &str) -> String {
+    let mut out = String::with_capacity(path.len());
+    for c in path.chars() {
+        match c {
+            'A'..='Z' | 'a'..='z' | '0'..='9' | '-' | '.' | '_' | '~' | '/' | ':' | '@' | '+' => {
+                out.push(c)
+            }
+            _ => {
+                let mut buf = [0; 4];
+                for byte in c.encode_utf8(&mut buf).bytes() {
+                    out.push_str(&format!("%{byte:02X}"));
+                }
+            }
+        }
+    }
+    out
+}
+
+fn xml_escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            // Control characters other than tab and newline are not valid XML.
+            c if c.is_control() && c != '\n' && c != '\t' => {}
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// A SARIF replacement turning `original` into `fixed`, covering the lines
+/// between their common prefix and suffix.
+fn replacement(original: &str, fixed: &str) -> Option<Value> {
+    if original == fixed {
+        return None;
+    }
+    let old: Vec<&str> = original.split_inclusive('\n').collect();
+    let new: Vec<&str> = fixed.split_inclusive('\n').collect();
+    let prefix = old.iter().zip(&new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let deleted_end = old.len() - suffix;
+    let inserted = new[prefix..new.len() - suffix].concat();
+    // The deleted region ends where the first kept line starts, or at the
+    // end of an unterminated last line.
+    let (end_line, end_column) = match old.last() {
+        Some(last) if deleted_end == old.len() && !last.ends_with('\n') => {
+            (old.len(), last.chars().count() + 1)
+        }
+        _ => (deleted_end + 1, 1),
+    };
+    Some(json!({
+        "deletedRegion": {
+            "startLine": prefix + 1,
+            "startColumn": 1,
+            "endLine": end_line,
+            "endColumn": end_column,
+        },
+        "insertedContent": { "text": inserted },
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Report {
+        let mut report = Report::new("validate");
+        report.base = Some(PathBuf::from("/repo"));
+        report.describe("missing-name", "Frontmatter has no `name` field");
+        let path = Path::new("/repo/skills/demo/SKILL.md");
+        let case = report.add_case("demo", path);
+        let mut error = Finding::new(
+            "codex-name",
+            Level::Error,
+            "Missing required 'name' field",
+            path,
+        );
+        error.line = Some(2);
+        error.suggestion = Some("Add 'name: your-skill-name' to frontmatter".into());
+        error.fix = Some(Fix {
+            description: "Added name: demo".into(),
+            original: "---\ndescription: d\n---\nBody\n".into(),
+            fixed: "---\nname: demo\ndescription: d\n---\nBody\n".into(),
+        });
+        case.findings.push(error);
+        case.findings.push(Finding::new(
+            "missing-name",
+            Level::Note,
+            "Frontmatter missing 'name' field",
+            path,
+        ));
+        report.add_case("clean <&>", Path::new("/elsewhere/SKILL.md"));
+        report
+    }
+
+    #[test]
+    fn sarif_maps_rules_levels_locations_and_fixes() {
+        let sarif = sample().to_sarif();
+        let run = &sarif["runs"][0];
+        assert_eq!(sarif["version"], "2.1.0");
+        assert_eq!(run["tool"]["driver"]["rules"][0]["id"], "codex-name");
+        assert_eq!(
+            run["tool"]["driver"]["rules"][1]["shortDescription"]["text"],
+            "Frontmatter has no `name` field"
+        );
+        assert_eq!(
+            run["originalUriBaseIds"]["%SRCROOT%"]["uri"],
+            "file:///repo/"
+        );
+
+        let error = &run["results"][0];
+        assert_eq!(error["ruleId"], "codex-name");
+        assert_eq!(error["ruleIndex"], 0);
+        assert_eq!(error["level"], "error");
+        let location = &error["locations"][0]["physicalLocation"];
+        assert_eq!(location["artifactLocation"]["uri"], "skills/demo/SKILL.md");
+        assert_eq!(location["artifactLocation"]["uriBaseId"], "%SRCROOT%");
+        assert_eq!(location["region"]["startLine"], 2);
+        let change = &error["fixes"][0]["artifactChanges"][0];
+        assert_eq!(
+            change["replacements"][0],
+            json!({
+                "deletedRegion": { "startLine": 2, "startColumn": 1, "endLine": 2, "endColumn": 1 },
+                "insertedContent": { "text": "name: demo\n" },
+            })
+        );
+
+        let note = &run["results"][1];
+        assert_eq!(note["level"], "note");
+        assert_eq!(note["ruleIndex"], 1);
+        assert!(note["locations"][0]["physicalLocation"]
+            .get("region")
+            .is_none());
+        assert!(note.get("fixes").is_none());
+    }
+
+    #[test]
+    fn validation_findings_keep_their_rules_and_fixes() {
+        let path = Path::new("/repo/skills/demo/SKILL.md");
+        let mut result = ValidationResult::new(path.to_path_buf(), "demo".into());
+        result.add_issue(
+            ValidationIssue::error(ValidationTarget::Codex, "Missing required 'name' field")
+                .with_rule("codex-name"),
+        );
+        result.add_issue(ValidationIssue::warning(
+            ValidationTarget::Claude,
+            "No rule",
+        ));
+        let fix = SuggestedFix {
+            fix: Fix {
+                description: "Added name: demo".into(),
+                original: "Body\n".into(),
+                fixed: "---\nname: demo\n---\nBody\n".into(),
+            },
+            resolves: vec![(
+                ValidationTarget::Codex,
+                "Missing required 'name' field".into(),
+            )],
+        };
+        let mut report = Report::new("validate");
+        report.base = Some(PathBuf::from("/repo"));
+        let case = report.add_validation(&result, Some(&fix));
+        assert!(case.findings[0].fix.is_some());
+        assert!(case.findings[1].fix.is_none());
+
+        let sarif = report.to_sarif();
+        let results = &sarif["runs"][0]["results"];
+        assert_eq!(results[0]["ruleId"], "codex-name");
+        assert!(results[0].get("fixes").is_some());
+        assert!(results[1].get("ruleId").is_none());
+        assert!(report
+            .to_junit()
+            .contains("<system-out>skills/demo/SKILL.md: warning No rule</system-out>"));
+    }
+
+    #[test]
+    fn junit_fails_cases_with_errors_and_escapes_text() {
+        let xml = sample().to_junit();
+        assert!(xml.contains(
+            "<testsuite name=\"skrills validate\" tests=\"2\" failures=\"1\" errors=\"0\" skipped=\"0\">"
+        ));
+        assert!(xml.contains(
+            "<failure type=\"codex-name\" message=\"Missing required &apos;name&apos; field\">"
+        ));
+        assert!(xml.contains("skills/demo/SKILL.md:2: error [codex-name]"));
+        assert!(xml.contains("<system-out>skills/demo/SKILL.md: note [missing-name]"));
+        assert!(xml.contains(
+            "<testcase name=\"clean &lt;&amp;&gt;\" classname=\"skrills.validate\" file=\"/elsewhere/SKILL.md\"/>"
+        ));
+    }
+
+    #[test]
+    fn replacement_covers_changed_lines_only() {
+        assert!(replacement("same\n", "same\n").is_none());
+
+        let insert = replacement("Body", "---\nname: x\n---\nBody").unwrap();
+        assert_eq!(
+            insert["deletedRegion"],
+            json!({ "startLine": 1, "startColumn": 1, "endLine": 1, "endColumn": 1 })
+        );
+        assert_eq!(insert["insertedContent"]["text"], "---\nname: x\n---\n");
+
+        let tail = replacement("a\nbé", "a\nc").unwrap();
+        assert_eq!(
+            tail["deletedRegion"],
+            json!({ "startLine": 2, "startColumn": 1, "endLine": 2, "endColumn": 3 })
+        );
+        assert_eq!(tail["insertedContent"]["text"], "c");
+
+        let middle = replacement("a\nb\nc\n", "a\nB\nc\n").unwrap();
+        assert_eq!(middle["deletedRegion"]["startLine"], 2);
+        assert_eq!(middle["deletedRegion"]["endLine"], 3);
+        assert_eq!(middle["insertedContent"]["text"], "B\n");
+    }
+
+    #[test]
+    fn uris_are_percent_encoded() {
+        assert_eq!(
+            encode_uri("my skills/Ünï#1.md"),
+            "my%20skills/%C3%9Cn%C3%AF%231.md"
+        );
+    }
+}
@@ -175,7 +175,7 @@ pub(crate) fn handle_serve_command(
 
     #[cfg(feature = "watch")]
     let _watcher = if watch {
-        Some(start_fs_watcher(&service)?)
+        Some(start_fs_watcher(&service, rt.handle().clone())?)
     } else {
         None
     };
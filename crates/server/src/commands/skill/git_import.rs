@@ -0,0 +1,719 @@
+//! Git-backed skill import.
+//!
+//! Repositories are cloned once into `~/.skrills/cache/git/` and fetched on
+//! later imports, then checked out (detached) at the requested ref. Every
+//! directory holding a `SKILL.md` below the requested subdirectory is a skill;
+//! the other files in that directory are its modules. Each skill is validated
+//! for the target CLI and installed with `origin_*` frontmatter keys recording
+//! where it came from.
+
+use anyhow::{bail, Context, Result};
+use skrills_validate::frontmatter::split_frontmatter;
+use skrills_validate::{parse_frontmatter, validate_skill, ValidationTarget};
+use std::path::{Component, Path, PathBuf};
+use std::process::Command;
+use walkdir::WalkDir;
+
+use crate::cli::SyncSource;
+use crate::registry::sha256_hex;
+use crate::signals::with_default_sigchld;
+
+use super::{escape_yaml_string, GitImportAction, GitImportResult, GitImportedSkill};
+
+/// Frontmatter keys written by git imports; replaced on re-import.
+const ORIGIN_KEYS: [&str; 4] = ["origin_repo", "origin_ref", "origin_commit", "origin_path"];
+
+/// Returns true when `source` should be imported as a git repository.
+///
+/// Remote URLs (`git://`, `ssh://`, `git@host:`, `http(s)://`), `file://`
+/// URLs, `*.git` paths, and local directories that are work trees or bare
+/// repositories all qualify.
+pub(super) fn is_git_source(source: &str) -> bool {
+    const PREFIXES: [&str; 6] = ["git://", "ssh://", "git@", "file://", "http://", "https://"];
+    if PREFIXES.iter().any(|p| source.starts_with(p)) || source.ends_with(".git") {
+        return true;
+    }
+    let path = Path::new(source);
+    path.is_dir()
+        && (path.join(".git").exists()
+            || (path.join("HEAD").is_file() && path.join("objects").is_dir()))
+}
+
+/// Default location of cached clones (`~/.skrills/cache/git`).
+pub(super) fn default_cache_dir(home: &Path) -> PathBuf {
+    home.join(".skrills").join("cache").join("git")
+}
+
+/// Where and how a git import should run.
+pub(super) struct GitImportRequest<'a> {
+    pub source: &'a str,
+    pub git_ref: Option<&'a str>,
+    pub subdir: Option<&'a str>,
+    pub target: SyncSource,
+    pub skills_dir: &'a Path,
+    pub cache_dir: &'a Path,
+    pub force: bool,
+    pub dry_run: bool,
+}
+
+/// Imports every valid skill found in the repository described by `req`.
+pub(super) fn import_from_git(req: &GitImportRequest<'_>) -> Result<GitImportResult> {
+    if let Some(r) = req.git_ref {
+        if r.is_empty() || r.starts_with('-') {
+            bail!("Invalid git ref: {r:?}");
+        }
+    }
+    let origin = normalize_source(req.source);
+    let checkout = req.cache_dir.join(&sha256_hex(origin.as_bytes())[..16]);
+    let commit = update_checkout(&origin, req.git_ref, &checkout)?;
+
+    let root = match req.subdir {
+        Some(sub) => checkout.join(safe_subdir(sub)?),
+        None => checkout.clone(),
+    };
+    if !root.is_dir() {
+        bail!(
+            "Subdirectory '{}' not found in {} at {}",
+            req.subdir.unwrap_or("."),
+            req.source,
+            short(&commit)
+        );
+    }
+
+    let skill_dirs = discover_skill_dirs(&root);
+    if skill_dirs.is_empty() {
+        bail!("No SKILL.md found in {} at {}", req.source, short(&commit));
+    }
+
+    let mut skills = Vec::with_capacity(skill_dirs.len());
+    for dir in &skill_dirs {
+        let repo_path = dir
+            .strip_prefix(&checkout)
+            .ok()
+            .map(|p| p.to_string_lossy().replace('\\', "/"))
+            .filter(|p| !p.is_empty())
+            .unwrap_or_else(|| ".".to_string());
+        let skill_md = dir.join("SKILL.md");
+        let content = std::fs::read_to_string(&skill_md)
+            .with_context(|| format!("Failed to read {}", skill_md.display()))?;
+        let name = skill_name(&content, dir, &checkout, &origin);
+        let target_path = req.skills_dir.join(&name);
+        let modules = module_files(dir);
+
+        let validation = validate_skill(&skill_md, &content, validation_target(req.target));
+        let issues: Vec<String> = validation
+            .issues
+            .iter()
+            .map(|i| format!("{:?}: {}", i.severity, i.message))
+            .collect();
+
+        let installed = std::fs::read_to_string(target_path.join("SKILL.md")).ok();
+        let up_to_date = installed.as_deref().is_some_and(|c| {
+            origin_value(c, "origin_repo").as_deref() == Some(origin.as_str())
+                && origin_value(c, "origin_commit").as_deref() == Some(commit.as_str())
+        });
+
+        let action = if validation.has_errors() {
+            GitImportAction::Invalid
+        } else if up_to_date && !req.force {
+            GitImportAction::UpToDate
+        } else if target_path.exists() && !req.force {
+            GitImportAction::SkippedExisting
+        } else if req.dry_run {
+            GitImportAction::WouldImport
+        } else {
+            let stamped = with_origin(&content, &origin, req.git_ref, &commit, &repo_path);
+            install(dir, &target_path, &stamped, &modules)?;
+            GitImportAction::Imported
+        };
+
+        skills.push(GitImportedSkill {
+            name,
+            repo_path,
+            target_path,
+            action,
+            modules: modules
+                .iter()
+                .map(|m| m.to_string_lossy().replace('\\', "/"))
+                .collect(),
+            issues,
+        });
+    }
+
+    let imported = skills
+        .iter()
+        .filter(|s| {
+            matches!(
+                s.action,
+                GitImportAction::Imported | GitImportAction::WouldImport
+            )
+        })
+        .count();
+
+    Ok(GitImportResult {
+        source: origin,
+        git_ref: req.git_ref.map(str::to_string),
+        subdir: req.subdir.map(str::to_string),
+        message: format!(
+            "{} {} of {} skill(s) from {} at {} into {}",
+            if req.dry_run {
+                "Would import"
+            } else {
+                "Imported"
+            },
+            imported,
+            skills.len(),
+            req.source,
+            short(&commit),
+            req.skills_dir.display()
+        ),
+        commit,
+        target: req.target.as_str().to_string(),
+        skills_imported: imported,
+        dry_run: req.dry_run,
+        skills,
+    })
+}
+
+/// Local repositories are recorded by absolute path so provenance survives a
+/// change of working directory.
+fn normalize_source(source: &str) -> String {
+    let path = Path::new(source);
+    if !source.contains("://") && !source.starts_with("git@") && path.exists() {
+        if let Ok(abs) = path.canonicalize() {
+            return abs.to_string_lossy().to_string();
+        }
+    }
+    source.to_string()
+}
+
+/// Runs git with prompts disabled, returning trimmed stdout.
+fn git(dir: Option<&Path>, args: &[&str]) -> Result<String> {
+    let mut cmd = Command::new("git");
+    if let Some(dir) = dir {
+        cmd.current_dir(dir);
+    }
+    cmd.args(args).env("GIT_TERMINAL_PROMPT", "0");
+    let output = with_default_sigchld(|| cmd.output()).context("Failed to run git")?;
+    if !output.status.success() {
+        bail!(
+            "git {} failed: {}",
+            args.first().copied().unwrap_or_default(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Clones or fetches `origin` into `checkout` and checks out `git_ref`
+/// (default: the remote's HEAD). Returns the checked-out commit.
+fn update_checkout(origin: &str, git_ref: Option<&str>, checkout: &Path) -> Result<String> {
+    if checkout.join(".git").is_dir() {
+        git(Some(checkout), &["remote", "set-url", "origin", origin])?;
+        git(
+            Some(checkout),
+            &["fetch", "--quiet", "--tags", "--force", "--prune", "origin"],
+        )?;
+    } else {
+        if checkout.exists() {
+            std::fs::remove_dir_all(checkout).with_context(|| {
+                format!("Failed to clear stale checkout {}", checkout.display())
+            })?;
+        }
+        if let Some(parent) = checkout.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let dest = checkout.to_string_lossy();
+        git(
+            None,
+            &["clone", "--quiet", "--no-checkout", "--", origin, &dest],
+        )
+        .with_context(|| format!("Failed to clone {origin}"))?;
+    }
+
+    let rev = match resolve_rev(checkout, git_ref) {
+        Some(rev) => rev,
+        None => {
+            let r = git_ref.unwrap_or("HEAD");
+            // Refs outside the default refspec (e.g. refs/pull/1/head).
+            git(Some(checkout), &["fetch", "--quiet", "origin", r])
+                .with_context(|| format!("Ref '{r}' not found in {origin}"))?;
+            "FETCH_HEAD".to_string()
+        }
+    };
+    git(
+        Some(checkout),
+        &["checkout", "--quiet", "--force", "--detach", &rev],
+    )?;
+    git(Some(checkout), &["rev-parse", "HEAD"])
+}
+
+/// Resolves `git_ref` to a commit, preferring remote branches over stale
+/// local ones.
+fn resolve_rev(checkout: &Path, git_ref: Option<&str>) -> Option<String> {
+    let candidates = match git_ref {
+        Some(r) => vec![format!("origin/{r}"), r.to_string()],
+        None => vec!["origin/HEAD".to_string(), "HEAD".to_string()],
+    };
+    candidates.into_iter().find_map(|c| {
+        git(
+            Some(checkout),
+            &[
+                "rev-parse",
+                "--verify",
+                "--quiet",
+                &format!("{c}^{{commit}}"),
+            ],
+        )
+        .ok()
+    })
+}
+
+fn safe_subdir(raw: &str) -> Result<PathBuf> {
+    let path = Path::new(raw.trim_matches('/'));
+    if path
+        .components()
+        .any(|c| !matches!(c, Component::Normal(_) | Component::CurDir))
+    {
+        bail!("Subdirectory must be a relative path inside the repository: {raw}");
+    }
+    Ok(path.to_path_buf())
+}
+
+fn is_hidden(entry: &walkdir::DirEntry) -> bool {
+    entry.depth() > 0 && entry.file_name().to_string_lossy().starts_with('.')
+}
+
+fn is_nested_skill(entry: &walkdir::DirEntry) -> bool {
+    entry.depth() > 0 && entry.file_type().is_dir() && entry.path().join("SKILL.md").is_file()
+}
+
+/// Directories under `root` (inclusive) that contain a SKILL.md.
+fn discover_skill_dirs(root: &Path) -> Vec<PathBuf> {
+    let mut dirs: Vec<PathBuf> = WalkDir::new(root)
+        .into_iter()
+        .filter_entry(|e| !is_hidden(e))
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file() && e.file_name() == "SKILL.md")
+        .filter_map(|e| e.path().parent().map(Path::to_path_buf))
+        .collect();
+    dirs.sort();
+    dirs
+}
+
+/// Files belonging to the skill in `dir`, excluding SKILL.md itself and any
+/// nested skill directories.
+fn module_files(dir: &Path) -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = WalkDir::new(dir)
+        .into_iter()
+        .filter_entry(|e| !is_hidden(e) && !is_nested_skill(e))
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file() && !(e.depth() == 1 && e.file_name() == "SKILL.md"))
+        .filter_map(|e| e.path().strip_prefix(dir).ok().map(Path::to_path_buf))
+        .collect();
+    files.sort();
+    files
+}
+
+/// Skill name from frontmatter, falling back to the directory name (or the
+/// repository name for a skill at the repository root).
+fn skill_name(content: &str, dir: &Path, checkout: &Path, origin: &str) -> String {
+    let is_safe = |n: &str| !n.is_empty() && n != "." && n != ".." && !n.contains(['/', '\\']);
+    if let Some(name) = parse_frontmatter(content)
+        .ok()
+        .and_then(|p| p.frontmatter)
+        .and_then(|f| f.name)
+        .map(|n| n.trim().to_string())
+        .filter(|n| is_safe(n))
+    {
+        return name;
+    }
+    let fallback = if dir == checkout {
+        origin
+            .trim_end_matches('/')
+            .rsplit(['/', ':'])
+            .next()
+            .unwrap_or_default()
+            .trim_end_matches(".git")
+            .to_string()
+    } else {
+        dir.file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default()
+    };
+    if is_safe(&fallback) {
+        fallback
+    } else {
+        "imported-skill".to_string()
+    }
+}
+
+fn validation_target(target: SyncSource) -> ValidationTarget {
+    match target {
+        SyncSource::Claude => ValidationTarget::Claude,
+        SyncSource::Cursor => ValidationTarget::Cursor,
+        SyncSource::Codex => ValidationTarget::Codex,
+        SyncSource::Copilot => ValidationTarget::Copilot,
+    }
+}
+
+fn is_origin_line(line: &str) -> bool {
+    ORIGIN_KEYS
+        .iter()
+        .any(|k| line.strip_prefix(k).is_some_and(|r| r.starts_with(':')))
+}
+
+/// Returns `content` with `origin_*` keys set in its frontmatter.
+fn with_origin(
+    content: &str,
+    origin: &str,
+    git_ref: Option<&str>,
+    commit: &str,
+    repo_path: &str,
+) -> String {
+    let (yaml, body, _) = split_frontmatter(content);
+    let mut out = String::from("---\n");
+    if let Some(ref yaml) = yaml {
+        for line in yaml.lines().filter(|l| !is_origin_line(l)) {
+            out.push_str(line);
+            out.push('\n');
+        }
+    }
+    out.push_str(&format!(
+        "origin_repo: \"{}\"\n",
+        escape_yaml_string(origin)
+    ));
+    if let Some(r) = git_ref {
+        out.push_str(&format!("origin_ref: \"{}\"\n", escape_yaml_string(r)));
+    }
+    out.push_str(&format!("origin_commit: \"{commit}\"\n"));
+    out.push_str(&format!(
+        "origin_path: \"{}\"\n",
+        escape_yaml_string(repo_path)
+    ));
+    out.push_str(if yaml.is_some() { "---\n" } else { "---\n\n" });
+    out.push_str(&body);
+    out
+}
+
+/// Reads an `origin_*` value back from an installed skill's frontmatter.
+pub(super) fn origin_value(content: &str, key: &str) -> Option<String> {
+    let (yaml, _, _) = split_frontmatter(content);
+    yaml?.lines().find_map(|line| {
+        let value = line.strip_prefix(key)?.strip_prefix(':')?.trim();
+        Some(
+            value
+                .trim_matches('"')
+                .replace("\\\"", "\"")
+                .replace("\\\\", "\\"),
+        )
+    })
+}
+
+/// Replaces `target` with the skill from `source_dir`.
+fn install(source_dir: &Path, target: &Path, skill_md: &str, modules: &[PathBuf]) -> Result<()> {
+    if target.exists() {
+        std::fs::remove_dir_all(target)
+            .with_context(|| format!("Failed to replace {}", target.display()))?;
+    }
+    std::fs::create_dir_all(target)
+        .with_context(|| format!("Failed to create {}", target.display()))?;
+    std::fs::write(target.join("SKILL.md"), skill_md)
+        .with_context(|| format!("Failed to write {}", target.join("SKILL.md").display()))?;
+    for module in modules {
+        let dest = target.join(module);
+        if let Some(parent) = dest.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::copy(source_dir.join(module), &dest)
+            .with_context(|| format!("Failed to write {}", dest.display()))?;
+    }
+    Ok(())
+}
+
+fn short(commit: &str) -> &str {
+    &commit[..commit.len().min(12)]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use skrills_test_utils::TestFixture;
+    use tempfile::tempdir;
+
+    /// A throwaway repository with committer identity set per invocation.
+    struct Repo {
+        dir: tempfile::TempDir,
+    }
+
+    impl Repo {
+        fn new() -> Self {
+            let dir = tempdir().unwrap();
+            run_git(dir.path(), &["init", "--quiet", "-b", "main"]);
+            Self { dir }
+        }
+
+        fn path(&self) -> &Path {
+            self.dir.path()
+        }
+
+        fn write(&self, rel: &str, content: &str) {
+            let path = self.path().join(rel);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, content).unwrap();
+        }
+
+        fn commit(&self, message: &str) -> String {
+            run_git(self.path(), &["add", "-A"]);
+            run_git(
+                self.path(),
+                &[
+                    "-c",
+                    "user.name=test",
+                    "-c",
+                    "user.email=test@example.com",
+                    "commit",
+                    "--quiet",
+                    "-m",
+                    message,
+                ],
+            );
+            git(Some(self.path()), &["rev-parse", "HEAD"]).unwrap()
+        }
+    }
+
+    fn run_git(dir: &Path, args: &[&str]) {
+        git(Some(dir), args).unwrap();
+    }
+
+    fn import(
+        fx: &TestFixture,
+        source: &str,
+        git_ref: Option<&str>,
+        subdir: Option<&str>,
+        force: bool,
+        dry_run: bool,
+    ) -> Result<GitImportResult> {
+        import_from_git(&GitImportRequest {
+            source,
+            git_ref,
+            subdir,
+            target: SyncSource::Claude,
+            skills_dir: &fx.claude_skills,
+            cache_dir: &default_cache_dir(fx.home_path()),
+            force,
+            dry_run,
+        })
+    }
+
+    const SKILL: &str = "---\nname: alpha\ndescription: First skill\n---\n# Alpha\n";
+
+    #[test]
+    fn detects_git_sources() {
+        assert!(is_git_source("https://github.com/org/skills"));
+        assert!(is_git_source("git@github.com:org/skills.git"));
+        assert!(is_git_source("file:///srv/skills.git"));
+        assert!(is_git_source("../skills.git"));
+        assert!(!is_git_source("/nonexistent/skill.md"));
+
+        let repo = Repo::new();
+        assert!(is_git_source(&repo.path().to_string_lossy()));
+    }
+
+    #[test]
+    fn imports_every_skill_with_modules_and_origin() {
+        let repo = Repo::new();
+        repo.write("skills/alpha/SKILL.md", SKILL);
+        repo.write("skills/alpha/modules/extra.md", "# Extra");
+        repo.write(
+            "skills/beta/SKILL.md",
+            "---\nname: beta\ndescription: Second\n---\n# Beta\n",
+        );
+        repo.write("README.md", "not a skill");
+        let commit = repo.commit("add skills");
+
+        let fx = TestFixture::new().unwrap();
+        let result = import(
+            &fx,
+            &format!("file://{}", repo.path().display()),
+            None,
+            None,
+            false,
+            false,
+        )
+        .unwrap();
+
+        assert_eq!(result.commit, commit);
+        assert_eq!(result.skills_imported, 2);
+        let alpha = &result.skills[0];
+        assert_eq!(alpha.name, "alpha");
+        assert_eq!(alpha.repo_path, "skills/alpha");
+        assert_eq!(alpha.modules, vec!["modules/extra.md"]);
+
+        let installed = std::fs::read_to_string(fx.claude_skills.join("alpha/SKILL.md")).unwrap();
+        assert_eq!(origin_value(&installed, "origin_commit").unwrap(), commit);
+        assert_eq!(
+            origin_value(&installed, "origin_repo").unwrap(),
+            result.source
+        );
+        assert_eq!(
+            origin_value(&installed, "origin_path").unwrap(),
+            "skills/alpha"
+        );
+        assert!(installed.contains("name: alpha"));
+        assert!(installed.ends_with("# Alpha\n"));
+        assert!(fx.claude_skills.join("alpha/modules/extra.md").exists());
+        assert!(fx.claude_skills.join("beta/SKILL.md").exists());
+    }
+
+    #[test]
+    fn imports_from_bare_repo_at_ref_and_subdir() {
+        let repo = Repo::new();
+        repo.write("a/alpha/SKILL.md", SKILL);
+        let first = repo.commit("v1");
+        run_git(repo.path(), &["tag", "v1"]);
+        repo.write(
+            "a/alpha/SKILL.md",
+            "---\nname: alpha\ndescription: v2\n---\n# v2\n",
+        );
+        repo.write(
+            "b/gamma/SKILL.md",
+            "---\nname: gamma\ndescription: G\n---\n# G\n",
+        );
+        repo.commit("v2");
+
+        let bare = tempdir().unwrap();
+        let bare_path = bare.path().join("skills.git");
+        git(
+            None,
+            &[
+                "clone",
+                "--quiet",
+                "--bare",
+                &repo.path().to_string_lossy(),
+                &bare_path.to_string_lossy(),
+            ],
+        )
+        .unwrap();
+
+        let fx = TestFixture::new().unwrap();
+        let result = import(
+            &fx,
+            &bare_path.to_string_lossy(),
+            Some("v1"),
+            Some("a"),
+            false,
+            false,
+        )
+        .unwrap();
+        assert_eq!(result.commit, first);
+        assert_eq!(result.skills.len(), 1);
+        let installed = std::fs::read_to_string(fx.claude_skills.join("alpha/SKILL.md")).unwrap();
+        assert!(installed.contains("# Alpha"));
+        assert_eq!(origin_value(&installed, "origin_ref").unwrap(), "v1");
+        assert!(!fx.claude_skills.join("gamma").exists());
+
+        // Re-importing at the same commit is a no-op; a new ref needs --force.
+        let again = import(
+            &fx,
+            &bare_path.to_string_lossy(),
+            Some("v1"),
+            Some("a"),
+            false,
+            false,
+        )
+        .unwrap();
+        assert_eq!(again.skills[0].action, GitImportAction::UpToDate);
+
+        let newer = import(
+            &fx,
+            &bare_path.to_string_lossy(),
+            Some("main"),
+            Some("a"),
+            false,
+            false,
+        )
+        .unwrap();
+        assert_eq!(newer.skills[0].action, GitImportAction::SkippedExisting);
+
+        let forced = import(
+            &fx,
+            &bare_path.to_string_lossy(),
+            Some("main"),
+            Some("a"),
+            true,
+            false,
+        )
+        .unwrap();
+        assert_eq!(forced.skills[0].action, GitImportAction::Imported);
+        let installed = std::fs::read_to_string(fx.claude_skills.join("alpha/SKILL.md")).unwrap();
+        assert!(installed.contains("# v2"));
+        assert_eq!(origin_value(&installed, "origin_ref").unwrap(), "main");
+    }
+
+    #[test]
+    fn invalid_skills_are_not_installed() {
+        let repo = Repo::new();
+        repo.write("good/SKILL.md", SKILL);
+        repo.write("bad/SKILL.md", "---\nname: [unclosed\n---\n# Bad\n");
+        repo.commit("mixed");
+
+        let fx = TestFixture::new().unwrap();
+        let result = import(
+            &fx,
+            &repo.path().to_string_lossy(),
+            None,
+            None,
+            false,
+            false,
+        )
+        .unwrap();
+        let bad = result.skills.iter().find(|s| s.repo_path == "bad").unwrap();
+        assert_eq!(bad.action, GitImportAction::Invalid);
+        assert!(!bad.issues.is_empty());
+        assert!(!fx.claude_skills.join("bad").exists());
+        assert!(fx.claude_skills.join("alpha/SKILL.md").exists());
+    }
+
+    #[test]
+    fn dry_run_writes_nothing() {
+        let repo = Repo::new();
+        repo.write("SKILL.md", SKILL);
+        repo.commit("root skill");
+
+        let fx = TestFixture::new().unwrap();
+        let result = import(&fx, &repo.path().to_string_lossy(), None, None, false, true).unwrap();
+        assert_eq!(result.skills[0].action, GitImportAction::WouldImport);
+        assert_eq!(result.skills[0].repo_path, ".");
+        assert_eq!(std::fs::read_dir(&fx.claude_skills).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn rejects_unknown_ref_and_escaping_subdir() {
+        let repo = Repo::new();
+        repo.write("SKILL.md", SKILL);
+        repo.commit("root skill");
+        let fx = TestFixture::new().unwrap();
+        let source = repo.path().to_string_lossy().to_string();
+
+        assert!(import(&fx, &source, Some("nope"), None, false, false).is_err());
+        assert!(import(&fx, &source, Some("--upload-pack=x"), None, false, false).is_err());
+        assert!(import(&fx, &source, None, Some("../etc"), false, false).is_err());
+    }
+
+    #[test]
+    fn with_origin_replaces_previous_keys() {
+        let once = with_origin(SKILL, "https://a/repo.git", None, "abc", ".");
+        let twice = with_origin(&once, "https://b/repo.git", Some("main"), "def", "x");
+        assert_eq!(twice.matches("origin_repo:").count(), 1);
+        assert_eq!(
+            origin_value(&twice, "origin_repo").unwrap(),
+            "https://b/repo.git"
+        );
+        assert_eq!(origin_value(&twice, "origin_commit").unwrap(), "def");
+
+        let bare = with_origin("# No frontmatter\n", "r", None, "abc", ".");
+        assert!(bare.starts_with("---\norigin_repo: \"r\"\n"));
+        assert!(bare.ends_with("---\n\n# No frontmatter\n"));
+    }
+}
@@ -3,11 +3,14 @@ use std::path::PathBuf;
 
 use crate::cli::{OutputFormat, SyncSource};
 
-use super::ImportResult;
+use super::git_import::{default_cache_dir, import_from_git, is_git_source, GitImportRequest};
+use super::{target_skills_dir, GitImportAction, ImportResult};
 
 /// Handle the skill-import command.
 pub(crate) fn handle_skill_import_command(
     source: String,
+    git_ref: Option<String>,
+    subdir: Option<String>,
     target: SyncSource,
     force: bool,
     dry_run: bool,
@@ -15,12 +18,46 @@ pub(crate) fn handle_skill_import_command(
 ) -> Result<()> {
     let home = dirs::home_dir().with_context(|| "Could not determine home directory")?;
 
-    let target_dir = match target {
-        SyncSource::Claude => home.join(".claude/skills"),
-        SyncSource::Codex => home.join(".codex/skills"),
-        SyncSource::Copilot => home.join(".copilot/skills"),
-        SyncSource::Cursor => home.join(".cursor/skills"),
-    };
+    let target_dir = target_skills_dir(&home, target);
+
+    if git_ref.is_some() || subdir.is_some() || is_git_source(&source) {
+        let result = import_from_git(&GitImportRequest {
+            source: &source,
+            git_ref: git_ref.as_deref(),
+            subdir: subdir.as_deref(),
+            target,
+            skills_dir: &target_dir,
+            cache_dir: &default_cache_dir(&home),
+            force,
+            dry_run,
+        })?;
+
+        if format.is_json() {
+            println!("{}", serde_json::to_string_pretty(&result)?);
+        } else {
+            for s in &result.skills {
+                let label = match s.action {
+                    GitImportAction::Imported => "imported",
+                    GitImportAction::WouldImport => "would import",
+                    GitImportAction::UpToDate => "up to date",
+                    GitImportAction::SkippedExisting => "skipped (already installed; use --force)",
+                    GitImportAction::Invalid => "invalid",
+                };
+                println!("  {} ({}) - {}", s.name, s.repo_path, label);
+                if s.action == GitImportAction::Invalid {
+                    for issue in &s.issues {
+                        println!("      {}", issue);
+                    }
+                }
+            }
+            if dry_run {
+                println!("[dry-run] {}", result.message);
+            } else {
+                println!("{}", result.message);
+            }
+        }
+        return Ok(());
+    }
 
     if !dry_run {
         std::fs::create_dir_all(&target_dir).with_context(|| {
@@ -31,29 +68,22 @@ pub(crate) fn handle_skill_import_command(
         })?;
     }
 
-    let (skill_content, skill_name) =
-        if source.starts_with("http://") || source.starts_with("https://") {
-            bail!(
-            "URL imports require the 'http-transport' feature. Use a local path or git URL instead."
-        );
-        } else if source.starts_with("git://") || source.ends_with(".git") {
-            bail!("Git imports not yet implemented. Clone the repo manually and use a local path.");
-        } else {
-            let path = PathBuf::from(&source);
-            if !path.exists() {
-                bail!("Source path does not exist: {}", source);
-            }
+    let (skill_content, skill_name) = {
+        let path = PathBuf::from(&source);
+        if !path.exists() {
+            bail!("Source path does not exist: {}", source);
+        }
 
-            let content = std::fs::read_to_string(&path)
-                .with_context(|| format!("Failed to read source file: {}", source))?;
+        let content = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read source file: {}", source))?;
 
-            let name = path
-                .file_stem()
-                .map(|s| s.to_string_lossy().to_string())
-                .unwrap_or_else(|| "imported-skill".to_string());
+        let name = path
+            .file_stem()
+            .map(|s| s.to_string_lossy().to_string())
+            .unwrap_or_else(|| "imported-skill".to_string());
 
-            (content, name)
-        };
+        (content, name)
+    };
 
     let target_path = target_dir.join(format!("{}.md", skill_name));
 
@@ -141,6 +171,8 @@ mod tests {
 
         let result = handle_skill_import_command(
             source_path.to_string_lossy().to_string(),
+            None,
+            None,
             SyncSource::Claude,
             false,
             false,
@@ -165,6 +197,8 @@ mod tests {
 
         let result = handle_skill_import_command(
             source_path.to_string_lossy().to_string(),
+            None,
+            None,
             SyncSource::Claude,
             false,
             true,
@@ -192,6 +226,8 @@ mod tests {
 
         let result = handle_skill_import_command(
             source_path.to_string_lossy().to_string(),
+            None,
+            None,
             SyncSource::Claude,
             true,
             false,
@@ -219,6 +255,8 @@ mod tests {
 
         let result = handle_skill_import_command(
             source_path.to_string_lossy().to_string(),
+            None,
+            None,
             SyncSource::Claude,
             false,
             false,
@@ -239,6 +277,8 @@ mod tests {
 
         let result = handle_skill_import_command(
             "/nonexistent/path/skill.md".to_string(),
+            None,
+            None,
             SyncSource::Claude,
             false,
             false,
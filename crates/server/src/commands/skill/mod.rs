@@ -1,13 +1,15 @@
 //! Skill management command handlers.
 //!
-//! Commands for deprecating, rolling back, profiling, cataloging, importing,
-//! scoring, and generating usage reports for skills.
+//! Commands for deprecating, rolling back, releasing, profiling, cataloging,
+//! importing, scoring, and generating usage reports for skills.
 
 mod catalog;
 mod deprecation;
+mod git_import;
 mod import;
 mod pre_commit;
 mod profiling;
+mod release;
 mod rollback;
 mod scoring;
 mod sync_pull;
@@ -18,11 +20,13 @@ pub(crate) use deprecation::handle_skill_deprecate_command;
 pub(crate) use import::handle_skill_import_command;
 pub(crate) use pre_commit::handle_pre_commit_validate_command;
 pub(crate) use profiling::handle_skill_profile_command;
+pub(crate) use release::handle_skill_release_command;
 pub(crate) use rollback::handle_skill_rollback_command;
 pub(crate) use scoring::handle_skill_score_command;
 pub(crate) use sync_pull::handle_sync_pull_command;
 pub(crate) use usage_report::handle_skill_usage_report_command;
 
+use crate::cli::SyncSource;
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
@@ -106,6 +110,54 @@ pub struct ImportResult {
     pub message: String,
 }
 
+/// What skill-import did with one skill found in a git repository.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum GitImportAction {
+    /// Validated and installed.
+    Imported,
+    /// Would be installed (dry run).
+    WouldImport,
+    /// Installed copy already records this repository and commit.
+    UpToDate,
+    /// A skill with the same name is already installed; left alone.
+    SkippedExisting,
+    /// Failed validation for the target CLI; not installed.
+    Invalid,
+}
+
+/// Per-skill outcome of a git import.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GitImportedSkill {
+    pub name: String,
+    /// Skill directory relative to the repository root (`.` for the root).
+    pub repo_path: String,
+    pub target_path: PathBuf,
+    pub action: GitImportAction,
+    /// Module files installed alongside SKILL.md, relative to the skill directory.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub modules: Vec<String>,
+    /// Validation errors (for `invalid` skills) and warnings.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub issues: Vec<String>,
+}
+
+/// Result of importing skills from a git repository.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GitImportResult {
+    pub source: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub git_ref: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subdir: Option<String>,
+    pub commit: String,
+    pub target: String,
+    pub skills_imported: usize,
+    pub dry_run: bool,
+    pub message: String,
+    pub skills: Vec<GitImportedSkill>,
+}
+
 /// Skill usage statistics for reports.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UsageStats {
@@ -143,6 +195,29 @@ pub struct SkillScoreResult {
     pub suggestions: Vec<String>,
 }
 
+/// What sync-pull did with one registry skill.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum PullAction {
+    /// Downloaded, verified, and installed.
+    Pulled,
+    /// Would be installed (dry run).
+    WouldPull,
+    /// Installed copy already matches the registry.
+    UpToDate,
+    /// A local copy exists that was not pulled (or was edited); left alone.
+    SkippedLocal,
+}
+
+/// Per-skill outcome of a sync-pull.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PulledSkill {
+    pub name: String,
+    pub version: String,
+    pub action: PullAction,
+    pub path: PathBuf,
+}
+
 /// Result of sync-pull operation.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct SyncPullResult {
@@ -151,6 +226,76 @@ pub struct SyncPullResult {
     pub skills_pulled: usize,
     pub dry_run: bool,
     pub message: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub skills: Vec<PulledSkill>,
+}
+
+/// Semver component a skill release bumps; ordered by severity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ReleaseLevel {
+    Patch,
+    Minor,
+    Major,
+}
+
+impl ReleaseLevel {
+    /// Level requested by `--major`/`--minor`/`--patch`, if any.
+    pub(crate) fn from_flags(major: bool, minor: bool, patch: bool) -> Option<Self> {
+        if major {
+            Some(Self::Major)
+        } else if minor {
+            Some(Self::Minor)
+        } else if patch {
+            Some(Self::Patch)
+        } else {
+            None
+        }
+    }
+}
+
+/// One change found between a skill and its last release.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReleaseChange {
+    pub level: ReleaseLevel,
+    pub description: String,
+}
+
+/// A dependent whose `depends` constraint the new version does not satisfy.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BrokenDependent {
+    pub name: String,
+    pub requirement: String,
+}
+
+/// Result of skill-release.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ReleaseResult {
+    pub skill_name: String,
+    pub skill_path: PathBuf,
+    pub previous_tag: Option<String>,
+    pub previous_version: Option<String>,
+    pub version: String,
+    /// Level implied by `changes`; `None` for a first release.
+    pub detected_level: Option<ReleaseLevel>,
+    pub changes: Vec<ReleaseChange>,
+    /// Skills that depend on this one, directly or transitively.
+    pub dependents: Vec<String>,
+    pub broken_dependents: Vec<BrokenDependent>,
+    pub tag: Option<String>,
+    pub dry_run: bool,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+}
+
+/// Skills directory of a sync target under `home`.
+pub(super) fn target_skills_dir(home: &std::path::Path, target: SyncSource) -> PathBuf {
+    match target {
+        SyncSource::Claude => home.join(".claude/skills"),
+        SyncSource::Codex => home.join(".codex/skills"),
+        SyncSource::Copilot => home.join(".copilot/skills"),
+        SyncSource::Cursor => home.join(".cursor/skills"),
+    }
 }
 
 #[cfg(test)]
@@ -350,16 +495,15 @@ mod tests {
     }
 
     #[test]
-    fn import_result_url_source_error() {
-        // URL imports should produce an error
-        let source = "https://example.com/skill.md";
-        assert!(source.starts_with("http://") || source.starts_with("https://"));
+    fn import_url_source_is_treated_as_git() {
+        // URL imports are cloned rather than rejected
+        assert!(git_import::is_git_source("https://example.com/org/skills"));
     }
 
     #[test]
-    fn import_result_git_source_error() {
-        let source = "git://github.com/repo.git";
-        assert!(source.starts_with("git://") || source.ends_with(".git"));
+    fn import_git_source_is_detected() {
+        assert!(git_import::is_git_source("git://github.com/repo.git"));
+        assert!(!git_import::is_git_source("/path/to/source.md"));
     }
 
     #[test]
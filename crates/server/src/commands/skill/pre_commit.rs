@@ -2,7 +2,9 @@ use anyhow::{bail, Context, Result};
 use skrills_discovery::{discover_skills, extra_skill_roots};
 use std::path::PathBuf;
 
-use crate::cli::ValidationTarget;
+use crate::cli::{ReportFormat, ValidationTarget};
+use crate::commands::report::{Finding, Level, Report, ReportKind};
+use crate::config::load_lint_config;
 use crate::discovery::merge_extra_dirs;
 
 /// Handle the pre-commit-validate command.
@@ -10,8 +12,9 @@ pub(crate) fn handle_pre_commit_validate_command(
     staged: bool,
     target: ValidationTarget,
     skill_dirs: Vec<PathBuf>,
+    format: ReportFormat,
 ) -> Result<()> {
-    use skrills_validate::{validate_skill, ValidationTarget as VT};
+    use skrills_validate::{validate_skill_with, ValidationTarget as VT};
     use std::process::Command;
 
     let extra_dirs = merge_extra_dirs(&skill_dirs);
@@ -21,6 +24,7 @@ pub(crate) fn handle_pre_commit_validate_command(
         ValidationTarget::Claude => VT::Claude,
         ValidationTarget::Codex => VT::Codex,
         ValidationTarget::Copilot => VT::Copilot,
+        ValidationTarget::Cursor => VT::Cursor,
         ValidationTarget::All => VT::All,
         ValidationTarget::Both => VT::Both,
     };
@@ -51,12 +55,19 @@ pub(crate) fn handle_pre_commit_validate_command(
     };
 
     if skill_files.is_empty() {
-        println!("No skill files to validate.");
+        match ReportKind::of(format) {
+            Some(kind) => Report::new("pre-commit-validate").print(kind)?,
+            None if format.is_json() => println!("[]"),
+            None => println!("No skill files to validate."),
+        }
         return Ok(());
     }
 
+    let lint = load_lint_config()?;
     let mut errors_found = false;
     let mut validated = 0;
+    let mut results = Vec::new();
+    let mut report = Report::new("pre-commit-validate");
 
     for path in &skill_files {
         if !path.exists() {
@@ -67,19 +78,40 @@ pub(crate) fn handle_pre_commit_validate_command(
             Ok(c) => c,
             Err(e) => {
                 errors_found = true;
-                eprintln!("✗ {} (read error: {})", path.display(), e);
+                if format.is_text() {
+                    eprintln!("✗ {} (read error: {})", path.display(), e);
+                }
+                report
+                    .add_case(path.display().to_string(), path)
+                    .findings
+                    .push(Finding::new(
+                        "file-read-failed",
+                        Level::Error,
+                        format!("Could not read skill: {e}"),
+                        path,
+                    ));
                 continue;
             }
         };
 
-        let result = validate_skill(path, &content, validation_target);
+        let result = validate_skill_with(path, &content, validation_target, &lint);
+
+        if !format.is_text() {
+            errors_found |= result.has_errors();
+            report.add_validation(&result, None);
+            results.push(result);
+            continue;
+        }
 
         if result.has_errors() {
             errors_found = true;
             eprintln!("✗ {}", path.display());
             for issue in &result.issues {
                 if issue.severity == skrills_validate::Severity::Error {
-                    eprintln!("  - {}", issue.message);
+                    match &issue.rule {
+                        Some(rule) => eprintln!("  - {} [{}]", issue.message, rule),
+                        None => eprintln!("  - {}", issue.message),
+                    }
                 }
             }
         } else {
@@ -87,6 +119,15 @@ pub(crate) fn handle_pre_commit_validate_command(
         }
     }
 
+    match ReportKind::of(format) {
+        Some(kind) => report.print(kind)?,
+        None if format.is_json() => println!("{}", serde_json::to_string_pretty(&results)?),
+        None => {}
+    }
+    if errors_found && !format.is_text() {
+        std::process::exit(1);
+    }
+
     if errors_found {
         eprintln!();
         eprintln!("Validation failed. Fix errors before committing.");
@@ -188,9 +229,10 @@ mod tests {
             ValidationTarget::Claude,
             ValidationTarget::Codex,
             ValidationTarget::Copilot,
+            ValidationTarget::Cursor,
             ValidationTarget::All,
             ValidationTarget::Both,
         ];
-        assert_eq!(targets.len(), 5);
+        assert_eq!(targets.len(), 6);
     }
 }
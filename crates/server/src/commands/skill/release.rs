@@ -0,0 +1,633 @@
+use anyhow::{anyhow, bail, Context, Result};
+use semver::Version;
+use skrills_analyze::RelationshipGraph;
+use skrills_discovery::{discover_skills, SkillMeta};
+use skrills_sync::sections::{diff_sections, SectionStatus};
+use skrills_validate::frontmatter::parse_frontmatter;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::cli::OutputFormat;
+use crate::deps::skill_name;
+use crate::discovery::{merge_extra_dirs, skill_roots};
+use crate::signals::with_default_sigchld;
+
+use super::{BrokenDependent, ReleaseChange, ReleaseLevel, ReleaseResult};
+
+/// Changelog kept in the skill directory.
+const CHANGELOG_FILE: &str = "CHANGELOG.md";
+
+/// Frontmatter field holding the skill version.
+const VERSION_FIELD: &str = "version";
+
+/// Handle the skill-release command.
+pub(crate) fn handle_skill_release_command(
+    name: String,
+    level: Option<ReleaseLevel>,
+    dry_run: bool,
+    no_tag: bool,
+    skill_dirs: Vec<PathBuf>,
+    format: OutputFormat,
+) -> Result<()> {
+    let skills = discover_skills(&skill_roots(&merge_extra_dirs(&skill_dirs))?, None)?;
+    let skill = skills
+        .iter()
+        .find(|s| skill_name(s) == name)
+        .with_context(|| format!("Skill '{}' not found in discovered skills", name))?;
+
+    let result = release_skill(skill, &skills, level, dry_run, no_tag)?;
+
+    if format.is_json() {
+        println!("{}", serde_json::to_string_pretty(&result)?);
+        return Ok(());
+    }
+    println!(
+        "{}{} {} -> {}{}",
+        if dry_run { "[dry-run] " } else { "" },
+        result.skill_name,
+        result.previous_version.as_deref().unwrap_or("unreleased"),
+        result.version,
+        result
+            .detected_level
+            .map(|l| format!(" (detected {})", level_name(l)))
+            .unwrap_or_default()
+    );
+    for change in &result.changes {
+        println!("  [{}] {}", level_name(change.level), change.description);
+    }
+    for warning in &result.warnings {
+        println!("  ⚠ {}", warning);
+    }
+    if !result.dependents.is_empty() {
+        println!("Dependents: {}", result.dependents.join(", "));
+    }
+    for broken in &result.broken_dependents {
+        println!(
+            "  ✗ {} requires {}, which {} does not satisfy",
+            broken.name, broken.requirement, result.version
+        );
+    }
+    if let Some(tag) = &result.tag {
+        println!("Tagged {}", tag);
+    }
+    Ok(())
+}
+
+/// Classifies the changes since the last release of `skill`, bumps its
+/// version, updates its changelog and, unless `no_tag`, commits the skill
+/// directory and tags the commit `<name>-v<version>`.
+fn release_skill(
+    skill: &SkillMeta,
+    skills: &[SkillMeta],
+    level: Option<ReleaseLevel>,
+    dry_run: bool,
+    no_tag: bool,
+) -> Result<ReleaseResult> {
+    let name = skill_name(skill);
+    let dir = skill
+        .path
+        .parent()
+        .with_context(|| "Skill has no parent directory")?;
+    let file_name = skill
+        .path
+        .file_name()
+        .map(|f| f.to_string_lossy().into_owned())
+        .with_context(|| "Skill path has no file name")?;
+    let content = std::fs::read_to_string(&skill.path)
+        .with_context(|| format!("Failed to read skill file: {}", skill.path.display()))?;
+    let current = parse_frontmatter(&content)
+        .map_err(|e| anyhow!(e))?
+        .frontmatter
+        .and_then(|fm| fm.parsed_version())
+        .transpose()
+        .with_context(|| format!("Invalid version in {}", skill.path.display()))?;
+
+    let previous = last_release(dir, &name)?;
+    let mut warnings = Vec::new();
+    let (changes, detected_level, version) = match &previous {
+        Some((tag, released)) => {
+            let old = git(dir, &["show", &format!("{tag}:./{file_name}")])?;
+            let mut changes = classify_document(&old, &content)?;
+            changes.extend(classify_files(dir, tag, &file_name)?);
+            changes.sort_by_key(|c| std::cmp::Reverse(c.level));
+            let detected = changes.iter().map(|c| c.level).max();
+            let bump = match (level, detected) {
+                (Some(requested), Some(detected)) if requested < detected => {
+                    warnings.push(format!(
+                        "Releasing as {} although a {} change was detected",
+                        level_name(requested),
+                        level_name(detected)
+                    ));
+                    requested
+                }
+                (Some(requested), _) => requested,
+                (None, Some(detected)) => detected,
+                (None, None) => bail!("No changes to '{}' since {}", name, tag),
+            };
+            (changes, detected, bump_version(released, bump))
+        }
+        None => {
+            let version = match (level, current.clone()) {
+                (Some(requested), current) => {
+                    bump_version(&current.unwrap_or(Version::new(0, 0, 0)), requested)
+                }
+                (None, Some(current)) => current,
+                (None, None) => Version::new(0, 1, 0),
+            };
+            (Vec::new(), None, version)
+        }
+    };
+
+    let tag = release_tag(&name, &version);
+    if git(dir, &["tag", "--list", &tag])?.trim() == tag {
+        bail!("Tag {} already exists", tag);
+    }
+    let (dependents, broken_dependents) = dependents_of(&name, &version, skills);
+
+    if !dry_run {
+        std::fs::write(
+            &skill.path,
+            set_version(&content, &version.to_string(), &name),
+        )
+        .with_context(|| format!("Failed to write skill file: {}", skill.path.display()))?;
+        let changelog_path = dir.join(CHANGELOG_FILE);
+        let existing = std::fs::read_to_string(&changelog_path).ok();
+        std::fs::write(
+            &changelog_path,
+            prepend_changelog(existing.as_deref(), &version, &today(), &changes),
+        )
+        .with_context(|| format!("Failed to write {}", changelog_path.display()))?;
+
+        if !no_tag {
+            let message = format!("Release {} {}", name, version);
+            git(dir, &["add", "-A", "--", "."])?;
+            git(dir, &["commit", "-m", &message, "--", "."])?;
+            git(dir, &["tag", "-a", &tag, "-m", &message])?;
+        }
+    }
+
+    Ok(ReleaseResult {
+        skill_name: name,
+        skill_path: skill.path.clone(),
+        previous_tag: previous.as_ref().map(|(tag, _)| tag.clone()),
+        previous_version: previous
+            .as_ref()
+            .map(|(_, v)| v.to_string())
+            .or_else(|| current.map(|v| v.to_string())),
+        version: version.to_string(),
+        detected_level,
+        changes,
+        dependents,
+        broken_dependents,
+        tag: (!dry_run && !no_tag).then_some(tag),
+        dry_run,
+        warnings,
+    })
+}
+
+fn level_name(level: ReleaseLevel) -> &'static str {
+    match level {
+        ReleaseLevel::Major => "major",
+        ReleaseLevel::Minor => "minor",
+        ReleaseLevel::Patch => "patch",
+    }
+}
+
+fn release_tag(name: &str, version: &Version) -> String {
+    format!("{name}-v{version}")
+}
+
+fn today() -> String {
+    time::OffsetDateTime::now_utc().date().to_string()
+}
+
+/// Runs git in `dir` and returns its stdout.
+fn git(dir: &Path, args: &[&str]) -> Result<String> {
+    let output = with_default_sigchld(|| Command::new("git").args(args).current_dir(dir).output())
+        .with_context(|| "Could not execute git")?;
+    if !output.status.success() {
+        bail!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Highest `<name>-v<semver>` tag in the repository containing `dir`.
+fn last_release(dir: &Path, name: &str) -> Result<Option<(String, Version)>> {
+    let prefix = format!("{name}-v");
+    let tags = git(dir, &["tag", "--list", &format!("{prefix}*")])?;
+    Ok(tags
+        .lines()
+        .filter_map(|tag| {
+            let version = Version::parse(tag.strip_prefix(&prefix)?).ok()?;
+            Some((tag.to_string(), version))
+        })
+        .max_by(|a, b| a.1.cmp(&b.1)))
+}
+
+fn bump_version(version: &Version, level: ReleaseLevel) -> Version {
+    match level {
+        ReleaseLevel::Major => Version::new(version.major + 1, 0, 0),
+        ReleaseLevel::Minor => Version::new(version.major, version.minor + 1, 0),
+        ReleaseLevel::Patch => Version::new(version.major, version.minor, version.patch + 1),
+    }
+}
+
+fn change(level: ReleaseLevel, description: String) -> ReleaseChange {
+    ReleaseChange { level, description }
+}
+
+/// Compares two versions of a skill document.
+///
+/// Removing a section or removing or changing a frontmatter field other
+/// than `description` breaks callers and is major. Added sections and fields
+/// are minor; reworded sections and descriptions are patches.
+fn classify_document(old: &str, new: &str) -> Result<Vec<ReleaseChange>> {
+    let old = parse_frontmatter(old).map_err(|e| anyhow!("Released version: {e}"))?;
+    let new = parse_frontmatter(new).map_err(|e| anyhow!(e))?;
+    let old_fields = frontmatter_fields(old.raw_frontmatter.as_deref())?;
+    let new_fields = frontmatter_fields(new.raw_frontmatter.as_deref())?;
+
+    let mut changes = Vec::new();
+    for (key, value) in &old_fields {
+        match new_fields.iter().find(|(k, _)| k == key) {
+            None => changes.push(change(
+                ReleaseLevel::Major,
+                format!("Removed frontmatter field `{key}`"),
+            )),
+            Some((_, new_value)) if new_value != value => changes.push(if key == "description" {
+                change(ReleaseLevel::Patch, "Changed description".to_string())
+            } else {
+                change(
+                    ReleaseLevel::Major,
+                    format!("Changed frontmatter field `{key}`"),
+                )
+            }),
+            Some(_) => {}
+        }
+    }
+    for (key, _) in &new_fields {
+        if !old_fields.iter().any(|(k, _)| k == key) {
+            changes.push(change(
+                ReleaseLevel::Minor,
+                format!("Added frontmatter field `{key}`"),
+            ));
+        }
+    }
+
+    for drift in diff_sections(&old.content, &new.content) {
+        let section = match &drift.heading {
+            Some(heading) => format!("section \"{heading}\""),
+            None => "introduction".to_string(),
+        };
+        match drift.status {
+            SectionStatus::OnlyInLeft => {
+                changes.push(change(ReleaseLevel::Major, format!("Removed {section}")))
+            }
+            SectionStatus::OnlyInRight => {
+                changes.push(change(ReleaseLevel::Minor, format!("Added {section}")))
+            }
+            SectionStatus::Changed => {
+                changes.push(change(ReleaseLevel::Patch, format!("Changed {section}")))
+            }
+            SectionStatus::Identical => {}
+        }
+    }
+    Ok(changes)
+}
+
+/// Frontmatter fields except `version`, in document order.
+fn frontmatter_fields(raw: Option<&str>) -> Result<Vec<(String, serde_yaml::Value)>> {
+    let Some(raw) = raw else {
+        return Ok(Vec::new());
+    };
+    let mapping: serde_yaml::Mapping = serde_yaml::from_str(raw)?;
+    Ok(mapping
+        .into_iter()
+        .filter_map(|(k, v)| Some((k.as_str()?.to_string(), v)))
+        .filter(|(k, _)| k != VERSION_FIELD)
+        .collect())
+}
+
+/// Classifies files in the skill directory added, removed or changed since
+/// `tag`. The skill document and changelog are left to the caller.
+fn classify_files(dir: &Path, tag: &str, skill_file: &str) -> Result<Vec<ReleaseChange>> {
+    let mut changes = Vec::new();
+    let skip = |path: &str| path == skill_file || path == CHANGELOG_FILE;
+    let diff = git(
+        dir,
+        &[
+            "diff",
+            "--name-status",
+            "--no-renames",
+            "--relative",
+            tag,
+            "--",
+            ".",
+        ],
+    )?;
+    for line in diff.lines() {
+        let Some((status, path)) = line.split_once('\t') else {
+            continue;
+        };
+        if skip(path) {
+            continue;
+        }
+        changes.push(match status {
+            "D" => change(ReleaseLevel::Major, format!("Removed file `{path}`")),
+            "A" => change(ReleaseLevel::Minor, format!("Added file `{path}`")),
+            _ => change(ReleaseLevel::Patch, format!("Changed file `{path}`")),
+        });
+    }
+    let untracked = git(
+        dir,
+        &["ls-files", "--others", "--exclude-standard", "--", "."],
+    )?;
+    for path in untracked.lines().filter(|p| !skip(p)) {
+        changes.push(change(ReleaseLevel::Minor, format!("Added file `{path}`")));
+    }
+    Ok(changes)
+}
+
+/// Skills depending on `name` transitively, and the direct dependents
+/// whose version requirement `version` would not satisfy.
+fn dependents_of(
+    name: &str,
+    version: &Version,
+    skills: &[SkillMeta],
+) -> (Vec<String>, Vec<BrokenDependent>) {
+    let mut graph = RelationshipGraph::new();
+    let mut broken = Vec::new();
+    for meta in skills {
+        let Some(deps) = std::fs::read_to_string(&meta.path)
+            .ok()
+            .and_then(|content| parse_frontmatter(&content).ok()?.frontmatter)
+            .and_then(|fm| fm.normalized_dependencies().ok())
+        else {
+            continue;
+        };
+        let dependent = skill_name(meta);
+        for dep in deps {
+            if dep.name == name {
+                if let Some(req) = dep.version_req.as_ref().filter(|r| !r.matches(version)) {
+                    broken.push(BrokenDependent {
+                        name: dependent.clone(),
+                        requirement: req.to_string(),
+                    });
+                }
+            }
+            graph.add_dependency(dependent.clone(), dep.name);
+        }
+    }
+    let mut dependents = graph.transitive_dependents(name);
+    dependents.sort();
+    (dependents, broken)
+}
+
+/// Sets the frontmatter `version` of a skill document, adding frontmatter
+/// if the document has none.
+fn set_version(content: &str, version: &str, name: &str) -> String {
+    let lines: Vec<&str> = content.split_inclusive('\n').collect();
+    let open = lines
+        .iter()
+        .position(|l| !l.trim().is_empty())
+        .filter(|&i| lines[i].trim_end() == "---");
+    let close = open.and_then(|open| {
+        lines[open + 1..]
+            .iter()
+            .position(|l| l.trim_end() == "---")
+            .map(|i| i + open + 1)
+    });
+    let (Some(open), Some(close)) = (open, close) else {
+        return format!("---\nname: {name}\nversion: {version}\n---\n\n{content}");
+    };
+
+    let field = format!("{VERSION_FIELD}: {version}\n");
+    let mut replaced = false;
+    let mut out = String::with_capacity(content.len() + field.len());
+    for (i, line) in lines.iter().enumerate() {
+        if i > open && i < close && line.starts_with(&format!("{VERSION_FIELD}:")) {
+            out.push_str(&field);
+            replaced = true;
+            continue;
+        }
+        if i == close && !replaced {
+            out.push_str(&field);
+        }
+        out.push_str(line);
+    }
+    out
+}
+
+/// Adds a release section above the newest entry of a changelog, creating
+/// the changelog if needed.
+fn prepend_changelog(
+    existing: Option<&str>,
+    version: &Version,
+    date: &str,
+    changes: &[ReleaseChange],
+) -> String {
+    let mut entry = format!("## {version} - {date}\n\n");
+    if changes.is_empty() {
+        entry.push_str("- Initial release\n");
+    }
+    for c in changes {
+        entry.push_str(&format!("- {}\n", c.description));
+    }
+
+    let Some(existing) = existing.filter(|e| !e.trim().is_empty()) else {
+        return format!("# Changelog\n\n{entry}");
+    };
+    let first_release = existing
+        .match_indices("## ")
+        .find(|(i, _)| *i == 0 || existing.as_bytes()[i - 1] == b'\n')
+        .map(|(i, _)| i);
+    match first_release {
+        Some(pos) => format!("{}{entry}\n{}", &existing[..pos], &existing[pos..]),
+        None => format!("{}\n\n{entry}", existing.trim_end()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use skrills_discovery::{SkillRoot, SkillSource};
+
+    const RELEASED: &str = "---\nname: base\ndescription: Base skill\nversion: 1.0.0\n---\n# Base\nIntro.\n\n## Usage\nCall it.\n\n## Notes\nNone.\n";
+
+    fn levels(changes: &[ReleaseChange]) -> Vec<(ReleaseLevel, &str)> {
+        changes
+            .iter()
+            .map(|c| (c.level, c.description.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn removed_sections_and_changed_contract_fields_are_major() {
+        let new = "---\nname: base\ndescription: Base skill\nversion: 1.0.0\nallowed-tools: Read\n---\n# Base\nIntro.\n\n## Notes\nNone.\n";
+        assert_eq!(
+            levels(&classify_document(RELEASED, new).unwrap()),
+            [
+                (
+                    ReleaseLevel::Minor,
+                    "Added frontmatter field `allowed-tools`"
+                ),
+                (ReleaseLevel::Major, "Removed section \"Usage\""),
+            ]
+        );
+
+        let renamed = RELEASED.replace("name: base", "name: base-skill");
+        assert_eq!(
+            levels(&classify_document(RELEASED, &renamed).unwrap()),
+            [(ReleaseLevel::Major, "Changed frontmatter field `name`")]
+        );
+    }
+
+    #[test]
+    fn added_sections_are_minor_and_rewording_is_a_patch() {
+        let new = RELEASED
+            .replace("Base skill", "The base skill")
+            .replace("Call it.", "Call it twice.")
+            + "\n## Examples\nSee above.\n";
+        assert_eq!(
+            levels(&classify_document(RELEASED, &new).unwrap()),
+            [
+                (ReleaseLevel::Patch, "Changed description"),
+                (ReleaseLevel::Patch, "Changed section \"Usage\""),
+                (ReleaseLevel::Minor, "Added section \"Examples\""),
+            ]
+        );
+        let bumped = RELEASED.replace("1.0.0", "1.0.1");
+        assert!(classify_document(RELEASED, &bumped).unwrap().is_empty());
+    }
+
+    #[test]
+    fn bumps_follow_semver() {
+        let v = Version::parse("1.4.2-beta.1").unwrap();
+        assert_eq!(bump_version(&v, ReleaseLevel::Major).to_string(), "2.0.0");
+        assert_eq!(bump_version(&v, ReleaseLevel::Minor).to_string(), "1.5.0");
+        assert_eq!(bump_version(&v, ReleaseLevel::Patch).to_string(), "1.4.3");
+    }
+
+    #[test]
+    fn set_version_replaces_or_adds_the_field() {
+        assert_eq!(
+            set_version(RELEASED, "2.0.0", "base"),
+            RELEASED.replace("1.0.0", "2.0.0")
+        );
+        assert_eq!(
+            set_version("---\nname: base\n---\nBody\n", "0.1.0", "base"),
+            "---\nname: base\nversion: 0.1.0\n---\nBody\n"
+        );
+        assert_eq!(
+            set_version("Body\n", "0.1.0", "base"),
+            "---\nname: base\nversion: 0.1.0\n---\n\nBody\n"
+        );
+    }
+
+    #[test]
+    fn changelog_lists_newest_release_first() {
+        let v1 = Version::new(1, 0, 0);
+        let first = prepend_changelog(None, &v1, "2026-01-02", &[]);
+        assert_eq!(
+            first,
+            "# Changelog\n\n## 1.0.0 - 2026-01-02\n\n- Initial release\n"
+        );
+
+        let changes = [change(
+            ReleaseLevel::Minor,
+            "Added section \"Examples\"".into(),
+        )];
+        let second =
+            prepend_changelog(Some(&first), &Version::new(1, 1, 0), "2026-02-03", &changes);
+        assert_eq!(
+            second,
+            "# Changelog\n\n## 1.1.0 - 2026-02-03\n\n- Added section \"Examples\"\n\n## 1.0.0 - 2026-01-02\n\n- Initial release\n"
+        );
+    }
+
+    fn run_git(dir: &Path, args: &[&str]) {
+        git(dir, args).unwrap();
+    }
+
+    #[test]
+    fn release_tags_a_major_bump_and_lists_broken_dependents() {
+        let tmp = tempfile::tempdir().unwrap();
+        let root = tmp.path();
+        let base = root.join("base");
+        let app = root.join("app");
+        std::fs::create_dir_all(&base).unwrap();
+        std::fs::create_dir_all(&app).unwrap();
+        std::fs::write(base.join("SKILL.md"), RELEASED).unwrap();
+        std::fs::write(base.join("helper.sh"), "echo hi\n").unwrap();
+        std::fs::write(
+            app.join("SKILL.md"),
+            "---\nname: app\ndescription: App\ndepends:\n  - base@^1.0\n---\nUses base.\n",
+        )
+        .unwrap();
+        run_git(root, &["init", "-q"]);
+        run_git(root, &["config", "user.email", "dev@example.com"]);
+        run_git(root, &["config", "user.name", "Dev"]);
+        run_git(root, &["config", "commit.gpgsign", "false"]);
+        run_git(root, &["config", "tag.gpgsign", "false"]);
+        run_git(root, &["add", "-A"]);
+        run_git(root, &["commit", "-q", "-m", "Initial"]);
+        run_git(root, &["tag", "base-v1.0.0"]);
+
+        std::fs::write(
+            base.join("SKILL.md"),
+            RELEASED.replace("## Usage\nCall it.\n\n", ""),
+        )
+        .unwrap();
+        std::fs::remove_file(base.join("helper.sh")).unwrap();
+        std::fs::write(base.join("extra.md"), "More.\n").unwrap();
+
+        let skills = discover_skills(
+            &[SkillRoot {
+                root: root.to_path_buf(),
+                source: SkillSource::Extra(0),
+            }],
+            None,
+        )
+        .unwrap();
+        let skill = skills.iter().find(|s| skill_name(s) == "base").unwrap();
+
+        let preview = release_skill(skill, &skills, None, true, false).unwrap();
+        assert_eq!(preview.version, "2.0.0");
+        assert!(preview.tag.is_none());
+        assert!(!base.join(CHANGELOG_FILE).exists());
+
+        let result = release_skill(skill, &skills, None, false, false).unwrap();
+        assert_eq!(result.previous_tag.as_deref(), Some("base-v1.0.0"));
+        assert_eq!(result.detected_level, Some(ReleaseLevel::Major));
+        assert_eq!(result.version, "2.0.0");
+        assert_eq!(
+            levels(&result.changes),
+            [
+                (ReleaseLevel::Major, "Removed section \"Usage\""),
+                (ReleaseLevel::Major, "Removed file `helper.sh`"),
+                (ReleaseLevel::Minor, "Added file `extra.md`"),
+            ]
+        );
+        assert_eq!(result.dependents, ["app"]);
+        assert_eq!(result.broken_dependents[0].name, "app");
+        assert_eq!(result.broken_dependents[0].requirement, "^1.0");
+        assert_eq!(result.tag.as_deref(), Some("base-v2.0.0"));
+
+        let skill_md = std::fs::read_to_string(base.join("SKILL.md")).unwrap();
+        assert!(skill_md.contains("version: 2.0.0"));
+        let changelog = std::fs::read_to_string(base.join(CHANGELOG_FILE)).unwrap();
+        assert!(changelog.contains("## 2.0.0 - "));
+        assert!(git(root, &["status", "--porcelain"]).unwrap().is_empty());
+        let err = release_skill(skill, &skills, None, true, false)
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err.contains("No changes to 'base' since base-v2.0.0"),
+            "{err}"
+        );
+    }
+}
@@ -2,7 +2,8 @@ use anyhow::{bail, Result};
 use skrills_discovery::{discover_skills, extra_skill_roots};
 use std::path::PathBuf;
 
-use crate::cli::OutputFormat;
+use crate::cli::ReportFormat;
+use crate::commands::report::{Finding, Level, Report, ReportKind};
 use crate::discovery::merge_extra_dirs;
 
 use super::{ScoreBreakdown, SkillScoreResult};
@@ -11,7 +12,7 @@ use super::{ScoreBreakdown, SkillScoreResult};
 pub(crate) fn handle_skill_score_command(
     name: Option<String>,
     skill_dirs: Vec<PathBuf>,
-    format: OutputFormat,
+    format: ReportFormat,
     below_threshold: Option<u8>,
 ) -> Result<()> {
     use skrills_validate::frontmatter::parse_frontmatter;
@@ -40,7 +41,8 @@ pub(crate) fn handle_skill_score_command(
         }
     }
 
-    let mut results: Vec<SkillScoreResult> = Vec::new();
+    // Each result with the report rule of each of its suggestions.
+    let mut results: Vec<(SkillScoreResult, Vec<&str>)> = Vec::new();
 
     for skill in skills_to_score {
         let content = match std::fs::read_to_string(&skill.path) {
@@ -51,6 +53,7 @@ pub(crate) fn handle_skill_score_command(
         let parsed = parse_frontmatter(&content);
 
         let mut suggestions = Vec::new();
+        let mut rules = Vec::new();
 
         let frontmatter_score = match &parsed {
             Ok(p) if p.frontmatter.is_some() => {
@@ -61,22 +64,26 @@ pub(crate) fn handle_skill_score_command(
                     score += 5;
                 } else {
                     suggestions.push("Add 'name' field to frontmatter".to_string());
+                    rules.push("score-frontmatter");
                 }
 
                 if fm.description.is_some() {
                     score += 10;
                 } else {
                     suggestions.push("Add 'description' field to frontmatter".to_string());
+                    rules.push("score-frontmatter");
                 }
 
                 score
             }
             Ok(_) => {
                 suggestions.push("Add YAML frontmatter with name and description".to_string());
+                rules.push("score-frontmatter");
                 0
             }
             Err(_) => {
                 suggestions.push("Fix frontmatter YAML syntax errors".to_string());
+                rules.push("score-validation");
                 0
             }
         };
@@ -104,6 +111,7 @@ pub(crate) fn handle_skill_score_command(
 
         if description_score < 20 {
             suggestions.push("Improve description (aim for 100+ characters)".to_string());
+            rules.push("score-description");
         }
 
         let token_score = {
@@ -124,6 +132,7 @@ pub(crate) fn handle_skill_score_command(
         if token_score < 15 {
             suggestions
                 .push("Consider splitting into smaller skills for token efficiency".to_string());
+            rules.push("score-tokens");
         }
 
         let total_score = frontmatter_score + validation_score + description_score + token_score;
@@ -134,22 +143,68 @@ pub(crate) fn handle_skill_score_command(
             }
         }
 
-        results.push(SkillScoreResult {
-            name: skill.name.clone(),
-            path: skill.path.clone(),
-            total_score,
-            breakdown: ScoreBreakdown {
-                frontmatter_completeness: frontmatter_score,
-                validation_score,
-                description_quality: description_score,
-                token_efficiency: token_score,
+        results.push((
+            SkillScoreResult {
+                name: skill.name.clone(),
+                path: skill.path.clone(),
+                total_score,
+                breakdown: ScoreBreakdown {
+                    frontmatter_completeness: frontmatter_score,
+                    validation_score,
+                    description_quality: description_score,
+                    token_efficiency: token_score,
+                },
+                suggestions,
             },
-            suggestions,
-        });
+            rules,
+        ));
     }
 
-    results.sort_by_key(|a| a.total_score);
+    results.sort_by_key(|(a, _)| a.total_score);
 
+    if let Some(kind) = ReportKind::of(format) {
+        let mut report = Report::new("skill-score");
+        report.describe(
+            "score-frontmatter",
+            "Frontmatter lacks fields that affect the score",
+        );
+        report.describe("score-validation", "Frontmatter does not parse");
+        report.describe("score-description", "Description is too short");
+        report.describe(
+            "score-tokens",
+            "Skill is large enough to cost token efficiency",
+        );
+        report.describe(
+            "score-below-threshold",
+            "Skill scores below --below-threshold",
+        );
+        for (result, rules) in &results {
+            let case = report.add_case(&result.name, &result.path);
+            if let Some(threshold) = below_threshold {
+                case.findings.push(Finding::new(
+                    "score-below-threshold",
+                    Level::Error,
+                    format!(
+                        "Score {}/100 is below the threshold of {}",
+                        result.total_score, threshold
+                    ),
+                    &result.path,
+                ));
+            }
+            for (suggestion, rule) in result.suggestions.iter().zip(rules) {
+                case.findings.push(Finding::new(
+                    *rule,
+                    Level::Note,
+                    format!("{} (score {}/100)", suggestion, result.total_score),
+                    &result.path,
+                ));
+            }
+        }
+        report.print(kind)?;
+        return Ok(());
+    }
+
+    let results: Vec<SkillScoreResult> = results.into_iter().map(|(r, _)| r).collect();
     if format.is_json() {
         println!("{}", serde_json::to_string_pretty(&results)?);
     } else {
@@ -214,7 +269,7 @@ mod tests {
         let result = handle_skill_score_command(
             Some("well-documented".to_string()),
             vec![fixture.claude_skills.clone()],
-            OutputFormat::Json,
+            ReportFormat::Json,
             None,
         );
 
@@ -237,7 +292,7 @@ mod tests {
         let result = handle_skill_score_command(
             Some("target-skill".to_string()),
             vec![fixture.claude_skills.clone()],
-            OutputFormat::Json,
+            ReportFormat::Json,
             None,
         );
 
@@ -253,7 +308,7 @@ mod tests {
         let result = handle_skill_score_command(
             Some("nonexistent-skill".to_string()),
             vec![fixture.claude_skills.clone()],
-            OutputFormat::Json,
+            ReportFormat::Json,
             None,
         );
 
@@ -283,7 +338,7 @@ mod tests {
         let result = handle_skill_score_command(
             None,
             vec![fixture.claude_skills.clone()],
-            OutputFormat::Json,
+            ReportFormat::Json,
             Some(50),
         );
 
@@ -1,8 +1,12 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
+use std::path::Path;
 
 use crate::cli::{OutputFormat, SyncSource};
+use crate::registry::{
+    install_files, installed_files_match, now_rfc3339, LockedSkill, RegistryClient, RegistryLock,
+};
 
-use super::SyncPullResult;
+use super::{target_skills_dir, PullAction, PulledSkill, SyncPullResult};
 
 /// Handle the sync-pull command.
 pub(crate) fn handle_sync_pull_command(
@@ -10,51 +14,340 @@ pub(crate) fn handle_sync_pull_command(
     skill: Option<String>,
     target: SyncSource,
     dry_run: bool,
+    force: bool,
     format: OutputFormat,
 ) -> Result<()> {
-    let result = SyncPullResult {
-        source: source.clone(),
-        target: target.as_str().to_string(),
-        skills_pulled: 0,
-        dry_run,
-        message: if source.is_some() {
-            "Remote skill registries not yet implemented. Use 'skill-import' for individual files or 'sync' to copy from another CLI.".to_string()
+    let Some(source) = source else {
+        let result = SyncPullResult {
+            source: None,
+            target: target.as_str().to_string(),
+            skills_pulled: 0,
+            dry_run,
+            message: "No source specified. Use --source <url> to specify a remote registry."
+                .to_string(),
+            skills: Vec::new(),
+        };
+        if format.is_json() {
+            println!("{}", serde_json::to_string_pretty(&result)?);
         } else {
-            "No source specified. Use --source <url> to specify a remote registry.".to_string()
-        },
+            println!("sync-pull: {}", result.message);
+        }
+        return Ok(());
     };
 
+    let home = dirs::home_dir().with_context(|| "Could not determine home directory")?;
+    let lock_path = RegistryLock::default_path()?;
+    let result = pull_from_registry(
+        &source,
+        skill.as_deref(),
+        target,
+        &target_skills_dir(&home, target),
+        &lock_path,
+        dry_run,
+        force,
+    )?;
+
     if format.is_json() {
         println!("{}", serde_json::to_string_pretty(&result)?);
     } else {
-        println!("sync-pull: {}", result.message);
-        println!();
-        println!("Available alternatives:");
-        println!("  skrills skill-import <path>      Import a skill from local path");
-        println!("  skrills sync --from claude       Sync skills from Claude to Codex");
-        println!("  skrills sync-all --from claude   Sync all assets from Claude");
-
-        if let Some(ref s) = skill {
-            println!();
-            println!(
-                "To import skill '{}', use: skrills skill-import /path/to/{}.md",
-                s, s
-            );
+        for s in &result.skills {
+            let label = match s.action {
+                PullAction::Pulled => "pulled",
+                PullAction::WouldPull => "would pull",
+                PullAction::UpToDate => "up to date",
+                PullAction::SkippedLocal => "skipped (local copy; use --force)",
+            };
+            println!("  {} {} - {}", s.name, s.version, label);
+        }
+        if dry_run {
+            println!("[dry-run] {}", result.message);
+        } else {
+            println!("{}", result.message);
         }
     }
 
     Ok(())
 }
 
+/// Pulls skills from `source` into `skills_dir`, recording them in the
+/// lockfile at `lock_path`.
+fn pull_from_registry(
+    source: &str,
+    skill: Option<&str>,
+    target: SyncSource,
+    skills_dir: &Path,
+    lock_path: &Path,
+    dry_run: bool,
+    force: bool,
+) -> Result<SyncPullResult> {
+    let client = RegistryClient::open(source)?;
+    let index = client.load_index()?;
+    let mut lock = RegistryLock::load(lock_path)?;
+    let target_name = target.as_str();
+
+    let entries: Vec<_> = match skill {
+        Some(name) => {
+            let entry = index
+                .skills
+                .iter()
+                .find(|e| e.name == name)
+                .with_context(|| format!("Skill '{name}' not found in registry {source}"))?;
+            vec![entry]
+        }
+        None => index.skills.iter().collect(),
+    };
+
+    // Fetch and verify everything before writing, so a bad hash later in
+    // the batch leaves the skills directory and the lockfile untouched.
+    let mut outcomes = Vec::with_capacity(entries.len());
+    let mut installs = Vec::new();
+    for entry in entries {
+        let dir = skills_dir.join(&entry.name);
+        let locked = lock.find(&entry.name, target_name);
+
+        let up_to_date = locked
+            .is_some_and(|l| l.version == entry.version && l.files == entry.files)
+            && installed_files_match(&dir, &entry.files);
+        // A local copy is ours to replace only if it is exactly what we pulled last time.
+        let pristine = locked.is_some_and(|l| installed_files_match(&dir, &l.files));
+
+        let action = if up_to_date {
+            PullAction::UpToDate
+        } else if dir.exists() && !pristine && !force {
+            PullAction::SkippedLocal
+        } else if dry_run {
+            PullAction::WouldPull
+        } else {
+            let files = client.fetch_verified(entry)?;
+            installs.push((entry, files, locked.map(|l| l.files.clone())));
+            PullAction::Pulled
+        };
+
+        outcomes.push(PulledSkill {
+            name: entry.name.clone(),
+            version: entry.version.clone(),
+            action,
+            path: dir,
+        });
+    }
+
+    for (entry, files, previous) in installs {
+        let dir = skills_dir.join(&entry.name);
+        if let Err(e) = install_files(&dir, &files, previous.as_deref()) {
+            // Keep the skills installed so far tracked.
+            lock.save(lock_path)?;
+            return Err(e);
+        }
+        lock.upsert(LockedSkill {
+            name: entry.name.clone(),
+            version: entry.version.clone(),
+            source: source.to_string(),
+            target: target_name.to_string(),
+            path: dir,
+            files: entry.files.clone(),
+            pulled_at: now_rfc3339(),
+        });
+    }
+
+    let pulled = outcomes
+        .iter()
+        .filter(|o| matches!(o.action, PullAction::Pulled | PullAction::WouldPull))
+        .count();
+    if !dry_run && pulled > 0 {
+        lock.save(lock_path)?;
+    }
+
+    Ok(SyncPullResult {
+        source: Some(source.to_string()),
+        target: target_name.to_string(),
+        skills_pulled: pulled,
+        dry_run,
+        message: format!(
+            "{} {} skill(s) from {} into {}",
+            if dry_run { "Would pull" } else { "Pulled" },
+            pulled,
+            source,
+            skills_dir.display()
+        ),
+        skills: outcomes,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use skrills_test_utils::TestFixture;
+    use std::path::PathBuf;
+
+    fn lock_path(fx: &TestFixture) -> PathBuf {
+        fx.home_path().join(".skrills/registry-lock.json")
+    }
+
+    fn pull(fx: &TestFixture, dry_run: bool, force: bool) -> SyncPullResult {
+        pull_from_registry(
+            &fx.registry_source(),
+            None,
+            SyncSource::Claude,
+            &fx.claude_skills,
+            &lock_path(fx),
+            dry_run,
+            force,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn sync_pull_without_source_reports_usage() {
+        handle_sync_pull_command(
+            None,
+            None,
+            SyncSource::Claude,
+            false,
+            false,
+            OutputFormat::Json,
+        )
+        .expect("sync-pull without a source should succeed");
+    }
+
+    #[test]
+    fn pull_installs_skill_and_records_lock() {
+        let fx = TestFixture::new().unwrap();
+        fx.publish_registry(&[("hello", "1.0.0", "---\nname: hello\n---\n# Hello")])
+            .unwrap();
+
+        let result = pull(&fx, false, false);
+        assert_eq!(result.skills_pulled, 1);
+        assert_eq!(result.skills[0].action, PullAction::Pulled);
+        assert_eq!(
+            std::fs::read_to_string(fx.claude_skills.join("hello/SKILL.md")).unwrap(),
+            "---\nname: hello\n---\n# Hello"
+        );
+
+        let lock = RegistryLock::load(&lock_path(&fx)).unwrap();
+        let locked = lock.find("hello", "claude").unwrap();
+        assert_eq!(locked.version, "1.0.0");
+        assert_eq!(locked.source, fx.registry_source());
+    }
+
+    #[test]
+    fn second_pull_is_up_to_date_and_new_version_updates() {
+        let fx = TestFixture::new().unwrap();
+        fx.publish_registry(&[("hello", "1.0.0", "# v1")]).unwrap();
+        pull(&fx, false, false);
+
+        let again = pull(&fx, false, false);
+        assert_eq!(again.skills[0].action, PullAction::UpToDate);
+        assert_eq!(again.skills_pulled, 0);
+
+        fx.publish_registry(&[("hello", "1.1.0", "# v2")]).unwrap();
+        let updated = pull(&fx, false, false);
+        assert_eq!(updated.skills[0].action, PullAction::Pulled);
+        assert_eq!(
+            std::fs::read_to_string(fx.claude_skills.join("hello/SKILL.md")).unwrap(),
+            "# v2"
+        );
+    }
+
+    #[test]
+    fn dry_run_writes_nothing() {
+        let fx = TestFixture::new().unwrap();
+        fx.publish_registry(&[("hello", "1.0.0", "# v1")]).unwrap();
+
+        let result = pull(&fx, true, false);
+        assert_eq!(result.skills[0].action, PullAction::WouldPull);
+        assert!(!fx.claude_skills.join("hello").exists());
+        assert!(!lock_path(&fx).exists());
+    }
+
+    #[test]
+    fn local_copy_is_kept_unless_forced() {
+        let fx = TestFixture::new().unwrap();
+        fx.publish_registry(&[("hello", "1.0.0", "# registry")])
+            .unwrap();
+        std::fs::create_dir_all(fx.claude_skills.join("hello")).unwrap();
+        std::fs::write(fx.claude_skills.join("hello/SKILL.md"), "# mine").unwrap();
+
+        let kept = pull(&fx, false, false);
+        assert_eq!(kept.skills[0].action, PullAction::SkippedLocal);
+        assert_eq!(
+            std::fs::read_to_string(fx.claude_skills.join("hello/SKILL.md")).unwrap(),
+            "# mine"
+        );
+
+        let forced = pull(&fx, false, true);
+        assert_eq!(forced.skills[0].action, PullAction::Pulled);
+        assert_eq!(
+            std::fs::read_to_string(fx.claude_skills.join("hello/SKILL.md")).unwrap(),
+            "# registry"
+        );
+    }
+
+    #[test]
+    fn tampered_registry_file_is_not_installed() {
+        let fx = TestFixture::new().unwrap();
+        fx.publish_registry(&[("hello", "1.0.0", "# v1")]).unwrap();
+        std::fs::write(
+            fx.registry_path().join("skills/hello-1.0.0/SKILL.md"),
+            "# evil",
+        )
+        .unwrap();
+
+        let err = pull_from_registry(
+            &fx.registry_source(),
+            None,
+            SyncSource::Claude,
+            &fx.claude_skills,
+            &lock_path(&fx),
+            false,
+            false,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("Hash mismatch"));
+        assert!(!fx.claude_skills.join("hello/SKILL.md").exists());
+    }
+
+    #[test]
+    fn bad_hash_later_in_batch_installs_nothing() {
+        let fx = TestFixture::new().unwrap();
+        fx.publish_registry(&[("alpha", "1.0.0", "# alpha"), ("beta", "1.0.0", "# beta")])
+            .unwrap();
+        std::fs::write(
+            fx.registry_path().join("skills/beta-1.0.0/SKILL.md"),
+            "# evil",
+        )
+        .unwrap();
+
+        let err = pull_from_registry(
+            &fx.registry_source(),
+            None,
+            SyncSource::Claude,
+            &fx.claude_skills,
+            &lock_path(&fx),
+            false,
+            false,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("Hash mismatch"));
+        assert!(!fx.claude_skills.join("alpha").exists());
+        assert!(!fx.claude_skills.join("beta").exists());
+        assert!(!lock_path(&fx).exists());
+    }
 
     #[test]
-    fn sync_pull_returns_placeholder_message() {
-        let result =
-            handle_sync_pull_command(None, None, SyncSource::Claude, false, OutputFormat::Json);
+    fn unknown_skill_name_errors() {
+        let fx = TestFixture::new().unwrap();
+        fx.publish_registry(&[("hello", "1.0.0", "# v1")]).unwrap();
 
-        result.expect("sync-pull should succeed (placeholder)");
+        let err = pull_from_registry(
+            &fx.registry_source(),
+            Some("missing"),
+            SyncSource::Claude,
+            &fx.claude_skills,
+            &lock_path(&fx),
+            false,
+            false,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("not found"));
     }
 }
@@ -1,9 +1,12 @@
+use crate::cli::{OutputFormat, SyncStrategy};
 use crate::discovery::merge_extra_dirs;
 use crate::sync::{
     mirror_source_root, sync_agents, sync_agents_only_from_claude, sync_skills_only_from_claude,
 };
 use anyhow::Result;
 use skrills_state::home_dir;
+use skrills_sync::snapshot::{format_history, snapshot_history, undo_sync};
+use skrills_sync::{SnapshotConfig, SyncPlan, SyncScope};
 use std::path::PathBuf;
 
 pub(crate) fn handle_sync_agents_command(
@@ -17,8 +20,66 @@ pub(crate) fn handle_sync_agents_command(
 }
 
 /// Handle the `sync` command.
-pub(crate) fn handle_sync_command(include_marketplace: bool) -> Result<()> {
+///
+/// Without a strategy this mirrors skills into `~/.codex/skills`. With one,
+/// skills go through the sync-state ledger so items edited on both sides are
+/// resolved with `strategy` (e.g. a three-way merge) instead of overwritten.
+///
+/// In project scope the repository's `.claude/` and `CLAUDE.md` are mirrored
+/// into its `.codex/` and `AGENTS.md` through the orchestrator.
+///
+/// `plan` writes the ledger sync's changes to a file instead of syncing;
+/// `apply` executes such a file.
+pub(crate) fn handle_sync_command(
+    include_marketplace: bool,
+    strategy: Option<SyncStrategy>,
+    scope: SyncScope,
+    plan: Option<PathBuf>,
+    apply: Option<PathBuf>,
+) -> Result<()> {
+    if let Some(path) = apply {
+        let plan = SyncPlan::load(&path)?;
+        let applied = plan.apply()?;
+        println!(
+            "Applied {}: {} written, {} deleted ({} -> {})",
+            path.display(),
+            applied.written,
+            applied.deleted,
+            plan.source,
+            plan.target
+        );
+        if let Some(id) = applied.snapshot {
+            println!("Undo with: skrills sync-undo --id {}", id);
+        }
+        return Ok(());
+    }
+    if let Some(path) = plan {
+        let params = ledger_sync_params(include_marketplace, strategy, scope);
+        let plan = skrills_sync::plan::plan_sync("claude", "codex", &params)?;
+        plan.save(&path)?;
+        print!("{}", plan.summary);
+        print!("{}", plan.format_changes());
+        println!(
+            "Planned {} change(s); wrote {}",
+            plan.changes.len() + plan.source_changes.len(),
+            path.display()
+        );
+        return Ok(());
+    }
+    if scope.is_project() {
+        let params = ledger_sync_params(include_marketplace, strategy, scope);
+        let report = skrills_sync::sync_between("claude", "codex", &params)?;
+        print!("{}", report.summary);
+        return Ok(());
+    }
     let home = home_dir()?;
+    if strategy.is_some() {
+        let params = ledger_sync_params(include_marketplace, strategy, scope);
+        let report = skrills_sync::sync_between("claude", "codex", &params)?;
+        let _ = crate::setup::ensure_codex_skills_feature_enabled(&home.join(".codex/config.toml"));
+        print!("{}", report.summary);
+        return Ok(());
+    }
     let report = sync_skills_only_from_claude(
         &mirror_source_root(&home),
         &home.join(".codex/skills"),
@@ -29,6 +90,41 @@ pub(crate) fn handle_sync_command(include_marketplace: bool) -> Result<()> {
     Ok(())
 }
 
+/// Params for a `sync` that goes through the ledger: everything in project
+/// scope, skills only at user level.
+fn ledger_sync_params(
+    include_marketplace: bool,
+    strategy: Option<SyncStrategy>,
+    scope: SyncScope,
+) -> skrills_sync::SyncParams {
+    let mut params = if scope.is_project() {
+        skrills_sync::SyncParams {
+            from: Some("claude".to_string()),
+            include_marketplace,
+            scope,
+            ..Default::default()
+        }
+    } else {
+        skrills_sync::SyncParams {
+            from: Some("claude".to_string()),
+            sync_skills: true,
+            sync_commands: false,
+            sync_mcp_servers: false,
+            sync_preferences: false,
+            sync_agents: false,
+            sync_hooks: false,
+            sync_instructions: false,
+            sync_plugin_assets: false,
+            include_marketplace,
+            ..Default::default()
+        }
+    };
+    if let Some(strategy) = strategy {
+        params.conflict_strategy = Some(strategy.into());
+    }
+    params
+}
+
 pub(crate) fn handle_mirror_command(
     dry_run: bool,
     skip_existing_commands: bool,
@@ -57,7 +153,12 @@ pub(crate) fn handle_mirror_command(
     // Mirror commands/mcp/prefs
     let source = skrills_sync::ClaudeAdapter::new()?;
     let target = skrills_sync::CodexAdapter::new()?;
-    let orch = skrills_sync::SyncOrchestrator::new(source, target);
+    let orch = skrills_sync::SyncOrchestrator::new(source, target)
+        .with_snapshots(skrills_sync::SnapshotConfig::default())
+        .with_model_map(skrills_sync::ModelMap::load_default()?)
+        .with_policy(skrills_sync::SyncPolicy::load_default(
+            &skrills_sync::SyncScope::User,
+        )?);
     let params = skrills_sync::SyncParams {
         dry_run,
         sync_skills: false,
@@ -98,6 +199,36 @@ pub(crate) fn handle_mirror_command(
     Ok(())
 }
 
+/// Handle the `sync-undo` command.
+///
+/// Restores the target files recorded in a sync snapshot and rolls the
+/// sync-state ledger back with them.
+pub(crate) fn handle_sync_undo_command(id: Option<String>, format: OutputFormat) -> Result<()> {
+    let config = SnapshotConfig::default();
+    let mut ledger = skrills_sync::SyncStateStore::load_default()?;
+    let result = undo_sync(&config, id.as_deref(), Some(&mut ledger))?;
+    if format.is_json() {
+        println!("{}", serde_json::to_string_pretty(&result)?);
+    } else {
+        print!("{}", result.format_summary());
+    }
+    Ok(())
+}
+
+/// Handle the `sync-history` command.
+pub(crate) fn handle_sync_history_command(
+    limit: Option<usize>,
+    format: OutputFormat,
+) -> Result<()> {
+    let records = snapshot_history(&SnapshotConfig::default(), limit)?;
+    if format.is_json() {
+        println!("{}", serde_json::to_string_pretty(&records)?);
+    } else {
+        print!("{}", format_history(&records));
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,145 @@
+//! `sync-watch`: continuous sync driven by filesystem events.
+
+use crate::cli::SyncPlatform;
+use anyhow::{bail, Result};
+use notify::{Config as NotifyConfig, RecommendedWatcher, RecursiveMode, Watcher};
+use skrills_metrics::{MetricsCollector, SyncOperation, SyncStatus};
+use skrills_sync::{
+    create_adapter_in, sync_between, watch_targets, AgentAdapter, ChangedArtifacts, SyncParams,
+    SyncReport, SyncScope,
+};
+use skrills_validate::watch::collect_debounced_paths;
+use std::path::PathBuf;
+use std::sync::mpsc;
+use std::time::Duration;
+
+/// Watches the source CLI's managed paths and, after each debounced batch of
+/// changes, syncs only the changed artifact types to every target.
+///
+/// Targets default to all other CLIs (built-in ones only in project scope).
+/// Syncs go through the sync-state ledger without `--force`, so a target
+/// edited since the last sync is never overwritten: the watch stops and
+/// reports the conflict instead. Every run is recorded as a sync event in
+/// the persistent metrics database.
+pub(crate) fn handle_sync_watch_command(
+    from: SyncPlatform,
+    to: Vec<SyncPlatform>,
+    scope: SyncScope,
+    debounce_ms: u64,
+) -> Result<()> {
+    let targets: Vec<SyncPlatform> = if !to.is_empty() {
+        to
+    } else if scope.is_project() {
+        from.other_targets()
+            .into_iter()
+            .filter(|t| matches!(t, SyncPlatform::Builtin(_)))
+            .collect()
+    } else {
+        from.other_targets()
+    };
+    if targets.iter().any(|t| t == &from) {
+        bail!("Source and target cannot be the same: {}", from.as_str());
+    }
+
+    let source = create_adapter_in(from.as_str(), &scope)?;
+    let watched = watch_targets(&source);
+    if watched.is_empty() {
+        bail!(
+            "Nothing to watch: no {} configuration under {}",
+            from.as_str(),
+            source.managed_root().display()
+        );
+    }
+
+    let (tx, rx) = mpsc::channel();
+    let mut watcher = RecommendedWatcher::new(
+        move |event: notify::Result<notify::Event>| {
+            let _ = tx.send(event);
+        },
+        NotifyConfig::default(),
+    )?;
+    for (path, recursive) in &watched {
+        let mode = if *recursive {
+            RecursiveMode::Recursive
+        } else {
+            RecursiveMode::NonRecursive
+        };
+        watcher.watch(path, mode)?;
+        tracing::info!(path = %path.display(), "Watching");
+    }
+
+    let metrics = MetricsCollector::persistent_default()
+        .inspect_err(|e| tracing::warn!(error = %e, "Sync events will not be recorded"))
+        .ok();
+
+    let target_names: Vec<&str> = targets.iter().map(SyncPlatform::as_str).collect();
+    println!(
+        "Watching {} ({} path{}) → {} (debounce: {}ms). Press Ctrl+C to stop.",
+        from.as_str(),
+        watched.len(),
+        if watched.len() == 1 { "" } else { "s" },
+        target_names.join(", "),
+        debounce_ms
+    );
+
+    let debounce = Duration::from_millis(debounce_ms);
+    while let Some(paths) = collect_debounced_paths(&rx, debounce) {
+        let changed = ChangedArtifacts::from_paths(&source, paths.iter().map(PathBuf::as_path));
+        if changed.is_empty() {
+            continue;
+        }
+        println!("Changed: {}", changed.names().join(", "));
+
+        for target in &targets {
+            let mut params = SyncParams {
+                from: Some(from.as_str().to_string()),
+                scope: scope.clone(),
+                ..Default::default()
+            };
+            changed.restrict(&mut params);
+            // Cursor discovers user-level skills from its plugin cache, which
+            // `sync-all` mirrors; a flat copy would duplicate them.
+            if target.is_cursor() && !scope.is_project() {
+                params.sync_skills = false;
+            }
+            let result = sync_between(from.as_str(), target.as_str(), &params);
+            record_run(metrics.as_ref(), &result);
+
+            match result {
+                Ok(report) => {
+                    print!("{}", report.summary);
+                    let conflicts = report.conflicts();
+                    if !conflicts.is_empty() {
+                        bail!(
+                            "Stopped: {} item(s) on {} changed since the last sync. Resolve them with `skrills sync-all --to {} --strategy <strategy>` and restart sync-watch",
+                            conflicts.len(),
+                            target.as_str(),
+                            target.as_str()
+                        );
+                    }
+                }
+                Err(e) => {
+                    tracing::error!(to = %target.as_str(), error = %e, "Sync failed");
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Records one watch-triggered sync in the metrics database. Runs that hit
+/// conflicts count as failed.
+fn record_run(metrics: Option<&MetricsCollector>, result: &Result<SyncReport>) {
+    let Some(metrics) = metrics else {
+        return;
+    };
+    let (files, status) = match result {
+        Ok(report) if report.conflicts().is_empty() => (report.total_synced(), SyncStatus::Success),
+        Ok(report) => (report.total_synced(), SyncStatus::Failed),
+        Err(_) => (0, SyncStatus::Failed),
+    };
+    if let Err(e) = metrics.record_sync_event(SyncOperation::Push, files, status) {
+        tracing::warn!(error = %e, "Failed to record sync event");
+    }
+}
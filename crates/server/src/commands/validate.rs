@@ -1,25 +1,35 @@
-use crate::cli::OutputFormat;
+use crate::cli::{OutputFormat, ReportFormat};
+use crate::config::load_lint_config;
 use crate::discovery::merge_extra_dirs;
 use anyhow::Result;
 use skrills_discovery::{discover_skills, extra_skill_roots};
 
+use super::links::{known_skills, link_warnings, warning_location, UrlProbe};
+use super::report::{rule_id, Finding, Fix, Level, Report, ReportKind, SuggestedFix};
+
 /// Handle the `validate` command.
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn handle_validate_command(
     skill_dirs: Vec<std::path::PathBuf>,
     target: crate::cli::ValidationTarget,
     autofix: bool,
     backup: bool,
-    format: OutputFormat,
+    format: ReportFormat,
     errors_only: bool,
+    check_links: bool,
+    check_urls: bool,
 ) -> Result<()> {
+    use skrills_analyze::WarningLevel;
     use skrills_validate::{
-        validate_skill, AutofixOptions, ValidationSummary, ValidationTarget as VT,
+        autofix_cursor, autofix_frontmatter, needs_autofix, validate_skill_with, AutofixOptions,
+        Severity, ValidationIssue, ValidationSummary, ValidationTarget as VT,
     };
 
     let validation_target = match target {
         crate::cli::ValidationTarget::Claude => VT::Claude,
         crate::cli::ValidationTarget::Codex => VT::Codex,
         crate::cli::ValidationTarget::Copilot => VT::Copilot,
+        crate::cli::ValidationTarget::Cursor => VT::Cursor,
         crate::cli::ValidationTarget::All => VT::All,
         crate::cli::ValidationTarget::Both => VT::Both,
     };
@@ -29,21 +39,36 @@ pub(crate) fn handle_validate_command(
     let skills = discover_skills(&roots, None)?;
 
     if skills.is_empty() {
-        if format.is_json() {
-            println!("[]");
-        } else {
-            println!("No skills found to validate.");
+        match ReportKind::of(format) {
+            Some(kind) => Report::new("validate").print(kind)?,
+            None if format.is_json() => println!("[]"),
+            None => println!("No skills found to validate."),
         }
         return Ok(());
     }
 
+    let known = if check_links {
+        known_skills(&extra_dirs)?
+    } else {
+        Vec::new()
+    };
+    let probe = if check_urls {
+        Some(UrlProbe::new()?)
+    } else {
+        None
+    };
+
+    let lint = load_lint_config()?;
+
     let mut results = Vec::new();
+    let mut suggested_fixes = Vec::new();
+    let mut link_notes = Vec::new();
     let mut fixed_count = 0;
     let mut skipped_files: Vec<(std::path::PathBuf, String)> = Vec::new();
     let mut autofix_failures: Vec<(std::path::PathBuf, String)> = Vec::new();
 
     for meta in skills.iter() {
-        let content = match std::fs::read_to_string(&meta.path) {
+        let mut content = match std::fs::read_to_string(&meta.path) {
             Ok(c) => c,
             Err(e) => {
                 skipped_files.push((meta.path.clone(), e.to_string()));
@@ -51,22 +76,28 @@ pub(crate) fn handle_validate_command(
             }
         };
 
-        let mut result = validate_skill(&meta.path, &content, validation_target);
+        let mut result = validate_skill_with(&meta.path, &content, validation_target, &lint);
 
-        if autofix && !result.codex_valid && validation_target != VT::Claude {
-            use skrills_validate::autofix_frontmatter;
+        let needs_fix = needs_autofix(&result, validation_target);
+        if autofix && needs_fix {
             let opts = AutofixOptions {
                 create_backup: backup,
                 write_changes: true,
                 suggested_name: Some(meta.name.clone()),
                 suggested_description: None,
             };
-            match autofix_frontmatter(&meta.path, &content, &opts) {
+            let fixed = if validation_target == VT::Cursor {
+                autofix_cursor(&meta.path, &content, &opts)
+            } else {
+                autofix_frontmatter(&meta.path, &content, &opts)
+            };
+            match fixed {
                 Ok(fix_result) => {
                     if fix_result.modified {
                         fixed_count += 1;
-                        let new_content = std::fs::read_to_string(&meta.path)?;
-                        result = validate_skill(&meta.path, &new_content, validation_target);
+                        content = std::fs::read_to_string(&meta.path)?;
+                        result =
+                            validate_skill_with(&meta.path, &content, validation_target, &lint);
                     }
                 }
                 Err(e) => {
@@ -75,21 +106,120 @@ pub(crate) fn handle_validate_command(
             }
         }
 
+        // In SARIF, the fix `--autofix` would apply is attached to the
+        // issues it resolves.
+        let mut suggested_fix = None;
+        if format == ReportFormat::Sarif && needs_fix {
+            let opts = AutofixOptions {
+                suggested_name: Some(meta.name.clone()),
+                ..Default::default()
+            };
+            let fixed = if validation_target == VT::Cursor {
+                autofix_cursor(&meta.path, &content, &opts)
+            } else {
+                autofix_frontmatter(&meta.path, &content, &opts)
+            };
+            if let Some(fixed) = fixed.ok().filter(|f| f.modified) {
+                let after =
+                    validate_skill_with(&meta.path, &fixed.content, validation_target, &lint);
+                let resolves: Vec<(VT, String)> = result
+                    .issues
+                    .iter()
+                    .filter(|i| {
+                        !after
+                            .issues
+                            .iter()
+                            .any(|a| a.target == i.target && a.message == i.message)
+                    })
+                    .map(|i| (i.target, i.message.clone()))
+                    .collect();
+                suggested_fix = Some(SuggestedFix {
+                    fix: Fix {
+                        description: fixed.changes.join("; "),
+                        original: content.clone(),
+                        fixed: fixed.content,
+                    },
+                    resolves,
+                });
+            }
+        }
+
+        if check_links {
+            for warning in link_warnings(&meta.path, &content, &known, probe.as_ref()) {
+                let mut issue = match warning.level {
+                    WarningLevel::Error => ValidationIssue::error(validation_target, ""),
+                    WarningLevel::Warning => ValidationIssue::warning(validation_target, ""),
+                    WarningLevel::Info => ValidationIssue::info(validation_target, ""),
+                }
+                .with_rule(rule_id(warning.kind));
+                // Issue lines refer to the skill file; problems in module
+                // files carry their location in the message instead.
+                let in_skill_file = warning.context.as_deref().is_none_or(|c| c == meta.path);
+                issue.message = match warning.line {
+                    Some(line) if in_skill_file => {
+                        issue.line = Some(line);
+                        warning.message.clone()
+                    }
+                    Some(_) => format!(
+                        "{}: {}",
+                        warning_location(&meta.path, &warning),
+                        warning.message
+                    ),
+                    None => warning.message.clone(),
+                };
+                if issue.severity != Severity::Error && !errors_only {
+                    link_notes.push(format!(
+                        "  {} ({}): [{}] {}",
+                        result.name,
+                        warning_location(&meta.path, &warning),
+                        warning.level,
+                        warning.message
+                    ));
+                }
+                result.add_issue(issue);
+            }
+        }
+
         if !errors_only || result.has_errors() {
             results.push(result);
+            suggested_fixes.push(suggested_fix);
         }
     }
 
-    if format.is_json() {
+    if let Some(kind) = ReportKind::of(format) {
+        let mut report = Report::new("validate");
+        for (result, fix) in results.iter().zip(&suggested_fixes) {
+            let case = report.add_validation(result, fix.as_ref());
+            if let Some((path, error)) = autofix_failures.iter().find(|(p, _)| *p == result.path) {
+                case.findings.push(Finding::new(
+                    "autofix-failed",
+                    Level::Warning,
+                    format!("Auto-fix failed: {error}"),
+                    path,
+                ));
+            }
+        }
+        for (path, error) in &skipped_files {
+            let name = path.display().to_string();
+            report.add_case(name, path).findings.push(Finding::new(
+                "file-read-failed",
+                Level::Error,
+                format!("Could not read skill: {error}"),
+                path,
+            ));
+        }
+        report.print(kind)?;
+    } else if format.is_json() {
         println!("{}", serde_json::to_string_pretty(&results)?);
     } else {
         let summary = ValidationSummary::from_results(&results);
         println!(
-            "Validated {} skills: {} Claude-valid, {} Codex-valid, {} Copilot-valid, {} all-valid",
+            "Validated {} skills: {} Claude-valid, {} Codex-valid, {} Copilot-valid, {} Cursor-valid, {} all-valid",
             summary.total,
             summary.claude_valid,
             summary.codex_valid,
             summary.copilot_valid,
+            summary.cursor_valid,
             summary.all_valid
         );
         if fixed_count > 0 {
@@ -117,7 +247,7 @@ pub(crate) fn handle_validate_command(
             println!("\nErrors ({}):", summary.error_count);
             for result in &results {
                 for issue in &result.issues {
-                    if issue.severity == skrills_validate::Severity::Error {
+                    if issue.severity == Severity::Error {
                         let location = match issue.line {
                             Some(line) => format!("{}:{}", result.path.display(), line),
                             None => result.path.display().to_string(),
@@ -126,23 +256,83 @@ pub(crate) fn handle_validate_command(
                             VT::Claude => "Claude",
                             VT::Codex => "Codex",
                             VT::Copilot => "Copilot",
+                            VT::Cursor => "Cursor",
                             VT::Both => "Claude & Codex",
-                            VT::All => "Claude, Codex & Copilot",
+                            VT::All => "Claude, Codex, Copilot & Cursor",
                         };
                         let suggestion = issue
                             .suggestion
                             .as_ref()
                             .map(|s| format!(" Suggestion: {}", s))
                             .unwrap_or_default();
+                        let rule = issue
+                            .rule
+                            .as_ref()
+                            .map(|r| format!(", rule: {}", r))
+                            .unwrap_or_default();
                         println!(
-                            "  {} ({}): {} [target: {}]{}",
-                            result.name, location, issue.message, target, suggestion
+                            "  {} ({}): {} [target: {}{}]{}",
+                            result.name, location, issue.message, target, rule, suggestion
                         );
                     }
                 }
             }
         }
+        if !link_notes.is_empty() {
+            println!("\nLink warnings ({}):", link_notes.len());
+            for note in &link_notes {
+                println!("{}", note);
+            }
+        }
     }
 
     Ok(())
 }
+
+/// Handle the `lint-rules` command.
+pub(crate) fn handle_lint_rules_command(format: OutputFormat) -> Result<()> {
+    use skrills_validate::{LintRule, Severity, LINT_RULES};
+
+    #[derive(serde::Serialize)]
+    struct RuleEntry<'a> {
+        #[serde(flatten)]
+        rule: &'a LintRule,
+        severity: Option<Severity>,
+    }
+
+    let config = load_lint_config()?;
+    let entries: Vec<_> = LINT_RULES
+        .iter()
+        .map(|rule| RuleEntry {
+            rule,
+            severity: config.severity(rule),
+        })
+        .collect();
+
+    if format.is_json() {
+        println!("{}", serde_json::to_string_pretty(&entries)?);
+        return Ok(());
+    }
+
+    let level = |severity: Option<Severity>| match severity {
+        Some(Severity::Error) => "error",
+        Some(Severity::Warning) => "warning",
+        Some(Severity::Info) => "info",
+        Some(_) => "other",
+        None => "off",
+    };
+    for entry in &entries {
+        let default = level(entry.rule.default_severity);
+        let effective = level(entry.severity);
+        if default == effective {
+            println!("{} [{}]", entry.rule.id, effective);
+        } else {
+            println!("{} [{}, default {}]", entry.rule.id, effective, default);
+        }
+        println!("  {}", entry.rule.description);
+        for option in entry.rule.options {
+            println!("  {}: {}", option.name, option.description);
+        }
+    }
+    Ok(())
+}
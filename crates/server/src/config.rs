@@ -27,11 +27,18 @@
 //!
 //! # Cache TTL in milliseconds
 //! cache_ttl_ms = 5000
+//!
+//! [deps]
+//! # Registries `skrills deps install` searches for missing dependencies
+//! registries = ["https://skills.example.com", "file:///srv/skills"]
 //! ```
 
 use anyhow::Result;
 use serde::Deserialize;
-use std::path::PathBuf;
+use skrills_analyze::{Tokenizer, TokenizerConfig};
+use skrills_validate::LintConfig;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 /// Top-level configuration structure.
 #[derive(Debug, Default, Deserialize)]
@@ -39,6 +46,9 @@ pub struct Config {
     /// Serve command configuration.
     #[serde(default)]
     pub serve: ServeConfig,
+    /// Dependency installer configuration.
+    #[serde(default)]
+    pub deps: DepsConfig,
 }
 
 /// Configuration for the serve command.
@@ -60,6 +70,15 @@ pub struct ServeConfig {
     pub cache_ttl_ms: Option<u64>,
 }
 
+/// Configuration for `skrills deps`.
+#[derive(Debug, Default, Deserialize)]
+pub struct DepsConfig {
+    /// Skill registries searched, in order, for dependencies that are not
+    /// installed locally.
+    #[serde(default)]
+    pub registries: Vec<String>,
+}
+
 /// Returns the path to the config file (~/.skrills/config.toml).
 fn config_path() -> Option<PathBuf> {
     dirs::home_dir().map(|h| h.join(".skrills").join("config.toml"))
@@ -91,6 +110,73 @@ pub fn load_config() -> Result<Option<Config>> {
     Ok(Some(config))
 }
 
+/// Returns the registries configured under `[deps]` in the config file.
+pub(crate) fn configured_registries() -> Result<Vec<String>> {
+    Ok(load_config()?
+        .map(|config| config.deps.registries)
+        .unwrap_or_default())
+}
+
+/// Returns the path to the tokenizer config (~/.skrills/tokenizers.toml).
+fn tokenizers_path() -> Option<PathBuf> {
+    dirs::home_dir().map(|h| h.join(".skrills").join("tokenizers.toml"))
+}
+
+/// Resolves the tokenizer for counting skills aimed at `target` CLI.
+///
+/// Falls back to the heuristic when no home directory or no
+/// `tokenizers.toml` exists; errors when the file or the vocab/merges it
+/// names cannot be loaded.
+pub(crate) fn load_tokenizer(target: Option<&str>) -> Result<Arc<dyn Tokenizer>> {
+    let config = match tokenizers_path() {
+        Some(path) => TokenizerConfig::load(&path)?,
+        None => TokenizerConfig::default(),
+    };
+    let tokenizer = config.tokenizer_for(target)?;
+    tracing::debug!(
+        target: "skrills::config",
+        cli = target.unwrap_or("default"),
+        tokenizer = tokenizer.name(),
+        "Resolved tokenizer"
+    );
+    Ok(tokenizer)
+}
+
+/// Returns the path to the user lint config (`$SKRILLS_LINT_CONFIG` or
+/// ~/.skrills/lint.toml).
+fn lint_config_path() -> Option<PathBuf> {
+    std::env::var_os("SKRILLS_LINT_CONFIG")
+        .map(PathBuf::from)
+        .or_else(|| dirs::home_dir().map(|h| h.join(".skrills").join("lint.toml")))
+}
+
+/// Finds the nearest `.skrills/lint.toml` at or above `dir`.
+fn project_lint_config_path(dir: &Path) -> Option<PathBuf> {
+    dir.ancestors()
+        .map(|d| d.join(".skrills").join("lint.toml"))
+        .find(|p| p.is_file())
+}
+
+/// Resolves the lint configuration: the user file with the nearest project
+/// `.skrills/lint.toml` above the working directory layered on top.
+///
+/// Missing files fall back to the default rule set; files that fail to
+/// parse or name unknown rules are errors.
+pub(crate) fn load_lint_config() -> Result<LintConfig> {
+    let mut config = match lint_config_path() {
+        Some(path) => LintConfig::load(&path)?,
+        None => LintConfig::default(),
+    };
+    let project = std::env::current_dir()
+        .ok()
+        .and_then(|cwd| project_lint_config_path(&cwd));
+    if let Some(path) = project {
+        tracing::debug!(target: "skrills::config", path = %path.display(), "Using project lint config");
+        config.layer(LintConfig::load(&path)?);
+    }
+    Ok(config)
+}
+
 /// Applies configuration file settings to environment variables.
 ///
 /// Only sets environment variables that are not already set, preserving
@@ -192,6 +278,57 @@ mod tests {
         assert!(path.ends_with(".skrills/config.toml"));
     }
 
+    #[test]
+    fn load_tokenizer_uses_per_cli_entry_from_home() {
+        let _guard = crate::test_support::env_guard();
+        let home = tempfile::tempdir().unwrap();
+        let dir = home.path().join(".skrills");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("vocab.json"), r#"{"a":0,"b":1,"ab":2}"#).unwrap();
+        std::fs::write(dir.join("merges.txt"), "#version: 0.2\na b\n").unwrap();
+        std::fs::write(
+            dir.join("tokenizers.toml"),
+            "[cli.codex]\nkind = \"bpe\"\nvocab = \"vocab.json\"\nmerges = \"merges.txt\"\n",
+        )
+        .unwrap();
+        let _home = crate::test_support::set_env_var("HOME", Some(home.path().to_str().unwrap()));
+
+        assert_eq!(load_tokenizer(Some("codex")).unwrap().name(), "bpe");
+        assert_eq!(load_tokenizer(Some("claude")).unwrap().name(), "heuristic");
+        assert_eq!(load_tokenizer(None).unwrap().name(), "heuristic");
+    }
+
+    #[test]
+    fn lint_config_layers_project_file_over_user_file() {
+        let _guard = crate::test_support::env_guard();
+        let tmp = tempfile::tempdir().unwrap();
+        let user = tmp.path().join("lint.toml");
+        std::fs::write(
+            &user,
+            "[rules]\nlong-line = \"off\"\nskill-size = \"error\"\n",
+        )
+        .unwrap();
+        let project = tmp.path().join("repo");
+        std::fs::create_dir_all(project.join(".skrills")).unwrap();
+        std::fs::create_dir_all(project.join("skills/nested")).unwrap();
+        std::fs::write(
+            project.join(".skrills/lint.toml"),
+            "[rules]\nskill-size = \"off\"\n",
+        )
+        .unwrap();
+        let _env = crate::test_support::set_env_var("SKRILLS_LINT_CONFIG", user.to_str());
+
+        let found = project_lint_config_path(&project.join("skills/nested")).unwrap();
+        assert_eq!(found, project.join(".skrills/lint.toml"));
+
+        let mut config = LintConfig::load(&lint_config_path().unwrap()).unwrap();
+        config.layer(LintConfig::load(&found).unwrap());
+        let rule = |id| skrills_validate::lint::find_rule(id).unwrap();
+        assert_eq!(config.severity(rule("long-line")), None);
+        assert_eq!(config.severity(rule("skill-size")), None);
+        assert!(project_lint_config_path(tmp.path()).is_none());
+    }
+
     #[test]
     fn parse_minimal_config() {
         let toml = r#"
@@ -228,6 +365,21 @@ mod tests {
         assert_eq!(config.serve.cache_ttl_ms, Some(5000));
     }
 
+    #[test]
+    fn parse_deps_registries() {
+        let toml = r#"
+            [deps]
+            registries = ["https://skills.example.com", "file:///srv/skills"]
+        "#;
+
+        let config: Config = toml::from_str(toml).unwrap();
+        assert_eq!(
+            config.deps.registries,
+            ["https://skills.example.com", "file:///srv/skills"]
+        );
+        assert!(config.serve.auth_token.is_none());
+    }
+
     #[test]
     fn load_nonexistent_config_returns_none() {
         // This test relies on the config file not existing in a typical CI environment
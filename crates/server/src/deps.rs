@@ -0,0 +1,1076 @@
+//! Dependency installer and the `skills.lock` lockfile behind `skrills deps`.
+//!
+//! `deps install <skill>` walks the `depends` frontmatter of a skill and its
+//! dependencies. Each dependency is taken from the discovered skill roots
+//! when a matching version is installed there. Otherwise it comes from the
+//! first configured registry (see [`crate::registry`]) that publishes a
+//! matching version. The collected set is checked with
+//! [`skrills_analyze::resolve::DependencyGraph`], which also orders it
+//! dependencies first. The result is pinned in a lockfile:
+//!
+//! ```json
+//! {
+//!   "version": 1,
+//!   "roots": ["release-notes"],
+//!   "skills": [
+//!     {
+//!       "name": "changelog-format",
+//!       "version": "1.2.0",
+//!       "registry": "https://skills.example.com",
+//!       "files": [{ "path": "SKILL.md", "sha256": "9f86d0..." }]
+//!     },
+//!     {
+//!       "name": "release-notes",
+//!       "files": [{ "path": "SKILL.md", "sha256": "60303a..." }]
+//!     }
+//!   ]
+//! }
+//! ```
+//!
+//! `deps install` without a skill installs exactly the locked files, and
+//! `deps verify` reports where the installed skills diverge from the lock.
+
+use crate::cli::SyncSource;
+use crate::registry::{
+    install_files, installed_files_match, now_rfc3339, sha256_hex, LockedSkill, RegistryClient,
+    RegistryEntry, RegistryFile, RegistryIndex, RegistryLock,
+};
+use anyhow::{anyhow, bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use skrills_analyze::resolve::{GraphBuilder, SkillInfo};
+use skrills_discovery::{SkillMeta, SkillSource};
+use skrills_validate::frontmatter::{parse_frontmatter, NormalizedDependency};
+use std::cell::OnceCell;
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+/// Default lockfile name, resolved against the current directory.
+pub(crate) const LOCK_FILE: &str = "skills.lock";
+
+/// Lockfile schema version.
+const LOCK_VERSION: u32 = 1;
+
+/// A resolved skill bundle pinned to exact file contents.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct SkillsLock {
+    version: u32,
+    /// Skills the bundle was resolved for.
+    #[serde(default)]
+    pub roots: Vec<String>,
+    /// Every skill in the bundle, dependencies before their dependents.
+    #[serde(default)]
+    pub skills: Vec<LockedDependency>,
+}
+
+impl Default for SkillsLock {
+    fn default() -> Self {
+        Self {
+            version: LOCK_VERSION,
+            roots: Vec::new(),
+            skills: Vec::new(),
+        }
+    }
+}
+
+/// One skill pinned by the lockfile.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct LockedDependency {
+    /// Skill name.
+    pub name: String,
+    /// Version at lock time, if the skill declares one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    /// Registry the skill was installed from; `None` for skills that were
+    /// already present in a local skill directory.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub registry: Option<String>,
+    /// Whether the skill is only reached through optional dependencies.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub optional: bool,
+    /// Files of the skill directory and their hashes, sorted by path.
+    pub files: Vec<RegistryFile>,
+}
+
+impl SkillsLock {
+    /// Loads the lockfile, returning an empty lock if it does not exist.
+    pub(crate) fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let raw = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let lock: Self = serde_json::from_str(&raw)
+            .with_context(|| format!("Failed to parse {}", path.display()))?;
+        if lock.version > LOCK_VERSION {
+            bail!(
+                "Lockfile {} has version {}, newer than supported version {}",
+                path.display(),
+                lock.version,
+                LOCK_VERSION
+            );
+        }
+        Ok(lock)
+    }
+
+    /// Writes the lockfile, creating parent directories as needed.
+    pub(crate) fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)?;
+        }
+        let tmp = path.with_extension("lock.tmp");
+        std::fs::write(&tmp, serde_json::to_string_pretty(self)? + "\n")?;
+        std::fs::rename(&tmp, path)
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+        Ok(())
+    }
+
+    /// Returns the locked entry for `name`.
+    pub(crate) fn find(&self, name: &str) -> Option<&LockedDependency> {
+        self.skills.iter().find(|s| s.name == name)
+    }
+}
+
+/// Where `deps install` writes registry skills and records them.
+pub(crate) struct InstallOptions<'a> {
+    /// CLI the skills are installed for.
+    pub target: SyncSource,
+    /// Skills directory of `target`.
+    pub skills_dir: &'a Path,
+    /// Registry lockfile shared with `sync-pull`.
+    pub registry_lock: &'a Path,
+    /// Report what would be installed without writing anything.
+    pub dry_run: bool,
+    /// Replace local copies that were not installed from a registry.
+    pub force: bool,
+}
+
+/// What `deps install` did with one skill.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) enum DepAction {
+    /// Already installed.
+    Present,
+    /// Downloaded, verified and installed.
+    Installed,
+    /// Would be installed (dry run).
+    WouldInstall,
+    /// Optional, locked but not available; left out.
+    Skipped,
+}
+
+/// Per-skill outcome of `deps install`.
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct DepOutcome {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    pub action: DepAction,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub registry: Option<String>,
+    pub optional: bool,
+}
+
+/// Result of `deps install`.
+#[derive(Debug, Serialize)]
+pub(crate) struct InstallReport {
+    pub dry_run: bool,
+    pub skills: Vec<DepOutcome>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+}
+
+/// How an installed skill differs from the lockfile.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) enum DivergenceKind {
+    /// A locked skill is not installed.
+    Missing,
+    /// An installed skill's files differ from the locked hashes.
+    Modified,
+    /// A locked skill requires a skill the lock does not pin.
+    Unlocked,
+}
+
+/// One difference found by `deps verify`.
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct Divergence {
+    pub name: String,
+    pub kind: DivergenceKind,
+    pub detail: String,
+}
+
+/// Registries searched for dependencies, opened on first use so that a
+/// fully installed bundle never touches the network.
+pub(crate) struct RegistrySet {
+    registries: Vec<(String, OnceCell<(RegistryClient, RegistryIndex)>)>,
+}
+
+impl RegistrySet {
+    /// Creates a set searching `sources` in order.
+    pub(crate) fn new(sources: Vec<String>) -> Self {
+        Self {
+            registries: sources.into_iter().map(|s| (s, OnceCell::new())).collect(),
+        }
+    }
+
+    fn open(&self, idx: usize) -> Result<&(RegistryClient, RegistryIndex)> {
+        let (source, cell) = &self.registries[idx];
+        if let Some(opened) = cell.get() {
+            return Ok(opened);
+        }
+        let client = RegistryClient::open(source)?;
+        let index = client
+            .load_index()
+            .with_context(|| format!("Failed to load registry {source}"))?;
+        Ok(cell.get_or_init(|| (client, index)))
+    }
+
+    /// Returns the highest version of `name` matching `req` from the first
+    /// registry that has one.
+    fn best_match(
+        &self,
+        name: &str,
+        req: Option<&semver::VersionReq>,
+    ) -> Result<Option<(&str, &RegistryClient, &RegistryEntry)>> {
+        for idx in 0..self.registries.len() {
+            let (client, index) = self.open(idx)?;
+            let best = index
+                .skills
+                .iter()
+                .filter(|e| e.name == name)
+                .filter_map(|e| {
+                    let version = semver::Version::parse(&e.version).ok();
+                    match (req, &version) {
+                        (Some(req), Some(v)) if !req.matches(v) => None,
+                        (Some(_), None) => None,
+                        _ => Some((version, e)),
+                    }
+                })
+                .max_by(|a, b| a.0.cmp(&b.0))
+                .map(|(_, e)| e);
+            if let Some(entry) = best {
+                return Ok(Some((self.registries[idx].0.as_str(), client, entry)));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Returns a registry entry publishing exactly the locked files,
+    /// preferring the registry the lock names.
+    fn exact_match(
+        &self,
+        locked: &LockedDependency,
+    ) -> Result<Option<(&str, &RegistryClient, &RegistryEntry)>> {
+        let mut order: Vec<usize> = (0..self.registries.len()).collect();
+        if let Some(preferred) = &locked.registry {
+            order.sort_by_key(|&idx| self.registries[idx].0 != *preferred);
+        }
+        for idx in order {
+            let (client, index) = self.open(idx)?;
+            let entry = index.skills.iter().find(|e| {
+                e.name == locked.name
+                    && locked.version.as_ref().is_none_or(|v| *v == e.version)
+                    && sorted_files(&e.files) == locked.files
+            });
+            if let Some(entry) = entry {
+                return Ok(Some((self.registries[idx].0.as_str(), client, entry)));
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// A skill chosen to satisfy a dependency.
+struct Candidate {
+    info: SkillInfo,
+    origin: Origin,
+}
+
+enum Origin {
+    /// Installed in a discovered skill directory.
+    Local { dir: PathBuf },
+    /// To be installed from a registry; files are already verified.
+    Registry {
+        source: String,
+        entry: RegistryEntry,
+        files: Vec<(PathBuf, Vec<u8>)>,
+    },
+}
+
+/// A dependency still to be satisfied while walking the closure.
+struct Request {
+    name: String,
+    source: Option<String>,
+    version_req: Option<semver::VersionReq>,
+    optional: bool,
+    required_by: Option<String>,
+}
+
+impl Request {
+    fn root(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            source: None,
+            version_req: None,
+            optional: false,
+            required_by: None,
+        }
+    }
+
+    fn dependency(dep: NormalizedDependency, required_by: &str) -> Self {
+        Self {
+            name: dep.name,
+            source: dep.source,
+            version_req: dep.version_req,
+            optional: dep.optional,
+            required_by: Some(required_by.to_string()),
+        }
+    }
+}
+
+/// The dependency closure of a set of root skills, in install order.
+pub(crate) struct Resolution {
+    skills: Vec<(Candidate, bool)>,
+    warnings: Vec<String>,
+}
+
+/// Resolves the transitive `depends` closure of `roots`.
+///
+/// Locally installed skills are preferred; registries are consulted for
+/// skills that are missing or whose installed version does not satisfy the
+/// requirement. Registry files are downloaded and verified here, but nothing
+/// is written until [`install`].
+pub(crate) fn resolve(
+    roots: &[String],
+    local: &[SkillMeta],
+    registries: &RegistrySet,
+    target: SyncSource,
+) -> Result<Resolution> {
+    let mut candidates: Vec<Candidate> = Vec::new();
+    let mut queue: VecDeque<Request> = roots.iter().map(|r| Request::root(r)).collect();
+
+    while let Some(request) = queue.pop_front() {
+        if candidates.iter().any(|c| c.info.name == request.name) {
+            continue;
+        }
+        let Some(candidate) = find_candidate(&request, local, registries, target)? else {
+            // Optional and unavailable; the graph reports it as skipped.
+            continue;
+        };
+        if let Some(fm) = &candidate.info.frontmatter {
+            let deps = fm
+                .normalized_dependencies()
+                .map_err(|e| anyhow!("Invalid dependencies in '{}': {e}", candidate.info.name))?;
+            queue.extend(
+                deps.into_iter()
+                    .map(|dep| Request::dependency(dep, &candidate.info.name)),
+            );
+        }
+        candidates.push(candidate);
+    }
+
+    let graph = GraphBuilder::new()
+        .add_skills(candidates.iter().map(|c| c.info.clone()))
+        .build()?;
+    let mut order: Vec<(String, bool)> = Vec::new();
+    let mut warnings: Vec<String> = Vec::new();
+    for root in roots {
+        let result = graph.resolve(root)?;
+        for warning in result.warnings {
+            if !warnings.contains(&warning) {
+                warnings.push(warning);
+            }
+        }
+        for dep in result.resolved {
+            match order.iter_mut().find(|(name, _)| *name == dep.name) {
+                Some((_, optional)) => *optional &= dep.optional,
+                None => order.push((dep.name, dep.optional)),
+            }
+        }
+    }
+
+    let mut skills = Vec::with_capacity(order.len());
+    for (name, optional) in order {
+        if let Some(pos) = candidates.iter().position(|c| c.info.name == name) {
+            skills.push((candidates.swap_remove(pos), optional));
+        }
+    }
+    Ok(Resolution { skills, warnings })
+}
+
+fn find_candidate(
+    request: &Request,
+    local: &[SkillMeta],
+    registries: &RegistrySet,
+    target: SyncSource,
+) -> Result<Option<Candidate>> {
+    let installed = local.iter().find(|meta| {
+        skill_name(meta) == request.name
+            && request
+                .source
+                .as_ref()
+                .is_none_or(|s| meta.source.label() == *s)
+    });
+    let installed = installed.map(local_candidate).transpose()?;
+    let satisfied =
+        installed
+            .as_ref()
+            .is_some_and(|c| match (&request.version_req, &c.info.version) {
+                (Some(req), Some(version)) => req.matches(version),
+                _ => true,
+            });
+    if satisfied {
+        return Ok(installed);
+    }
+
+    let from_registry = if request.source.as_ref().is_none_or(|s| s == target.as_str()) {
+        registries.best_match(&request.name, request.version_req.as_ref())?
+    } else {
+        None
+    };
+    if let Some((source, client, entry)) = from_registry {
+        return registry_candidate(source, client, entry, target).map(Some);
+    }
+    if installed.is_some() {
+        // Let the graph report the version mismatch.
+        return Ok(installed);
+    }
+    if request.optional {
+        return Ok(None);
+    }
+
+    let wanted = match &request.version_req {
+        Some(req) => format!("'{}' {}", request.name, req),
+        None => format!("'{}'", request.name),
+    };
+    match &request.required_by {
+        Some(parent) => {
+            bail!("Dependency {wanted} of '{parent}' is not installed and no registry provides it")
+        }
+        None => bail!("Skill {wanted} is not installed and no registry provides it"),
+    }
+}
+
+fn local_candidate(meta: &SkillMeta) -> Result<Candidate> {
+    let content = std::fs::read_to_string(&meta.path)
+        .with_context(|| format!("Failed to read {}", meta.path.display()))?;
+    let frontmatter = parse_frontmatter(&content)
+        .map_err(|e| anyhow!("Invalid frontmatter in {}: {e}", meta.path.display()))?
+        .frontmatter;
+    let version = frontmatter
+        .as_ref()
+        .and_then(|fm| fm.parsed_version())
+        .and_then(|v| v.ok());
+    let relative = meta.path.strip_prefix(&meta.root).unwrap_or(&meta.path);
+    Ok(Candidate {
+        info: SkillInfo {
+            name: skill_name(meta),
+            source: meta.source.clone(),
+            uri: format!(
+                "skill://skrills/{}/{}",
+                meta.source.label(),
+                relative.to_string_lossy()
+            ),
+            version,
+            frontmatter,
+        },
+        origin: Origin::Local {
+            dir: skill_dir(meta).to_path_buf(),
+        },
+    })
+}
+
+fn registry_candidate(
+    source: &str,
+    client: &RegistryClient,
+    entry: &RegistryEntry,
+    target: SyncSource,
+) -> Result<Candidate> {
+    let files = client.fetch_verified(entry)?;
+    let skill_md = files
+        .iter()
+        .find(|(path, _)| path == Path::new("SKILL.md"))
+        .map(|(_, content)| String::from_utf8_lossy(content).into_owned())
+        .unwrap_or_default();
+    let frontmatter = parse_frontmatter(&skill_md)
+        .map_err(|e| {
+            anyhow!(
+                "Invalid frontmatter in registry skill '{}': {e}",
+                entry.name
+            )
+        })?
+        .frontmatter;
+    Ok(Candidate {
+        info: SkillInfo {
+            name: entry.name.clone(),
+            source: discovery_source(target),
+            uri: format!(
+                "skill://skrills/{}/{}/SKILL.md",
+                target.as_str(),
+                entry.name
+            ),
+            version: semver::Version::parse(&entry.version).ok(),
+            frontmatter,
+        },
+        origin: Origin::Registry {
+            source: source.to_string(),
+            entry: entry.clone(),
+            files,
+        },
+    })
+}
+
+/// Installs the registry skills of `resolution` and returns the lockfile
+/// pinning the whole closure of `roots`.
+///
+/// Every install directory is checked before anything is written, so a
+/// refused overwrite leaves the skills directory untouched.
+pub(crate) fn install(
+    resolution: Resolution,
+    roots: Vec<String>,
+    opts: &InstallOptions<'_>,
+) -> Result<(SkillsLock, InstallReport)> {
+    let mut registry_lock = RegistryLock::load(opts.registry_lock)?;
+    for (candidate, _) in &resolution.skills {
+        if let Origin::Registry { entry, .. } = &candidate.origin {
+            check_replaceable(&entry.name, &registry_lock, opts)?;
+        }
+    }
+
+    let mut lock = SkillsLock {
+        roots,
+        ..SkillsLock::default()
+    };
+    let mut outcomes = Vec::with_capacity(resolution.skills.len());
+    for (candidate, optional) in resolution.skills {
+        let version = candidate.info.version.as_ref().map(|v| v.to_string());
+        let (action, registry, files) = match candidate.origin {
+            Origin::Local { dir } => (DepAction::Present, None, skill_files(&dir)?),
+            Origin::Registry {
+                source,
+                entry,
+                files,
+            } => {
+                let action = if opts.dry_run {
+                    DepAction::WouldInstall
+                } else {
+                    install_entry(&source, &entry, &files, &mut registry_lock, opts)?;
+                    DepAction::Installed
+                };
+                (action, Some(source), sorted_files(&entry.files))
+            }
+        };
+        outcomes.push(DepOutcome {
+            name: candidate.info.name.clone(),
+            version: version.clone(),
+            action,
+            registry: registry.clone(),
+            optional,
+        });
+        lock.skills.push(LockedDependency {
+            name: candidate.info.name,
+            version,
+            registry,
+            optional,
+            files,
+        });
+    }
+
+    if outcomes.iter().any(|o| o.action == DepAction::Installed) {
+        registry_lock.save(opts.registry_lock)?;
+    }
+    Ok((
+        lock,
+        InstallReport {
+            dry_run: opts.dry_run,
+            skills: outcomes,
+            warnings: resolution.warnings,
+        },
+    ))
+}
+
+/// Installs exactly what `lock` pins.
+///
+/// Locked skills that are installed with the locked files are left alone;
+/// missing ones are installed from a registry publishing the same files.
+/// Installed skills whose files differ are an error unless `force` is set,
+/// in which case they are reinstalled from a registry too.
+pub(crate) fn install_locked(
+    lock: &SkillsLock,
+    local: &[SkillMeta],
+    registries: &RegistrySet,
+    opts: &InstallOptions<'_>,
+) -> Result<InstallReport> {
+    let mut registry_lock = RegistryLock::load(opts.registry_lock)?;
+    let mut planned = Vec::new();
+    let mut outcomes = Vec::new();
+    let mut warnings = Vec::new();
+    let mut modified = Vec::new();
+
+    for locked in &lock.skills {
+        let outcome = |action, registry: Option<&str>| DepOutcome {
+            name: locked.name.clone(),
+            version: locked.version.clone(),
+            action,
+            registry: registry.map(str::to_string),
+            optional: locked.optional,
+        };
+        let installed = local.iter().find(|meta| skill_name(meta) == locked.name);
+        if let Some(meta) = installed {
+            if skill_files(skill_dir(meta))? == locked.files {
+                outcomes.push(outcome(DepAction::Present, None));
+                continue;
+            }
+            if !opts.force {
+                modified.push(locked.name.clone());
+                continue;
+            }
+        }
+        match registries.exact_match(locked)? {
+            Some((source, client, entry)) => {
+                check_replaceable(&entry.name, &registry_lock, opts)?;
+                let files = if opts.dry_run {
+                    Vec::new()
+                } else {
+                    client.fetch_verified(entry)?
+                };
+                let action = if opts.dry_run {
+                    DepAction::WouldInstall
+                } else {
+                    DepAction::Installed
+                };
+                outcomes.push(outcome(action, Some(source)));
+                planned.push((source.to_string(), entry.clone(), files));
+            }
+            None if locked.optional => {
+                warnings.push(format!(
+                    "Skipped optional dependency '{}': no registry provides the locked files",
+                    locked.name
+                ));
+                outcomes.push(outcome(DepAction::Skipped, None));
+            }
+            None => bail!(
+                "'{}'{} is not installed and no registry provides the locked files",
+                locked.name,
+                locked
+                    .version
+                    .as_ref()
+                    .map(|v| format!(" {v}"))
+                    .unwrap_or_default()
+            ),
+        }
+    }
+
+    if !modified.is_empty() {
+        bail!(
+            "Installed skills differ from the lock: {}. Run `skrills deps verify` for details, \
+             or pass --force to reinstall them from a registry",
+            modified.join(", ")
+        );
+    }
+    if !opts.dry_run {
+        for (source, entry, files) in &planned {
+            install_entry(source, entry, files, &mut registry_lock, opts)?;
+        }
+        if !planned.is_empty() {
+            registry_lock.save(opts.registry_lock)?;
+        }
+    }
+    Ok(InstallReport {
+        dry_run: opts.dry_run,
+        skills: outcomes,
+        warnings,
+    })
+}
+
+/// Compares the installed skills with `lock`.
+pub(crate) fn verify(lock: &SkillsLock, local: &[SkillMeta]) -> Result<Vec<Divergence>> {
+    let mut divergences = Vec::new();
+    for locked in &lock.skills {
+        let Some(meta) = local.iter().find(|meta| skill_name(meta) == locked.name) else {
+            if !locked.optional {
+                divergences.push(Divergence {
+                    name: locked.name.clone(),
+                    kind: DivergenceKind::Missing,
+                    detail: "not installed".to_string(),
+                });
+            }
+            continue;
+        };
+
+        let files = skill_files(skill_dir(meta))?;
+        if files != locked.files {
+            divergences.push(Divergence {
+                name: locked.name.clone(),
+                kind: DivergenceKind::Modified,
+                detail: describe_file_changes(&locked.files, &files),
+            });
+        }
+
+        let candidate = local_candidate(meta)?;
+        let deps = match &candidate.info.frontmatter {
+            Some(fm) => fm
+                .normalized_dependencies()
+                .map_err(|e| anyhow!("Invalid dependencies in '{}': {e}", locked.name))?,
+            None => Vec::new(),
+        };
+        for dep in deps.into_iter().filter(|d| !d.optional) {
+            if lock.find(&dep.name).is_none() {
+                divergences.push(Divergence {
+                    name: dep.name,
+                    kind: DivergenceKind::Unlocked,
+                    detail: format!("required by '{}' but not in the lock", locked.name),
+                });
+            }
+        }
+    }
+    Ok(divergences)
+}
+
+/// Refuses to replace a local copy that was not installed from a registry,
+/// or was edited since, unless `force` is set.
+fn check_replaceable(
+    name: &str,
+    registry_lock: &RegistryLock,
+    opts: &InstallOptions<'_>,
+) -> Result<()> {
+    let dir = opts.skills_dir.join(name);
+    let pristine = registry_lock
+        .find(name, opts.target.as_str())
+        .is_some_and(|l| installed_files_match(&dir, &l.files));
+    if dir.exists() && !pristine && !opts.force {
+        bail!(
+            "{} exists and was not installed from a registry; pass --force to replace it",
+            dir.display()
+        );
+    }
+    Ok(())
+}
+
+fn install_entry(
+    source: &str,
+    entry: &RegistryEntry,
+    files: &[(PathBuf, Vec<u8>)],
+    registry_lock: &mut RegistryLock,
+    opts: &InstallOptions<'_>,
+) -> Result<()> {
+    let dir = opts.skills_dir.join(&entry.name);
+    let previous = registry_lock
+        .find(&entry.name, opts.target.as_str())
+        .map(|l| l.files.clone());
+    install_files(&dir, files, previous.as_deref())?;
+    registry_lock.upsert(LockedSkill {
+        name: entry.name.clone(),
+        version: entry.version.clone(),
+        source: source.to_string(),
+        target: opts.target.as_str().to_string(),
+        path: dir,
+        files: entry.files.clone(),
+        pulled_at: now_rfc3339(),
+    });
+    Ok(())
+}
+
+/// Name dependencies refer to a skill by: its frontmatter `name`, or the
+/// name of its directory.
+pub(crate) fn skill_name(meta: &SkillMeta) -> String {
+    meta.frontmatter_name.clone().unwrap_or_else(|| {
+        skill_dir(meta)
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_else(|| meta.name.clone())
+    })
+}
+
+fn skill_dir(meta: &SkillMeta) -> &Path {
+    meta.path.parent().unwrap_or(&meta.root)
+}
+
+/// Discovery source that skills installed for `target` are found under.
+pub(crate) fn discovery_source(target: SyncSource) -> SkillSource {
+    match target {
+        SyncSource::Claude => SkillSource::Claude,
+        SyncSource::Codex => SkillSource::Codex,
+        SyncSource::Copilot => SkillSource::Copilot,
+        SyncSource::Cursor => SkillSource::Cursor,
+    }
+}
+
+/// Hashes the files of a skill directory, skipping hidden entries and
+/// nested skills.
+pub(crate) fn skill_files(dir: &Path) -> Result<Vec<RegistryFile>> {
+    let walker = WalkDir::new(dir).into_iter().filter_entry(|e| {
+        e.depth() == 0
+            || !(e.file_name().to_string_lossy().starts_with('.')
+                || (e.file_type().is_dir() && e.path().join("SKILL.md").is_file()))
+    });
+    let mut files = Vec::new();
+    for entry in walker {
+        let entry = entry?;
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        let relative = entry.path().strip_prefix(dir).unwrap_or(entry.path());
+        let path = relative
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        let content = std::fs::read(entry.path())
+            .with_context(|| format!("Failed to read {}", entry.path().display()))?;
+        files.push(RegistryFile {
+            path,
+            sha256: sha256_hex(&content),
+        });
+    }
+    files.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(files)
+}
+
+fn sorted_files(files: &[RegistryFile]) -> Vec<RegistryFile> {
+    let mut files = files.to_vec();
+    for file in &mut files {
+        file.sha256.make_ascii_lowercase();
+    }
+    files.sort_by(|a, b| a.path.cmp(&b.path));
+    files
+}
+
+fn describe_file_changes(locked: &[RegistryFile], installed: &[RegistryFile]) -> String {
+    let mut parts = Vec::new();
+    let changed: Vec<_> = locked
+        .iter()
+        .filter(|l| {
+            installed
+                .iter()
+                .any(|i| i.path == l.path && i.sha256 != l.sha256)
+        })
+        .map(|l| l.path.as_str())
+        .collect();
+    let missing: Vec<_> = locked
+        .iter()
+        .filter(|l| !installed.iter().any(|i| i.path == l.path))
+        .map(|l| l.path.as_str())
+        .collect();
+    let added: Vec<_> = installed
+        .iter()
+        .filter(|i| !locked.iter().any(|l| l.path == i.path))
+        .map(|i| i.path.as_str())
+        .collect();
+    for (label, paths) in [("changed", changed), ("missing", missing), ("added", added)] {
+        if !paths.is_empty() {
+            parts.push(format!("{label}: {}", paths.join(", ")));
+        }
+    }
+    parts.join("; ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use skrills_discovery::{discover_skills, SkillRoot};
+    use skrills_test_utils::TestFixture;
+
+    /// Project directory holding the skills that declare dependencies.
+    fn project(fx: &TestFixture) -> PathBuf {
+        let project = fx.home_path().join("project");
+        std::fs::create_dir_all(&project).unwrap();
+        project
+    }
+
+    fn registry_lock(fx: &TestFixture) -> PathBuf {
+        fx.home_path().join(".skrills/registry-lock.json")
+    }
+
+    fn local_skill(fx: &TestFixture, name: &str, frontmatter: &str) {
+        let dir = project(fx).join(name);
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(
+            dir.join("SKILL.md"),
+            format!("---\nname: {name}\ndescription: {name}\n{frontmatter}---\n# {name}\n"),
+        )
+        .unwrap();
+    }
+
+    fn registries(fx: &TestFixture) -> RegistrySet {
+        RegistrySet::new(vec![fx.registry_source()])
+    }
+
+    fn local(fx: &TestFixture) -> Vec<SkillMeta> {
+        let roots = [
+            SkillRoot {
+                root: fx.claude_skills.clone(),
+                source: SkillSource::Claude,
+            },
+            SkillRoot {
+                root: project(fx),
+                source: SkillSource::Extra(0),
+            },
+        ];
+        discover_skills(&roots, None).unwrap()
+    }
+
+    fn opts<'a>(fx: &'a TestFixture, registry_lock: &'a Path) -> InstallOptions<'a> {
+        InstallOptions {
+            target: SyncSource::Claude,
+            skills_dir: &fx.claude_skills,
+            registry_lock,
+            dry_run: false,
+            force: false,
+        }
+    }
+
+    fn install_roots(fx: &TestFixture, roots: &[&str]) -> Result<(SkillsLock, InstallReport)> {
+        let roots: Vec<String> = roots.iter().map(|r| r.to_string()).collect();
+        let resolution = resolve(&roots, &local(fx), &registries(fx), SyncSource::Claude)?;
+        install(resolution, roots, &opts(fx, &registry_lock(fx)))
+    }
+
+    fn registry_skill(name: &str, version: &str, depends: &str) -> String {
+        format!("---\nname: {name}\ndescription: {name}\nversion: {version}\n{depends}---\n# {name} {version}\n")
+    }
+
+    #[test]
+    fn install_fetches_missing_dependencies_and_locks_the_closure() {
+        let fx = TestFixture::new().unwrap();
+        local_skill(
+            &fx,
+            "release-notes",
+            "depends:\n  - changelog-format@^1.0\n",
+        );
+        fx.publish_registry(&[
+            (
+                "changelog-format",
+                "1.2.0",
+                &registry_skill("changelog-format", "1.2.0", "depends:\n  - wording\n"),
+            ),
+            (
+                "changelog-format",
+                "2.0.0",
+                &registry_skill("changelog-format", "2.0.0", ""),
+            ),
+            ("wording", "0.3.0", &registry_skill("wording", "0.3.0", "")),
+        ])
+        .unwrap();
+
+        let (lock, report) = install_roots(&fx, &["release-notes"]).unwrap();
+
+        let names: Vec<_> = lock.skills.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, ["wording", "changelog-format", "release-notes"]);
+        assert_eq!(lock.skills[1].version.as_deref(), Some("1.2.0"));
+        assert!(lock.skills[1].registry.is_some());
+        assert!(lock.skills[2].registry.is_none());
+        assert_eq!(report.skills[2].action, DepAction::Present);
+        let installed =
+            std::fs::read_to_string(fx.claude_skills.join("changelog-format/SKILL.md")).unwrap();
+        assert!(installed.contains("changelog-format 1.2.0"));
+        assert!(RegistryLock::load(&registry_lock(&fx))
+            .unwrap()
+            .find("wording", "claude")
+            .is_some());
+
+        assert!(verify(&lock, &local(&fx)).unwrap().is_empty());
+    }
+
+    #[test]
+    fn install_reports_missing_required_dependencies_and_skips_optional_ones() {
+        let fx = TestFixture::new().unwrap();
+        fx.publish_registry(&[]).unwrap();
+        local_skill(
+            &fx,
+            "app",
+            "depends:\n  - name: extras\n    optional: true\n  - base@^1.0\n",
+        );
+        let err = install_roots(&fx, &["app"]).unwrap_err().to_string();
+        assert!(err.contains("'base' ^1.0 of 'app'"), "{err}");
+
+        local_skill(
+            &fx,
+            "app",
+            "depends:\n  - name: extras\n    optional: true\n",
+        );
+        let (lock, report) = install_roots(&fx, &["app"]).unwrap();
+        assert_eq!(lock.skills.len(), 1);
+        assert!(report.warnings.iter().any(|w| w.contains("extras")));
+    }
+
+    #[test]
+    fn version_mismatch_with_local_copy_is_reported_by_the_graph() {
+        let fx = TestFixture::new().unwrap();
+        fx.publish_registry(&[]).unwrap();
+        local_skill(&fx, "base", "version: 0.9.0\n");
+        local_skill(&fx, "app", "depends:\n  - base@^1.0\n");
+        let err = install_roots(&fx, &["app"]).unwrap_err().to_string();
+        assert!(err.contains("Version mismatch for 'base'"), "{err}");
+    }
+
+    #[test]
+    fn installing_over_an_unmanaged_copy_needs_force() {
+        let fx = TestFixture::new().unwrap();
+        fx.publish_registry(&[("base", "1.0.0", &registry_skill("base", "1.0.0", ""))])
+            .unwrap();
+        local_skill(&fx, "app", "depends:\n  - base@^1.0\n");
+        std::fs::create_dir_all(fx.claude_skills.join("base")).unwrap();
+        std::fs::write(fx.claude_skills.join("base/notes.txt"), "mine").unwrap();
+
+        let err = install_roots(&fx, &["app"]).unwrap_err().to_string();
+        assert!(err.contains("--force"), "{err}");
+        assert!(!fx.claude_skills.join("base/SKILL.md").exists());
+    }
+
+    #[test]
+    fn locked_install_restores_exact_files_and_verify_reports_drift() {
+        let fx = TestFixture::new().unwrap();
+        local_skill(&fx, "app", "depends:\n  - base@^1.0\n");
+        fx.publish_registry(&[("base", "1.0.0", &registry_skill("base", "1.0.0", ""))])
+            .unwrap();
+        let (lock, _) = install_roots(&fx, &["app"]).unwrap();
+        let lock_path = project(&fx).join(LOCK_FILE);
+        lock.save(&lock_path).unwrap();
+        let lock = SkillsLock::load(&lock_path).unwrap();
+
+        // A teammate without the dependency, while the registry moved on.
+        std::fs::remove_dir_all(fx.claude_skills.join("base")).unwrap();
+        fx.publish_registry(&[
+            ("base", "1.0.0", &registry_skill("base", "1.0.0", "")),
+            ("base", "1.1.0", &registry_skill("base", "1.1.0", "")),
+        ])
+        .unwrap();
+        let missing = verify(&lock, &local(&fx)).unwrap();
+        assert_eq!(missing[0].kind, DivergenceKind::Missing);
+
+        let report = install_locked(
+            &lock,
+            &local(&fx),
+            &registries(&fx),
+            &opts(&fx, &registry_lock(&fx)),
+        )
+        .unwrap();
+        assert_eq!(report.skills[0].action, DepAction::Installed);
+        let installed = std::fs::read_to_string(fx.claude_skills.join("base/SKILL.md")).unwrap();
+        assert!(installed.contains("base 1.0.0"));
+        assert!(verify(&lock, &local(&fx)).unwrap().is_empty());
+
+        std::fs::write(fx.claude_skills.join("base/SKILL.md"), "edited").unwrap();
+        local_skill(&fx, "app", "depends:\n  - base@^1.0\n  - other\n");
+        let kinds: Vec<_> = verify(&lock, &local(&fx))
+            .unwrap()
+            .into_iter()
+            .map(|d| (d.name, d.kind))
+            .collect();
+        assert!(kinds.contains(&("base".to_string(), DivergenceKind::Modified)));
+        assert!(kinds.contains(&("app".to_string(), DivergenceKind::Modified)));
+        assert!(kinds.contains(&("other".to_string(), DivergenceKind::Unlocked)));
+
+        let err = install_locked(
+            &lock,
+            &local(&fx),
+            &registries(&fx),
+            &opts(&fx, &registry_lock(&fx)),
+        )
+        .unwrap_err()
+        .to_string();
+        assert!(err.contains("base"), "{err}");
+    }
+}
@@ -5,8 +5,11 @@
 //!
 //! - `list_resources()` - lists available skill resources
 //! - `read_resource()` - reads a specific skill resource by URI
+//! - `subscribe()` / `unsubscribe()` - track resource URIs for change notifications
 //! - `list_tools()` - lists all MCP tools with their JSON schemas
 //! - `call_tool()` - dispatches tool calls to specific handlers
+//! - `list_prompts()` - lists skills and slash commands as prompt templates
+//! - `get_prompt()` - renders a prompt with its arguments substituted
 //!
 //! # Tool Naming Convention
 //!
@@ -26,8 +29,10 @@ use crate::sync::mirror_source_root;
 use crate::tool_schemas;
 use anyhow::{anyhow, Result};
 use rmcp::model::{
-    CallToolRequestParam, CallToolResult, Content, ListResourcesResult, ListToolsResult,
-    PaginatedRequestParam, ReadResourceRequestParam, ReadResourceResult,
+    CallToolRequestParam, CallToolResult, Content, GetPromptRequestParam, GetPromptResult,
+    ListPromptsResult, ListResourcesResult, ListToolsResult, PaginatedRequestParam,
+    ReadResourceRequestParam, ReadResourceResult, ServerCapabilities, ServerInfo,
+    SubscribeRequestParam, UnsubscribeRequestParam,
 };
 use rmcp::ServerHandler;
 use serde_json::json;
@@ -40,12 +45,14 @@ struct SyncToolArgs {
     dry_run: bool,
     include_marketplace: bool,
     skip_existing_commands: bool,
+    scope: skrills_sync::SyncScope,
+    redact_secrets: bool,
 }
 
 impl SyncToolArgs {
-    fn from_request(request: &CallToolRequestParam) -> Self {
+    fn from_request(request: &CallToolRequestParam) -> Result<Self> {
         let args = request.arguments.as_ref();
-        Self {
+        Ok(Self {
             from: args
                 .and_then(|obj| obj.get("from"))
                 .and_then(|v| v.as_str())
@@ -63,11 +70,55 @@ impl SyncToolArgs {
                 .and_then(|obj| obj.get("skip_existing_commands"))
                 .and_then(|v| v.as_bool())
                 .unwrap_or(false),
-        }
+            scope: match args {
+                Some(obj) => SkillService::parse_sync_scope(obj)?,
+                None => skrills_sync::SyncScope::User,
+            },
+            redact_secrets: args
+                .and_then(|obj| obj.get("redact_secrets"))
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false),
+        })
     }
 }
 
 impl ServerHandler for SkillService {
+    /// Advertises tools, resources, and prompts. Resource subscriptions and
+    /// list-change notifications are only advertised while the filesystem
+    /// watcher (`serve --watch`) is running, since nothing else sends them.
+    fn get_info(&self) -> ServerInfo {
+        let capabilities = if self.subscriptions.is_watching() {
+            ServerCapabilities::builder()
+                .enable_tools()
+                .enable_tool_list_changed()
+                .enable_resources()
+                .enable_resources_subscribe()
+                .enable_resources_list_changed()
+                .enable_prompts()
+                .enable_prompts_list_changed()
+                .build()
+        } else {
+            ServerCapabilities::builder()
+                .enable_tools()
+                .enable_resources()
+                .enable_prompts()
+                .build()
+        };
+        ServerInfo {
+            capabilities,
+            ..ServerInfo::default()
+        }
+    }
+
+    /// Remember the client so the watcher can send list-change notifications.
+    fn on_initialized(
+        &self,
+        context: rmcp::service::NotificationContext<rmcp::RoleServer>,
+    ) -> impl std::future::Future<Output = ()> + Send + '_ {
+        self.subscriptions.attach(context.peer);
+        std::future::ready(())
+    }
+
     /// List all available resources, including skills and the AGENTS.md document.
     fn list_resources(
         &self,
@@ -98,6 +149,63 @@ impl ServerHandler for SkillService {
         std::future::ready(result)
     }
 
+    /// Subscribe to `notifications/resources/updated` for a skill URI.
+    fn subscribe(
+        &self,
+        request: SubscribeRequestParam,
+        context: rmcp::service::RequestContext<rmcp::RoleServer>,
+    ) -> impl std::future::Future<Output = Result<(), rmcp::ErrorData>> + Send + '_ {
+        let result = match self.read_resource_sync(&request.uri) {
+            Ok(_) => {
+                self.subscriptions.attach(context.peer);
+                self.subscriptions.subscribe(request.uri);
+                Ok(())
+            }
+            Err(e) => Err(rmcp::ErrorData::resource_not_found(e.to_string(), None)),
+        };
+        std::future::ready(result)
+    }
+
+    /// Stop notifications for a previously subscribed URI.
+    fn unsubscribe(
+        &self,
+        request: UnsubscribeRequestParam,
+        _context: rmcp::service::RequestContext<rmcp::RoleServer>,
+    ) -> impl std::future::Future<Output = Result<(), rmcp::ErrorData>> + Send + '_ {
+        self.subscriptions.unsubscribe(&request.uri);
+        std::future::ready(Ok(()))
+    }
+
+    /// List every discovered skill and Claude/Codex slash command as a prompt.
+    fn list_prompts(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+        _context: rmcp::service::RequestContext<rmcp::RoleServer>,
+    ) -> impl std::future::Future<Output = Result<ListPromptsResult, rmcp::ErrorData>> + Send + '_
+    {
+        let result = self
+            .list_prompts_payload()
+            .map(|prompts| ListPromptsResult {
+                prompts,
+                next_cursor: None,
+            })
+            .map_err(|e| rmcp::ErrorData::internal_error(e.to_string(), None));
+        std::future::ready(result)
+    }
+
+    /// Render a prompt, substituting `$ARGUMENTS`-style placeholders.
+    fn get_prompt(
+        &self,
+        request: GetPromptRequestParam,
+        _context: rmcp::service::RequestContext<rmcp::RoleServer>,
+    ) -> impl std::future::Future<Output = Result<GetPromptResult, rmcp::ErrorData>> + Send + '_
+    {
+        let result = self
+            .get_prompt_payload(&request.name, request.arguments.as_ref())
+            .map_err(|e| rmcp::ErrorData::invalid_params(e.to_string(), None));
+        std::future::ready(result)
+    }
+
     /// Lists the tools provided by this service.
     ///
     /// It defines several tools for interacting with skills, including
@@ -265,7 +373,7 @@ impl ServerHandler for SkillService {
                     "sync-commands" => {
                         use skrills_sync::{default_target_for, sync_between, SyncParams};
 
-                        let args = SyncToolArgs::from_request(&request);
+                        let args = SyncToolArgs::from_request(&request)?;
                         let to = request
                             .arguments
                             .as_ref()
@@ -284,6 +392,7 @@ impl ServerHandler for SkillService {
                             sync_agents: false,
                             sync_instructions: false,
                             include_marketplace: args.include_marketplace,
+                            scope: args.scope.clone(),
                             ..Default::default()
                         };
 
@@ -305,7 +414,7 @@ impl ServerHandler for SkillService {
                     "sync-mcp-servers" => {
                         use skrills_sync::{default_target_for, sync_between, SyncParams};
 
-                        let args = SyncToolArgs::from_request(&request);
+                        let args = SyncToolArgs::from_request(&request)?;
                         let to = request
                             .arguments
                             .as_ref()
@@ -322,6 +431,8 @@ impl ServerHandler for SkillService {
                             sync_skills: false,
                             sync_agents: false,
                             sync_instructions: false,
+                            scope: args.scope.clone(),
+                            redact_secrets: args.redact_secrets,
                             ..Default::default()
                         };
 
@@ -342,7 +453,7 @@ impl ServerHandler for SkillService {
                     "sync-preferences" => {
                         use skrills_sync::{default_target_for, sync_between, SyncParams};
 
-                        let args = SyncToolArgs::from_request(&request);
+                        let args = SyncToolArgs::from_request(&request)?;
                         let to = request
                             .arguments
                             .as_ref()
@@ -359,6 +470,7 @@ impl ServerHandler for SkillService {
                             sync_skills: false,
                             sync_agents: false,
                             sync_instructions: false,
+                            scope: args.scope.clone(),
                             ..Default::default()
                         };
 
@@ -380,10 +492,18 @@ impl ServerHandler for SkillService {
                         let args = request.arguments.clone().unwrap_or_default();
                         self.sync_all_tool(args)
                     }
+                    "sync-undo" => {
+                        let args = request.arguments.clone().unwrap_or_default();
+                        self.sync_undo_tool(args)
+                    }
+                    "sync-history" => {
+                        let args = request.arguments.clone().unwrap_or_default();
+                        self.sync_history_tool(args)
+                    }
                     "sync-status" => {
                         use skrills_sync::{default_target_for, sync_between, SyncParams};
 
-                        let args = SyncToolArgs::from_request(&request);
+                        let args = SyncToolArgs::from_request(&request)?;
 
                         let to = request
                             .arguments
@@ -401,6 +521,8 @@ impl ServerHandler for SkillService {
                             sync_skills: true,
                             sync_agents: true,
                             sync_instructions: true,
+                            scope: args.scope.clone(),
+                            redact_secrets: args.redact_secrets,
                             ..Default::default()
                         };
 
@@ -430,9 +552,12 @@ impl ServerHandler for SkillService {
                         self.skill_diff_tool(args)
                     }
                     "analyze-skills" => {
-                        use skrills_analyze::analyze_skill;
+                        use skrills_analyze::analyze_skill_with;
 
                         let args = request.arguments.clone().unwrap_or_default();
+                        let tokenizer = crate::config::load_tokenizer(
+                            args.get("target").and_then(|v| v.as_str()),
+                        )?;
                         let min_tokens = args
                             .get("min_tokens")
                             .and_then(|v| v.as_u64())
@@ -450,7 +575,8 @@ impl ServerHandler for SkillService {
                                 Ok(c) => c,
                                 Err(_) => continue,
                             };
-                            let analysis = analyze_skill(&meta.path, &content);
+                            let analysis =
+                                analyze_skill_with(&meta.path, &content, tokenizer.as_ref());
 
                             if let Some(min) = min_tokens {
                                 if analysis.tokens.total < min {
@@ -868,6 +994,254 @@ mod tests {
         );
     }
 
+    #[test]
+    fn prompts_expose_skills_and_commands() {
+        /*
+        GIVEN a Claude slash command using $ARGUMENTS and a discovered skill
+        WHEN listing prompts and getting the command prompt with arguments
+        THEN both are listed and the command renders with the arguments substituted
+        */
+        let _guard = test_support::env_guard();
+        let temp = tempdir().expect("tempdir");
+        let _home = set_env_var(
+            "HOME",
+            Some(
+                temp.path()
+                    .to_str()
+                    .expect("temp home should be valid utf-8"),
+            ),
+        );
+        let commands = temp.path().join(".claude/commands");
+        std::fs::create_dir_all(&commands).expect("create commands dir");
+        std::fs::write(
+            commands.join("commit.md"),
+            "---\ndescription: Write a commit message\n---\nCommit: $ARGUMENTS",
+        )
+        .expect("write command");
+
+        let service = build_service(&temp);
+        let (listed, rendered) = run_async(async move {
+            let (running, context, _client) = service_with_context(service);
+            let listed = running
+                .service()
+                .list_prompts(None, context.clone())
+                .await
+                .expect("list_prompts should succeed");
+            let mut arguments = serde_json::Map::new();
+            arguments.insert("arguments".into(), json!("fix the parser"));
+            let rendered = running
+                .service()
+                .get_prompt(
+                    GetPromptRequestParam {
+                        name: "commit".into(),
+                        arguments: Some(arguments),
+                    },
+                    context,
+                )
+                .await
+                .expect("get_prompt should succeed");
+            (listed, rendered)
+        });
+
+        let commit = listed
+            .prompts
+            .iter()
+            .find(|p| p.name == "commit")
+            .expect("command prompt should be listed");
+        assert_eq!(
+            commit.description.as_deref(),
+            Some("Write a commit message")
+        );
+        assert!(listed.prompts.iter().any(|p| p.name == "skill-demo"));
+        match &rendered.messages[0].content {
+            rmcp::model::PromptMessageContent::Text { text } => {
+                assert_eq!(text, "Commit: fix the parser")
+            }
+            other => panic!("unexpected prompt content: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn change_notifications_are_advertised_only_while_watching() {
+        /*
+        GIVEN a skill service
+        WHEN it serves without the filesystem watcher, and then with it
+        THEN resource subscriptions and list_changed are advertised only with the watcher
+        */
+        let _guard = test_support::env_guard();
+        let temp = tempdir().expect("tempdir");
+        let _home = set_env_var(
+            "HOME",
+            Some(
+                temp.path()
+                    .to_str()
+                    .expect("temp home should be valid utf-8"),
+            ),
+        );
+        let service = build_service(&temp);
+
+        let capabilities = service.get_info().capabilities;
+        let resources = capabilities.resources.expect("resources advertised");
+        assert_eq!(resources.subscribe, None);
+        assert_eq!(resources.list_changed, None);
+        assert_eq!(
+            capabilities.tools.expect("tools advertised").list_changed,
+            None
+        );
+        assert_eq!(
+            capabilities
+                .prompts
+                .expect("prompts advertised")
+                .list_changed,
+            None
+        );
+
+        #[cfg(feature = "watch")]
+        {
+            let runtime = tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .expect("runtime should build");
+            let _watcher = crate::app::start_fs_watcher(&service, runtime.handle().clone())
+                .expect("watcher should start");
+            let resources = service
+                .get_info()
+                .capabilities
+                .resources
+                .expect("resources advertised");
+            assert_eq!(resources.subscribe, Some(true));
+            assert_eq!(resources.list_changed, Some(true));
+        }
+    }
+
+    #[cfg(feature = "watch")]
+    #[test]
+    fn watcher_notifies_subscribed_clients_of_skill_changes() {
+        /*
+        GIVEN a served skill service with the filesystem watcher running
+        WHEN a client subscribes to a skill, the skill is edited, and a new skill is added
+        THEN the client receives resources/updated for the skill and list_changed notifications
+        */
+        use rmcp::model::{ResourceUpdatedNotificationParam, SubscribeRequestParam};
+        use rmcp::service::NotificationContext;
+        use rmcp::{RoleClient, ServiceExt};
+        use tokio::sync::mpsc;
+
+        struct Recorder(mpsc::UnboundedSender<String>);
+
+        impl rmcp::ClientHandler for Recorder {
+            fn on_resource_updated(
+                &self,
+                params: ResourceUpdatedNotificationParam,
+                _context: NotificationContext<RoleClient>,
+            ) -> impl Future<Output = ()> + Send + '_ {
+                let _ = self.0.send(format!("updated {}", params.uri));
+                std::future::ready(())
+            }
+            fn on_resource_list_changed(
+                &self,
+                _context: NotificationContext<RoleClient>,
+            ) -> impl Future<Output = ()> + Send + '_ {
+                let _ = self.0.send("resources/list_changed".into());
+                std::future::ready(())
+            }
+            fn on_tool_list_changed(
+                &self,
+                _context: NotificationContext<RoleClient>,
+            ) -> impl Future<Output = ()> + Send + '_ {
+                let _ = self.0.send("tools/list_changed".into());
+                std::future::ready(())
+            }
+        }
+
+        async fn expect(rx: &mut mpsc::UnboundedReceiver<String>, wanted: &str) {
+            let deadline = tokio::time::Instant::now() + Duration::from_secs(10);
+            loop {
+                let received = tokio::time::timeout_at(deadline, rx.recv())
+                    .await
+                    .unwrap_or_else(|_| panic!("timed out waiting for {wanted}"));
+                if received.as_deref() == Some(wanted) {
+                    return;
+                }
+            }
+        }
+
+        let _guard = test_support::env_guard();
+        let temp = tempdir().expect("tempdir");
+        let _home = set_env_var(
+            "HOME",
+            Some(
+                temp.path()
+                    .to_str()
+                    .expect("temp home should be valid utf-8"),
+            ),
+        );
+        let service = build_service(&temp);
+        let skills = temp.path().join(".codex/skills");
+
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(2)
+            .enable_all()
+            .build()
+            .expect("runtime should build");
+        let _watcher = crate::app::start_fs_watcher(&service, runtime.handle().clone())
+            .expect("watcher should start");
+
+        runtime.block_on(async move {
+            let (tx, mut rx) = mpsc::unbounded_channel();
+            let (server_io, client_io) = tokio::io::duplex(64 * 1024);
+            let (server, client) =
+                tokio::join!(service.serve(server_io), Recorder(tx).serve(client_io));
+            let _server = server.expect("server should start");
+            let client = client.expect("client should connect");
+
+            let uri = "skill://skrills/codex/demo/SKILL.md".to_string();
+            client
+                .peer()
+                .subscribe(SubscribeRequestParam { uri: uri.clone() })
+                .await
+                .expect("subscribe should succeed");
+
+            std::fs::write(skills.join("demo/SKILL.md"), "demo skill, edited").expect("edit");
+            expect(&mut rx, &format!("updated {uri}")).await;
+
+            std::fs::create_dir_all(skills.join("other")).expect("create skill dir");
+            std::fs::write(skills.join("other/SKILL.md"), "another skill").expect("add skill");
+            expect(&mut rx, "resources/list_changed").await;
+            expect(&mut rx, "tools/list_changed").await;
+        });
+        runtime.shutdown_timeout(Duration::from_millis(100));
+    }
+
+    #[test]
+    fn subscribe_rejects_unknown_uri() {
+        let _guard = test_support::env_guard();
+        let temp = tempdir().expect("tempdir");
+        let _home = set_env_var(
+            "HOME",
+            Some(
+                temp.path()
+                    .to_str()
+                    .expect("temp home should be valid utf-8"),
+            ),
+        );
+
+        let service = build_service(&temp);
+        let result = run_async(async move {
+            let (running, context, _client) = service_with_context(service);
+            running
+                .service()
+                .subscribe(
+                    SubscribeRequestParam {
+                        uri: "skill://skrills/codex/missing/SKILL.md".into(),
+                    },
+                    context,
+                )
+                .await
+        });
+        assert!(result.is_err(), "unknown skill URIs cannot be subscribed");
+    }
+
     #[test]
     fn call_tool_unknown_returns_error() {
         /*
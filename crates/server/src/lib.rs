@@ -14,10 +14,12 @@ pub mod app;
 pub(crate) mod cache;
 mod commands;
 pub(crate) mod config;
+pub(crate) mod deps;
 mod doctor;
 mod handler;
 pub mod mcp_gateway;
 pub(crate) mod metrics_types;
+pub(crate) mod registry;
 mod setup;
 mod skill_trace;
 mod sync;
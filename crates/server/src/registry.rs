@@ -0,0 +1,571 @@
+//! Remote skill registry protocol used by `skrills sync-pull`.
+//!
+//! A registry is a static file tree with an `index.json` at its root:
+//!
+//! ```json
+//! {
+//!   "version": 1,
+//!   "skills": [
+//!     {
+//!       "name": "commit-helper",
+//!       "version": "1.2.0",
+//!       "description": "Writes conventional commit messages",
+//!       "path": "skills/commit-helper",
+//!       "files": [
+//!         { "path": "SKILL.md", "sha256": "9f86d0..." },
+//!         { "path": "scripts/lint.sh", "sha256": "60303a..." }
+//!       ]
+//!     }
+//!   ]
+//! }
+//! ```
+//!
+//! Each file is fetched from `<registry>/<entry.path>/<file.path>` and its
+//! SHA-256 must match the index before anything is written. Registries are
+//! served over `http(s)://` or read from a `file://` directory, so any static
+//! file host (or a checked-out directory) can act as a team registry.
+//!
+//! Pulled skills are recorded in a lockfile at
+//! `~/.skrills/registry-lock.json`.
+
+use anyhow::{anyhow, bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::path::{Component, Path, PathBuf};
+use std::time::Duration;
+
+/// File name of the registry index.
+pub(crate) const INDEX_FILE: &str = "index.json";
+
+/// Registry index schema version understood by this build.
+const INDEX_VERSION: u32 = 1;
+
+/// Lockfile schema version.
+const LOCK_VERSION: u32 = 1;
+
+/// HTTP request timeout for registry downloads.
+const HTTP_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Top-level registry index.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct RegistryIndex {
+    /// Index schema version.
+    pub version: u32,
+    /// Skills published in the registry.
+    #[serde(default)]
+    pub skills: Vec<RegistryEntry>,
+}
+
+/// One published skill.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct RegistryEntry {
+    /// Skill name; also the directory name it is installed under.
+    pub name: String,
+    /// Published version (free-form, typically semver).
+    pub version: String,
+    /// Optional one-line description.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Directory of the skill relative to the registry root.
+    /// Defaults to `skills/<name>`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    /// Files making up the skill. Must include `SKILL.md`.
+    pub files: Vec<RegistryFile>,
+}
+
+impl RegistryEntry {
+    /// Directory of the skill relative to the registry root.
+    pub(crate) fn base_path(&self) -> String {
+        self.path
+            .clone()
+            .unwrap_or_else(|| format!("skills/{}", self.name))
+    }
+}
+
+/// A file belonging to a published skill.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct RegistryFile {
+    /// Path relative to the skill directory.
+    pub path: String,
+    /// Lowercase hex SHA-256 of the file content.
+    pub sha256: String,
+}
+
+/// Returns the lowercase hex SHA-256 of `data`.
+pub(crate) fn sha256_hex(data: &[u8]) -> String {
+    hex::encode(Sha256::digest(data))
+}
+
+/// Validates a registry-supplied relative path, rejecting anything that
+/// could escape the install directory.
+pub(crate) fn safe_relative_path(raw: &str) -> Result<PathBuf> {
+    let path = Path::new(raw);
+    if raw.is_empty() || path.is_absolute() {
+        bail!("Invalid registry path '{raw}': must be relative");
+    }
+    for component in path.components() {
+        match component {
+            Component::Normal(_) | Component::CurDir => {}
+            _ => bail!("Invalid registry path '{raw}': must not contain '..' or a root"),
+        }
+    }
+    Ok(path.to_path_buf())
+}
+
+/// Validates a skill name for use as a directory name.
+fn validate_skill_name(name: &str) -> Result<()> {
+    let valid = !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.')
+        && !name.starts_with('.');
+    if !valid {
+        bail!("Invalid skill name in registry index: '{name}'");
+    }
+    Ok(())
+}
+
+enum Transport {
+    Http {
+        base: String,
+        client: reqwest::Client,
+        runtime: tokio::runtime::Runtime,
+    },
+    Local {
+        root: PathBuf,
+    },
+}
+
+/// Reads an index and skill files from an `http(s)://` or `file://` registry.
+pub(crate) struct RegistryClient {
+    transport: Transport,
+    index_name: String,
+}
+
+impl RegistryClient {
+    /// Opens a registry from a `--source` value.
+    ///
+    /// The source may point at the registry root or directly at its index
+    /// (any `*.json` file), in which case the index's directory is the root.
+    pub(crate) fn open(source: &str) -> Result<Self> {
+        let (location, index_name) = split_index(source);
+
+        if location.starts_with("http://") || location.starts_with("https://") {
+            let client = reqwest::Client::builder().timeout(HTTP_TIMEOUT).build()?;
+            let runtime = tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()?;
+            Ok(Self {
+                transport: Transport::Http {
+                    base: location.trim_end_matches('/').to_string(),
+                    client,
+                    runtime,
+                },
+                index_name,
+            })
+        } else if let Some(path) = location.strip_prefix("file://") {
+            let root = PathBuf::from(path);
+            if !root.is_dir() {
+                bail!("Registry directory does not exist: {}", root.display());
+            }
+            Ok(Self {
+                transport: Transport::Local { root },
+                index_name,
+            })
+        } else {
+            bail!(
+                "Unsupported registry source '{source}'. Use an http(s):// URL or a file:// directory"
+            )
+        }
+    }
+
+    /// Fetches a file relative to the registry root.
+    pub(crate) fn fetch(&self, relative: &str) -> Result<Vec<u8>> {
+        let relative = safe_relative_path(relative)?;
+        match &self.transport {
+            Transport::Http {
+                base,
+                client,
+                runtime,
+            } => {
+                let url = format!("{base}/{}", relative.to_string_lossy().replace('\\', "/"));
+                runtime.block_on(async {
+                    let resp = client
+                        .get(&url)
+                        .send()
+                        .await
+                        .with_context(|| format!("Failed to fetch {url}"))?;
+                    if !resp.status().is_success() {
+                        return Err(anyhow!(
+                            "Registry request failed with HTTP {}: {url}",
+                            resp.status().as_u16()
+                        ));
+                    }
+                    Ok(resp.bytes().await?.to_vec())
+                })
+            }
+            Transport::Local { root } => {
+                let path = root.join(&relative);
+                std::fs::read(&path)
+                    .with_context(|| format!("Failed to read registry file {}", path.display()))
+            }
+        }
+    }
+
+    /// Loads and validates the registry index.
+    pub(crate) fn load_index(&self) -> Result<RegistryIndex> {
+        let raw = self.fetch(&self.index_name)?;
+        let index: RegistryIndex = serde_json::from_slice(&raw)
+            .with_context(|| format!("Failed to parse registry {}", self.index_name))?;
+        if index.version > INDEX_VERSION {
+            bail!(
+                "Registry index version {} is newer than supported version {}",
+                index.version,
+                INDEX_VERSION
+            );
+        }
+        for entry in &index.skills {
+            validate_skill_name(&entry.name)?;
+            safe_relative_path(&entry.base_path())?;
+            if !entry.files.iter().any(|f| f.path == "SKILL.md") {
+                bail!("Registry skill '{}' does not list a SKILL.md", entry.name);
+            }
+            for file in &entry.files {
+                safe_relative_path(&file.path)?;
+            }
+        }
+        Ok(index)
+    }
+
+    /// Downloads every file of `entry` and verifies it against the index.
+    ///
+    /// Returns `(relative path, content)` pairs; nothing is written to disk,
+    /// so a hash mismatch leaves the install directory untouched.
+    pub(crate) fn fetch_verified(&self, entry: &RegistryEntry) -> Result<Vec<(PathBuf, Vec<u8>)>> {
+        let base = entry.base_path();
+        let mut files = Vec::with_capacity(entry.files.len());
+        for file in &entry.files {
+            let content = self.fetch(&format!("{}/{}", base.trim_end_matches('/'), file.path))?;
+            let actual = sha256_hex(&content);
+            if !actual.eq_ignore_ascii_case(&file.sha256) {
+                bail!(
+                    "Hash mismatch for {}/{}: index says {}, downloaded {}",
+                    entry.name,
+                    file.path,
+                    file.sha256,
+                    actual
+                );
+            }
+            files.push((safe_relative_path(&file.path)?, content));
+        }
+        Ok(files)
+    }
+}
+
+/// Splits a source into `(registry root, index file name)`.
+fn split_index(source: &str) -> (String, String) {
+    let trimmed = source.trim_end_matches('/');
+    if trimmed.ends_with(".json") {
+        if let Some((root, file)) = trimmed.rsplit_once('/') {
+            return (root.to_string(), file.to_string());
+        }
+    }
+    (trimmed.to_string(), INDEX_FILE.to_string())
+}
+
+/// Lockfile recording which registry skills were pulled where.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct RegistryLock {
+    version: u32,
+    /// Pulled skills, sorted by target then name.
+    #[serde(default)]
+    pub skills: Vec<LockedSkill>,
+}
+
+impl Default for RegistryLock {
+    fn default() -> Self {
+        Self {
+            version: LOCK_VERSION,
+            skills: Vec::new(),
+        }
+    }
+}
+
+/// A pulled skill as recorded in the lockfile.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct LockedSkill {
+    /// Skill name.
+    pub name: String,
+    /// Version that was pulled.
+    pub version: String,
+    /// Registry the skill was pulled from.
+    pub source: String,
+    /// Target CLI the skill was installed into.
+    pub target: String,
+    /// Install directory.
+    pub path: PathBuf,
+    /// Files and hashes that were installed.
+    pub files: Vec<RegistryFile>,
+    /// RFC 3339 timestamp of the pull.
+    pub pulled_at: String,
+}
+
+impl RegistryLock {
+    /// Default lockfile location (`~/.skrills/registry-lock.json`).
+    pub(crate) fn default_path() -> Result<PathBuf> {
+        let home = dirs::home_dir().context("Could not determine home directory")?;
+        Ok(home.join(".skrills").join("registry-lock.json"))
+    }
+
+    /// Loads the lockfile, returning an empty lock if it does not exist.
+    pub(crate) fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let raw = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let lock: Self = serde_json::from_str(&raw)
+            .with_context(|| format!("Failed to parse {}", path.display()))?;
+        if lock.version > LOCK_VERSION {
+            bail!(
+                "Lockfile {} has version {}, newer than supported version {}",
+                path.display(),
+                lock.version,
+                LOCK_VERSION
+            );
+        }
+        Ok(lock)
+    }
+
+    /// Writes the lockfile, creating parent directories as needed.
+    pub(crate) fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let tmp = path.with_extension("json.tmp");
+        std::fs::write(&tmp, serde_json::to_string_pretty(self)?)?;
+        std::fs::rename(&tmp, path)
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+        Ok(())
+    }
+
+    /// Returns the lock entry for `name` installed into `target`.
+    pub(crate) fn find(&self, name: &str, target: &str) -> Option<&LockedSkill> {
+        self.skills
+            .iter()
+            .find(|s| s.name == name && s.target == target)
+    }
+
+    /// Inserts or replaces the entry for `skill.name` / `skill.target`.
+    pub(crate) fn upsert(&mut self, skill: LockedSkill) {
+        self.skills
+            .retain(|s| !(s.name == skill.name && s.target == skill.target));
+        self.skills.push(skill);
+        self.skills
+            .sort_by(|a, b| (&a.target, &a.name).cmp(&(&b.target, &b.name)));
+    }
+}
+
+/// Returns true when every file in `files` exists under `dir` with the
+/// recorded hash.
+pub(crate) fn installed_files_match(dir: &Path, files: &[RegistryFile]) -> bool {
+    files.iter().all(|f| {
+        std::fs::read(dir.join(&f.path))
+            .map(|content| sha256_hex(&content).eq_ignore_ascii_case(&f.sha256))
+            .unwrap_or(false)
+    })
+}
+
+/// Writes verified skill `files` into `dir`, first removing the files of a
+/// previous install (`previous`) that the new file list no longer contains.
+pub(crate) fn install_files(
+    dir: &Path,
+    files: &[(PathBuf, Vec<u8>)],
+    previous: Option<&[RegistryFile]>,
+) -> Result<()> {
+    for stale in previous
+        .unwrap_or_default()
+        .iter()
+        .filter(|old| !files.iter().any(|(path, _)| path == Path::new(&old.path)))
+    {
+        let _ = std::fs::remove_file(dir.join(&stale.path));
+    }
+    for (relative, content) in files {
+        let path = dir.join(relative);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&path, content)
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+    }
+    Ok(())
+}
+
+/// Current time as an RFC 3339 timestamp for lockfile entries.
+pub(crate) fn now_rfc3339() -> String {
+    time::OffsetDateTime::now_utc()
+        .format(&time::format_description::well_known::Rfc3339)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn write_registry(root: &Path, skill_md: &str, index_hash: Option<&str>) {
+        let skill_dir = root.join("skills/hello");
+        std::fs::create_dir_all(&skill_dir).unwrap();
+        std::fs::write(skill_dir.join("SKILL.md"), skill_md).unwrap();
+        let hash = index_hash
+            .map(str::to_string)
+            .unwrap_or_else(|| sha256_hex(skill_md.as_bytes()));
+        let index = serde_json::json!({
+            "version": 1,
+            "skills": [{
+                "name": "hello",
+                "version": "1.0.0",
+                "files": [{ "path": "SKILL.md", "sha256": hash }]
+            }]
+        });
+        std::fs::write(root.join(INDEX_FILE), index.to_string()).unwrap();
+    }
+
+    #[test]
+    fn split_index_accepts_root_or_index_url() {
+        assert_eq!(
+            split_index("https://example.com/reg/"),
+            ("https://example.com/reg".into(), "index.json".into())
+        );
+        assert_eq!(
+            split_index("https://example.com/reg/team.json"),
+            ("https://example.com/reg".into(), "team.json".into())
+        );
+    }
+
+    #[test]
+    fn safe_relative_path_rejects_traversal() {
+        assert!(safe_relative_path("SKILL.md").is_ok());
+        assert!(safe_relative_path("scripts/run.sh").is_ok());
+        assert!(safe_relative_path("../evil").is_err());
+        assert!(safe_relative_path("a/../../evil").is_err());
+        assert!(safe_relative_path("/etc/passwd").is_err());
+        assert!(safe_relative_path("").is_err());
+    }
+
+    #[test]
+    fn open_rejects_unsupported_scheme() {
+        assert!(RegistryClient::open("ftp://example.com/reg").is_err());
+        assert!(RegistryClient::open("file:///definitely/not/here").is_err());
+    }
+
+    #[test]
+    fn file_registry_loads_index_and_verifies_files() {
+        let dir = tempdir().unwrap();
+        write_registry(dir.path(), "# Hello", None);
+
+        let client = RegistryClient::open(&format!("file://{}", dir.path().display())).unwrap();
+        let index = client.load_index().unwrap();
+        assert_eq!(index.skills.len(), 1);
+        assert_eq!(index.skills[0].base_path(), "skills/hello");
+
+        let files = client.fetch_verified(&index.skills[0]).unwrap();
+        assert_eq!(
+            files,
+            vec![(PathBuf::from("SKILL.md"), b"# Hello".to_vec())]
+        );
+    }
+
+    #[test]
+    fn hash_mismatch_is_rejected() {
+        let dir = tempdir().unwrap();
+        write_registry(dir.path(), "# Hello", Some(&"0".repeat(64)));
+
+        let client = RegistryClient::open(&format!("file://{}", dir.path().display())).unwrap();
+        let index = client.load_index().unwrap();
+        let err = client.fetch_verified(&index.skills[0]).unwrap_err();
+        assert!(err.to_string().contains("Hash mismatch"));
+    }
+
+    #[test]
+    fn index_without_skill_md_is_rejected() {
+        let dir = tempdir().unwrap();
+        std::fs::write(
+            dir.path().join(INDEX_FILE),
+            r#"{"version":1,"skills":[{"name":"x","version":"1","files":[{"path":"README.md","sha256":"00"}]}]}"#,
+        )
+        .unwrap();
+        let client = RegistryClient::open(&format!("file://{}", dir.path().display())).unwrap();
+        assert!(client.load_index().is_err());
+    }
+
+    #[test]
+    fn http_registry_serves_index_and_files() {
+        use std::io::{Read, Write};
+        use std::net::TcpListener;
+
+        let dir = tempdir().unwrap();
+        write_registry(dir.path(), "# Hello over HTTP", None);
+        let root = dir.path().to_path_buf();
+
+        // Minimal static-file stand-in: one request per connection.
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        std::thread::spawn(move || {
+            for stream in listener.incoming().take(2) {
+                let mut stream = stream.unwrap();
+                let mut buf = [0u8; 2048];
+                let n = stream.read(&mut buf).unwrap();
+                let request = String::from_utf8_lossy(&buf[..n]);
+                let path = request.split_whitespace().nth(1).unwrap_or("/");
+                let body = std::fs::read(root.join(path.trim_start_matches('/')));
+                let response = match body {
+                    Ok(body) => {
+                        let mut r = format!(
+                            "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                            body.len()
+                        )
+                        .into_bytes();
+                        r.extend(body);
+                        r
+                    }
+                    Err(_) => {
+                        b"HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+                            .to_vec()
+                    }
+                };
+                stream.write_all(&response).unwrap();
+            }
+        });
+
+        let client = RegistryClient::open(&format!("http://{addr}/")).unwrap();
+        let index = client.load_index().unwrap();
+        let files = client.fetch_verified(&index.skills[0]).unwrap();
+        assert_eq!(files[0].1, b"# Hello over HTTP".to_vec());
+    }
+
+    #[test]
+    fn lock_round_trips_and_upserts() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("lock.json");
+        let mut lock = RegistryLock::load(&path).unwrap();
+        let skill = |version: &str| LockedSkill {
+            name: "hello".into(),
+            version: version.into(),
+            source: "file:///reg".into(),
+            target: "claude".into(),
+            path: dir.path().join("hello"),
+            files: vec![],
+            pulled_at: "2026-01-01T00:00:00Z".into(),
+        };
+        lock.upsert(skill("1.0.0"));
+        lock.upsert(skill("1.1.0"));
+        lock.save(&path).unwrap();
+
+        let reloaded = RegistryLock::load(&path).unwrap();
+        assert_eq!(reloaded.skills.len(), 1);
+        assert_eq!(reloaded.find("hello", "claude").unwrap().version, "1.1.0");
+        assert!(reloaded.find("hello", "codex").is_none());
+    }
+}
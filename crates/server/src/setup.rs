@@ -162,8 +162,8 @@ pub fn is_first_run() -> Result<bool> {
 /// Prompts the user to run setup on first run.
 /// Returns true if user wants to proceed with setup.
 pub fn prompt_first_run_setup() -> Result<bool> {
-    println!("\nSkrills is not configured on this system.");
-    println!("Setup creates hooks, registers MCP servers, and configures directories.\n");
+    eprintln!("\nSkrills is not configured on this system.");
+    eprintln!("Setup creates hooks, registers MCP servers, and configures directories.\n");
 
     Confirm::new("Would you like to run setup now?")
         .with_default(true)
@@ -40,3 +40,38 @@ pub fn ignore_sigchld() -> Result<()> {
 pub fn ignore_sigchld() -> Result<()> {
     Ok(())
 }
+
+#[cfg(unix)]
+/// Runs `f` with the default `SIGCHLD` disposition, so it can wait on the
+/// child processes it spawns, then restores the previous handler.
+///
+/// Under the handler installed by [`ignore_sigchld`], children are reaped
+/// automatically and `std::process::Command::output` fails with `ECHILD`.
+#[allow(unsafe_code)]
+pub fn with_default_sigchld<T>(f: impl FnOnce() -> T) -> T {
+    use std::ptr;
+    // SAFETY: Same process-wide SIGCHLD configuration as `ignore_sigchld`;
+    // the struct is zero-initialized with an empty mask, and the previous
+    // disposition is only restored if it was successfully read.
+    let mut previous: libc::sigaction = unsafe { std::mem::zeroed() };
+    let installed = unsafe {
+        let mut sa: libc::sigaction = std::mem::zeroed();
+        sa.sa_sigaction = libc::SIG_DFL;
+        libc::sigemptyset(&mut sa.sa_mask);
+        libc::sigaction(libc::SIGCHLD, &sa, &mut previous) == 0
+    };
+    let result = f();
+    if installed {
+        // SAFETY: Restores the disposition read above.
+        unsafe {
+            libc::sigaction(libc::SIGCHLD, &previous, ptr::null_mut());
+        }
+    }
+    result
+}
+
+#[cfg(not(unix))]
+/// Provides a stub for `with_default_sigchld` on non-Unix platforms.
+pub fn with_default_sigchld<T>(f: impl FnOnce() -> T) -> T {
+    f()
+}
@@ -55,7 +55,16 @@ fn array_output_schema(item_desc: &str) -> Option<Arc<JsonMap<String, serde_json
 }
 
 /// Returns the schema for sync tools (from, to, dry_run, force parameters).
+///
+/// The platform enum includes external adapters registered in
+/// `~/.skrills/adapters.toml`.
 fn sync_schema() -> Arc<JsonMap<String, serde_json::Value>> {
+    let platforms = skrills_sync::available_platforms();
+    let names = platforms
+        .iter()
+        .map(|p| format!("'{p}'"))
+        .collect::<Vec<_>>()
+        .join(", ");
     let mut schema = JsonMap::new();
     schema.insert("type".into(), json!("object"));
     schema.insert(
@@ -63,13 +72,13 @@ fn sync_schema() -> Arc<JsonMap<String, serde_json::Value>> {
         json!({
             "from": {
                 "type": "string",
-                "enum": ["claude", "codex", "copilot", "cursor"],
-                "description": "Source agent: 'claude', 'codex', 'copilot', or 'cursor'"
+                "enum": platforms,
+                "description": format!("Source agent: {names}")
             },
             "to": {
                 "type": "string",
-                "enum": ["claude", "codex", "copilot", "cursor"],
-                "description": "Target agent: 'claude', 'codex', 'copilot', or 'cursor'. Defaults to codex (for claude source) or claude (for others)"
+                "enum": platforms,
+                "description": format!("Target agent: {names}. Defaults to codex (for claude source) or claude (for others)")
             },
             "dry_run": {
                 "type": "boolean",
@@ -77,7 +86,20 @@ fn sync_schema() -> Arc<JsonMap<String, serde_json::Value>> {
             },
             "force": {
                 "type": "boolean",
-                "description": "Skip confirmation prompts"
+                "description": "Overwrite target edits and conflicts detected since the last sync"
+            },
+            "scope": {
+                "type": "string",
+                "enum": ["user", "project"],
+                "description": "Sync the user-level tool directories (default) or a repository's .claude/, .codex/, .cursor/, .github/ and AGENTS.md"
+            },
+            "project_dir": {
+                "type": "string",
+                "description": "Repository root for project scope (default: the server's working directory). Implies scope 'project'"
+            },
+            "redact_secrets": {
+                "type": "boolean",
+                "description": "Never write secret-looking MCP env/header values in plaintext and omit them from the report"
             }
         }),
     );
@@ -88,7 +110,8 @@ fn sync_schema() -> Arc<JsonMap<String, serde_json::Value>> {
 /// Returns sync-related tools.
 ///
 /// Tools: sync-from-claude, sync-from-copilot, sync-to-copilot, sync-skills,
-/// sync-commands, sync-mcp-servers, sync-preferences, sync-all, sync-status
+/// sync-commands, sync-mcp-servers, sync-preferences, sync-all, sync-status,
+/// sync-undo, sync-history
 pub(crate) fn sync_tools() -> Vec<Tool> {
     let schema_empty = empty_schema();
     let sync_schema = sync_schema();
@@ -226,6 +249,59 @@ pub(crate) fn sync_tools() -> Vec<Tool> {
             icons: None,
             meta: None,
         },
+        Tool {
+            name: "sync-undo".into(),
+            title: Some("Undo a sync".into()),
+            description: Some(
+                "Restore the target files changed by a sync from the snapshot taken before it wrote, and roll back the sync-state ledger. Defaults to the most recent snapshot.".into(),
+            ),
+            input_schema: Arc::new({
+                let mut schema = JsonMap::new();
+                schema.insert("type".into(), json!("object"));
+                schema.insert(
+                    "properties".into(),
+                    json!({
+                        "id": {
+                            "type": "string",
+                            "description": "Snapshot id from sync-history (default: the most recent)"
+                        }
+                    }),
+                );
+                schema.insert("additionalProperties".into(), json!(false));
+                schema
+            }),
+            output_schema: None,
+            annotations: Some(ToolAnnotations::default()),
+            icons: None,
+            meta: None,
+        },
+        Tool {
+            name: "sync-history".into(),
+            title: Some("List sync snapshots".into()),
+            description: Some(
+                "List the snapshots taken before each sync, newest first, with the files each sync changed and their before/after hashes.".into(),
+            ),
+            input_schema: Arc::new({
+                let mut schema = JsonMap::new();
+                schema.insert("type".into(), json!("object"));
+                schema.insert(
+                    "properties".into(),
+                    json!({
+                        "limit": {
+                            "type": "integer",
+                            "minimum": 1,
+                            "description": "Maximum number of snapshots to return"
+                        }
+                    }),
+                );
+                schema.insert("additionalProperties".into(), json!(false));
+                schema
+            }),
+            output_schema: None,
+            annotations: Some(ToolAnnotations::default()),
+            icons: None,
+            meta: None,
+        },
     ]
 }
 
@@ -238,7 +314,7 @@ pub(crate) fn validation_tools() -> Vec<Tool> {
             name: "validate-skills".into(),
             title: Some("Validate skills for CLI compatibility".into()),
             description: Some(
-                "Validate skills for Claude Code, Codex, and/or Copilot CLI compatibility. Returns validation errors and warnings.".into(),
+                "Validate skills for Claude Code, Codex, Copilot CLI, and/or Cursor compatibility. Returns validation errors and warnings.".into(),
             ),
             input_schema: Arc::new({
                 let mut schema = JsonMap::new();
@@ -248,9 +324,9 @@ pub(crate) fn validation_tools() -> Vec<Tool> {
                     json!({
                         "target": {
                             "type": "string",
-                            "enum": ["claude", "codex", "copilot", "both", "all"],
+                            "enum": ["claude", "codex", "copilot", "cursor", "both", "all"],
                             "default": "both",
-                            "description": "Validation target: 'claude', 'codex', 'copilot', 'both' (claude+codex), or 'all'"
+                            "description": "Validation target: 'claude', 'codex', 'copilot', 'cursor', 'both' (claude+codex), or 'all'"
                         },
                         "autofix": {
                             "type": "boolean",
@@ -297,6 +373,10 @@ pub(crate) fn validation_tools() -> Vec<Tool> {
                             "type": "boolean",
                             "default": true,
                             "description": "Include optimization suggestions"
+                        },
+                        "target": {
+                            "type": "string",
+                            "description": "Target CLI whose tokenizer counts tokens (per ~/.skrills/tokenizers.toml); heuristic when unset"
                         }
                     }),
                 );
@@ -1201,8 +1281,8 @@ mod tests {
     #[test]
     fn test_all_tools_returns_expected_count() {
         let tools = all_tools();
-        // 11 sync + 3 validation + 1 dependency + 1 recommend + 1 metrics + 4 trace + 6 intelligence + 9 research = 36 tools
-        assert_eq!(tools.len(), 36);
+        // 13 sync + 3 validation + 1 dependency + 1 recommend + 1 metrics + 4 trace + 6 intelligence + 9 research = 38 tools
+        assert_eq!(tools.len(), 38);
     }
 
     #[test]
@@ -1222,7 +1302,7 @@ mod tests {
 
     #[test]
     fn test_sync_tools_count() {
-        assert_eq!(sync_tools().len(), 11);
+        assert_eq!(sync_tools().len(), 13);
     }
 
     #[test]
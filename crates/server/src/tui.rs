@@ -10,7 +10,10 @@ use std::io::IsTerminal;
 use std::path::PathBuf;
 
 use crate::sync::{mirror_source_root, sync_agents_only_from_claude, sync_skills_only_from_claude};
-use skrills_sync::{ClaudeAdapter, CodexAdapter, SyncOrchestrator, SyncParams};
+use skrills_sync::{
+    ClaudeAdapter, CodexAdapter, ModelMap, SnapshotConfig, SyncOrchestrator, SyncParams,
+    SyncPolicy, SyncScope,
+};
 
 /// Runs an interactive TUI for sync management.
 ///
@@ -54,7 +57,10 @@ pub(crate) fn tui_flow(_extra_dirs: &[PathBuf]) -> Result<()> {
     // Mirror commands/prefs/MCP
     let source = ClaudeAdapter::new()?;
     let target = CodexAdapter::new()?;
-    let orch = SyncOrchestrator::new(source, target);
+    let orch = SyncOrchestrator::new(source, target)
+        .with_snapshots(SnapshotConfig::default())
+        .with_model_map(ModelMap::load_default()?)
+        .with_policy(SyncPolicy::load_default(&SyncScope::User)?);
     let params = SyncParams {
         sync_skills: false,
         sync_commands: true,
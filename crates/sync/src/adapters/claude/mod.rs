@@ -7,6 +7,7 @@ use crate::common::{Command, McpServer, PluginAsset, Preferences};
 #[cfg(test)]
 use crate::common::{ContentFormat, McpTransport};
 use crate::report::WriteReport;
+use crate::secrets::EnvSyntax;
 use crate::Result;
 use anyhow::Context;
 use std::collections::HashMap;
@@ -38,6 +39,11 @@ pub(super) fn semver_tuple(entry: &fs::DirEntry) -> (u64, u64, u64) {
             );
             String::new()
         });
+    version_tuple(&name)
+}
+
+/// Parses a version string as `(major, minor, patch)`, like [`semver_tuple`].
+pub(crate) fn version_tuple(name: &str) -> (u64, u64, u64) {
     let parts: Vec<&str> = name.split('.').collect();
     let major = parts.first().and_then(|s| s.parse().ok()).unwrap_or(0u64);
     let minor = parts.get(1).and_then(|s| s.parse().ok()).unwrap_or(0u64);
@@ -48,6 +54,7 @@ pub(super) fn semver_tuple(entry: &fs::DirEntry) -> (u64, u64, u64) {
 /// Adapter for Claude Code configuration.
 pub struct ClaudeAdapter {
     root: PathBuf,
+    project: Option<PathBuf>,
     kill_switch: Option<skrills_snapshot::KillSwitch>,
 }
 
@@ -57,6 +64,7 @@ impl ClaudeAdapter {
         let home = dirs::home_dir().context("Could not determine home directory")?;
         Ok(Self {
             root: home.join(".claude"),
+            project: None,
             kill_switch: None,
         })
     }
@@ -65,6 +73,17 @@ impl ClaudeAdapter {
     pub fn with_root(root: PathBuf) -> Self {
         Self {
             root,
+            project: None,
+            kill_switch: None,
+        }
+    }
+
+    /// Creates a ClaudeAdapter for a repository: `.claude/` under
+    /// `project_dir`, with `CLAUDE.md` at the repository root.
+    pub fn for_project(project_dir: PathBuf) -> Self {
+        Self {
+            root: project_dir.join(".claude"),
+            project: Some(project_dir),
             kill_switch: None,
         }
     }
@@ -106,8 +125,20 @@ impl ClaudeAdapter {
         self.root.join("settings.json")
     }
 
+    /// MCP servers live in `settings.json`, except for projects, where Claude
+    /// Code reads them from `.mcp.json` at the repository root.
+    pub(super) fn mcp_config_path(&self) -> PathBuf {
+        match &self.project {
+            Some(project) => project.join(".mcp.json"),
+            None => self.settings_path(),
+        }
+    }
+
     pub(super) fn instructions_path(&self) -> PathBuf {
-        self.root.join("CLAUDE.md")
+        self.project
+            .as_ref()
+            .unwrap_or(&self.root)
+            .join("CLAUDE.md")
     }
 }
 
@@ -137,6 +168,33 @@ impl AgentAdapter for ClaudeAdapter {
         }
     }
 
+    fn project_root(&self) -> Option<PathBuf> {
+        self.project.clone()
+    }
+
+    fn managed_paths(&self) -> Vec<PathBuf> {
+        let root = self.managed_root();
+        let mut paths: Vec<PathBuf> = [
+            self.commands_dir(),
+            self.skills_dir(),
+            self.hooks_dir(),
+            self.agents_dir(),
+            self.settings_path(),
+            self.mcp_config_path(),
+            self.instructions_path(),
+        ]
+        .into_iter()
+        .filter_map(|p| p.strip_prefix(&root).ok().map(PathBuf::from))
+        .collect();
+        // Outside a project, MCP servers share `settings.json`.
+        paths.dedup();
+        paths
+    }
+
+    fn env_syntax(&self) -> Option<EnvSyntax> {
+        Some(EnvSyntax::Braced)
+    }
+
     fn read_commands(&self, include_marketplace: bool) -> Result<Vec<Command>> {
         commands::read_commands_impl(self, include_marketplace)
     }
@@ -386,6 +444,46 @@ mod tests {
         assert!(server.enabled);
     }
 
+    #[test]
+    fn project_mcp_servers_use_repo_mcp_json() {
+        let tmp = tempdir().unwrap();
+        let adapter = ClaudeAdapter::for_project(tmp.path().to_path_buf());
+
+        let mut servers = HashMap::new();
+        servers.insert(
+            "my-server".to_string(),
+            McpServer {
+                name: "my-server".to_string(),
+                transport: McpTransport::Stdio,
+                command: "/bin/server".to_string(),
+                args: vec![],
+                env: HashMap::new(),
+                url: None,
+                headers: None,
+                enabled: true,
+                allowed_tools: vec![],
+                disabled_tools: vec![],
+            },
+        );
+
+        let report = adapter.write_mcp_servers(&servers).unwrap();
+        assert_eq!(report.written, 1);
+
+        let mcp_json = tmp.path().join(".mcp.json");
+        let content: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&mcp_json).unwrap()).unwrap();
+        assert_eq!(content["mcpServers"]["my-server"]["command"], "/bin/server");
+        assert!(!tmp.path().join(".claude/settings.json").exists());
+
+        assert!(adapter
+            .read_mcp_servers()
+            .unwrap()
+            .contains_key("my-server"));
+        assert!(adapter
+            .managed_paths()
+            .contains(&PathBuf::from(".mcp.json")));
+    }
+
     #[test]
     fn write_mcp_servers_creates_settings() {
         let tmp = tempdir().unwrap();
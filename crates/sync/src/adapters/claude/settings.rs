@@ -15,7 +15,7 @@ use std::fs;
 use super::ClaudeAdapter;
 
 pub(super) fn read_mcp_servers_impl(adapter: &ClaudeAdapter) -> Result<HashMap<String, McpServer>> {
-    let path = adapter.settings_path();
+    let path = adapter.mcp_config_path();
     if !path.exists() {
         return Ok(HashMap::new());
     }
@@ -133,7 +133,7 @@ pub(super) fn write_mcp_servers_impl(
     adapter: &ClaudeAdapter,
     servers: &HashMap<String, McpServer>,
 ) -> Result<WriteReport> {
-    let path = adapter.settings_path();
+    let path = adapter.mcp_config_path();
 
     // Read existing settings or create new
     let mut settings: serde_json::Value = if path.exists() {
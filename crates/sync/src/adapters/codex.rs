@@ -6,10 +6,28 @@
 //! When syncing agents FROM Claude TO Codex, agents are converted to skills
 //! with an "agent-" prefix (e.g., "my-agent" becomes skill "agent-my-agent").
 //! This allows agent functionality to be preserved until Codex adds official support.
+//!
+//! ## Hooks
+//!
+//! Codex has no hook configuration; it runs the `notify` program from
+//! `config.toml` when an agent turn completes. `Stop` hooks are written there
+//! as `notify = ["sh", "-c", "<commands>"]` under a marker comment; other
+//! events are skipped (see [`crate::hooks`]). A `notify` program without the
+//! marker belongs to the user and is never replaced.
+//!
+//! ## Project Scope
+//!
+//! A project-scoped adapter ([`CodexAdapter::for_project`]) uses the
+//! repository's `.codex/` directory and syncs instructions through the
+//! `AGENTS.md` file at the repository root.
 
 use super::traits::{AgentAdapter, FieldSupport};
-use super::utils::{collect_module_files, hash_content, is_hidden_path, sanitize_name};
+use super::utils::{
+    collect_module_files, hash_content, is_hidden_path, read_instructions_file, sanitize_name,
+    write_instructions_file,
+};
 use crate::common::{Command, ContentFormat, McpServer, McpTransport, Preferences};
+use crate::hooks::{self, HookDialect, HookHandler};
 use crate::report::{SkipReason, WriteReport};
 use crate::Result;
 use anyhow::Context;
@@ -22,6 +40,7 @@ use walkdir::WalkDir;
 /// Adapter for Codex CLI configuration.
 pub struct CodexAdapter {
     root: PathBuf,
+    project: Option<PathBuf>,
     kill_switch: Option<skrills_snapshot::KillSwitch>,
 }
 
@@ -31,6 +50,7 @@ impl CodexAdapter {
         let home = dirs::home_dir().context("Could not determine home directory")?;
         Ok(Self {
             root: home.join(".codex"),
+            project: None,
             kill_switch: None,
         })
     }
@@ -39,6 +59,17 @@ impl CodexAdapter {
     pub fn with_root(root: PathBuf) -> Self {
         Self {
             root,
+            project: None,
+            kill_switch: None,
+        }
+    }
+
+    /// Creates a CodexAdapter for a repository: `.codex/` under
+    /// `project_dir`, with instructions in the repository's `AGENTS.md`.
+    pub fn for_project(project_dir: PathBuf) -> Self {
+        Self {
+            root: project_dir.join(".codex"),
+            project: Some(project_dir),
             kill_switch: None,
         }
     }
@@ -69,6 +100,11 @@ impl CodexAdapter {
         self.root.join("config.toml")
     }
 
+    /// `AGENTS.md` at the repository root; `None` outside project scope.
+    fn agents_md_path(&self) -> Option<PathBuf> {
+        self.project.as_ref().map(|dir| dir.join("AGENTS.md"))
+    }
+
     /// Ensure Codex's experimental skills feature flag is enabled in `config.toml`.
     ///
     /// Codex loads skills only when `[features] skills = true` is set.
@@ -163,6 +199,74 @@ impl CodexAdapter {
 
         Ok(changed)
     }
+
+    /// Reads the top-level `notify` program from `config.toml`.
+    fn read_notify(&self) -> Result<Option<Vec<String>>> {
+        #[derive(serde::Deserialize)]
+        struct NotifyConfig {
+            notify: Option<Vec<String>>,
+        }
+
+        let path = self.config_toml_path();
+        if !path.exists() {
+            return Ok(None);
+        }
+        let content = fs::read_to_string(&path)?;
+        let config: NotifyConfig = toml::from_str(&content)
+            .with_context(|| format!("Failed to parse {}", path.display()))?;
+        Ok(config.notify.filter(|argv| !argv.is_empty()))
+    }
+
+    /// Parses `config.toml` for editing, keeping its comments and layout.
+    fn read_config_document(&self) -> Result<toml_edit::DocumentMut> {
+        let path = self.config_toml_path();
+        if !path.exists() {
+            return Ok(toml_edit::DocumentMut::new());
+        }
+        let content = fs::read_to_string(&path)?;
+        content
+            .parse()
+            .with_context(|| format!("Failed to parse {}", path.display()))
+    }
+
+    /// Sets the top-level `notify` program in `config.toml`, marked with
+    /// [`NOTIFY_MARKER`] so later syncs know they own it.
+    fn write_notify(&self, mut doc: toml_edit::DocumentMut, argv: &[String]) -> Result<()> {
+        let array: toml_edit::Array = argv.iter().map(String::as_str).collect();
+        doc["notify"] = toml_edit::value(array);
+        if let Some(mut key) = doc.as_table_mut().key_mut("notify") {
+            key.leaf_decor_mut()
+                .set_prefix(format!("{NOTIFY_MARKER}\n"));
+        }
+
+        let path = self.config_toml_path();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&path, doc.to_string())?;
+        Ok(())
+    }
+}
+
+/// Comment written above the `notify` entry skrills manages.
+const NOTIFY_MARKER: &str = "# Managed by skrills sync (Stop hooks)";
+
+/// Whether `config.toml` has a `notify` entry skrills did not write.
+fn has_foreign_notify(doc: &toml_edit::DocumentMut) -> bool {
+    doc.as_table().key("notify").is_some_and(|key| {
+        !key.leaf_decor()
+            .prefix()
+            .and_then(|prefix| prefix.as_str())
+            .is_some_and(|prefix| prefix.contains(NOTIFY_MARKER))
+    })
+}
+
+/// Command line of a `notify` program, as a hook command.
+fn notify_command(argv: &[String]) -> String {
+    match argv {
+        [shell, flag, script, ..] if shell == "sh" && flag == "-c" => script.clone(),
+        _ => argv.join(" "),
+    }
 }
 
 // Note: We intentionally do not implement Default for CodexAdapter because
@@ -184,13 +288,33 @@ impl AgentAdapter for CodexAdapter {
             mcp_servers: true,
             preferences: true,
             skills: true,
-            hooks: false,         // Codex doesn't support hooks
-            agents: false,        // Codex doesn't read agents, but write_agents converts to skills
-            instructions: false,  // Codex doesn't support instructions
+            hooks: true,   // Stop hooks map to the `notify` program
+            agents: false, // Codex doesn't read agents, but write_agents converts to skills
+            // Codex only reads instructions from a repository's AGENTS.md
+            instructions: self.project.is_some(),
             plugin_assets: false, // Codex doesn't support plugin assets
         }
     }
 
+    fn project_root(&self) -> Option<PathBuf> {
+        self.project.clone()
+    }
+
+    fn managed_paths(&self) -> Vec<PathBuf> {
+        let root = self.managed_root();
+        [
+            Some(self.prompts_dir()),
+            Some(self.skills_dir()),
+            Some(self.settings_path()),
+            Some(self.config_toml_path()),
+            self.agents_md_path(),
+        ]
+        .into_iter()
+        .flatten()
+        .filter_map(|p| p.strip_prefix(&root).ok().map(PathBuf::from))
+        .collect()
+    }
+
     fn read_commands(&self, _include_marketplace: bool) -> Result<Vec<Command>> {
         let active_dir = self.prompts_dir();
         if !active_dir.exists() {
@@ -579,8 +703,20 @@ impl AgentAdapter for CodexAdapter {
     }
 
     fn read_hooks(&self) -> Result<Vec<Command>> {
-        // Codex does not support hooks
-        Ok(Vec::new())
+        let Some(argv) = self.read_notify()? else {
+            return Ok(Vec::new());
+        };
+        let path = self.config_toml_path();
+        let modified = fs::metadata(&path)
+            .and_then(|m| m.modified())
+            .unwrap_or(SystemTime::UNIX_EPOCH);
+        let handler = HookHandler::command(notify_command(&argv));
+        Ok(vec![hooks::hook_command(
+            "Stop".to_string(),
+            &[handler],
+            path,
+            modified,
+        )?])
     }
 
     fn read_agents(&self) -> Result<Vec<Command>> {
@@ -651,10 +787,58 @@ impl AgentAdapter for CodexAdapter {
         Ok(agents)
     }
 
-    fn write_hooks(&self, _hooks: &[Command]) -> Result<WriteReport> {
+    fn write_hooks(&self, hooks: &[Command]) -> Result<WriteReport> {
         super::utils::ensure_not_engaged(self.kill_switch.as_ref())?;
-        // Codex does not support hooks
-        Ok(WriteReport::default())
+        let mut report = WriteReport::default();
+
+        for hook in hooks {
+            if hooks::native_event(HookDialect::Codex, &hook.name).is_none() {
+                report
+                    .skipped
+                    .push(hooks::unsupported_event(HookDialect::Codex, &hook.name));
+                continue;
+            }
+            let handlers = match hooks::parse_handlers(hook) {
+                Ok(handlers) if !handlers.is_empty() => handlers,
+                Ok(_) => continue,
+                Err(reason) => {
+                    report.skipped.push(reason);
+                    continue;
+                }
+            };
+
+            // Codex runs a single program per turn, so several handlers
+            // become one shell script.
+            let script = handlers
+                .iter()
+                .map(|h| h.command.as_str())
+                .collect::<Vec<_>>()
+                .join("; ");
+            let argv = vec!["sh".to_string(), "-c".to_string(), script];
+            let doc = self.read_config_document()?;
+            if has_foreign_notify(&doc) {
+                report.skipped.push(SkipReason::AgentSpecificFeature {
+                    item: hook.name.clone(),
+                    feature: "config.toml already runs a notify program skrills did not write"
+                        .to_string(),
+                    suggestion: format!(
+                        "Move the existing notify command into a Stop hook on the source, or \
+                         add a \"{NOTIFY_MARKER}\" comment above it to let skrills replace it"
+                    ),
+                });
+                continue;
+            }
+            if self.read_notify()? == Some(argv.clone()) {
+                report.skipped.push(SkipReason::Unchanged {
+                    item: hook.name.clone(),
+                });
+                continue;
+            }
+            self.write_notify(doc, &argv)?;
+            report.written += 1;
+        }
+
+        Ok(report)
     }
 
     fn write_agents(&self, agents: &[Command]) -> Result<WriteReport> {
@@ -718,14 +902,19 @@ impl AgentAdapter for CodexAdapter {
     }
 
     fn read_instructions(&self) -> Result<Vec<Command>> {
-        // Codex does not support instructions
-        Ok(Vec::new())
+        // Outside a repository Codex has no instructions file to sync
+        match self.agents_md_path() {
+            Some(path) => read_instructions_file(&path),
+            None => Ok(Vec::new()),
+        }
     }
 
-    fn write_instructions(&self, _instructions: &[Command]) -> Result<WriteReport> {
+    fn write_instructions(&self, instructions: &[Command]) -> Result<WriteReport> {
         super::utils::ensure_not_engaged(self.kill_switch.as_ref())?;
-        // Codex does not support instructions
-        Ok(WriteReport::default())
+        match self.agents_md_path() {
+            Some(path) => write_instructions_file(&path, instructions),
+            None => Ok(WriteReport::default()),
+        }
     }
 }
 
@@ -1336,4 +1525,111 @@ mod tests {
         assert!(server_json.get("allowedTools").is_none());
         assert!(server_json.get("disabledTools").is_none());
     }
+
+    #[test]
+    fn stop_hooks_become_notify_program() {
+        let tmp = tempdir().unwrap();
+        let adapter = CodexAdapter::with_root(tmp.path().to_path_buf());
+        fs::write(
+            tmp.path().join("config.toml"),
+            "model = \"o3\" # default model\n\n[features]\nskills = true\n",
+        )
+        .unwrap();
+
+        let hooks = vec![
+            crate::adapters::utils::test_helpers::make_command(
+                "Stop",
+                r#"[{"command": "./done.sh"}, {"command": "say done"}]"#,
+            ),
+            crate::adapters::utils::test_helpers::make_command(
+                "PreToolUse",
+                r#"[{"command": "./guard.sh"}]"#,
+            ),
+        ];
+        let report = adapter.write_hooks(&hooks).unwrap();
+        assert_eq!(report.written, 1);
+        assert!(matches!(
+            &report.skipped[0],
+            SkipReason::UnsupportedHookEvent { event, target }
+                if event == "PreToolUse" && target == "codex"
+        ));
+
+        let content = fs::read_to_string(tmp.path().join("config.toml")).unwrap();
+        assert_eq!(
+            content,
+            format!(
+                "model = \"o3\" # default model\n{NOTIFY_MARKER}\n\
+                 notify = [\"sh\", \"-c\", \"./done.sh; say done\"]\n\n[features]\nskills = true\n"
+            )
+        );
+
+        let read_back = adapter.read_hooks().unwrap();
+        assert_eq!(read_back[0].name, "Stop");
+        let handlers = hooks::parse_handlers(&read_back[0]).unwrap();
+        assert_eq!(handlers[0].command, "./done.sh; say done");
+
+        // Writing the same program again is a no-op.
+        let report = adapter.write_hooks(&hooks[..1]).unwrap();
+        assert_eq!(report.written, 0);
+
+        // A changed Stop hook replaces the program skrills wrote.
+        let changed = vec![crate::adapters::utils::test_helpers::make_command(
+            "Stop",
+            r#"[{"command": "./finish.sh"}]"#,
+        )];
+        assert_eq!(adapter.write_hooks(&changed).unwrap().written, 1);
+        assert_eq!(
+            adapter.read_notify().unwrap().unwrap(),
+            vec!["sh", "-c", "./finish.sh"]
+        );
+    }
+
+    #[test]
+    fn user_notify_program_is_not_replaced() {
+        let tmp = tempdir().unwrap();
+        let adapter = CodexAdapter::with_root(tmp.path().to_path_buf());
+        let config = "notify = [\"sh\", \"-c\", \"echo ']' [done]\"]\n\n\
+                      [profiles.dev]\ninstructions = \"\"\"\n[not a table]\nnotify = 1\n\"\"\"\n";
+        fs::write(tmp.path().join("config.toml"), config).unwrap();
+
+        let hooks = vec![crate::adapters::utils::test_helpers::make_command(
+            "Stop",
+            r#"[{"command": "./done.sh"}]"#,
+        )];
+        let report = adapter.write_hooks(&hooks).unwrap();
+
+        assert_eq!(report.written, 0);
+        assert!(matches!(
+            &report.skipped[0],
+            SkipReason::AgentSpecificFeature { item, .. } if item == "Stop"
+        ));
+        assert_eq!(
+            fs::read_to_string(tmp.path().join("config.toml")).unwrap(),
+            config
+        );
+    }
+
+    #[test]
+    fn notify_is_added_before_first_table() {
+        let tmp = tempdir().unwrap();
+        let adapter = CodexAdapter::with_root(tmp.path().to_path_buf());
+        fs::write(
+            tmp.path().join("config.toml"),
+            "[features]\nskills = true\n",
+        )
+        .unwrap();
+
+        let doc = adapter.read_config_document().unwrap();
+        adapter
+            .write_notify(doc, &["notify-send".to_string()])
+            .unwrap();
+        let content = fs::read_to_string(tmp.path().join("config.toml")).unwrap();
+        assert!(content.starts_with(&format!(
+            "{NOTIFY_MARKER}\nnotify = [\"notify-send\"]\n[features]"
+        )));
+        assert_eq!(
+            notify_command(&adapter.read_notify().unwrap().unwrap()),
+            "notify-send"
+        );
+    }
 }
@@ -1,10 +1,13 @@
-//! Agents, instructions, and hooks for Copilot adapter.
+//! Agents and instructions for Copilot adapter.
 
 use super::paths::{agents_dir, instructions_dir};
 use super::utils::transform_agent_for_copilot;
-use crate::adapters::utils::{hash_content, is_hidden_path, sanitize_name_segments};
+use crate::adapters::utils::{
+    hash_content, is_hidden_path, sanitize_name_segments, shadowed_warnings,
+};
 use crate::common::{Command, ContentFormat};
 use crate::report::{SkipReason, WriteReport};
+use crate::sections::merge_managed_bytes;
 use crate::Result;
 use anyhow::Context;
 use std::fs;
@@ -186,6 +189,23 @@ pub fn write_instructions(root: &Path, instructions: &[Command]) -> Result<Write
     // Note: ~/.copilot/instructions/ is a staging location, not a standard Copilot path.
     // Copilot uses repository-level instructions at .github/instructions/*.instructions.md
     // or IDE-specific global paths (e.g., ~/.config/github-copilot/intellij/).
+    let mut report = write_instruction_files(root, instructions)?;
+
+    // Add warning about the staging location
+    if report.written > 0 {
+        report.warnings.push(format!(
+            "Instructions written to {} (staging). \
+             Copy to .github/instructions/ in your repository for Copilot to use them, \
+             or to ~/.config/github-copilot/intellij/global-copilot-instructions.md for JetBrains IDEs.",
+            instructions_dir(root).display()
+        ));
+    }
+
+    Ok(report)
+}
+
+/// Writes `*.instructions.md` files without the staging-location warning.
+pub fn write_instruction_files(root: &Path, instructions: &[Command]) -> Result<WriteReport> {
     let dir = instructions_dir(root);
     fs::create_dir_all(&dir)
         .with_context(|| format!("Failed to create instructions directory: {}", dir.display()))?;
@@ -196,44 +216,29 @@ pub fn write_instructions(root: &Path, instructions: &[Command]) -> Result<Write
         let safe_name = sanitize_name_segments(&instruction.name);
         let path = dir.join(format!("{}.instructions.md", safe_name));
 
-        if path.exists() {
-            let existing = fs::read(&path).with_context(|| {
+        let existing = if path.exists() {
+            Some(fs::read(&path).with_context(|| {
                 format!("Failed to read existing instruction: {}", path.display())
-            })?;
-            if hash_content(&existing) == hash_content(&instruction.content) {
-                report.skipped.push(SkipReason::Unchanged {
-                    item: instruction.name.clone(),
-                });
-                continue;
-            }
+            })?)
+        } else {
+            None
+        };
+        let (content, shadowed) = merge_managed_bytes(existing.as_deref(), &instruction.content);
+        report.warnings.extend(shadowed_warnings(
+            &format!("{}.instructions.md", safe_name),
+            &shadowed,
+        ));
+        if existing.is_some_and(|e| hash_content(&e) == hash_content(&content)) {
+            report.skipped.push(SkipReason::Unchanged {
+                item: instruction.name.clone(),
+            });
+            continue;
         }
 
-        fs::write(&path, &instruction.content)
+        fs::write(&path, &content)
             .with_context(|| format!("Failed to write instruction: {}", path.display()))?;
         report.written += 1;
     }
 
-    // Add warning about the staging location
-    if report.written > 0 {
-        report.warnings.push(format!(
-            "Instructions written to {} (staging). \
-             Copy to .github/instructions/ in your repository for Copilot to use them, \
-             or to ~/.config/github-copilot/intellij/global-copilot-instructions.md for JetBrains IDEs.",
-            dir.display()
-        ));
-    }
-
     Ok(report)
 }
-
-/// Reads hooks - Copilot does not support hooks.
-pub fn read_hooks(_root: &Path) -> Result<Vec<Command>> {
-    // Copilot does not support hooks
-    Ok(Vec::new())
-}
-
-/// Writes hooks - Copilot does not support hooks.
-pub fn write_hooks(_root: &Path, _hooks: &[Command]) -> Result<WriteReport> {
-    // Copilot does not support hooks
-    Ok(WriteReport::default())
-}
@@ -0,0 +1,260 @@
+//! Hook reading and writing for Copilot adapter.
+//!
+//! Copilot runs hooks from `hooks/*.json` (`.github/hooks/` in a repository)
+//! with camelCase event names and shell-specific commands:
+//!
+//! ```json
+//! { "version": 1, "hooks": { "preToolUse": [{ "type": "command", "bash": "./check.sh", "timeoutSec": 30 }] } }
+//! ```
+//!
+//! skrills reads and writes `hooks/hooks.json`. Copilot hooks apply to every
+//! tool, so handlers scoped by a matcher are skipped rather than widened.
+
+use super::paths::hooks_path;
+use crate::common::Command;
+use crate::hooks::{self, HookDialect, HookHandler};
+use crate::report::{SkipReason, WriteReport};
+use crate::Result;
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::time::SystemTime;
+use tracing::{debug, warn};
+
+/// Copilot hooks file structure.
+#[derive(Debug, Serialize, Deserialize, Default)]
+struct CopilotHooksConfig {
+    #[serde(default)]
+    version: u32,
+    #[serde(default)]
+    hooks: BTreeMap<String, Vec<CopilotHookEntry>>,
+}
+
+/// A single Copilot hook command.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CopilotHookEntry {
+    #[serde(default = "default_command_type")]
+    r#type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    bash: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    powershell: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    cwd: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    timeout_sec: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    env: Option<BTreeMap<String, String>>,
+    /// Fields skrills does not know about, kept as written.
+    #[serde(flatten)]
+    extra: BTreeMap<String, serde_json::Value>,
+}
+
+fn default_command_type() -> String {
+    "command".to_string()
+}
+
+impl CopilotHookEntry {
+    /// The command skrills reads: `bash`, falling back to `powershell`.
+    fn command(&self) -> Option<&String> {
+        self.bash.as_ref().or(self.powershell.as_ref())
+    }
+
+    fn to_handler(&self) -> Option<HookHandler> {
+        let command = self.command()?;
+        Some(HookHandler {
+            timeout: self.timeout_sec,
+            ..HookHandler::command(command.clone())
+        })
+    }
+
+    fn from_handler(handler: &HookHandler) -> Self {
+        Self {
+            r#type: default_command_type(),
+            bash: Some(handler.command.clone()),
+            powershell: None,
+            cwd: None,
+            timeout_sec: handler.timeout,
+            env: None,
+            extra: BTreeMap::new(),
+        }
+    }
+
+    /// Updates the fields skrills owns, keeping `powershell`, `cwd`, `env`
+    /// and unknown fields the user set.
+    fn update_from(&mut self, handler: &HookHandler) {
+        match (&self.bash, &self.powershell) {
+            (None, Some(_)) => self.powershell = Some(handler.command.clone()),
+            _ => self.bash = Some(handler.command.clone()),
+        }
+        self.timeout_sec = handler.timeout;
+    }
+}
+
+/// Builds the entries for one event from `handlers`, reusing `existing`.
+///
+/// Each handler updates the existing entry running the same command, or the
+/// entry at the same position, so fields skrills does not sync survive.
+fn merge_entries(
+    existing: &[CopilotHookEntry],
+    handlers: &[&HookHandler],
+) -> Vec<CopilotHookEntry> {
+    let mut used = vec![false; existing.len()];
+    let mut matched: Vec<Option<usize>> = handlers
+        .iter()
+        .map(|handler| {
+            let index = existing
+                .iter()
+                .enumerate()
+                .position(|(i, entry)| !used[i] && entry.command() == Some(&handler.command))?;
+            used[index] = true;
+            Some(index)
+        })
+        .collect();
+    for (position, slot) in matched.iter_mut().enumerate() {
+        if slot.is_none() && position < existing.len() && !used[position] {
+            used[position] = true;
+            *slot = Some(position);
+        }
+    }
+
+    handlers
+        .iter()
+        .zip(matched)
+        .map(|(handler, index)| match index {
+            Some(index) => {
+                let mut entry = existing[index].clone();
+                entry.update_from(handler);
+                entry
+            }
+            None => CopilotHookEntry::from_handler(handler),
+        })
+        .collect()
+}
+
+/// Reads hooks from `hooks/hooks.json`, one Command per event.
+pub fn read_hooks(root: &Path) -> Result<Vec<Command>> {
+    let path = hooks_path(root);
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let content = fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read hooks: {}", path.display()))?;
+    let config: CopilotHooksConfig = serde_json::from_str(&content)
+        .with_context(|| format!("Failed to parse hooks: {}", path.display()))?;
+    let modified = fs::metadata(&path)
+        .and_then(|m| m.modified())
+        .unwrap_or(SystemTime::UNIX_EPOCH);
+
+    let mut hooks = Vec::new();
+    for (event, entries) in &config.hooks {
+        let handlers: Vec<HookHandler> = entries
+            .iter()
+            .filter_map(CopilotHookEntry::to_handler)
+            .collect();
+        if handlers.is_empty() {
+            continue;
+        }
+        let name = hooks::canonical_event(HookDialect::Copilot, event)
+            .map(str::to_string)
+            .unwrap_or_else(|| event.clone());
+        hooks.push(hooks::hook_command(
+            name,
+            &handlers,
+            path.clone(),
+            modified,
+        )?);
+    }
+
+    hooks.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(hooks)
+}
+
+/// Writes hooks to `hooks/hooks.json`, keeping events skrills does not sync.
+///
+/// Existing entries keep the fields skrills does not sync (`powershell`,
+/// `cwd`, `env`); an event counts as written only when its entries change.
+///
+/// Events without a Copilot equivalent are skipped with
+/// [`SkipReason::UnsupportedHookEvent`].
+pub fn write_hooks(root: &Path, hooks: &[Command]) -> Result<WriteReport> {
+    let mut report = WriteReport::default();
+    if hooks.is_empty() {
+        return Ok(report);
+    }
+
+    let path = hooks_path(root);
+    let mut config = match fs::read_to_string(&path) {
+        Ok(content) => serde_json::from_str(&content).unwrap_or_else(|e| {
+            warn!(path = %path.display(), error = %e, "Existing hooks.json has invalid JSON; starting fresh");
+            report
+                .warnings
+                .push(format!("Existing hooks.json could not be parsed: {}", e));
+            CopilotHooksConfig::default()
+        }),
+        Err(_) => CopilotHooksConfig::default(),
+    };
+    config.version = 1;
+
+    for hook in hooks {
+        let Some(event) = hooks::native_event(HookDialect::Copilot, &hook.name) else {
+            debug!(event = %hook.name, "Skipping hook with no Copilot equivalent");
+            report
+                .skipped
+                .push(hooks::unsupported_event(HookDialect::Copilot, &hook.name));
+            continue;
+        };
+        let handlers = match hooks::parse_handlers(hook) {
+            Ok(handlers) => handlers,
+            Err(reason) => {
+                report.skipped.push(reason);
+                continue;
+            }
+        };
+
+        let mut unscoped = Vec::new();
+        for handler in &handlers {
+            if handler.is_scoped() {
+                report.skipped.push(SkipReason::AgentSpecificFeature {
+                    item: format!("{} ({})", hook.name, handler.command),
+                    feature: format!(
+                        "Hook matcher '{}'",
+                        handler.matcher.as_deref().unwrap_or_default()
+                    ),
+                    suggestion: "Copilot hooks run for every tool; check the tool name inside the hook script instead".to_string(),
+                });
+                continue;
+            }
+            unscoped.push(handler);
+        }
+        if unscoped.is_empty() {
+            continue;
+        }
+
+        let existing = config
+            .hooks
+            .get(event)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        let entries = merge_entries(existing, &unscoped);
+        if serde_json::to_value(&entries)? == serde_json::to_value(existing)? {
+            continue;
+        }
+        config.hooks.insert(event.to_string(), entries);
+        report.written += 1;
+    }
+
+    if report.written > 0 {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&path, serde_json::to_string_pretty(&config)?)
+            .with_context(|| format!("Failed to write hooks: {}", path.display()))?;
+    }
+
+    Ok(report)
+}
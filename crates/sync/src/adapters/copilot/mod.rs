@@ -13,10 +13,12 @@
 //! - Skills: Same format as Codex (`skills/<name>/SKILL.md`)
 //! - Commands: NOT synced - Copilot prompts (detailed instruction files) are
 //!   conceptually different from Claude commands/Codex prompts (quick atomic shortcuts)
+//! - Hooks: `hooks/hooks.json`, mapped through the canonical event table
 //! - No config.toml feature flag management
 
 mod agents;
 mod commands;
+mod hooks;
 mod mcp;
 mod paths;
 mod preferences;
@@ -27,8 +29,10 @@ mod utils;
 mod tests;
 
 use super::traits::{AgentAdapter, FieldSupport};
+use super::utils::{is_root_instructions, read_instructions_file, write_instructions_file};
 use crate::common::{Command, McpServer, Preferences};
 use crate::report::WriteReport;
+use crate::secrets::EnvSyntax;
 use crate::Result;
 use std::collections::HashMap;
 use std::path::PathBuf;
@@ -36,6 +40,7 @@ use std::path::PathBuf;
 /// Adapter for GitHub Copilot CLI configuration.
 pub struct CopilotAdapter {
     root: PathBuf,
+    project: Option<PathBuf>,
     kill_switch: Option<skrills_snapshot::KillSwitch>,
 }
 
@@ -50,6 +55,7 @@ impl CopilotAdapter {
         let root = paths::resolve_config_root()?;
         Ok(Self {
             root,
+            project: None,
             kill_switch: None,
         })
     }
@@ -58,6 +64,21 @@ impl CopilotAdapter {
     pub fn with_root(root: PathBuf) -> Self {
         Self {
             root,
+            project: None,
+            kill_switch: None,
+        }
+    }
+
+    /// Creates a CopilotAdapter for a repository's `.github/` directory.
+    ///
+    /// Repository-wide instructions (`CLAUDE.md`, `AGENTS.md`) map to
+    /// `.github/copilot-instructions.md`; other instructions go to
+    /// `.github/instructions/`. Copilot has no repository-level MCP or
+    /// preferences file, so those are not synced.
+    pub fn for_project(project_dir: PathBuf) -> Self {
+        Self {
+            root: project_dir.join(".github"),
+            project: Some(project_dir),
             kill_switch: None,
         }
     }
@@ -95,25 +116,61 @@ impl AgentAdapter for CopilotAdapter {
     fn supported_fields(&self) -> FieldSupport {
         FieldSupport {
             commands: false, // Copilot prompts are NOT equivalent to Claude commands/Codex prompts
-            mcp_servers: true,
-            preferences: true,
+            // Repositories have no Copilot MCP or preferences file
+            mcp_servers: self.project.is_none(),
+            preferences: self.project.is_none(),
             skills: true,
-            hooks: false,         // Copilot doesn't support hooks
+            hooks: true,          // Copilot runs hooks from hooks/hooks.json
             agents: true,         // Copilot supports custom agents in ~/.copilot/agents/
             instructions: true,   // Copilot supports *.instructions.md files
             plugin_assets: false, // Copilot doesn't support plugin assets
         }
     }
 
+    fn project_root(&self) -> Option<PathBuf> {
+        self.project.clone()
+    }
+
+    fn managed_paths(&self) -> Vec<PathBuf> {
+        let root = self.root.as_path();
+        let managed_root = self.managed_root();
+        let mut managed = vec![
+            paths::skills_dir(root),
+            paths::agents_dir(root),
+            paths::prompts_dir(root),
+            paths::instructions_dir(root),
+            paths::hooks_path(root),
+        ];
+        if self.project.is_some() {
+            managed.push(paths::repo_instructions_path(root));
+        } else {
+            managed.extend([paths::mcp_config_path(root), paths::config_path(root)]);
+        }
+        managed
+            .into_iter()
+            .filter_map(|p| p.strip_prefix(&managed_root).ok().map(PathBuf::from))
+            .collect()
+    }
+
+    fn env_syntax(&self) -> Option<EnvSyntax> {
+        Some(EnvSyntax::Braced)
+    }
+
     fn read_commands(&self, include_marketplace: bool) -> Result<Vec<Command>> {
         commands::read_commands(&self.root, include_marketplace)
     }
 
     fn read_mcp_servers(&self) -> Result<HashMap<String, McpServer>> {
+        if self.project.is_some() {
+            return Ok(HashMap::new());
+        }
         mcp::read_mcp_servers(&self.root)
     }
 
     fn read_preferences(&self) -> Result<Preferences> {
+        if self.project.is_some() {
+            return Ok(Preferences::default());
+        }
         preferences::read_preferences(&self.root)
     }
 
@@ -128,11 +185,17 @@ impl AgentAdapter for CopilotAdapter {
 
     fn write_mcp_servers(&self, servers: &HashMap<String, McpServer>) -> Result<WriteReport> {
         crate::adapters::utils::ensure_not_engaged(self.kill_switch.as_ref())?;
+        if self.project.is_some() {
+            return Ok(project_unsupported("MCP servers", !servers.is_empty()));
+        }
         mcp::write_mcp_servers(&self.root, servers)
     }
 
     fn write_preferences(&self, prefs: &Preferences) -> Result<WriteReport> {
         crate::adapters::utils::ensure_not_engaged(self.kill_switch.as_ref())?;
+        if self.project.is_some() {
+            return Ok(project_unsupported("preferences", prefs.model.is_some()));
+        }
         preferences::write_preferences(&self.root, prefs)
     }
 
@@ -142,7 +205,7 @@ impl AgentAdapter for CopilotAdapter {
     }
 
     fn read_hooks(&self) -> Result<Vec<Command>> {
-        agents::read_hooks(&self.root)
+        hooks::read_hooks(&self.root)
     }
 
     fn read_agents(&self) -> Result<Vec<Command>> {
@@ -151,7 +214,7 @@ impl AgentAdapter for CopilotAdapter {
 
     fn write_hooks(&self, hooks: &[Command]) -> Result<WriteReport> {
         crate::adapters::utils::ensure_not_engaged(self.kill_switch.as_ref())?;
-        agents::write_hooks(&self.root, hooks)
+        hooks::write_hooks(&self.root, hooks)
     }
 
     fn write_agents(&self, agents: &[Command]) -> Result<WriteReport> {
@@ -160,11 +223,45 @@ impl AgentAdapter for CopilotAdapter {
     }
 
     fn read_instructions(&self) -> Result<Vec<Command>> {
-        agents::read_instructions(&self.root)
+        let mut instructions = Vec::new();
+        if self.project.is_some() {
+            instructions.extend(read_instructions_file(&paths::repo_instructions_path(
+                &self.root,
+            ))?);
+        }
+        instructions.extend(agents::read_instructions(&self.root)?);
+        Ok(instructions)
     }
 
     fn write_instructions(&self, instructions: &[Command]) -> Result<WriteReport> {
         crate::adapters::utils::ensure_not_engaged(self.kill_switch.as_ref())?;
-        agents::write_instructions(&self.root, instructions)
+        if self.project.is_none() {
+            return agents::write_instructions(&self.root, instructions);
+        }
+
+        let (repo_wide, scoped): (Vec<Command>, Vec<Command>) = instructions
+            .iter()
+            .cloned()
+            .partition(|i| is_root_instructions(&i.name));
+        let mut report =
+            write_instructions_file(&paths::repo_instructions_path(&self.root), &repo_wide)?;
+        if !scoped.is_empty() {
+            let scoped_report = agents::write_instruction_files(&self.root, &scoped)?;
+            report.written += scoped_report.written;
+            report.skipped.extend(scoped_report.skipped);
+        }
+        Ok(report)
+    }
+}
+
+/// Report for an artifact a project-scoped Copilot adapter cannot store.
+fn project_unsupported(what: &str, had_items: bool) -> WriteReport {
+    let mut report = WriteReport::default();
+    if had_items {
+        report.warnings.push(format!(
+            "Copilot has no repository-level {} file; {} not synced in project scope",
+            what, what
+        ));
     }
+    report
 }
@@ -49,6 +49,16 @@ pub fn instructions_dir(root: &std::path::Path) -> PathBuf {
     root.join("instructions")
 }
 
+/// Path to the repository-wide instructions file (`.github/copilot-instructions.md`).
+pub fn repo_instructions_path(root: &std::path::Path) -> PathBuf {
+    root.join("copilot-instructions.md")
+}
+
+/// Path to the hook configuration skrills writes (`hooks/hooks.json`).
+pub fn hooks_path(root: &std::path::Path) -> PathBuf {
+    root.join("hooks").join("hooks.json")
+}
+
 /// Path to MCP server configuration (separate from main config).
 pub fn mcp_config_path(root: &std::path::Path) -> PathBuf {
     root.join("mcp-config.json")
@@ -635,6 +635,106 @@ fn mcp_servers_roundtrip() {
     assert!(server.enabled);
 }
 
+// ==========================================
+// Hooks Tests
+// ==========================================
+
+#[test]
+fn hooks_translate_events_and_round_trip() {
+    let tmp = tempdir().unwrap();
+    let adapter = CopilotAdapter::with_root(tmp.path().to_path_buf());
+    let hooks = vec![
+        make_command(
+            "UserPromptSubmit",
+            r#"[{"command": "./context.sh", "timeout": 5}]"#,
+        ),
+        make_command("SessionStart", r#"[{"command": "./start.sh"}]"#),
+    ];
+
+    let report = adapter.write_hooks(&hooks).unwrap();
+    assert_eq!(report.written, 2);
+
+    let json: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(tmp.path().join("hooks/hooks.json")).unwrap())
+            .unwrap();
+    assert_eq!(
+        json["hooks"]["userPromptSubmitted"][0]["bash"],
+        "./context.sh"
+    );
+    assert_eq!(json["hooks"]["userPromptSubmitted"][0]["timeoutSec"], 5);
+
+    let read_back = adapter.read_hooks().unwrap();
+    let names: Vec<&str> = read_back.iter().map(|h| h.name.as_str()).collect();
+    assert_eq!(names, ["SessionStart", "UserPromptSubmit"]);
+}
+
+#[test]
+fn hooks_skip_unsupported_events_and_matchers() {
+    let tmp = tempdir().unwrap();
+    let adapter = CopilotAdapter::with_root(tmp.path().to_path_buf());
+    let hooks = vec![
+        make_command("PreCompact", r#"[{"command": "./compact.sh"}]"#),
+        make_command(
+            "PreToolUse",
+            r#"[{"matcher": "Bash", "hooks": [{"type": "command", "command": "./guard.sh"}]},
+                {"hooks": [{"type": "command", "command": "./audit.sh"}]}]"#,
+        ),
+    ];
+
+    let report = adapter.write_hooks(&hooks).unwrap();
+    assert_eq!(report.written, 1);
+    assert!(report.skipped.iter().any(|r| matches!(
+        r,
+        crate::report::SkipReason::UnsupportedHookEvent { event, .. } if event == "PreCompact"
+    )));
+    assert!(report
+        .skipped
+        .iter()
+        .any(|r| r.description().contains("./guard.sh")));
+
+    let read_back = adapter.read_hooks().unwrap();
+    let content = String::from_utf8_lossy(&read_back[0].content);
+    assert!(content.contains("./audit.sh") && !content.contains("./guard.sh"));
+}
+
+#[test]
+fn hooks_keep_user_fields_and_count_only_changes() {
+    let tmp = tempdir().unwrap();
+    let adapter = CopilotAdapter::with_root(tmp.path().to_path_buf());
+    let path = tmp.path().join("hooks/hooks.json");
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(
+        &path,
+        r#"{"version": 1, "hooks": {"sessionStart": [{
+            "type": "command", "bash": "./start.sh", "powershell": "./start.ps1",
+            "cwd": "scripts", "env": {"MODE": "ci"}, "timeoutSec": 10, "comment": "mine"
+        }]}}"#,
+    )
+    .unwrap();
+
+    let hooks = vec![make_command(
+        "SessionStart",
+        r#"[{"command": "./start.sh", "timeout": 10}]"#,
+    )];
+    assert_eq!(adapter.write_hooks(&hooks).unwrap().written, 0);
+
+    let hooks = vec![make_command(
+        "SessionStart",
+        r#"[{"command": "./boot.sh", "timeout": 30}]"#,
+    )];
+    assert_eq!(adapter.write_hooks(&hooks).unwrap().written, 1);
+
+    let json: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+    let entry = &json["hooks"]["sessionStart"][0];
+    assert_eq!(entry["bash"], "./boot.sh");
+    assert_eq!(entry["timeoutSec"], 30);
+    assert_eq!(entry["powershell"], "./start.ps1");
+    assert_eq!(entry["cwd"], "scripts");
+    assert_eq!(entry["env"]["MODE"], "ci");
+    assert_eq!(entry["comment"], "mine");
+}
+
 // ==========================================
 // Agents Tests
 // ==========================================
@@ -3,15 +3,18 @@
 //! Cursor agents are markdown files with YAML frontmatter in `.cursor/agents/`.
 //! Key differences from Claude agents:
 //! - `background: true` → `is_background: true`
-//! - `tools` and `isolation` fields are Claude-only (stripped on write)
+//! - `tools` and `isolation` fields are Claude-only (stripped on write, kept in
+//!   the [`SidecarManifest`] so an unedited agent reads back unchanged)
 //! - `readonly` is Cursor-only (preserved on read)
-//! - Model names translated via `transform_model`
+//! - Model names mapped by the orchestrator via `crate::models::ModelMap`
 
 use super::paths::agents_dir;
 use super::utils::sanitize_name;
 use crate::adapters::utils::{hash_content, split_frontmatter};
 use crate::common::{Command, ContentFormat};
+use crate::conflict::ArtifactType;
 use crate::report::{SkipReason, WriteReport};
+use crate::sidecar::{dropped_fields, SidecarManifest};
 use crate::Result;
 use std::fs;
 use std::path::Path;
@@ -71,6 +74,7 @@ pub fn read_agents(root: &Path) -> Result<Vec<Command>> {
     }
 
     agents.sort_by(|a, b| a.name.cmp(&b.name));
+    SidecarManifest::load(root).rehydrate(ArtifactType::Agent, &mut agents);
     Ok(agents)
 }
 
@@ -78,7 +82,7 @@ pub fn read_agents(root: &Path) -> Result<Vec<Command>> {
 ///
 /// Translates Claude agent frontmatter to Cursor conventions:
 /// - `background: true` → `is_background: true`
-/// - `tools` and `isolation` fields are stripped
+/// - `tools` and `isolation` fields are stripped (recorded in the sidecar)
 pub fn write_agents(root: &Path, agents: &[Command]) -> Result<WriteReport> {
     let dir = agents_dir(root);
     let mut report = WriteReport::default();
@@ -88,6 +92,7 @@ pub fn write_agents(root: &Path, agents: &[Command]) -> Result<WriteReport> {
     }
 
     fs::create_dir_all(&dir)?;
+    let mut sidecar = SidecarManifest::load(root);
 
     for agent in agents {
         let name = sanitize_name(&agent.name);
@@ -96,6 +101,17 @@ pub fn write_agents(root: &Path, agents: &[Command]) -> Result<WriteReport> {
         // Translate frontmatter fields
         let content_str = String::from_utf8_lossy(&agent.content);
         let translated = translate_agent_frontmatter(&content_str);
+        let dropped = dropped_fields(&content_str, &[])
+            .into_iter()
+            .filter(|field| field == "tools" || field == "isolation")
+            .collect();
+        sidecar.record(
+            ArtifactType::Agent,
+            &name,
+            &agent.content,
+            translated.as_bytes(),
+            dropped,
+        );
 
         if path.exists() {
             let existing = fs::read(&path)?;
@@ -112,6 +128,7 @@ pub fn write_agents(root: &Path, agents: &[Command]) -> Result<WriteReport> {
         report.written += 1;
     }
 
+    sidecar.save(root)?;
     Ok(report)
 }
 
@@ -1,26 +1,14 @@
 //! Hook reading and writing for Cursor adapter.
 //!
-//! Cursor hooks are configured in `.cursor/hooks.json` with camelCase event names
-//! and 18+ lifecycle events. Claude Code uses PascalCase with 8 events.
-//!
-//! ## Event Mapping (Claude → Cursor)
-//!
-//! | Claude (PascalCase) | Cursor (camelCase)      |
-//! |---------------------|-------------------------|
-//! | PreToolUse          | preToolUse              |
-//! | PostToolUse         | postToolUse             |
-//! | SessionStart        | sessionStart            |
-//! | SessionEnd          | sessionEnd              |
-//! | Stop                | stop                    |
-//! | SubagentStop        | subagentStop            |
-//! | UserPromptSubmit    | beforeSubmitPrompt      |
-//! | PreCompact          | preCompact              |
-//! | Notification        | *(no equivalent)*       |
+//! Cursor hooks are configured in `.cursor/hooks.json` with camelCase event
+//! names and 18+ lifecycle events. Event names are translated through the
+//! canonical table in [`crate::hooks`]; Cursor-only events (e.g.
+//! `afterFileEdit`) are read under their own name and preserved on write.
 
 use super::paths::hooks_path;
-use crate::adapters::utils::hash_content;
-use crate::common::{Command, ContentFormat};
-use crate::report::{SkipReason, WriteReport};
+use crate::common::Command;
+use crate::hooks::{self, HookDialect, HookHandler};
+use crate::report::WriteReport;
 use crate::Result;
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
@@ -35,59 +23,18 @@ struct CursorHooksConfig {
     #[serde(default)]
     version: u32,
     #[serde(default)]
-    hooks: BTreeMap<String, Vec<HookEntry>>,
-}
-
-/// A single hook entry within an event.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-struct HookEntry {
-    command: String,
-    #[serde(default = "default_command_type")]
-    r#type: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    timeout: Option<u32>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    matcher: Option<String>,
-    #[serde(rename = "failClosed", skip_serializing_if = "Option::is_none")]
-    fail_closed: Option<bool>,
-}
-
-fn default_command_type() -> String {
-    "command".to_string()
-}
-
-/// Bidirectional event name mapping between Claude (PascalCase) and Cursor (camelCase).
-static CLAUDE_TO_CURSOR_EVENTS: &[(&str, &str)] = &[
-    ("PreToolUse", "preToolUse"),
-    ("PostToolUse", "postToolUse"),
-    ("SessionStart", "sessionStart"),
-    ("SessionEnd", "sessionEnd"),
-    ("Stop", "stop"),
-    ("SubagentStop", "subagentStop"),
-    ("UserPromptSubmit", "beforeSubmitPrompt"),
-    ("PreCompact", "preCompact"),
-];
-
-/// Maps a Claude event name to Cursor event name.
-pub fn claude_to_cursor_event(claude_event: &str) -> Option<&'static str> {
-    CLAUDE_TO_CURSOR_EVENTS
-        .iter()
-        .find(|(c, _)| *c == claude_event)
-        .map(|(_, cursor)| *cursor)
+    hooks: BTreeMap<String, Vec<HookHandler>>,
 }
 
 /// Maps a Cursor event name to Claude event name.
 pub fn cursor_to_claude_event(cursor_event: &str) -> Option<&'static str> {
-    CLAUDE_TO_CURSOR_EVENTS
-        .iter()
-        .find(|(_, c)| *c == cursor_event)
-        .map(|(claude, _)| *claude)
+    hooks::canonical_event(HookDialect::Cursor, cursor_event)
 }
 
 /// Reads hooks from `.cursor/hooks.json`.
 ///
-/// Each event group becomes a separate Command entry, with the event name
-/// as the Command name and the JSON-serialized hook entries as content.
+/// Each event group becomes a separate Command entry, with the canonical
+/// event name as the Command name and the hook handlers as JSON content.
 pub fn read_hooks(root: &Path) -> Result<Vec<Command>> {
     let path = hooks_path(root);
     if !path.exists() {
@@ -97,30 +44,16 @@ pub fn read_hooks(root: &Path) -> Result<Vec<Command>> {
     let content = fs::read_to_string(&path)?;
     let config: CursorHooksConfig = serde_json::from_str(&content)?;
 
-    let mut hooks = Vec::new();
     let modified = fs::metadata(&path)
         .and_then(|m| m.modified())
         .unwrap_or(SystemTime::UNIX_EPOCH);
 
+    let mut hooks = Vec::new();
     for (event_name, entries) in &config.hooks {
-        let entries_json = serde_json::to_string_pretty(entries)?;
-        let content_bytes = entries_json.into_bytes();
-        let hash = hash_content(&content_bytes);
-
         let name = cursor_to_claude_event(event_name)
             .map(|s| s.to_string())
             .unwrap_or_else(|| event_name.clone());
-
-        hooks.push(Command {
-            name,
-            content: content_bytes,
-            source_path: path.clone(),
-            modified,
-            hash,
-            modules: vec![],
-            content_format: ContentFormat::Json,
-            plugin_origin: None,
-        });
+        hooks.push(hooks::hook_command(name, entries, path.clone(), modified)?);
     }
 
     hooks.sort_by(|a, b| a.name.cmp(&b.name));
@@ -129,8 +62,8 @@ pub fn read_hooks(root: &Path) -> Result<Vec<Command>> {
 
 /// Writes hooks to `.cursor/hooks.json`.
 ///
-/// Translates Claude PascalCase event names to Cursor camelCase.
-/// Events without a Cursor equivalent (e.g., Notification) are skipped.
+/// Translates canonical event names to Cursor camelCase. Events without a
+/// Cursor equivalent (e.g., Notification) are skipped.
 pub fn write_hooks(root: &Path, hooks: &[Command]) -> Result<WriteReport> {
     let mut report = WriteReport::default();
 
@@ -162,65 +95,19 @@ pub fn write_hooks(root: &Path, hooks: &[Command]) -> Result<WriteReport> {
     config.version = 1;
 
     for hook in hooks {
-        // Try to map the event name
-        let cursor_event = if let Some(mapped) = claude_to_cursor_event(&hook.name) {
-            mapped.to_string()
-        } else {
-            // Check if it's already a Cursor event name (passthrough)
-            if CLAUDE_TO_CURSOR_EVENTS.iter().any(|(_, c)| *c == hook.name) {
-                hook.name.clone()
-            } else {
-                warn!(
-                    event = %hook.name,
-                    "Skipping hook with no Cursor equivalent"
-                );
-                report.skipped.push(SkipReason::AgentSpecificFeature {
-                    item: hook.name.clone(),
-                    feature: format!("Hook event '{}' has no Cursor equivalent", hook.name),
-                    suggestion: "This hook event is Claude-specific and cannot be mapped to Cursor"
-                        .to_string(),
-                });
-                continue;
-            }
+        let Some(cursor_event) = hooks::native_event(HookDialect::Cursor, &hook.name) else {
+            warn!(event = %hook.name, "Skipping hook with no Cursor equivalent");
+            report
+                .skipped
+                .push(hooks::unsupported_event(HookDialect::Cursor, &hook.name));
+            continue;
         };
 
-        // Parse hook content based on its declared format
-        let content_str = String::from_utf8_lossy(&hook.content);
-        let entries: Vec<HookEntry> = if hook.content_format == ContentFormat::Json {
-            // JSON source, attempt parse, skip gracefully on failure
-            match serde_json::from_str(&content_str) {
-                Ok(e) => e,
-                Err(e) => {
-                    warn!(
-                        event = %hook.name,
-                        error = %e,
-                        "Skipping hook with malformed JSON content"
-                    );
-                    report.skipped.push(SkipReason::AgentSpecificFeature {
-                        item: hook.name.clone(),
-                        feature: "Hook content is not valid JSON".to_string(),
-                        suggestion: "Hook content must be a JSON array of hook entries".to_string(),
-                    });
-                    continue;
-                }
-            }
-        } else {
-            // Markdown/plain text, try JSON first, skip if unparseable
-            match serde_json::from_str(&content_str) {
-                Ok(e) => e,
-                Err(e) => {
-                    warn!(
-                        event = %hook.name,
-                        error = %e,
-                        "Skipping hook with non-JSON content"
-                    );
-                    report.skipped.push(SkipReason::AgentSpecificFeature {
-                        item: hook.name.clone(),
-                        feature: "Hook content is not valid JSON".to_string(),
-                        suggestion: "Hook content must be a JSON array of hook entries".to_string(),
-                    });
-                    continue;
-                }
+        let entries = match hooks::parse_handlers(hook) {
+            Ok(entries) => entries,
+            Err(reason) => {
+                report.skipped.push(reason);
+                continue;
             }
         };
 
@@ -230,7 +117,7 @@ pub fn write_hooks(root: &Path, hooks: &[Command]) -> Result<WriteReport> {
         }
 
         debug!(event = %cursor_event, count = entries.len(), "Writing Cursor hook");
-        config.hooks.insert(cursor_event, entries);
+        config.hooks.insert(cursor_event.to_string(), entries);
         report.written += 1;
     }
 
@@ -249,6 +136,11 @@ pub fn write_hooks(root: &Path, hooks: &[Command]) -> Result<WriteReport> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::common::ContentFormat;
+
+    fn claude_to_cursor_event(claude_event: &str) -> Option<&'static str> {
+        hooks::native_event(HookDialect::Cursor, claude_event)
+    }
 
     #[test]
     fn event_mapping_claude_to_cursor() {
@@ -275,9 +167,11 @@ mod tests {
 
     #[test]
     fn event_mapping_bidirectional_roundtrip() {
-        for (claude, cursor) in CLAUDE_TO_CURSOR_EVENTS {
-            assert_eq!(claude_to_cursor_event(claude), Some(*cursor));
-            assert_eq!(cursor_to_claude_event(cursor), Some(*claude));
+        for event in hooks::HOOK_EVENTS {
+            if let Some(cursor) = event.cursor {
+                assert_eq!(claude_to_cursor_event(event.canonical), Some(cursor));
+                assert_eq!(cursor_to_claude_event(cursor), Some(event.canonical));
+            }
         }
     }
 
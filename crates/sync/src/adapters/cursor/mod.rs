@@ -14,10 +14,12 @@
 //!   (PascalCase). Event name mapping is handled in the hooks module.
 //! - **Skills**: Cursor skills have no YAML frontmatter, Claude frontmatter is stripped
 //!   on write, but `description` is preserved as a plain-text first line and `model_hint`
-//!   as an HTML comment. **Partially lossy**: a Claude→Cursor→Claude roundtrip loses
-//!   most frontmatter metadata (name, dependencies, version, tags).
+//!   as an HTML comment. The rest (name, dependencies, version, tags) is kept
+//!   in a [`SidecarManifest`](crate::sidecar::SidecarManifest) so an unedited
+//!   Claude→Cursor→Claude roundtrip restores it.
 //! - **Agents**: Field translation: `background` ↔ `is_background`, model name
-//!   mapping, `tools`/`isolation` dropped (Cursor-only: `readonly`).
+//!   mapping, `tools`/`isolation` dropped and kept in the sidecar
+//!   (Cursor-only: `readonly`).
 //! - **Commands**: Near-identical to Claude format (`.cursor/commands/*.md`).
 //! - **MCP**: `.cursor/mcp.json` (similar to Claude's `.mcp.json`).
 
@@ -36,6 +38,7 @@ mod tests;
 use super::traits::{AgentAdapter, FieldSupport};
 use crate::common::{Command, McpServer, PluginAsset, Preferences};
 use crate::report::WriteReport;
+use crate::secrets::EnvSyntax;
 use crate::Result;
 use std::collections::HashMap;
 use std::path::PathBuf;
@@ -44,6 +47,7 @@ use std::path::PathBuf;
 #[derive(Debug)]
 pub struct CursorAdapter {
     root: PathBuf,
+    project: Option<PathBuf>,
     kill_switch: Option<skrills_snapshot::KillSwitch>,
 }
 
@@ -53,6 +57,7 @@ impl CursorAdapter {
         let root = paths::resolve_config_root()?;
         Ok(Self {
             root,
+            project: None,
             kill_switch: None,
         })
     }
@@ -61,6 +66,17 @@ impl CursorAdapter {
     pub fn with_root(root: PathBuf) -> Self {
         Self {
             root,
+            project: None,
+            kill_switch: None,
+        }
+    }
+
+    /// Creates a CursorAdapter for a repository's `.cursor/` directory
+    /// (rules in `.cursor/rules`, agents in `.cursor/agents`, ...).
+    pub fn for_project(project_dir: PathBuf) -> Self {
+        Self {
+            root: project_dir.join(".cursor"),
+            project: Some(project_dir),
             kill_switch: None,
         }
     }
@@ -97,6 +113,32 @@ impl AgentAdapter for CursorAdapter {
         }
     }
 
+    fn project_root(&self) -> Option<PathBuf> {
+        self.project.clone()
+    }
+
+    fn managed_paths(&self) -> Vec<PathBuf> {
+        let root = self.root.as_path();
+        let managed_root = self.managed_root();
+        [
+            paths::skills_dir(root),
+            paths::commands_dir(root),
+            paths::agents_dir(root),
+            paths::rules_dir(root),
+            paths::hooks_path(root),
+            paths::mcp_config_path(root),
+            root.join("plugins").join("local"),
+            crate::sidecar::SidecarManifest::path(root),
+        ]
+        .into_iter()
+        .filter_map(|p| p.strip_prefix(&managed_root).ok().map(PathBuf::from))
+        .collect()
+    }
+
+    fn env_syntax(&self) -> Option<EnvSyntax> {
+        Some(EnvSyntax::CursorEnv)
+    }
+
     fn read_commands(&self, _include_marketplace: bool) -> Result<Vec<Command>> {
         commands::read_commands(&self.root)
     }
@@ -18,7 +18,7 @@
 
 use super::paths::rules_dir;
 use super::utils::{parse_frontmatter, render_frontmatter, sanitize_name};
-use crate::adapters::utils::hash_content;
+use crate::adapters::utils::{hash_content, is_root_instructions};
 use crate::common::{Command, ContentFormat};
 use crate::report::{SkipReason, WriteReport};
 use crate::Result;
@@ -178,17 +178,18 @@ fn derive_rule_mode(
         return (fields, body.to_string());
     }
 
-    // CLAUDE.md, CLAUDE, claude-md (read-back form), or claude-instructions → always apply
-    let name_lower = name.to_lowercase();
-    if name_lower == "claude"
-        || name_lower == "claude.md"
-        || name_lower == "claude-md"
-        || name_lower.contains("claude-instruction")
-    {
+    // Repository-wide instructions (CLAUDE.md, AGENTS.md, copilot-instructions.md,
+    // in any of their read-back forms) → always apply
+    if is_root_instructions(name) {
+        let source = if name.to_lowercase().contains("claude") {
+            "CLAUDE.md".to_string()
+        } else {
+            name.to_string()
+        };
         fields.insert("alwaysApply".to_string(), "true".to_string());
         fields.insert(
             "description".to_string(),
-            "Project instructions (migrated from CLAUDE.md)".to_string(),
+            format!("Project instructions (migrated from {})", source),
         );
         return (fields, body.to_string());
     }
@@ -207,6 +208,13 @@ fn derive_rule_mode(
 mod tests {
     use super::*;
 
+    #[test]
+    fn derive_agents_md_as_always_apply() {
+        let (fields, _body) = derive_rule_mode("AGENTS", "# Agents\n\nRun the tests.\n");
+        assert_eq!(fields.get("alwaysApply").unwrap(), "true");
+        assert!(fields.get("description").unwrap().contains("AGENTS"));
+    }
+
     #[test]
     fn derive_claude_md_as_always_apply() {
         let (fields, _body) =
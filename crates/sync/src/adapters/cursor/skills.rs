@@ -10,20 +10,22 @@
 //! comment for routing. When reading Cursor skills back, no frontmatter
 //! is expected.
 //!
-//! ## Lossy roundtrip warning
+//! ## Lossy format, lossless roundtrip
 //!
 //! Syncing Claude → Cursor **strips most YAML frontmatter** (name,
-//! dependencies, version, tags, etc.). `description` and `model_hint`
-//! are preserved in non-YAML form. A subsequent Cursor → Claude sync
-//! will **not** restore the original metadata. Treat Cursor as a
-//! one-way *consumer* of Claude skills; do not use it as the source
-//! of truth for skills that originated in Claude.
+//! dependencies, version, tags, etc.) and trims non-essential sections.
+//! The original document is kept in the Cursor root's
+//! [`SidecarManifest`](crate::sidecar::SidecarManifest), and a later
+//! Cursor → Claude sync restores it as long as the Cursor copy has not been
+//! edited. Skills edited in Cursor are read back as plain markdown.
 
 use super::paths::skills_dir;
 use super::utils::{parse_frontmatter, sanitize_name, strip_yaml_quotes, trim_skill_body};
 use crate::adapters::utils::{collect_module_files, hash_content};
 use crate::common::{Command, ContentFormat, PluginOrigin};
+use crate::conflict::ArtifactType;
 use crate::report::{SkipReason, WriteReport};
+use crate::sidecar::{dropped_fields, SidecarManifest};
 use crate::Result;
 use std::fs;
 use std::path::Path;
@@ -180,6 +182,7 @@ pub fn read_skills(root: &Path) -> Result<Vec<Command>> {
     }
 
     skills.sort_by(|a, b| a.name.cmp(&b.name));
+    SidecarManifest::load(root).rehydrate(ArtifactType::Skill, &mut skills);
     Ok(skills)
 }
 
@@ -187,7 +190,8 @@ pub fn read_skills(root: &Path) -> Result<Vec<Command>> {
 /// a [`PluginOrigin`], to `.cursor/plugins/local/{plugin}/skills/{name}/SKILL.md`
 /// so that Cursor's plugin system discovers them as installed plugins.
 ///
-/// Strips YAML frontmatter from content (Cursor skills don't use frontmatter).
+/// Strips YAML frontmatter from content (Cursor skills don't use frontmatter)
+/// and records the original document in the sidecar manifest.
 pub fn write_skills(root: &Path, skills: &[Command]) -> Result<WriteReport> {
     let flat_dir = skills_dir(root);
     let local_plugins_dir = root.join("plugins").join("local");
@@ -198,6 +202,7 @@ pub fn write_skills(root: &Path, skills: &[Command]) -> Result<WriteReport> {
     }
 
     fs::create_dir_all(&flat_dir)?;
+    let mut sidecar = SidecarManifest::load(root);
 
     // Track which plugins we've written so we can create their manifests once
     let mut seen_plugins: std::collections::HashSet<String> = std::collections::HashSet::new();
@@ -245,6 +250,13 @@ pub fn write_skills(root: &Path, skills: &[Command]) -> Result<WriteReport> {
         };
 
         let skill_path = skill_dir.join("SKILL.md");
+        sidecar.record(
+            ArtifactType::Skill,
+            &name,
+            &skill.content,
+            body.as_bytes(),
+            dropped_fields(&content_str, &["description", "model_hint"]),
+        );
 
         let skill_unchanged = if skill_path.exists() {
             let existing = fs::read(&skill_path)?;
@@ -284,5 +296,6 @@ pub fn write_skills(root: &Path, skills: &[Command]) -> Result<WriteReport> {
         report.written += 1;
     }
 
+    sidecar.save(root)?;
     Ok(report)
 }
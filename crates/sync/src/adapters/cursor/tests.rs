@@ -24,6 +24,22 @@ fn make_skill_with_modules(name: &str) -> Command {
     cmd
 }
 
+/// Reads skills as Cursor sees them, without sidecar rehydration.
+fn read_skills_on_disk(adapter: &CursorAdapter) -> Vec<Command> {
+    let _ = std::fs::remove_file(crate::sidecar::SidecarManifest::path(
+        &adapter.config_root(),
+    ));
+    adapter.read_skills().unwrap()
+}
+
+/// Reads agents as Cursor sees them, without sidecar rehydration.
+fn read_agents_on_disk(adapter: &CursorAdapter) -> Vec<Command> {
+    let _ = std::fs::remove_file(crate::sidecar::SidecarManifest::path(
+        &adapter.config_root(),
+    ));
+    adapter.read_agents().unwrap()
+}
+
 // --- Adapter basics ---
 
 #[test]
@@ -82,7 +98,7 @@ fn skills_strip_frontmatter_on_write() {
     assert_eq!(report.written, 1);
 
     // Read back and verify frontmatter was stripped but description preserved
-    let read_back = adapter.read_skills().unwrap();
+    let read_back = read_skills_on_disk(&adapter);
     assert_eq!(read_back.len(), 1);
     let content = String::from_utf8_lossy(&read_back[0].content);
     assert!(!content.contains("---"), "Frontmatter should be stripped");
@@ -107,7 +123,7 @@ fn skills_description_before_model_hint() {
     let report = adapter.write_skills(&skills).unwrap();
     assert_eq!(report.written, 1);
 
-    let read_back = adapter.read_skills().unwrap();
+    let read_back = read_skills_on_disk(&adapter);
     let body = String::from_utf8_lossy(&read_back[0].content);
 
     assert!(
@@ -139,7 +155,7 @@ fn skills_description_strips_yaml_quotes() {
     let skills = vec![make_command("quoted", content)];
 
     adapter.write_skills(&skills).unwrap();
-    let read_back = adapter.read_skills().unwrap();
+    let read_back = read_skills_on_disk(&adapter);
     let body = String::from_utf8_lossy(&read_back[0].content);
     assert!(
         body.starts_with("A quoted description\n"),
@@ -157,7 +173,7 @@ fn skills_block_scalar_description() {
     let skills = vec![make_command("research", content)];
 
     adapter.write_skills(&skills).unwrap();
-    let read_back = adapter.read_skills().unwrap();
+    let read_back = read_skills_on_disk(&adapter);
     let body = String::from_utf8_lossy(&read_back[0].content);
     assert!(
         body.starts_with("Search GitHub for implementations. Use when the user wants code.\n"),
@@ -176,7 +192,7 @@ fn skills_single_quoted_description() {
     let skills = vec![make_command("single-q", content)];
 
     adapter.write_skills(&skills).unwrap();
-    let read_back = adapter.read_skills().unwrap();
+    let read_back = read_skills_on_disk(&adapter);
     let body = String::from_utf8_lossy(&read_back[0].content);
     assert!(
         body.starts_with("A single-quoted desc\n"),
@@ -195,7 +211,7 @@ fn skills_description_only_no_model_hint() {
     let skills = vec![make_command("plain", content)];
 
     adapter.write_skills(&skills).unwrap();
-    let read_back = adapter.read_skills().unwrap();
+    let read_back = read_skills_on_disk(&adapter);
     let body = String::from_utf8_lossy(&read_back[0].content);
     assert!(
         body.starts_with("Just a description\n"),
@@ -221,7 +237,7 @@ fn skills_model_hint_only_no_description() {
     let skills = vec![make_command("hinted", content)];
 
     adapter.write_skills(&skills).unwrap();
-    let read_back = adapter.read_skills().unwrap();
+    let read_back = read_skills_on_disk(&adapter);
     let body = String::from_utf8_lossy(&read_back[0].content);
     assert!(
         body.starts_with("<!-- model_hint: fast -->\n"),
@@ -239,7 +255,7 @@ fn skills_multiline_quoted_description() {
     let skills = vec![make_command("modular-monolith", content)];
 
     adapter.write_skills(&skills).unwrap();
-    let read_back = adapter.read_skills().unwrap();
+    let read_back = read_skills_on_disk(&adapter);
     let body = String::from_utf8_lossy(&read_back[0].content);
     assert!(
         body.starts_with("Single deployable with enforced module boundaries for team autonomy.\n"),
@@ -283,7 +299,7 @@ fn agents_translate_frontmatter_on_write() {
     let report = adapter.write_agents(&agents).unwrap();
     assert_eq!(report.written, 1);
 
-    let read_back = adapter.read_agents().unwrap();
+    let read_back = read_agents_on_disk(&adapter);
     assert_eq!(read_back.len(), 1);
     let content = String::from_utf8_lossy(&read_back[0].content);
     assert!(
@@ -297,6 +313,27 @@ fn agents_translate_frontmatter_on_write() {
     );
 }
 
+#[test]
+fn agents_round_trip_restores_dropped_fields() {
+    let tmp = TempDir::new().unwrap();
+    let adapter = CursorAdapter::with_root(tmp.path().to_path_buf());
+
+    let agent_content =
+        "---\nname: builder\ntools: [Read, Write]\nisolation: worktree\n---\n\nBuild things.\n";
+    adapter
+        .write_agents(&[make_command("builder", agent_content)])
+        .unwrap();
+
+    let sidecar = crate::sidecar::SidecarManifest::load(tmp.path());
+    let entry = sidecar
+        .get(crate::conflict::ArtifactType::Agent, "builder")
+        .expect("sidecar entry");
+    assert_eq!(entry.dropped, vec!["tools", "isolation"]);
+
+    let read_back = adapter.read_agents().unwrap();
+    assert_eq!(read_back[0].content, agent_content.as_bytes());
+}
+
 // --- Hooks ---
 
 #[test]
@@ -335,7 +372,8 @@ fn hooks_skip_unmappable_events() {
     assert_eq!(report.skipped.len(), 1);
     assert!(matches!(
         &report.skipped[0],
-        crate::report::SkipReason::AgentSpecificFeature { .. }
+        crate::report::SkipReason::UnsupportedHookEvent { event, target }
+            if event == "Notification" && target == "cursor"
     ));
 }
 
@@ -378,6 +416,39 @@ fn rules_with_globs_preserved() {
     assert!(content.contains("alwaysApply: false"));
 }
 
+#[test]
+fn skills_round_trip_restores_frontmatter() {
+    let tmp = TempDir::new().unwrap();
+    let adapter = CursorAdapter::with_root(tmp.path().to_path_buf());
+    let skill = make_skill_with_frontmatter("shared");
+
+    adapter.write_skills(std::slice::from_ref(&skill)).unwrap();
+    let on_disk = std::fs::read_to_string(tmp.path().join("skills/shared/SKILL.md")).unwrap();
+    assert!(
+        !on_disk.contains("tags:"),
+        "Cursor copy stays frontmatter-free"
+    );
+
+    let read_back = adapter.read_skills().unwrap();
+    assert_eq!(read_back[0].content, skill.content);
+    assert_eq!(read_back[0].hash, skill.hash);
+}
+
+#[test]
+fn skills_edited_in_cursor_are_not_rehydrated() {
+    let tmp = TempDir::new().unwrap();
+    let adapter = CursorAdapter::with_root(tmp.path().to_path_buf());
+    adapter
+        .write_skills(&[make_skill_with_frontmatter("shared")])
+        .unwrap();
+
+    let path = tmp.path().join("skills/shared/SKILL.md");
+    std::fs::write(&path, "Edited in Cursor\n").unwrap();
+
+    let read_back = adapter.read_skills().unwrap();
+    assert_eq!(read_back[0].content, b"Edited in Cursor\n");
+}
+
 // --- Skills edge cases ---
 
 #[test]
@@ -392,7 +463,7 @@ fn skills_frontmatter_only_content_preserves_description() {
     let report = adapter.write_skills(&skills).unwrap();
     assert_eq!(report.written, 1);
 
-    let read_back = adapter.read_skills().unwrap();
+    let read_back = read_skills_on_disk(&adapter);
     assert_eq!(read_back.len(), 1);
     let body = String::from_utf8_lossy(&read_back[0].content);
     assert!(!body.contains("---"), "Frontmatter should be stripped");
@@ -1024,7 +1095,7 @@ fn skills_model_hint_injected_as_comment() {
     let skills = vec![make_command("smart-skill", content)];
     adapter.write_skills(&skills).unwrap();
 
-    let read_back = adapter.read_skills().unwrap();
+    let read_back = read_skills_on_disk(&adapter);
     let body = String::from_utf8_lossy(&read_back[0].content);
     assert!(
         body.contains("<!-- model_hint: opus -->"),
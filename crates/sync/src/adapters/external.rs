@@ -96,6 +96,13 @@ impl ExternalAdapterConfig {
         expand_tilde(&self.config_root)
     }
 
+    /// Resolved skills directory, if the adapter declares one.
+    pub fn skills_path(&self) -> Option<PathBuf> {
+        self.skills_dir
+            .as_ref()
+            .map(|d| self.resolved_root().join(d))
+    }
+
     /// Validates the configuration and returns diagnostics.
     pub fn validate(&self) -> AdapterDiagnostic {
         let mut diag = AdapterDiagnostic {
@@ -132,10 +139,8 @@ impl ExternalAdapterConfig {
             if let Some(ref commands_dir) = self.commands_dir {
                 let commands_path = root.join(commands_dir);
                 if commands_path.exists() {
-                    diag.info.push(format!(
-                        "commands_dir exists: {}",
-                        commands_path.display()
-                    ));
+                    diag.info
+                        .push(format!("commands_dir exists: {}", commands_path.display()));
                 } else {
                     diag.issues.push(format!(
                         "commands_dir does not exist: {}",
@@ -163,10 +168,8 @@ impl ExternalAdapterConfig {
                     diag.info
                         .push(format!("mcp_config exists: {}", mcp_path.display()));
                 } else {
-                    diag.issues.push(format!(
-                        "mcp_config does not exist: {}",
-                        mcp_path.display()
-                    ));
+                    diag.issues
+                        .push(format!("mcp_config does not exist: {}", mcp_path.display()));
                 }
             }
         }
@@ -198,10 +201,7 @@ impl ExternalAdapter {
     }
 
     fn commands_dir(&self) -> Option<PathBuf> {
-        self.config
-            .commands_dir
-            .as_ref()
-            .map(|d| self.root.join(d))
+        self.config.commands_dir.as_ref().map(|d| self.root.join(d))
     }
 
     fn rules_dir(&self) -> Option<PathBuf> {
@@ -209,10 +209,7 @@ impl ExternalAdapter {
     }
 
     fn mcp_config_path(&self) -> Option<PathBuf> {
-        self.config
-            .mcp_config
-            .as_ref()
-            .map(|f| self.root.join(f))
+        self.config.mcp_config.as_ref().map(|f| self.root.join(f))
     }
 }
 
@@ -238,6 +235,19 @@ impl AgentAdapter for ExternalAdapter {
         }
     }
 
+    fn managed_paths(&self) -> Vec<PathBuf> {
+        [
+            &self.config.skills_dir,
+            &self.config.commands_dir,
+            &self.config.rules_dir,
+            &self.config.mcp_config,
+        ]
+        .into_iter()
+        .flatten()
+        .map(PathBuf::from)
+        .collect()
+    }
+
     fn read_commands(&self, _include_marketplace: bool) -> Result<Vec<Command>> {
         let Some(dir) = self.commands_dir() else {
             return Ok(Vec::new());
@@ -404,6 +414,7 @@ fn read_markdown_dir(dir: &Path) -> Result<Vec<Command>> {
             hash,
             modules: vec![],
             content_format: crate::common::ContentFormat::Markdown,
+            plugin_origin: None,
         });
     }
 
@@ -543,75 +554,28 @@ fn write_mcp_json(path: &Path, servers: &HashMap<String, McpServer>) -> Result<W
         serde_json::json!({})
     };
 
-    // Determine if existing uses mcpServers wrapper
-    let uses_wrapper = existing.get("mcpServers").is_some();
-
-    let servers_obj = if uses_wrapper {
+    // Keep a flat file flat; new, empty or unreadable files get an
+    // mcpServers wrapper
+    let flat = existing.get("mcpServers").is_none()
+        && existing.as_object().is_some_and(|obj| !obj.is_empty());
+    if !flat && !existing.get("mcpServers").is_some_and(|v| v.is_object()) {
+        if !existing.is_object() {
+            existing = serde_json::json!({});
+        }
+        existing["mcpServers"] = serde_json::json!({});
+    }
+    let obj = if flat {
+        existing.as_object_mut()
+    } else {
         existing
             .get_mut("mcpServers")
             .and_then(|v| v.as_object_mut())
-    } else {
-        existing.as_object_mut()
-    };
+    }
+    .expect("MCP servers object was ensured above");
 
-    if let Some(obj) = servers_obj {
-        for (name, server) in servers {
-            let mut entry = serde_json::Map::new();
-            entry.insert(
-                "command".to_string(),
-                serde_json::Value::String(server.command.clone()),
-            );
-            if !server.args.is_empty() {
-                entry.insert(
-                    "args".to_string(),
-                    serde_json::Value::Array(
-                        server
-                            .args
-                            .iter()
-                            .map(|a| serde_json::Value::String(a.clone()))
-                            .collect(),
-                    ),
-                );
-            }
-            if !server.env.is_empty() {
-                let env_obj: serde_json::Map<String, serde_json::Value> = server
-                    .env
-                    .iter()
-                    .map(|(k, v)| (k.clone(), serde_json::Value::String(v.clone())))
-                    .collect();
-                entry.insert("env".to_string(), serde_json::Value::Object(env_obj));
-            }
-            if let Some(ref url) = server.url {
-                entry.insert("url".to_string(), serde_json::Value::String(url.clone()));
-            }
-            obj.insert(name.clone(), serde_json::Value::Object(entry));
-            report.written += 1;
-        }
-    } else {
-        // Create fresh with mcpServers wrapper
-        let mut mcp_obj = serde_json::Map::new();
-        for (name, server) in servers {
-            let mut entry = serde_json::Map::new();
-            entry.insert(
-                "command".to_string(),
-                serde_json::Value::String(server.command.clone()),
-            );
-            if !server.args.is_empty() {
-                entry.insert(
-                    "args".to_string(),
-                    serde_json::Value::Array(
-                        server
-                            .args
-                            .iter()
-                            .map(|a| serde_json::Value::String(a.clone()))
-                            .collect(),
-                    ),
-                );
-            }
-            mcp_obj.insert(name.clone(), serde_json::Value::Object(entry));
-            report.written += 1;
-        }
-        existing = serde_json::json!({ "mcpServers": mcp_obj });
+    for (name, server) in servers {
+        obj.insert(name.clone(), mcp_entry(server));
+        report.written += 1;
     }
 
     let json_str = serde_json::to_string_pretty(&existing)?;
@@ -620,18 +584,50 @@ fn write_mcp_json(path: &Path, servers: &HashMap<String, McpServer>) -> Result<W
     Ok(report)
 }
 
+/// Serializes one MCP server as an `mcpServers` entry.
+fn mcp_entry(server: &McpServer) -> serde_json::Value {
+    let mut entry = serde_json::Map::new();
+    if !server.command.is_empty() || server.url.is_none() {
+        entry.insert(
+            "command".to_string(),
+            serde_json::Value::String(server.command.clone()),
+        );
+    }
+    if !server.args.is_empty() {
+        entry.insert(
+            "args".to_string(),
+            serde_json::Value::Array(
+                server
+                    .args
+                    .iter()
+                    .map(|a| serde_json::Value::String(a.clone()))
+                    .collect(),
+            ),
+        );
+    }
+    if !server.env.is_empty() {
+        let env_obj: serde_json::Map<String, serde_json::Value> = server
+            .env
+            .iter()
+            .map(|(k, v)| (k.clone(), serde_json::Value::String(v.clone())))
+            .collect();
+        entry.insert("env".to_string(), serde_json::Value::Object(env_obj));
+    }
+    if let Some(ref url) = server.url {
+        entry.insert("url".to_string(), serde_json::Value::String(url.clone()));
+    }
+    serde_json::Value::Object(entry)
+}
+
 /// Creates a report indicating an unsupported field.
 fn unsupported_report(field: &str) -> WriteReport {
     let mut report = WriteReport::default();
-    report
-        .skipped
-        .push(SkipReason::AgentSpecificFeature {
-            item: field.to_string(),
-            feature: "Not configured for this external adapter".to_string(),
-            suggestion: format!(
-                "Add the corresponding directory setting to adapters.toml for this field"
-            ),
-        });
+    report.skipped.push(SkipReason::AgentSpecificFeature {
+        item: field.to_string(),
+        feature: "Not configured for this external adapter".to_string(),
+        suggestion: "Add the corresponding directory setting to adapters.toml for this field"
+            .to_string(),
+    });
     report
 }
 
@@ -697,8 +693,7 @@ pub fn load_external_configs() -> Result<Vec<ExternalAdapterConfig>> {
         return Ok(Vec::new());
     }
 
-    let raw =
-        fs::read_to_string(&path).with_context(|| format!("reading {}", path.display()))?;
+    let raw = fs::read_to_string(&path).with_context(|| format!("reading {}", path.display()))?;
     let file: AdaptersFile =
         toml::from_str(&raw).with_context(|| format!("parsing {}", path.display()))?;
 
@@ -711,6 +706,17 @@ pub fn load_external_adapters() -> Result<Vec<ExternalAdapter>> {
     Ok(configs.into_iter().map(ExternalAdapter::new).collect())
 }
 
+/// Finds the external adapter configuration registered under `name`.
+///
+/// Matching is case-insensitive. Returns `Ok(None)` when no adapter with that
+/// name is configured.
+pub fn find_external_config(name: &str) -> Result<Option<ExternalAdapterConfig>> {
+    let wanted = name.to_lowercase();
+    Ok(load_external_configs()?
+        .into_iter()
+        .find(|c| c.name.to_lowercase() == wanted))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -926,6 +932,68 @@ mod tests {
         assert!(content.get("mcpServers").is_some());
     }
 
+    #[test]
+    fn external_adapter_writes_env_and_url_to_new_mcp_file() {
+        let dir = tempdir().unwrap();
+        let adapter = ExternalAdapter::new(make_config(dir.path()));
+
+        let mut servers = HashMap::new();
+        servers.insert(
+            "local".to_string(),
+            McpServer {
+                name: "local".to_string(),
+                transport: McpTransport::Stdio,
+                command: "npx".to_string(),
+                args: vec!["-y".to_string(), "server".to_string()],
+                env: HashMap::from([("API_KEY".to_string(), "${API_KEY}".to_string())]),
+                url: None,
+                headers: None,
+                enabled: true,
+                allowed_tools: vec![],
+                disabled_tools: vec![],
+            },
+        );
+        servers.insert(
+            "remote".to_string(),
+            McpServer {
+                name: "remote".to_string(),
+                transport: McpTransport::Http,
+                command: String::new(),
+                args: vec![],
+                env: HashMap::new(),
+                url: Some("https://mcp.example.com/sse".to_string()),
+                headers: None,
+                enabled: true,
+                allowed_tools: vec![],
+                disabled_tools: vec![],
+            },
+        );
+
+        let report = adapter.write_mcp_servers(&servers).unwrap();
+        assert_eq!(report.written, 2);
+
+        let content: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(dir.path().join("mcp.json")).unwrap())
+                .unwrap();
+        assert_eq!(
+            content,
+            serde_json::json!({
+                "mcpServers": {
+                    "local": {
+                        "command": "npx",
+                        "args": ["-y", "server"],
+                        "env": { "API_KEY": "${API_KEY}" },
+                    },
+                    "remote": { "url": "https://mcp.example.com/sse" },
+                }
+            })
+        );
+
+        let read = adapter.read_mcp_servers().unwrap();
+        assert_eq!(read["local"].env["API_KEY"], "${API_KEY}");
+        assert_eq!(read["remote"].transport, McpTransport::Http);
+    }
+
     #[test]
     fn external_adapter_write_skips_unchanged() {
         let dir = tempdir().unwrap();
@@ -962,9 +1030,7 @@ mod tests {
         assert_eq!(report.skipped.len(), 1);
 
         // Preferences are never supported
-        let report = adapter
-            .write_preferences(&Preferences::default())
-            .unwrap();
+        let report = adapter.write_preferences(&Preferences::default()).unwrap();
         assert_eq!(report.skipped.len(), 1);
     }
 
@@ -1065,9 +1131,9 @@ commands_dir = "prompts"
         // Set env to a path that doesn't exist
         let dir = tempdir().unwrap();
         let path = dir.path().join("nonexistent.toml");
-        std::env::set_var("SKRILLS_ADAPTERS_CONFIG", path.to_str().unwrap());
+        let _lock = skrills_test_utils::env_guard();
+        let _env = skrills_test_utils::set_env_var("SKRILLS_ADAPTERS_CONFIG", path.to_str());
         let configs = load_external_configs().unwrap();
-        std::env::remove_var("SKRILLS_ADAPTERS_CONFIG");
         assert!(configs.is_empty());
     }
 
@@ -1086,14 +1152,41 @@ skills_dir = "skills"
         )
         .unwrap();
 
-        std::env::set_var("SKRILLS_ADAPTERS_CONFIG", config_path.to_str().unwrap());
+        let _lock = skrills_test_utils::env_guard();
+        let _env = skrills_test_utils::set_env_var("SKRILLS_ADAPTERS_CONFIG", config_path.to_str());
         let configs = load_external_configs().unwrap();
-        std::env::remove_var("SKRILLS_ADAPTERS_CONFIG");
 
         assert_eq!(configs.len(), 1);
         assert_eq!(configs[0].name, "test-cli");
     }
 
+    #[test]
+    fn find_external_config_matches_name_case_insensitively() {
+        let dir = tempdir().unwrap();
+        let config_path = dir.path().join("adapters.toml");
+        fs::write(
+            &config_path,
+            r#"
+[adapter.windsurf]
+name = "windsurf"
+config_root = "/tmp/windsurf"
+skills_dir = "skills"
+"#,
+        )
+        .unwrap();
+
+        let _lock = skrills_test_utils::env_guard();
+        let _env = skrills_test_utils::set_env_var("SKRILLS_ADAPTERS_CONFIG", config_path.to_str());
+
+        let found = find_external_config("WindSurf").unwrap().unwrap();
+        assert_eq!(found.name, "windsurf");
+        assert_eq!(
+            found.skills_path(),
+            Some(PathBuf::from("/tmp/windsurf/skills"))
+        );
+        assert!(find_external_config("aider").unwrap().is_none());
+    }
+
     #[test]
     fn hidden_files_skipped_in_read() {
         let dir = tempdir().unwrap();
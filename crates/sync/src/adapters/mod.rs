@@ -4,13 +4,16 @@ mod claude;
 mod codex;
 mod copilot;
 mod cursor;
+pub mod external;
 #[cfg(test)]
 mod tests_common;
 pub mod traits;
 pub(crate) mod utils;
 
+pub(crate) use claude::version_tuple;
 pub use claude::ClaudeAdapter;
 pub use codex::CodexAdapter;
 pub use copilot::CopilotAdapter;
 pub use cursor::CursorAdapter;
+pub use external::{ExternalAdapter, ExternalAdapterConfig};
 pub use traits::{AgentAdapter, FieldSupport};
@@ -2,6 +2,7 @@
 
 use crate::common::{Command, CommonConfig, McpServer, PluginAsset, Preferences};
 use crate::report::WriteReport;
+use crate::secrets::EnvSyntax;
 use crate::Result;
 use std::collections::HashMap;
 use std::path::PathBuf;
@@ -34,6 +35,39 @@ pub trait AgentAdapter: Send + Sync {
     /// What this adapter supports
     fn supported_fields(&self) -> FieldSupport;
 
+    /// Repository the adapter is scoped to, when it reads and writes a
+    /// project's `.claude/`, `.cursor/`, `.github/` or `AGENTS.md` instead
+    /// of the home-directory configuration.
+    fn project_root(&self) -> Option<PathBuf> {
+        None
+    }
+
+    /// Directory [`managed_paths`](Self::managed_paths) are relative to: the
+    /// project root for project-scoped adapters, otherwise
+    /// [`config_root`](Self::config_root).
+    fn managed_root(&self) -> PathBuf {
+        self.project_root().unwrap_or_else(|| self.config_root())
+    }
+
+    /// Files and directories, relative to [`managed_root`](Self::managed_root),
+    /// that the write operations may create or modify.
+    ///
+    /// The orchestrator snapshots everything under these paths before a sync
+    /// so the run can be undone. The default is empty, which disables
+    /// snapshots for the adapter.
+    fn managed_paths(&self) -> Vec<PathBuf> {
+        Vec::new()
+    }
+
+    /// Syntax the MCP config uses to reference environment variables, if any.
+    ///
+    /// Secret values in MCP server `env` and `headers` are written as
+    /// references in this syntax instead of plaintext. The default is `None`:
+    /// the target only takes literal values.
+    fn env_syntax(&self) -> Option<EnvSyntax> {
+        None
+    }
+
     // --- Read operations ---
 
     /// Read slash commands from native format
@@ -125,6 +159,18 @@ impl AgentAdapter for Box<dyn AgentAdapter> {
     fn supported_fields(&self) -> FieldSupport {
         (**self).supported_fields()
     }
+    fn project_root(&self) -> Option<PathBuf> {
+        (**self).project_root()
+    }
+    fn managed_root(&self) -> PathBuf {
+        (**self).managed_root()
+    }
+    fn managed_paths(&self) -> Vec<PathBuf> {
+        (**self).managed_paths()
+    }
+    fn env_syntax(&self) -> Option<EnvSyntax> {
+        (**self).env_syntax()
+    }
     fn read_commands(&self, include_marketplace: bool) -> Result<Vec<Command>> {
         (**self).read_commands(include_marketplace)
     }
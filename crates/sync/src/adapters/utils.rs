@@ -1,11 +1,15 @@
 //! Shared utility functions for agent adapters.
 
-use crate::common::ModuleFile;
+use crate::common::{Command, ContentFormat, ModuleFile};
 use crate::error::SyncError;
+use crate::report::{SkipReason, WriteReport};
+use crate::sections::merge_managed_bytes;
 use crate::Result;
 use sha2::{Digest, Sha256};
 use skrills_snapshot::KillSwitch;
+use std::fs;
 use std::path::Path;
+use std::time::SystemTime;
 use tracing::{debug, warn};
 use walkdir::WalkDir;
 
@@ -171,6 +175,118 @@ pub fn sanitize_name_segments(name: &str) -> String {
         .join("/")
 }
 
+/// Whether `name` is a tool's repository-wide instructions document
+/// (`CLAUDE.md`, `AGENTS.md`, `copilot-instructions.md`) rather than a
+/// scoped instruction or rule.
+pub fn is_root_instructions(name: &str) -> bool {
+    let name = name.to_lowercase();
+    let stem = name
+        .strip_suffix(".md")
+        .or_else(|| name.strip_suffix("-md"))
+        .unwrap_or(&name);
+    matches!(stem, "claude" | "agents" | "copilot-instructions")
+        || stem.contains("claude-instruction")
+}
+
+/// Reads a single instructions file as one instruction named after its stem
+/// (`CLAUDE.md` → `CLAUDE`). Returns nothing when the file is missing.
+pub fn read_instructions_file(path: &Path) -> Result<Vec<Command>> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let content = fs::read(path)?;
+    let modified = fs::metadata(path)
+        .and_then(|m| m.modified())
+        .unwrap_or(SystemTime::UNIX_EPOCH);
+    let hash = hash_content(&content);
+    let name = path
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("instructions")
+        .to_string();
+
+    Ok(vec![Command {
+        name,
+        content,
+        source_path: path.to_path_buf(),
+        modified,
+        hash,
+        modules: Vec::new(),
+        content_format: ContentFormat::default(),
+        plugin_origin: None,
+    }])
+}
+
+/// Writes `instructions` to a tool that only reads one instructions file.
+///
+/// A single document is written as-is; several are merged with
+/// `<!-- Source: name -->` headers so a single read still reflects all
+/// sources. The result only replaces the file's managed sections (see
+/// [`crate::sections`]); hand-written sections are kept.
+pub fn write_instructions_file(path: &Path, instructions: &[Command]) -> Result<WriteReport> {
+    let mut report = WriteReport::default();
+    if instructions.is_empty() {
+        return Ok(report);
+    }
+
+    let merged_content: Vec<u8> = if instructions.len() == 1 {
+        instructions[0].content.clone()
+    } else {
+        let mut merged = Vec::new();
+        for (i, instruction) in instructions.iter().enumerate() {
+            if i > 0 {
+                merged.extend_from_slice(b"\n\n---\n\n");
+            }
+            merged
+                .extend_from_slice(format!("<!-- Source: {} -->\n\n", instruction.name).as_bytes());
+            merged.extend_from_slice(&instruction.content);
+        }
+        merged
+    };
+
+    let file_name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let existing = if path.exists() {
+        Some(fs::read(path)?)
+    } else {
+        None
+    };
+    let (content, shadowed) = merge_managed_bytes(existing.as_deref(), &merged_content);
+    report
+        .warnings
+        .extend(shadowed_warnings(&file_name, &shadowed));
+    if existing.is_some_and(|e| hash_content(&e) == hash_content(&content)) {
+        report
+            .skipped
+            .push(SkipReason::Unchanged { item: file_name });
+        return Ok(report);
+    }
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, &content)?;
+    report.written += 1;
+
+    Ok(report)
+}
+
+/// Warnings for source sections a target's hand-written sections shadow.
+pub fn shadowed_warnings(file_name: &str, headings: &[String]) -> Vec<String> {
+    headings
+        .iter()
+        .map(|heading| {
+            format!(
+                "Section '{}' in {} is hand-written and was not updated; delete it to let skrills manage it",
+                heading, file_name
+            )
+        })
+        .collect()
+}
+
 /// Collects companion files from a skill directory (files other than SKILL.md).
 pub fn collect_module_files(skill_dir: &Path) -> Vec<ModuleFile> {
     let mut modules = Vec::new();
@@ -324,6 +440,71 @@ mod tests {
         assert_eq!(body, content);
     }
 
+    #[test]
+    fn root_instructions_names_are_recognized() {
+        for name in [
+            "CLAUDE",
+            "CLAUDE.md",
+            "claude-md",
+            "AGENTS",
+            "copilot-instructions",
+        ] {
+            assert!(is_root_instructions(name), "{name}");
+        }
+        assert!(!is_root_instructions("testing"));
+        assert!(!is_root_instructions("agents-guide"));
+    }
+
+    #[test]
+    fn instructions_file_roundtrip_merges_multiple_sources() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("AGENTS.md");
+        let docs = [
+            test_helpers::make_command("CLAUDE", "Be brief."),
+            test_helpers::make_command("testing", "Run tests."),
+        ];
+
+        let report = write_instructions_file(&path, &docs).unwrap();
+        assert_eq!(report.written, 1);
+        let written = fs::read_to_string(&path).unwrap();
+        assert!(written.contains("<!-- Source: CLAUDE -->\n\nBe brief."));
+        assert!(written.contains("<!-- Source: testing -->\n\nRun tests."));
+
+        let read = read_instructions_file(&path).unwrap();
+        assert_eq!(read.len(), 1);
+        assert_eq!(read[0].name, "AGENTS");
+
+        let again = write_instructions_file(&path, &docs).unwrap();
+        assert_eq!(again.written, 0);
+        assert_eq!(again.skipped.len(), 1);
+    }
+
+    #[test]
+    fn instructions_file_keeps_hand_written_sections() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("AGENTS.md");
+        fs::write(&path, "## Sandbox\nAlways on.\n\n## Style\nMine.\n").unwrap();
+        let claude = "## Style\nBe brief.\n\n## Testing\nRun tests.\n";
+
+        let report =
+            write_instructions_file(&path, &[test_helpers::make_command("CLAUDE", claude)])
+                .unwrap();
+
+        assert_eq!(report.written, 1);
+        assert_eq!(report.warnings.len(), 1);
+        assert!(report.warnings[0].contains("'Style' in AGENTS.md"));
+        let written = fs::read_to_string(&path).unwrap();
+        assert!(written.starts_with("## Sandbox\nAlways on.\n\n## Style\nMine.\n"));
+        assert!(written.contains("<!-- skrills:begin testing -->\n## Testing\nRun tests.\n"));
+
+        let updated = claude.replace("Run tests.", "Run all tests.");
+        write_instructions_file(&path, &[test_helpers::make_command("CLAUDE", &updated)]).unwrap();
+        let written = fs::read_to_string(&path).unwrap();
+        assert!(written.contains("Run all tests."));
+        assert!(written.contains("Mine."));
+        assert_eq!(written.matches("## Testing").count(), 1);
+    }
+
     #[test]
     fn test_is_hidden_component() {
         assert!(is_hidden_component(".git"));
@@ -75,6 +75,7 @@ impl ArtifactType {
     }
 
     /// Parse from a stored string.
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(s: &str) -> Option<Self> {
         match s {
             "command" => Some(Self::Command),
@@ -108,11 +109,7 @@ pub struct Conflict {
 
 impl fmt::Display for Conflict {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "{} '{}' ({})",
-            self.artifact_type, self.name, self.kind
-        )
+        write!(f, "{} '{}' ({})", self.artifact_type, self.name, self.kind)
     }
 }
 
@@ -126,6 +123,8 @@ pub enum Resolution {
     KeepTarget,
     /// Skip this artifact entirely (no changes).
     Skip,
+    /// Three-way merge both versions against the last-synced content.
+    Merge,
 }
 
 impl fmt::Display for Resolution {
@@ -134,6 +133,7 @@ impl fmt::Display for Resolution {
             Self::KeepSource => write!(f, "keep source"),
             Self::KeepTarget => write!(f, "keep target"),
             Self::Skip => write!(f, "skip"),
+            Self::Merge => write!(f, "merge"),
         }
     }
 }
@@ -145,6 +145,7 @@ impl Resolution {
             Self::KeepSource => "keep_source",
             Self::KeepTarget => "keep_target",
             Self::Skip => "skip",
+            Self::Merge => "merge",
         }
     }
 }
@@ -271,6 +272,72 @@ where
     }
 }
 
+/// Classifies every source item that differs from its target counterpart
+/// against the two-sided baseline recorded by [`crate::state::SyncStateStore`].
+///
+/// Unlike [`detect_conflicts`], this returns all three [`ConflictKind`]s so
+/// the caller can skip `TargetChanged` items instead of overwriting them.
+/// Items with no recorded baseline are classified as `SourceChanged`: the
+/// first sync of a pair keeps the source-wins behavior and records the
+/// baseline for subsequent runs.
+///
+/// When the baseline has no target hash (the target stores the item under a
+/// different name or format), target-side edits cannot be observed and only
+/// source changes are reported.
+pub fn classify_against_baseline<F>(
+    source_items: &[Command],
+    target_items: &[Command],
+    artifact_type: ArtifactType,
+    get_baseline: F,
+) -> Vec<Conflict>
+where
+    F: Fn(&str) -> Option<crate::state::Baseline>,
+{
+    let target_map: std::collections::HashMap<&str, &Command> =
+        target_items.iter().map(|c| (c.name.as_str(), c)).collect();
+
+    let mut changes = Vec::new();
+
+    for source in source_items {
+        let Some(target) = target_map.get(source.name.as_str()) else {
+            continue;
+        };
+        if source.hash == target.hash {
+            continue;
+        }
+
+        let kind = match get_baseline(&source.name) {
+            Some(baseline) => {
+                let source_changed = source.hash != baseline.source_hash;
+                let target_changed = baseline
+                    .target_hash
+                    .as_ref()
+                    .is_some_and(|h| *h != target.hash);
+                match (source_changed, target_changed) {
+                    (true, true) => ConflictKind::BothChanged,
+                    (false, true) => ConflictKind::TargetChanged,
+                    // Neither side moved: the difference is the target
+                    // adapter's format conversion, re-syncing is harmless.
+                    (true, false) | (false, false) => ConflictKind::SourceChanged,
+                }
+            }
+            None => ConflictKind::SourceChanged,
+        };
+
+        changes.push(Conflict {
+            artifact_type,
+            name: source.name.clone(),
+            source_content: String::from_utf8_lossy(&source.content).into_owned(),
+            target_content: String::from_utf8_lossy(&target.content).into_owned(),
+            source_hash: source.hash.clone(),
+            target_hash: target.hash.clone(),
+            kind,
+        });
+    }
+
+    changes
+}
+
 // Diff formatting
 
 /// Formats a unified diff between two strings.
@@ -364,10 +431,11 @@ fn truncate_or_pad(s: &str, width: usize) -> String {
 // Interactive resolution
 
 /// Strategy for how to handle conflicts during sync.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum ConflictStrategy {
     /// Prompt the user interactively for each conflict.
+    #[default]
     Prompt,
     /// Automatically keep the source version for all conflicts.
     ForceSource,
@@ -375,12 +443,9 @@ pub enum ConflictStrategy {
     ForceTarget,
     /// Skip all conflicting artifacts.
     SkipAll,
-}
-
-impl Default for ConflictStrategy {
-    fn default() -> Self {
-        Self::Prompt
-    }
+    /// Three-way merge every conflict, writing conflict markers where the
+    /// two sides' edits overlap.
+    Merge,
 }
 
 /// Resolves a list of conflicts according to the given strategy.
@@ -405,6 +470,7 @@ where
                 ConflictStrategy::ForceSource => Resolution::KeepSource,
                 ConflictStrategy::ForceTarget => Resolution::KeepTarget,
                 ConflictStrategy::SkipAll => Resolution::Skip,
+                ConflictStrategy::Merge => Resolution::Merge,
             };
             ResolvedConflict {
                 conflict,
@@ -429,12 +495,16 @@ pub fn prompt_conflict_resolution(conflict: &Conflict) -> Resolution {
     );
     eprintln!("Both source and target have been modified since last sync.");
     eprintln!();
-    eprintln!("{}", format_side_by_side(&conflict.source_content, &conflict.target_content, width));
+    eprintln!(
+        "{}",
+        format_side_by_side(&conflict.source_content, &conflict.target_content, width)
+    );
 
     let options = vec![
         "Keep source (overwrite target)",
         "Keep target (skip this artifact)",
         "Skip (no changes)",
+        "Merge (three-way against the last sync)",
     ];
 
     match inquire::Select::new("How should this conflict be resolved?", options).prompt() {
@@ -443,6 +513,8 @@ pub fn prompt_conflict_resolution(conflict: &Conflict) -> Resolution {
                 Resolution::KeepSource
             } else if choice.starts_with("Keep target") {
                 Resolution::KeepTarget
+            } else if choice.starts_with("Merge") {
+                Resolution::Merge
             } else {
                 Resolution::Skip
             }
@@ -457,6 +529,7 @@ pub fn prompt_conflict_resolution(conflict: &Conflict) -> Resolution {
 /// Attempts to detect the terminal width.
 ///
 /// Returns `None` if detection fails.
+#[allow(unsafe_code)]
 fn terminal_width() -> Option<usize> {
     // Use a simple ioctl-based approach on Unix
     #[cfg(unix)]
@@ -531,6 +604,7 @@ mod tests {
         assert_eq!(Resolution::KeepSource.to_string(), "keep source");
         assert_eq!(Resolution::KeepTarget.to_string(), "keep target");
         assert_eq!(Resolution::Skip.to_string(), "skip");
+        assert_eq!(Resolution::Merge.to_string(), "merge");
     }
 
     #[test]
@@ -538,6 +612,7 @@ mod tests {
         assert_eq!(Resolution::KeepSource.as_str(), "keep_source");
         assert_eq!(Resolution::KeepTarget.as_str(), "keep_target");
         assert_eq!(Resolution::Skip.as_str(), "skip");
+        assert_eq!(Resolution::Merge.as_str(), "merge");
     }
 
     // ==========================================
@@ -579,8 +654,7 @@ mod tests {
         let target = vec![make_command("skill-a", "new target")];
 
         // Baseline hash is different from both
-        let baseline_hash =
-            crate::adapters::utils::hash_content(b"original content");
+        let baseline_hash = crate::adapters::utils::hash_content(b"original content");
 
         let conflicts = detect_conflicts(&source, &target, ArtifactType::Skill, |name| {
             if name == "skill-a" {
@@ -600,8 +674,7 @@ mod tests {
         let target = vec![make_command("skill-a", "original content")];
 
         // Baseline matches target, only source changed
-        let baseline_hash =
-            crate::adapters::utils::hash_content(b"original content");
+        let baseline_hash = crate::adapters::utils::hash_content(b"original content");
 
         let conflicts = detect_conflicts(&source, &target, ArtifactType::Skill, |name| {
             if name == "skill-a" {
@@ -621,8 +694,7 @@ mod tests {
         let target = vec![make_command("skill-a", "new target")];
 
         // Baseline matches source, only target changed
-        let baseline_hash =
-            crate::adapters::utils::hash_content(b"original content");
+        let baseline_hash = crate::adapters::utils::hash_content(b"original content");
 
         let conflicts = detect_conflicts(&source, &target, ArtifactType::Skill, |name| {
             if name == "skill-a" {
@@ -678,7 +750,9 @@ mod tests {
         assert!(diff.contains("--- a"));
         assert!(diff.contains("+++ b"));
         // No change hunks for identical content
-        assert!(!diff.contains('-'));
+        let body: String = diff.lines().skip(2).collect();
+        assert!(!body.contains('-'));
+        assert!(!diff.contains("@@"));
     }
 
     #[test]
@@ -807,6 +881,25 @@ mod tests {
         assert!(resolved.iter().all(|r| r.resolution == Resolution::Skip));
     }
 
+    #[test]
+    fn resolve_conflicts_merge() {
+        let conflicts = vec![Conflict {
+            artifact_type: ArtifactType::Skill,
+            name: "a".to_string(),
+            source_content: String::new(),
+            target_content: String::new(),
+            source_hash: "aaa".to_string(),
+            target_hash: "bbb".to_string(),
+            kind: ConflictKind::BothChanged,
+        }];
+
+        let resolved = resolve_conflicts(conflicts, ConflictStrategy::Merge, |_| {
+            panic!("prompt_fn should not be called for Merge")
+        });
+
+        assert_eq!(resolved[0].resolution, Resolution::Merge);
+    }
+
     #[test]
     fn resolve_conflicts_prompt_calls_fn() {
         let conflicts = vec![Conflict {
@@ -851,6 +944,7 @@ mod tests {
             Resolution::KeepSource,
             Resolution::KeepTarget,
             Resolution::Skip,
+            Resolution::Merge,
         ] {
             let json = serde_json::to_string(&res).unwrap();
             let restored: Resolution = serde_json::from_str(&json).unwrap();
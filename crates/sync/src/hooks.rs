@@ -0,0 +1,350 @@
+//! Canonical hook model shared by all adapters.
+//!
+//! A hook travels between adapters as a [`Command`] named after its
+//! *canonical* event (Claude's PascalCase names) whose content is a JSON array
+//! of [`HookHandler`]s. Each adapter translates event names through
+//! [`HOOK_EVENTS`] when writing its native format and back when reading it.
+//!
+//! ## Event Mapping
+//!
+//! | Canonical (Claude)  | Cursor               | Copilot               | Codex                  |
+//! |---------------------|----------------------|-----------------------|------------------------|
+//! | PreToolUse          | preToolUse           | preToolUse            | *(none)*               |
+//! | PostToolUse         | postToolUse          | postToolUse           | *(none)*               |
+//! | SessionStart        | sessionStart         | sessionStart          | *(none)*               |
+//! | SessionEnd          | sessionEnd           | sessionEnd            | *(none)*               |
+//! | Stop                | stop                 | *(none)*              | `notify` (turn end)    |
+//! | SubagentStop        | subagentStop         | *(none)*              | *(none)*               |
+//! | UserPromptSubmit    | beforeSubmitPrompt   | userPromptSubmitted   | *(none)*               |
+//! | PreCompact          | preCompact           | *(none)*              | *(none)*               |
+//! | Notification        | *(none)*             | *(none)*              | *(none)*               |
+//!
+//! Events a target cannot express are reported as
+//! [`SkipReason::UnsupportedHookEvent`]. Native events without a canonical
+//! name (e.g. Cursor's `afterFileEdit`) keep their native name when read.
+
+use crate::adapters::utils::hash_content;
+use crate::common::{Command, ContentFormat};
+use crate::report::SkipReason;
+use crate::Result;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+/// Hook dialects skrills translates between.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HookDialect {
+    Claude,
+    Cursor,
+    Copilot,
+    Codex,
+}
+
+impl HookDialect {
+    /// Name of the CLI, as used in skip reasons.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Claude => "claude",
+            Self::Cursor => "cursor",
+            Self::Copilot => "copilot",
+            Self::Codex => "codex",
+        }
+    }
+}
+
+/// One row of the event-mapping table.
+#[derive(Debug, Clone, Copy)]
+pub struct HookEvent {
+    /// Canonical (Claude) event name.
+    pub canonical: &'static str,
+    pub cursor: Option<&'static str>,
+    pub copilot: Option<&'static str>,
+    /// Codex only runs a `notify` program when an agent turn completes.
+    pub codex: Option<&'static str>,
+}
+
+impl HookEvent {
+    fn native(&self, dialect: HookDialect) -> Option<&'static str> {
+        match dialect {
+            HookDialect::Claude => Some(self.canonical),
+            HookDialect::Cursor => self.cursor,
+            HookDialect::Copilot => self.copilot,
+            HookDialect::Codex => self.codex,
+        }
+    }
+}
+
+/// Codex `notify` payload type, the only event Codex reports.
+pub const CODEX_TURN_COMPLETE: &str = "agent-turn-complete";
+
+/// Event-mapping table covering every CLI skrills syncs hooks to.
+pub static HOOK_EVENTS: &[HookEvent] = &[
+    HookEvent {
+        canonical: "PreToolUse",
+        cursor: Some("preToolUse"),
+        copilot: Some("preToolUse"),
+        codex: None,
+    },
+    HookEvent {
+        canonical: "PostToolUse",
+        cursor: Some("postToolUse"),
+        copilot: Some("postToolUse"),
+        codex: None,
+    },
+    HookEvent {
+        canonical: "SessionStart",
+        cursor: Some("sessionStart"),
+        copilot: Some("sessionStart"),
+        codex: None,
+    },
+    HookEvent {
+        canonical: "SessionEnd",
+        cursor: Some("sessionEnd"),
+        copilot: Some("sessionEnd"),
+        codex: None,
+    },
+    HookEvent {
+        canonical: "Stop",
+        cursor: Some("stop"),
+        copilot: None,
+        codex: Some(CODEX_TURN_COMPLETE),
+    },
+    HookEvent {
+        canonical: "SubagentStop",
+        cursor: Some("subagentStop"),
+        copilot: None,
+        codex: None,
+    },
+    HookEvent {
+        canonical: "UserPromptSubmit",
+        cursor: Some("beforeSubmitPrompt"),
+        copilot: Some("userPromptSubmitted"),
+        codex: None,
+    },
+    HookEvent {
+        canonical: "PreCompact",
+        cursor: Some("preCompact"),
+        copilot: None,
+        codex: None,
+    },
+    HookEvent {
+        canonical: "Notification",
+        cursor: None,
+        copilot: None,
+        codex: None,
+    },
+];
+
+/// Maps a canonical event to `dialect`'s native name.
+///
+/// A name that is already native to `dialect` is passed through, so hooks
+/// read from the same CLI (or its Cursor-only events) round-trip.
+pub fn native_event(dialect: HookDialect, event: &str) -> Option<&'static str> {
+    HOOK_EVENTS
+        .iter()
+        .find(|e| e.canonical == event)
+        .and_then(|e| e.native(dialect))
+        .or_else(|| {
+            HOOK_EVENTS
+                .iter()
+                .filter_map(|e| e.native(dialect))
+                .find(|native| *native == event)
+        })
+}
+
+/// Maps `dialect`'s native event name to its canonical name.
+pub fn canonical_event(dialect: HookDialect, native: &str) -> Option<&'static str> {
+    HOOK_EVENTS
+        .iter()
+        .find(|e| e.native(dialect) == Some(native))
+        .map(|e| e.canonical)
+}
+
+/// Skip reason for an event `dialect` has no equivalent for.
+pub fn unsupported_event(dialect: HookDialect, event: &str) -> SkipReason {
+    SkipReason::UnsupportedHookEvent {
+        event: event.to_string(),
+        target: dialect.as_str().to_string(),
+    }
+}
+
+/// A single command run for an event.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HookHandler {
+    pub command: String,
+    #[serde(default = "default_handler_type")]
+    pub r#type: String,
+    /// Timeout in seconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<u32>,
+    /// Tool-name pattern the handler is limited to (tool events only).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub matcher: Option<String>,
+    #[serde(
+        rename = "failClosed",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub fail_closed: Option<bool>,
+}
+
+fn default_handler_type() -> String {
+    "command".to_string()
+}
+
+impl HookHandler {
+    /// Creates a command handler with no timeout or matcher.
+    pub fn command(command: impl Into<String>) -> Self {
+        Self {
+            command: command.into(),
+            r#type: default_handler_type(),
+            timeout: None,
+            matcher: None,
+            fail_closed: None,
+        }
+    }
+
+    /// Whether the handler only applies to some tools.
+    pub fn is_scoped(&self) -> bool {
+        self.matcher
+            .as_deref()
+            .is_some_and(|m| !m.is_empty() && m != "*")
+    }
+}
+
+/// Claude's settings format: handlers grouped under a shared matcher.
+#[derive(Deserialize)]
+struct ClaudeMatcherGroup {
+    #[serde(default)]
+    matcher: Option<String>,
+    hooks: Vec<HookHandler>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum HandlerEntry {
+    Handler(HookHandler),
+    Group(ClaudeMatcherGroup),
+}
+
+/// Parses the handlers of a hook item.
+///
+/// Accepts the canonical flat array and Claude's `{matcher, hooks: [...]}`
+/// groups. Content that is neither yields the skip reason to report.
+pub fn parse_handlers(hook: &Command) -> std::result::Result<Vec<HookHandler>, SkipReason> {
+    let entries: Vec<HandlerEntry> = serde_json::from_slice(&hook.content).map_err(|e| {
+        tracing::warn!(event = %hook.name, error = %e, "Skipping hook with non-JSON content");
+        SkipReason::AgentSpecificFeature {
+            item: hook.name.clone(),
+            feature: "Hook content is not valid JSON".to_string(),
+            suggestion: "Hook content must be a JSON array of hook entries".to_string(),
+        }
+    })?;
+    Ok(entries
+        .into_iter()
+        .flat_map(|entry| match entry {
+            HandlerEntry::Handler(handler) => vec![handler],
+            HandlerEntry::Group(group) => group
+                .hooks
+                .into_iter()
+                .map(|mut handler| {
+                    handler.matcher = handler.matcher.or_else(|| group.matcher.clone());
+                    handler
+                })
+                .collect(),
+        })
+        .collect())
+}
+
+/// Builds the canonical hook item for `event` read from `source_path`.
+pub fn hook_command(
+    event: String,
+    handlers: &[HookHandler],
+    source_path: PathBuf,
+    modified: SystemTime,
+) -> Result<Command> {
+    let content = serde_json::to_string_pretty(handlers)?.into_bytes();
+    Ok(Command {
+        name: event,
+        hash: hash_content(&content),
+        content,
+        source_path,
+        modified,
+        modules: vec![],
+        content_format: ContentFormat::Json,
+        plugin_origin: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::utils::test_helpers::make_command;
+
+    #[test]
+    fn maps_events_per_dialect() {
+        assert_eq!(
+            native_event(HookDialect::Copilot, "UserPromptSubmit"),
+            Some("userPromptSubmitted")
+        );
+        assert_eq!(
+            native_event(HookDialect::Codex, "Stop"),
+            Some(CODEX_TURN_COMPLETE)
+        );
+        assert_eq!(native_event(HookDialect::Codex, "PreToolUse"), None);
+        assert_eq!(native_event(HookDialect::Cursor, "Notification"), None);
+        // Native names pass through.
+        assert_eq!(
+            native_event(HookDialect::Cursor, "beforeSubmitPrompt"),
+            Some("beforeSubmitPrompt")
+        );
+        assert_eq!(
+            canonical_event(HookDialect::Copilot, "userPromptSubmitted"),
+            Some("UserPromptSubmit")
+        );
+        assert_eq!(canonical_event(HookDialect::Copilot, "errorOccurred"), None);
+    }
+
+    #[test]
+    fn every_native_name_round_trips() {
+        for dialect in [
+            HookDialect::Claude,
+            HookDialect::Cursor,
+            HookDialect::Copilot,
+            HookDialect::Codex,
+        ] {
+            for event in HOOK_EVENTS {
+                if let Some(native) = native_event(dialect, event.canonical) {
+                    assert_eq!(canonical_event(dialect, native), Some(event.canonical));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn parses_flat_and_grouped_handlers() {
+        let flat = make_command(
+            "PreToolUse",
+            r#"[{"command": "./lint.sh", "timeout": 10, "matcher": "Bash"}]"#,
+        );
+        let handlers = parse_handlers(&flat).unwrap();
+        assert_eq!(handlers[0].command, "./lint.sh");
+        assert_eq!(handlers[0].r#type, "command");
+        assert!(handlers[0].is_scoped());
+
+        let grouped = make_command(
+            "PreToolUse",
+            r#"[{"matcher": "Edit|Write", "hooks": [{"type": "command", "command": "./guard.sh"}]},
+                {"hooks": [{"type": "command", "command": "./log.sh"}]}]"#,
+        );
+        let handlers = parse_handlers(&grouped).unwrap();
+        assert_eq!(handlers.len(), 2);
+        assert_eq!(handlers[0].matcher.as_deref(), Some("Edit|Write"));
+        assert!(!handlers[1].is_scoped());
+
+        assert!(matches!(
+            parse_handlers(&make_command("Stop", "# not json")),
+            Err(SkipReason::AgentSpecificFeature { .. })
+        ));
+    }
+}
@@ -0,0 +1,234 @@
+//! Mapping filesystem changes to the artifacts a sync has to revisit.
+//!
+//! Continuous sync (`skrills sync-watch`) watches a source adapter's
+//! [`managed_paths`](AgentAdapter::managed_paths) and, for each debounced
+//! batch of changed files, syncs only the artifact types those files belong
+//! to. [`ChangedArtifacts`] does the classification; [`watch_targets`] lists
+//! what to watch.
+
+use crate::adapters::AgentAdapter;
+use crate::orchestrator::SyncParams;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// Config files that hold MCP servers, preferences and (for Claude) hooks.
+const SETTINGS_FILES: &[&str] = &[
+    "settings.json",
+    "config.json",
+    "config.toml",
+    "mcp.json",
+    ".mcp.json",
+    "mcp-config.json",
+];
+
+/// Top-level instruction files.
+const INSTRUCTION_FILES: &[&str] = &["CLAUDE.md", "AGENTS.md", "copilot-instructions.md"];
+
+/// Artifact types touched by a batch of filesystem changes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ChangedArtifacts {
+    pub skills: bool,
+    pub commands: bool,
+    pub mcp_servers: bool,
+    pub preferences: bool,
+    pub agents: bool,
+    pub hooks: bool,
+    pub instructions: bool,
+}
+
+impl ChangedArtifacts {
+    /// Classifies the changed `paths` of `adapter`.
+    ///
+    /// Only paths at or below one of the adapter's
+    /// [`managed_paths`](AgentAdapter::managed_paths) count, so writes to a
+    /// target that shares a directory with the source (e.g. `AGENTS.md` next
+    /// to `CLAUDE.md` in project scope) do not trigger another sync. Files
+    /// skrills keeps for itself are ignored too.
+    pub fn from_paths<'a>(
+        adapter: &impl AgentAdapter,
+        paths: impl IntoIterator<Item = &'a Path>,
+    ) -> Self {
+        Self::classify(&adapter.managed_root(), &adapter.managed_paths(), paths)
+    }
+
+    fn classify<'a>(
+        root: &Path,
+        managed: &[PathBuf],
+        paths: impl IntoIterator<Item = &'a Path>,
+    ) -> Self {
+        let mut changed = Self::default();
+        for path in paths {
+            let Ok(rel) = path.strip_prefix(root) else {
+                continue;
+            };
+            if managed.iter().any(|m| rel.starts_with(m)) {
+                changed.add(rel);
+            }
+        }
+        changed
+    }
+
+    fn add(&mut self, rel: &Path) {
+        let file = rel
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        if file.starts_with(".skrills-") {
+            return;
+        }
+        for component in rel.components() {
+            match component.as_os_str().to_str() {
+                Some("skills") => self.skills = true,
+                Some("commands" | "prompts") => self.commands = true,
+                Some("agents") => self.agents = true,
+                Some("hooks") => self.hooks = true,
+                Some("rules" | "instructions") => self.instructions = true,
+                _ => {}
+            }
+        }
+        if SETTINGS_FILES.contains(&file.as_str()) {
+            self.mcp_servers = true;
+            self.preferences = true;
+            self.hooks = true;
+        }
+        if file == "hooks.json" {
+            self.hooks = true;
+        }
+        if INSTRUCTION_FILES.contains(&file.as_str()) || file.ends_with(".instructions.md") {
+            self.instructions = true;
+        }
+    }
+
+    /// Returns true when no artifact type changed.
+    pub fn is_empty(&self) -> bool {
+        self.names().is_empty()
+    }
+
+    /// Names of the changed artifact types, for logging.
+    pub fn names(&self) -> Vec<&'static str> {
+        [
+            (self.skills, "skills"),
+            (self.commands, "commands"),
+            (self.mcp_servers, "mcp_servers"),
+            (self.preferences, "preferences"),
+            (self.agents, "agents"),
+            (self.hooks, "hooks"),
+            (self.instructions, "instructions"),
+        ]
+        .into_iter()
+        .filter_map(|(changed, name)| changed.then_some(name))
+        .collect()
+    }
+
+    /// Limits `params` to the changed artifact types. Plugin assets are
+    /// never synced incrementally.
+    pub fn restrict(&self, params: &mut SyncParams) {
+        params.sync_skills = self.skills;
+        params.sync_commands = self.commands;
+        params.sync_mcp_servers = self.mcp_servers;
+        params.sync_preferences = self.preferences;
+        params.sync_agents = self.agents;
+        params.sync_hooks = self.hooks;
+        params.sync_instructions = self.instructions;
+        params.sync_plugin_assets = false;
+    }
+}
+
+/// Paths to watch for changes to `adapter`'s artifacts, each with whether it
+/// is watched recursively.
+///
+/// Managed directories are watched recursively. Managed files are covered by
+/// watching their parent directory non-recursively, so editors that save by
+/// replacing the file are still seen. Paths that do not exist yet are
+/// skipped.
+pub fn watch_targets(adapter: &impl AgentAdapter) -> Vec<(PathBuf, bool)> {
+    let root = adapter.managed_root();
+    let mut targets: BTreeMap<PathBuf, bool> = BTreeMap::new();
+    for rel in adapter.managed_paths() {
+        let abs = root.join(rel);
+        if abs.is_dir() {
+            targets.insert(abs, true);
+        } else if let Some(parent) = abs.parent().filter(|p| p.is_dir()) {
+            targets.entry(parent.to_path_buf()).or_insert(false);
+        }
+    }
+    targets.into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::ClaudeAdapter;
+    use std::fs;
+    use tempfile::tempdir;
+
+    fn classify(paths: &[&str]) -> ChangedArtifacts {
+        let root = Path::new("/home/me/.claude");
+        let managed: Vec<PathBuf> = [
+            "skills",
+            "commands",
+            "prompts",
+            "agents",
+            "rules",
+            "todos",
+            "settings.json",
+            "CLAUDE.md",
+            ".skrills-sidecar.json",
+        ]
+        .iter()
+        .map(PathBuf::from)
+        .collect();
+        let abs: Vec<PathBuf> = paths.iter().map(|p| root.join(p)).collect();
+        ChangedArtifacts::classify(root, &managed, abs.iter().map(PathBuf::as_path))
+    }
+
+    #[test]
+    fn classifies_changed_paths() {
+        assert_eq!(classify(&["skills/review/SKILL.md"]).names(), ["skills"]);
+        assert_eq!(classify(&["prompts/deploy.md"]).names(), ["commands"]);
+        assert_eq!(
+            classify(&["settings.json"]).names(),
+            ["mcp_servers", "preferences", "hooks"]
+        );
+        assert_eq!(
+            classify(&["CLAUDE.md", "rules/style.mdc", "agents/builder.md"]).names(),
+            ["agents", "instructions"]
+        );
+        assert!(classify(&[".skrills-sidecar.json", "todos/x.json"]).is_empty());
+    }
+
+    #[test]
+    fn unmanaged_paths_are_ignored() {
+        let dir = tempdir().unwrap();
+        let adapter = ClaudeAdapter::with_root(dir.path().to_path_buf());
+        let outside = dir.path().with_file_name("codex").join("skills/a/SKILL.md");
+        let unmanaged = dir.path().join("projects/x/skills/a/SKILL.md");
+        let managed = dir.path().join("skills/a/SKILL.md");
+
+        let changed =
+            ChangedArtifacts::from_paths(&adapter, [outside.as_path(), unmanaged.as_path()]);
+        assert!(changed.is_empty());
+        let changed = ChangedArtifacts::from_paths(&adapter, [managed.as_path()]);
+        assert_eq!(changed.names(), ["skills"]);
+    }
+
+    #[test]
+    fn restrict_limits_params() {
+        let mut params = SyncParams::default();
+        classify(&["commands/x.md"]).restrict(&mut params);
+        assert!(params.sync_commands);
+        assert!(!params.sync_skills && !params.sync_mcp_servers && !params.sync_plugin_assets);
+    }
+
+    #[test]
+    fn watch_targets_cover_existing_managed_paths() {
+        let dir = tempdir().unwrap();
+        fs::create_dir_all(dir.path().join("skills")).unwrap();
+        fs::write(dir.path().join("settings.json"), "{}").unwrap();
+
+        let targets = watch_targets(&ClaudeAdapter::with_root(dir.path().to_path_buf()));
+        assert!(targets.contains(&(dir.path().join("skills"), true)));
+        assert!(targets.contains(&(dir.path().to_path_buf(), false)));
+        assert!(!targets.iter().any(|(p, _)| p.ends_with("commands")));
+    }
+}
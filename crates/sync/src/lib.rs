@@ -131,26 +131,53 @@ pub type Result<T> = std::result::Result<T, Error>;
 
 pub mod adapters;
 pub mod common;
+pub mod conflict;
 pub mod error;
+pub mod hooks;
+pub mod incremental;
+pub mod merge;
 pub mod models;
 pub mod orchestrator;
+pub mod plan;
 pub mod platform_routing;
+pub mod plugins;
+pub mod policy;
+pub mod preview;
 pub mod report;
+pub mod secrets;
+pub mod sections;
+pub mod sidecar;
+pub mod snapshot;
+pub mod state;
 pub mod validation;
 
 pub use error::SyncError;
+pub use hooks::{HookDialect, HookHandler, HOOK_EVENTS};
+pub use incremental::{watch_targets, ChangedArtifacts};
+pub use merge::{merge_three_way, MergeResult, MergeStatus};
 pub use skrills_snapshot::KillSwitch;
 
 pub use adapters::{
-    AgentAdapter, ClaudeAdapter, CodexAdapter, CopilotAdapter, CursorAdapter, FieldSupport,
+    AgentAdapter, ClaudeAdapter, CodexAdapter, CopilotAdapter, CursorAdapter, ExternalAdapter,
+    ExternalAdapterConfig, FieldSupport,
 };
 pub use common::{Command, CommonConfig, ContentFormat, McpServer, PluginAsset, Preferences};
-pub use models::transform_model;
+pub use conflict::{ConflictKind, ConflictStrategy, Resolution};
+pub use models::{transform_model, ModelMap, UnmappedPolicy};
 pub use orchestrator::{
-    create_adapter, is_valid_platform, sync_between, SyncOrchestrator, SyncParams,
+    available_platforms, create_adapter, create_adapter_in, is_valid_platform, sync_between,
+    SyncOrchestrator, SyncParams, SyncScope, BUILTIN_PLATFORMS,
 };
+pub use plan::{AppliedPlan, SyncPlan};
 pub use platform_routing::default_target_for;
-pub use report::{SkipReason, SyncReport, WriteReport};
+pub use plugins::{PluginBundle, PluginInstall, PluginRegistry};
+pub use policy::{PolicyDecision, PolicySection, SyncPolicy};
+pub use preview::{ChangeSet, FileChange, PendingChange};
+pub use report::{MergeOutcome, SkipReason, SyncReport, UnmappedModel, WriteReport};
+pub use secrets::{EnvSyntax, McpSecret, SecretAction};
+pub use sidecar::SidecarManifest;
+pub use snapshot::SnapshotConfig;
+pub use state::SyncStateStore;
 pub use validation::{
     apply_autofix_to_skill, skill_is_codex_compatible, skill_is_copilot_compatible,
     validate_skill_for_sync, validate_skills_for_sync, SkillValidationResult,
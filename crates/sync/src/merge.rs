@@ -0,0 +1,297 @@
+//! Line-based three-way merge for artifacts changed on both sides.
+//!
+//! [`merge_three_way`] diffs the source and the target against the content
+//! recorded at the last sync (see [`crate::state::Baseline::content`]) and
+//! combines the two change sets. Hunks that touch or overlap the same base
+//! lines are kept side by side between conflict markers unless both sides
+//! made the identical change:
+//!
+//! ```text
+//! <<<<<<< source
+//! source lines
+//! =======
+//! target lines
+//! >>>>>>> target
+//! ```
+
+use serde::{Deserialize, Serialize};
+use similar::{capture_diff_slices, Algorithm, DiffTag};
+use std::fmt;
+use std::ops::Range;
+
+/// Opens a conflict hunk; the source lines follow.
+pub const SOURCE_MARKER: &str = "<<<<<<< source";
+/// Separates the source lines from the target lines.
+pub const SEPARATOR_MARKER: &str = "=======";
+/// Closes a conflict hunk.
+pub const TARGET_MARKER: &str = ">>>>>>> target";
+
+/// How a three-way merge turned out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MergeStatus {
+    /// The result equals one side: the other side's edits were already
+    /// contained in it.
+    Clean,
+    /// Non-overlapping edits from both sides were combined.
+    Merged,
+    /// Overlapping edits were written between conflict markers.
+    Conflicted,
+}
+
+impl fmt::Display for MergeStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Clean => write!(f, "clean"),
+            Self::Merged => write!(f, "merged"),
+            Self::Conflicted => write!(f, "conflicted"),
+        }
+    }
+}
+
+/// Output of [`merge_three_way`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MergeResult {
+    /// Merged text, including conflict markers when `conflicts > 0`.
+    pub content: String,
+    /// Overall outcome.
+    pub status: MergeStatus,
+    /// Number of conflict hunks written.
+    pub conflicts: usize,
+}
+
+/// A changed region: `base` lines replaced by `side` lines.
+#[derive(Debug, Clone)]
+struct Hunk {
+    base: Range<usize>,
+    side: Range<usize>,
+}
+
+/// Merges `source` and `target`, both derived from `base`.
+///
+/// ```
+/// use skrills_sync::merge::{merge_three_way, MergeStatus};
+///
+/// let base = "title\nintro\nbody\nfooter\n";
+/// let source = "title\nnew intro\nbody\nfooter\n";
+/// let target = "title\nintro\nbody\nnew footer\n";
+///
+/// let merged = merge_three_way(base, source, target);
+/// assert_eq!(merged.status, MergeStatus::Merged);
+/// assert_eq!(merged.content, "title\nnew intro\nbody\nnew footer\n");
+/// ```
+pub fn merge_three_way(base: &str, source: &str, target: &str) -> MergeResult {
+    let base_lines: Vec<&str> = base.split_inclusive('\n').collect();
+    let source_lines: Vec<&str> = source.split_inclusive('\n').collect();
+    let target_lines: Vec<&str> = target.split_inclusive('\n').collect();
+
+    let source_hunks = hunks(&base_lines, &source_lines);
+    let target_hunks = hunks(&base_lines, &target_lines);
+
+    let mut content = String::with_capacity(source.len().max(target.len()));
+    let mut conflicts = 0;
+    let mut pos = 0;
+    let (mut i, mut j) = (0, 0);
+
+    while i < source_hunks.len() || j < target_hunks.len() {
+        let source_first = j >= target_hunks.len()
+            || (i < source_hunks.len() && source_hunks[i].base.start <= target_hunks[j].base.start);
+        let (mut lo, mut hi) = if source_first {
+            (source_hunks[i].base.start, source_hunks[i].base.end)
+        } else {
+            (target_hunks[j].base.start, target_hunks[j].base.end)
+        };
+
+        // Grow the region until no hunk on either side touches it.
+        let (source_from, target_from) = (i, j);
+        loop {
+            if i < source_hunks.len() && touches(&source_hunks[i].base, lo, hi) {
+                lo = lo.min(source_hunks[i].base.start);
+                hi = hi.max(source_hunks[i].base.end);
+                i += 1;
+            } else if j < target_hunks.len() && touches(&target_hunks[j].base, lo, hi) {
+                lo = lo.min(target_hunks[j].base.start);
+                hi = hi.max(target_hunks[j].base.end);
+                j += 1;
+            } else {
+                break;
+            }
+        }
+
+        content.extend(base_lines[pos..lo].iter().copied());
+        let ours = &source_hunks[source_from..i];
+        let theirs = &target_hunks[target_from..j];
+        if theirs.is_empty() {
+            content.push_str(&side_text(&base_lines, &source_lines, ours, lo, hi));
+        } else if ours.is_empty() {
+            content.push_str(&side_text(&base_lines, &target_lines, theirs, lo, hi));
+        } else {
+            let ours = side_text(&base_lines, &source_lines, ours, lo, hi);
+            let theirs = side_text(&base_lines, &target_lines, theirs, lo, hi);
+            if ours == theirs {
+                content.push_str(&ours);
+            } else {
+                conflicts += 1;
+                push_marker(&mut content, SOURCE_MARKER);
+                push_block(&mut content, &ours);
+                push_marker(&mut content, SEPARATOR_MARKER);
+                push_block(&mut content, &theirs);
+                push_marker(&mut content, TARGET_MARKER);
+            }
+        }
+        pos = hi;
+    }
+    content.extend(base_lines[pos..].iter().copied());
+
+    let status = if conflicts > 0 {
+        MergeStatus::Conflicted
+    } else if content == source || content == target {
+        MergeStatus::Clean
+    } else {
+        MergeStatus::Merged
+    };
+
+    MergeResult {
+        content,
+        status,
+        conflicts,
+    }
+}
+
+/// Returns true if `text` still contains a conflict hunk written by
+/// [`merge_three_way`].
+pub fn has_conflict_markers(text: &str) -> bool {
+    let mut open = false;
+    for line in text.lines() {
+        if line == SOURCE_MARKER {
+            open = true;
+        } else if open && line == TARGET_MARKER {
+            return true;
+        }
+    }
+    false
+}
+
+/// Changed regions of `side` relative to `base`, adjacent edits coalesced.
+fn hunks(base: &[&str], side: &[&str]) -> Vec<Hunk> {
+    let mut hunks: Vec<Hunk> = Vec::new();
+    for op in capture_diff_slices(Algorithm::Myers, base, side) {
+        let (tag, base_range, side_range) = op.as_tag_tuple();
+        if tag == DiffTag::Equal {
+            continue;
+        }
+        match hunks.last_mut() {
+            Some(last)
+                if last.base.end == base_range.start && last.side.end == side_range.start =>
+            {
+                last.base.end = base_range.end;
+                last.side.end = side_range.end;
+            }
+            _ => hunks.push(Hunk {
+                base: base_range,
+                side: side_range,
+            }),
+        }
+    }
+    hunks
+}
+
+/// Whether `range` overlaps or is adjacent to the base region `lo..hi`.
+fn touches(range: &Range<usize>, lo: usize, hi: usize) -> bool {
+    range.start <= hi && lo <= range.end
+}
+
+/// One side's text for the base region `lo..hi`.
+fn side_text(base: &[&str], side: &[&str], hunks: &[Hunk], lo: usize, hi: usize) -> String {
+    let mut text = String::new();
+    let mut pos = lo;
+    for hunk in hunks {
+        text.extend(base[pos..hunk.base.start].iter().copied());
+        text.extend(side[hunk.side.clone()].iter().copied());
+        pos = hunk.base.end;
+    }
+    text.extend(base[pos..hi].iter().copied());
+    text
+}
+
+fn push_marker(out: &mut String, marker: &str) {
+    out.push_str(marker);
+    out.push('\n');
+}
+
+fn push_block(out: &mut String, block: &str) {
+    out.push_str(block);
+    if !block.is_empty() && !block.ends_with('\n') {
+        out.push('\n');
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BASE: &str = "one\ntwo\nthree\nfour\nfive\n";
+
+    #[test]
+    fn unchanged_sides_merge_to_base() {
+        let merged = merge_three_way(BASE, BASE, BASE);
+        assert_eq!(merged.content, BASE);
+        assert_eq!(merged.status, MergeStatus::Clean);
+    }
+
+    #[test]
+    fn one_sided_change_is_clean() {
+        let source = "one\nTWO\nthree\nfour\nfive\n";
+        let merged = merge_three_way(BASE, source, BASE);
+        assert_eq!(merged.content, source);
+        assert_eq!(merged.status, MergeStatus::Clean);
+    }
+
+    #[test]
+    fn disjoint_changes_are_combined() {
+        let source = "zero\none\ntwo\nthree\nfour\nfive\n";
+        let target = "one\ntwo\nthree\nfour\nFIVE\nsix\n";
+        let merged = merge_three_way(BASE, source, target);
+        assert_eq!(merged.content, "zero\none\ntwo\nthree\nfour\nFIVE\nsix\n");
+        assert_eq!(merged.status, MergeStatus::Merged);
+        assert_eq!(merged.conflicts, 0);
+    }
+
+    #[test]
+    fn identical_changes_do_not_conflict() {
+        let both = "one\ntwo\n3\nfour\nfive\n";
+        let merged = merge_three_way(BASE, both, both);
+        assert_eq!(merged.content, both);
+        assert_eq!(merged.status, MergeStatus::Clean);
+    }
+
+    #[test]
+    fn overlapping_changes_get_markers() {
+        let source = "one\ntwo\nsource three\nfour\nfive\n";
+        let target = "one\ntwo\ntarget three\nfour\nFIVE\n";
+        let merged = merge_three_way(BASE, source, target);
+        assert_eq!(merged.status, MergeStatus::Conflicted);
+        assert_eq!(merged.conflicts, 1);
+        assert_eq!(
+            merged.content,
+            "one\ntwo\n<<<<<<< source\nsource three\n=======\ntarget three\n>>>>>>> target\nfour\nFIVE\n"
+        );
+        assert!(has_conflict_markers(&merged.content));
+    }
+
+    #[test]
+    fn markers_stay_on_their_own_lines_without_trailing_newline() {
+        let merged = merge_three_way("a", "b", "c");
+        assert_eq!(
+            merged.content,
+            "<<<<<<< source\nb\n=======\nc\n>>>>>>> target\n"
+        );
+    }
+
+    #[test]
+    fn detects_only_complete_marker_hunks() {
+        assert!(!has_conflict_markers("plain text\n"));
+        assert!(!has_conflict_markers("<<<<<<< source\nno close\n"));
+        assert!(!has_conflict_markers(">>>>>>> target\n<<<<<<< source\n"));
+    }
+}
@@ -1,169 +1,419 @@
-//! Model mapping between Claude and Codex/OpenAI platforms.
+//! Model mapping between Claude, Codex/OpenAI, Copilot and Cursor platforms.
 //!
 //! When syncing configurations between agents, model names need to be
-//! transformed to their equivalents on the target platform.
+//! transformed to their equivalents on the target platform. The mapping is
+//! data-driven: a [`ModelMap`] holds ordered [`ModelRule`]s that match full
+//! model ids with case-insensitive glob patterns, per-target exact-id
+//! overrides, and an [`UnmappedPolicy`] deciding what happens to models no
+//! rule covers.
+//!
+//! The built-in map ships with skrills; `~/.skrills/models.toml` extends it:
+//!
+//! ```toml
+//! # What to do with models no rule maps: "passthrough", "drop" or "error".
+//! unmapped = "passthrough"
+//!
+//! # User rules are tried before the built-in ones.
+//! [[rule]]
+//! from = ["claude"]
+//! to = ["codex", "copilot"]
+//! match = ["claude-opus-4*"]
+//! model = "gpt-5"
+//!
+//! # Exact model ids for one target, checked before any rule.
+//! [targets.cursor]
+//! unmapped = "drop"
+//! models = { "claude-sonnet-4-6" = "sonnet-4.6" }
+//! ```
 
-use std::collections::HashMap;
+use crate::Result;
+use anyhow::{bail, Context};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
 use std::sync::LazyLock;
 
-/// Claude model shorthand names.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub enum ClaudeModel {
-    Opus,
-    Sonnet,
-    Haiku,
+/// File name of the user model map under `~/.skrills`.
+pub const MODELS_FILE_NAME: &str = "models.toml";
+
+/// Mapping table shipped with skrills.
+///
+/// Rules are tried in order, so more specific patterns (`*gpt-4o-mini*`)
+/// come before broader ones (`*gpt-4o*`).
+const BUILTIN_MODELS: &str = r#"
+unmapped = "passthrough"
+
+# Claude (and Cursor using Claude ids) -> OpenAI-family targets
+[[rule]]
+from = ["claude", "cursor"]
+to = ["codex", "copilot"]
+match = ["*opus*"]
+model = "gpt-4o"
+
+[[rule]]
+from = ["claude", "cursor"]
+to = ["codex", "copilot"]
+match = ["*sonnet*", "*haiku*"]
+model = "gpt-4o-mini"
+
+# Cursor special values: "fast" is the lightweight model, "inherit" means
+# "use the parent model" and carries no preference to other platforms.
+[[rule]]
+from = ["cursor"]
+to = ["codex", "copilot"]
+match = ["fast"]
+model = "gpt-4o-mini"
+
+[[rule]]
+from = ["cursor"]
+to = ["claude", "codex", "copilot"]
+match = ["inherit"]
+
+[[rule]]
+from = ["cursor"]
+to = ["claude"]
+match = ["fast"]
+model = "haiku"
+
+[[rule]]
+from = ["cursor"]
+to = ["claude"]
+match = ["*opus*", "*sonnet*", "*haiku*"]
+keep = true
+
+# OpenAI-family -> Claude
+[[rule]]
+from = ["codex", "copilot"]
+to = ["claude"]
+match = ["*o3-mini*", "*o3_mini*", "*o1-mini*", "*o1_mini*"]
+model = "haiku"
+
+[[rule]]
+from = ["codex", "copilot"]
+to = ["claude"]
+match = ["*gpt-4o-mini*", "*gpt4o-mini*"]
+model = "sonnet"
+
+[[rule]]
+from = ["codex", "copilot"]
+to = ["claude"]
+match = ["*gpt-4o*", "*gpt4o*", "*o1*"]
+model = "opus"
+
+# Cursor accepts Claude and OpenAI ids directly; Codex and Copilot share ids.
+[[rule]]
+from = ["claude", "codex", "copilot"]
+to = ["cursor"]
+match = ["*"]
+keep = true
+
+[[rule]]
+from = ["codex", "copilot"]
+to = ["codex", "copilot"]
+match = ["*"]
+keep = true
+"#;
+
+static BUILTIN: LazyLock<ModelMap> =
+    LazyLock::new(|| toml::from_str(BUILTIN_MODELS).expect("built-in model map must parse"));
+
+/// What to do with a model that no rule or override maps.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum UnmappedPolicy {
+    /// Write the source model id unchanged.
+    #[default]
+    Passthrough,
+    /// Write no model, letting the target use its default.
+    Drop,
+    /// Refuse to sync until the model is mapped.
+    Error,
+}
+
+impl std::fmt::Display for UnmappedPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Passthrough => "passthrough",
+            Self::Drop => "drop",
+            Self::Error => "error",
+        })
+    }
+}
+
+/// One ordered mapping rule.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModelRule {
+    /// Source platforms the rule applies to; empty means any.
+    #[serde(default)]
+    pub from: Vec<String>,
+    /// Target platforms the rule applies to; empty means any.
+    #[serde(default)]
+    pub to: Vec<String>,
+    /// Case-insensitive glob patterns (`*`, `?`) matched against the full
+    /// model id.
+    #[serde(rename = "match")]
+    pub patterns: Vec<String>,
+    /// Model id written to the target. Without `model` or `keep` the target
+    /// gets no model at all.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    /// Write the source model id unchanged.
+    #[serde(default)]
+    pub keep: bool,
+}
+
+impl ModelRule {
+    fn applies(&self, model: &str, source: &str, target: &str) -> bool {
+        let platform_ok = |list: &[String], name: &str| {
+            list.is_empty() || list.iter().any(|p| p.eq_ignore_ascii_case(name))
+        };
+        platform_ok(&self.from, source)
+            && platform_ok(&self.to, target)
+            && self.patterns.iter().any(|p| glob_match(p, model))
+    }
+}
+
+/// Overrides that apply to a single target platform.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TargetOverrides {
+    /// Replaces the map-wide [`UnmappedPolicy`] for this target.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unmapped: Option<UnmappedPolicy>,
+    /// Exact source model id → target model id, checked before any rule.
+    #[serde(default)]
+    pub models: BTreeMap<String, String>,
+}
+
+/// Outcome of looking a model up in a [`ModelMap`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ModelLookup {
+    /// A rule or override produced this target model id.
+    Mapped(String),
+    /// A rule says the target should carry no model (e.g. Cursor `inherit`).
+    Omitted,
+    /// No rule or override matched.
+    Unmapped,
+}
+
+/// Data-driven model mapping table.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModelMap {
+    /// Policy for models no rule maps.
+    #[serde(default)]
+    pub unmapped: UnmappedPolicy,
+    /// Keep the built-in rules after the ones in this file. Only meaningful
+    /// for `models.toml`.
+    #[serde(default = "default_true")]
+    pub builtin: bool,
+    /// Ordered rules; the first match wins.
+    #[serde(default, rename = "rule")]
+    pub rules: Vec<ModelRule>,
+    /// Per-target overrides keyed by platform name.
+    #[serde(default)]
+    pub targets: BTreeMap<String, TargetOverrides>,
+}
+
+fn default_true() -> bool {
+    true
 }
 
-/// OpenAI/Codex model shorthand names.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub enum OpenAiModel {
-    Gpt4o,
-    Gpt4oMini,
-    O1,
-    O1Mini,
-    O3Mini,
+impl Default for ModelMap {
+    fn default() -> Self {
+        Self::builtin().clone()
+    }
 }
 
-impl ClaudeModel {
-    /// Parse a Claude model string into the enum variant.
-    pub fn parse(s: &str) -> Option<Self> {
-        let lower = s.to_lowercase();
-        // Match both shorthand and full model IDs
-        if lower.contains("opus") {
-            Some(Self::Opus)
-        } else if lower.contains("sonnet") {
-            Some(Self::Sonnet)
-        } else if lower.contains("haiku") {
-            Some(Self::Haiku)
-        } else {
-            None
+impl ModelMap {
+    /// The mapping table shipped with skrills.
+    pub fn builtin() -> &'static ModelMap {
+        &BUILTIN
+    }
+
+    /// Loads a model map file and layers it over the built-in table.
+    ///
+    /// Rules from the file are tried first; unless the file sets
+    /// `builtin = false`, the built-in rules follow. Target overrides from
+    /// the file replace built-in ones for the same platform.
+    pub fn load(path: &Path) -> Result<Self> {
+        let raw =
+            fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+        let user: ModelMap =
+            toml::from_str(&raw).with_context(|| format!("parsing {}", path.display()))?;
+        Ok(user.layered_over_builtin())
+    }
+
+    /// Loads [`models_config_path`], falling back to the built-in table when
+    /// the file does not exist.
+    pub fn load_default() -> Result<Self> {
+        match models_config_path() {
+            Some(path) if path.exists() => Self::load(&path),
+            _ => Ok(Self::builtin().clone()),
         }
     }
 
-    /// Get the canonical shorthand name.
-    pub fn as_str(&self) -> &'static str {
-        match self {
-            Self::Opus => "opus",
-            Self::Sonnet => "sonnet",
-            Self::Haiku => "haiku",
+    fn layered_over_builtin(mut self) -> Self {
+        if !self.builtin {
+            return self;
         }
+        let builtin = Self::builtin();
+        self.rules.extend(builtin.rules.iter().cloned());
+        for (target, overrides) in &builtin.targets {
+            self.targets
+                .entry(target.clone())
+                .or_insert_with(|| overrides.clone());
+        }
+        self
+    }
+
+    /// Looks `model` up for a `source` → `target` sync.
+    ///
+    /// Same-platform syncs always keep the model. Per-target overrides are
+    /// checked before the rules.
+    pub fn lookup(&self, model: &str, source: &str, target: &str) -> ModelLookup {
+        if source == target {
+            return ModelLookup::Mapped(model.to_string());
+        }
+        if let Some(mapped) = self.targets.get(target).and_then(|t| t.models.get(model)) {
+            return ModelLookup::Mapped(mapped.clone());
+        }
+        match self.rules.iter().find(|r| r.applies(model, source, target)) {
+            Some(rule) if rule.keep => ModelLookup::Mapped(model.to_string()),
+            Some(rule) => match &rule.model {
+                Some(mapped) => ModelLookup::Mapped(mapped.clone()),
+                None => ModelLookup::Omitted,
+            },
+            None => ModelLookup::Unmapped,
+        }
+    }
+
+    /// The [`UnmappedPolicy`] in effect for `target`.
+    pub fn unmapped_policy(&self, target: &str) -> UnmappedPolicy {
+        self.targets
+            .get(target)
+            .and_then(|t| t.unmapped)
+            .unwrap_or(self.unmapped)
     }
-}
 
-impl OpenAiModel {
-    /// Parse an OpenAI model string into the enum variant.
-    pub fn parse(s: &str) -> Option<Self> {
-        let lower = s.to_lowercase();
-        // Match model patterns - order matters for specificity
-        if lower.contains("o3-mini") || lower.contains("o3_mini") {
-            Some(Self::O3Mini)
-        } else if lower.contains("o1-mini") || lower.contains("o1_mini") {
-            Some(Self::O1Mini)
-        } else if lower.contains("o1") && !lower.contains("mini") {
-            Some(Self::O1)
-        } else if lower.contains("gpt-4o-mini") || lower.contains("gpt4o-mini") {
-            Some(Self::Gpt4oMini)
-        } else if lower.contains("gpt-4o") || lower.contains("gpt4o") {
-            Some(Self::Gpt4o)
-        } else {
-            None
+    /// Maps `model` for a `source` → `target` sync, applying the unmapped
+    /// policy.
+    ///
+    /// Returns the model id to write, or `None` when the target should carry
+    /// no model. Fails when the model is unmapped and the policy is
+    /// [`UnmappedPolicy::Error`].
+    pub fn map(&self, model: &str, source: &str, target: &str) -> Result<Option<String>> {
+        match self.lookup(model, source, target) {
+            ModelLookup::Mapped(mapped) => Ok(Some(mapped)),
+            ModelLookup::Omitted => Ok(None),
+            ModelLookup::Unmapped => match self.unmapped_policy(target) {
+                UnmappedPolicy::Passthrough => Ok(Some(model.to_string())),
+                UnmappedPolicy::Drop => Ok(None),
+                UnmappedPolicy::Error => bail!(
+                    "No model mapping for '{}' ({} → {}); add a rule to {}",
+                    model,
+                    source,
+                    target,
+                    MODELS_FILE_NAME
+                ),
+            },
         }
     }
+}
 
-    /// Get the canonical model name.
-    pub fn as_str(&self) -> &'static str {
-        match self {
-            Self::Gpt4o => "gpt-4o",
-            Self::Gpt4oMini => "gpt-4o-mini",
-            Self::O1 => "o1",
-            Self::O1Mini => "o1-mini",
-            Self::O3Mini => "o3-mini",
+/// Returns the user model map path.
+///
+/// `$SKRILLS_MODELS_CONFIG` overrides the default `~/.skrills/models.toml`.
+pub fn models_config_path() -> Option<PathBuf> {
+    if let Ok(custom) = std::env::var("SKRILLS_MODELS_CONFIG") {
+        return Some(PathBuf::from(custom));
+    }
+    Some(dirs::home_dir()?.join(".skrills").join(MODELS_FILE_NAME))
+}
+
+/// Case-insensitive glob match supporting `*` and `?`.
+pub(crate) fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.to_lowercase().chars().collect();
+    let text: Vec<char> = text.to_lowercase().chars().collect();
+
+    let (mut p, mut t) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, t));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match backtrack {
+                Some((star_p, star_t)) => {
+                    p = star_p + 1;
+                    t = star_t + 1;
+                    backtrack = Some((star_p, star_t + 1));
+                }
+                None => return false,
+            },
         }
     }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+/// Returns the top-level `model:` value from markdown frontmatter, if any.
+pub(crate) fn frontmatter_model(content: &str) -> Option<String> {
+    let (frontmatter, _) = crate::adapters::utils::split_frontmatter(content);
+    frontmatter?.lines().find_map(|line| {
+        let value = line.strip_prefix("model:")?.trim();
+        let value = value.trim_matches(|c| c == '"' || c == '\'');
+        (!value.is_empty()).then(|| value.to_string())
+    })
 }
 
-// Model Mapping Tables
-
-/// Claude → OpenAI mapping table.
-static CLAUDE_TO_OPENAI: LazyLock<HashMap<ClaudeModel, OpenAiModel>> = LazyLock::new(|| {
-    HashMap::from([
-        (ClaudeModel::Opus, OpenAiModel::Gpt4o),
-        (ClaudeModel::Sonnet, OpenAiModel::Gpt4oMini),
-        (ClaudeModel::Haiku, OpenAiModel::Gpt4oMini),
-    ])
-});
-
-/// OpenAI → Claude mapping table.
-static OPENAI_TO_CLAUDE: LazyLock<HashMap<OpenAiModel, ClaudeModel>> = LazyLock::new(|| {
-    HashMap::from([
-        (OpenAiModel::Gpt4o, ClaudeModel::Opus),
-        (OpenAiModel::Gpt4oMini, ClaudeModel::Sonnet),
-        (OpenAiModel::O1, ClaudeModel::Opus),
-        (OpenAiModel::O1Mini, ClaudeModel::Haiku),
-        (OpenAiModel::O3Mini, ClaudeModel::Haiku),
-    ])
-});
+/// Rewrites the top-level `model:` line of markdown frontmatter, removing it
+/// when `model` is `None`. The rest of the content is left byte-for-byte.
+pub(crate) fn set_frontmatter_model(content: &str, model: Option<&str>) -> String {
+    let mut out = String::with_capacity(content.len());
+    let mut fences = 0;
+    let mut replaced = false;
+    for line in content.split_inclusive('\n') {
+        let bare = line.trim_end_matches(['\n', '\r']);
+        if fences == 0 && !bare.trim().is_empty() && bare.trim_end() != "---" {
+            // Content before any fence: there is no frontmatter to rewrite.
+            fences = 2;
+        }
+        if fences < 2 && bare.trim_end() == "---" {
+            fences += 1;
+        } else if fences == 1 && !replaced && bare.starts_with("model:") {
+            replaced = true;
+            if let Some(model) = model {
+                out.push_str("model: ");
+                out.push_str(model);
+                out.push_str(&line[bare.len()..]);
+            }
+            continue;
+        }
+        out.push_str(line);
+    }
+    out
+}
 
 // Public API
 
-/// Transform a model name from source platform to target platform.
+/// Transform a model name from source platform to target platform using the
+/// built-in [`ModelMap`].
 ///
-/// Returns `None` if the model is unrecognized (passthrough recommended).
+/// Returns `None` if the model is unrecognized (passthrough recommended) or
+/// the target should carry no model.
 ///
 /// # Arguments
 /// * `model` - The model name to transform
 /// * `source` - Source adapter name ("claude", "codex", "copilot", or "cursor")
 /// * `target` - Target adapter name ("claude", "codex", "copilot", or "cursor")
 pub fn transform_model(model: &str, source: &str, target: &str) -> Option<String> {
-    // Same platform = no transformation needed
-    if source == target {
-        return Some(model.to_string());
-    }
-
-    match (source, target) {
-        ("claude", "codex") | ("claude", "copilot") => {
-            let claude_model = ClaudeModel::parse(model)?;
-            let openai_model = CLAUDE_TO_OPENAI.get(&claude_model)?;
-            Some(openai_model.as_str().to_string())
-        }
-        ("codex", "claude") | ("copilot", "claude") => {
-            let openai_model = OpenAiModel::parse(model)?;
-            let claude_model = OPENAI_TO_CLAUDE.get(&openai_model)?;
-            Some(claude_model.as_str().to_string())
-        }
-        // Cursor accepts Claude model IDs directly, pass through
-        ("claude", "cursor") => Some(model.to_string()),
-        // Cursor special values: "inherit" = use parent model, "fast" = lightweight model
-        ("cursor", "claude") => match model {
-            "inherit" => None, // No model preference, let target decide
-            "fast" => Some("haiku".to_string()),
-            _ => {
-                // Cursor may use Claude model IDs directly
-                if ClaudeModel::parse(model).is_some() {
-                    Some(model.to_string())
-                } else {
-                    None
-                }
-            }
-        },
-        // Cursor ↔ Codex: route through Claude as intermediate
-        ("cursor", "codex") | ("cursor", "copilot") => {
-            // First try to parse as Claude model (Cursor often uses Claude IDs)
-            if let Some(claude_model) = ClaudeModel::parse(model) {
-                let openai_model = CLAUDE_TO_OPENAI.get(&claude_model)?;
-                Some(openai_model.as_str().to_string())
-            } else {
-                match model {
-                    "fast" => Some("gpt-4o-mini".to_string()),
-                    _ => None,
-                }
-            }
-        }
-        ("codex", "cursor") | ("copilot", "cursor") => {
-            // OpenAI model → pass through (Cursor accepts various model IDs)
-            Some(model.to_string())
-        }
-        _ => None,
+    match ModelMap::builtin().lookup(model, source, target) {
+        ModelLookup::Mapped(mapped) => Some(mapped),
+        ModelLookup::Omitted | ModelLookup::Unmapped => None,
     }
 }
 
@@ -297,20 +547,137 @@ mod tests {
         );
     }
 
+    #[test]
+    fn glob_patterns_match_full_ids_case_insensitively() {
+        assert!(glob_match("claude-opus-4*", "Claude-Opus-4-6"));
+        assert!(glob_match("gpt-?o", "gpt-4o"));
+        assert!(glob_match("*sonnet*", "claude-3-5-sonnet-20240620"));
+        assert!(!glob_match("claude-opus-4*", "claude-3-opus"));
+        assert!(!glob_match("fast", "faster"));
+    }
+
+    #[test]
+    fn builtin_map_parses() {
+        let map = ModelMap::builtin();
+        assert!(!map.rules.is_empty());
+        assert_eq!(map.unmapped, UnmappedPolicy::Passthrough);
+    }
+
+    #[test]
+    fn user_rules_take_precedence_over_builtin() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(MODELS_FILE_NAME);
+        fs::write(
+            &path,
+            r#"
+[[rule]]
+from = ["claude"]
+to = ["codex"]
+match = ["claude-opus-4*"]
+model = "gpt-5"
+"#,
+        )
+        .unwrap();
+
+        let map = ModelMap::load(&path).unwrap();
+        assert_eq!(
+            map.lookup("claude-opus-4-6", "claude", "codex"),
+            ModelLookup::Mapped("gpt-5".into())
+        );
+        // Built-in rules still apply behind the user ones
+        assert_eq!(
+            map.lookup("claude-3-opus", "claude", "codex"),
+            ModelLookup::Mapped("gpt-4o".into())
+        );
+    }
+
+    #[test]
+    fn builtin_false_drops_builtin_rules() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(MODELS_FILE_NAME);
+        fs::write(&path, "builtin = false\n").unwrap();
+
+        let map = ModelMap::load(&path).unwrap();
+        assert_eq!(map.lookup("opus", "claude", "codex"), ModelLookup::Unmapped);
+    }
+
+    #[test]
+    fn target_overrides_win_over_rules() {
+        let map: ModelMap = toml::from_str(
+            r#"
+[targets.cursor]
+unmapped = "error"
+models = { "claude-sonnet-4-6" = "sonnet-4.6" }
+"#,
+        )
+        .unwrap();
+        let map = map.layered_over_builtin();
+
+        assert_eq!(
+            map.lookup("claude-sonnet-4-6", "claude", "cursor"),
+            ModelLookup::Mapped("sonnet-4.6".into())
+        );
+        assert_eq!(map.unmapped_policy("cursor"), UnmappedPolicy::Error);
+        assert_eq!(map.unmapped_policy("codex"), UnmappedPolicy::Passthrough);
+    }
+
+    #[test]
+    fn unmapped_policy_controls_map_result() {
+        let mut map = ModelMap::builtin().clone();
+        assert_eq!(
+            map.map("davinci", "codex", "claude").unwrap(),
+            Some("davinci".to_string())
+        );
+
+        map.unmapped = UnmappedPolicy::Drop;
+        assert_eq!(map.map("davinci", "codex", "claude").unwrap(), None);
+
+        map.unmapped = UnmappedPolicy::Error;
+        let err = map.map("davinci", "codex", "claude").unwrap_err();
+        assert!(err.to_string().contains("davinci"));
+        // Mapped and intentionally omitted models are unaffected by the policy
+        assert_eq!(
+            map.map("gpt-4o", "codex", "claude").unwrap(),
+            Some("opus".to_string())
+        );
+        assert_eq!(map.map("inherit", "cursor", "claude").unwrap(), None);
+    }
+
+    #[test]
+    fn frontmatter_model_is_read_and_rewritten() {
+        let content = "---\nname: reviewer\nmodel: \"opus\"\n---\n\nmodel: body text\n";
+        assert_eq!(frontmatter_model(content).as_deref(), Some("opus"));
+
+        let rewritten = set_frontmatter_model(content, Some("gpt-4o"));
+        assert_eq!(
+            rewritten,
+            "---\nname: reviewer\nmodel: gpt-4o\n---\n\nmodel: body text\n"
+        );
+
+        let dropped = set_frontmatter_model(content, None);
+        assert_eq!(dropped, "---\nname: reviewer\n---\n\nmodel: body text\n");
+    }
+
+    #[test]
+    fn frontmatter_rewrite_ignores_content_without_frontmatter() {
+        let content = "# Title\n\n---\nmodel: opus\n---\n";
+        assert_eq!(frontmatter_model(content), None);
+        assert_eq!(set_frontmatter_model(content, Some("gpt-4o")), content);
+    }
+
     mod proptests {
         use super::*;
         use proptest::prelude::*;
 
         proptest! {
             #[test]
-            fn claude_parse_never_panics(s in "\\PC*") {
-                // Should return Some or None, never panic
-                let _ = ClaudeModel::parse(&s);
+            fn glob_match_never_panics(pattern in "\\PC{0,20}", text in "\\PC{0,50}") {
+                let _ = glob_match(&pattern, &text);
             }
 
             #[test]
-            fn openai_parse_never_panics(s in "\\PC*") {
-                let _ = OpenAiModel::parse(&s);
+            fn star_matches_everything(text in "\\PC{0,50}") {
+                prop_assert!(glob_match("*", &text));
             }
 
             #[test]
@@ -1,12 +1,30 @@
 //! Sync orchestrator that coordinates adapters and manages sync flow.
 
+use crate::adapters::utils::hash_content;
 use crate::adapters::AgentAdapter;
-use crate::models::transform_model;
-use crate::report::{SkipReason, SyncReport, WriteReport};
+use crate::common::{Command, ContentFormat};
+use crate::conflict::{
+    classify_against_baseline, prompt_conflict_resolution, resolve_conflicts, ArtifactType,
+    ConflictKind, ConflictStrategy, Resolution,
+};
+use crate::merge::{has_conflict_markers, merge_three_way, MergeResult, MergeStatus};
+use crate::models::{
+    frontmatter_model, set_frontmatter_model, ModelLookup, ModelMap, UnmappedPolicy,
+};
+use crate::policy::SyncPolicy;
+use crate::report::{MergeOutcome, SkipReason, SyncReport, UnmappedModel, WriteReport};
+use crate::secrets::{self, SecretAction};
+use crate::snapshot::{
+    collect_managed_files, create_snapshot, finalize_snapshot, prune_snapshots, save_baselines,
+    SnapshotConfig,
+};
+use crate::state::{BaselineKey, SyncStateStore};
 use crate::Result;
-use anyhow::bail;
+use anyhow::{bail, Context};
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
 
 /// Parameters for a sync operation.
 ///
@@ -73,6 +91,27 @@ pub struct SyncParams {
     /// Used when the target needs a complete plugin mirror (e.g., Cursor).
     #[serde(default)]
     pub full_plugin_mirror: bool,
+    /// How to resolve items changed on both sides since the last sync.
+    ///
+    /// Only consulted when the orchestrator has a sync-state ledger attached
+    /// (see [`SyncOrchestrator::with_state_store`]) and `force` is false. A
+    /// `None` defers to the per-target `conflict_strategy` of the attached
+    /// [`SyncPolicy`], then to `SkipAll`; an explicit strategy always wins.
+    #[serde(default)]
+    pub conflict_strategy: Option<ConflictStrategy>,
+    /// Whether to sync the user-level tool directories or a repository's.
+    ///
+    /// Only consulted by [`sync_between`]; an orchestrator built by hand
+    /// syncs whatever its adapters point at.
+    #[serde(default)]
+    pub scope: SyncScope,
+    /// Never write secret-looking MCP values in plaintext, and keep them out
+    /// of the report.
+    ///
+    /// Targets with an env-reference syntax get references either way; for
+    /// targets without one the value is left out instead of copied.
+    #[serde(default)]
+    pub redact_secrets: bool,
 }
 
 impl Default for SyncParams {
@@ -95,6 +134,9 @@ impl Default for SyncParams {
             interactive: false,
             exclude_plugins: Vec::new(),
             full_plugin_mirror: false,
+            conflict_strategy: None,
+            scope: SyncScope::default(),
+            redact_secrets: false,
         }
     }
 }
@@ -112,6 +154,84 @@ fn default_true() -> bool {
     true
 }
 
+/// Which copy of each tool's configuration a sync reads and writes.
+///
+/// ```
+/// use skrills_sync::SyncScope;
+///
+/// let scope = SyncScope::resolve(Some("user"), None).unwrap();
+/// assert_eq!(scope, SyncScope::User);
+/// assert!(SyncScope::resolve(Some("team"), None).is_err());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum SyncScope {
+    /// The user-level directories (`~/.claude`, `~/.codex`, ...).
+    #[default]
+    User,
+    /// A repository's `.claude/`, `.codex/`, `.cursor/`, `.github/` and
+    /// `AGENTS.md`.
+    Project {
+        /// Repository root.
+        dir: PathBuf,
+    },
+}
+
+impl SyncScope {
+    /// Resolves a `--scope` / `--project-dir` pair.
+    ///
+    /// A project directory implies project scope; project scope without one
+    /// uses the current directory. The directory must exist.
+    pub fn resolve(scope: Option<&str>, project_dir: Option<PathBuf>) -> Result<Self> {
+        let project = match scope.map(str::to_lowercase).as_deref() {
+            None => project_dir.is_some(),
+            Some("user") => {
+                if project_dir.is_some() {
+                    bail!("--project-dir requires --scope project");
+                }
+                false
+            }
+            Some("project") => true,
+            Some(other) => bail!("Unknown scope '{}'. Use one of: user, project", other),
+        };
+        if !project {
+            return Ok(Self::User);
+        }
+        let dir = match project_dir {
+            Some(dir) => dir,
+            None => std::env::current_dir().context("Failed to read current directory")?,
+        };
+        if !dir.is_dir() {
+            bail!("Project directory not found: {}", dir.display());
+        }
+        Ok(Self::Project { dir })
+    }
+
+    /// Returns true for [`SyncScope::Project`].
+    pub fn is_project(&self) -> bool {
+        matches!(self, Self::Project { .. })
+    }
+
+    /// The repository root in project scope.
+    pub fn project_dir(&self) -> Option<&Path> {
+        match self {
+            Self::User => None,
+            Self::Project { dir } => Some(dir),
+        }
+    }
+}
+
+/// Name an adapter is recorded under in the ledger and snapshots.
+///
+/// Project-scoped adapters are qualified with their repository so each
+/// repository keeps its own baselines apart from the user-level pair.
+pub(crate) fn scoped_name(adapter: &impl AgentAdapter) -> String {
+    match adapter.project_root() {
+        Some(dir) => format!("{}@{}", adapter.name(), dir.display()),
+        None => adapter.name().to_string(),
+    }
+}
+
 /// Applies force/dry_run/skip_existing policy when syncing a collection of named items.
 ///
 /// Encapsulates the shared conditional logic used by both commands and instructions
@@ -212,16 +332,505 @@ fn dedup_by_name<Item>(
     (deduped, dup_count)
 }
 
+/// Names of items that `sync_items` declined to write because they already
+/// existed on the target.
+fn would_overwrite_names(report: &WriteReport) -> HashSet<&str> {
+    report
+        .skipped
+        .iter()
+        .filter_map(|r| match r {
+            SkipReason::WouldOverwrite { item } => Some(item.as_str()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// An item changed on both sides whose conflict was resolved with
+/// [`Resolution::Merge`].
+struct PendingMerge {
+    source: Command,
+    result: MergeResult,
+    /// The source's own `model:` value when the item was model-mapped for
+    /// the target, restored in the copy written back to the source.
+    source_model: Option<String>,
+}
+
+/// Items [`SyncOrchestrator::filter_conflicts`] held back from the plain
+/// write path.
+#[derive(Default)]
+struct Deferred {
+    skipped: Vec<SkipReason>,
+    merges: Vec<PendingMerge>,
+    /// Models of items rewritten by [`SyncOrchestrator::map_agent_models`],
+    /// by item name, before mapping.
+    source_models: HashMap<String, String>,
+}
+
+/// A source file and the merge result a sync held back instead of writing.
+pub(crate) type SourceWrite = (PathBuf, Vec<u8>);
+
+/// `content` with its frontmatter `model:` set to `model`, added after the
+/// opening fence when the target copy carries no model.
+fn with_source_model(content: &str, model: &str) -> String {
+    let restored = set_frontmatter_model(content, Some(model));
+    if frontmatter_model(&restored).is_some() {
+        return restored;
+    }
+    match content.split_once('\n') {
+        Some((fence, rest)) if fence.trim_end() == "---" => {
+            format!("{fence}\nmodel: {model}\n{rest}")
+        }
+        _ => restored,
+    }
+}
+
 /// Orchestrates sync operations between agents.
 pub struct SyncOrchestrator<S: AgentAdapter, T: AgentAdapter> {
     source: S,
     target: T,
+    state: Option<Mutex<SyncStateStore>>,
+    snapshots: Option<SnapshotConfig>,
+    models: Option<ModelMap>,
+    policy: Option<SyncPolicy>,
+    held_source_writes: Option<Mutex<Vec<SourceWrite>>>,
 }
 
 impl<S: AgentAdapter, T: AgentAdapter> SyncOrchestrator<S, T> {
     /// Creates a new orchestrator with source and target adapters.
     pub fn new(source: S, target: T) -> Self {
-        Self { source, target }
+        Self {
+            source,
+            target,
+            state: None,
+            snapshots: None,
+            models: None,
+            policy: None,
+            held_source_writes: None,
+        }
+    }
+
+    /// Attaches a sync-state ledger used as the conflict-detection baseline.
+    ///
+    /// With a ledger attached, items edited only on the target since the last
+    /// sync are skipped instead of overwritten, items edited on both sides are
+    /// resolved with [`SyncParams::conflict_strategy`], and the hashes of every
+    /// successfully written item are recorded for the next run.
+    pub fn with_state_store(mut self, store: SyncStateStore) -> Self {
+        self.state = Some(Mutex::new(store));
+        self
+    }
+
+    /// Snapshots the target's [`managed_paths`](AgentAdapter::managed_paths)
+    /// before each writing sync so the run can be restored with
+    /// [`crate::snapshot::restore_snapshot`].
+    pub fn with_snapshots(mut self, config: SnapshotConfig) -> Self {
+        self.snapshots = Some(config);
+        self
+    }
+
+    /// Uses `map` to translate preference and agent models instead of the
+    /// built-in [`ModelMap`].
+    pub fn with_model_map(mut self, map: ModelMap) -> Self {
+        self.models = Some(map);
+        self
+    }
+
+    /// Filters, renames and protects items according to `policy`, and uses
+    /// its per-target conflict strategy. Exclusions are reported as
+    /// [`SkipReason::ExcludedByConfig`] naming the rule.
+    pub fn with_policy(mut self, policy: SyncPolicy) -> Self {
+        self.policy = Some(policy);
+        self
+    }
+
+    /// Holds merge results meant for the source instead of writing them, so
+    /// the sync leaves the source untouched. Collect them with
+    /// [`Self::take_held_source_writes`].
+    pub(crate) fn holding_source_writes(mut self) -> Self {
+        self.held_source_writes = Some(Mutex::new(Vec::new()));
+        self
+    }
+
+    /// Source files (path and content) the sync would have written back.
+    pub(crate) fn take_held_source_writes(&self) -> Vec<SourceWrite> {
+        self.held_source_writes
+            .as_ref()
+            .map(|held| std::mem::take(&mut *held.lock().unwrap_or_else(|e| e.into_inner())))
+            .unwrap_or_default()
+    }
+
+    /// Consumes the orchestrator and returns the attached ledger, if any.
+    pub fn into_state_store(self) -> Option<SyncStateStore> {
+        self.state
+            .map(|m| m.into_inner().unwrap_or_else(|e| e.into_inner()))
+    }
+
+    fn baseline_key(&self, artifact_type: ArtifactType, name: &str) -> BaselineKey {
+        BaselineKey::new(
+            &scoped_name(&self.source),
+            &scoped_name(&self.target),
+            artifact_type,
+            name,
+        )
+    }
+
+    /// Drops items whose target copy must not be overwritten according to the
+    /// ledger, pushing a [`SkipReason`] for each into `deferred`. Conflicts
+    /// resolved with [`Resolution::Merge`] are merged here and handed to
+    /// [`Self::apply_merges`] through `deferred`.
+    fn filter_conflicts(
+        &self,
+        items: Vec<Command>,
+        artifact_type: ArtifactType,
+        params: &SyncParams,
+        read_target: impl FnOnce() -> Result<Vec<Command>>,
+        deferred: &mut Deferred,
+    ) -> Result<Vec<Command>> {
+        let Some(state) = &self.state else {
+            return Ok(items);
+        };
+        if params.force || items.is_empty() {
+            return Ok(items);
+        }
+
+        let target_items = read_target()?;
+        let changes = {
+            let store = state.lock().unwrap_or_else(|e| e.into_inner());
+            classify_against_baseline(&items, &target_items, artifact_type, |name| {
+                store.get(&self.baseline_key(artifact_type, name)).cloned()
+            })
+        };
+
+        let mut blocked: HashSet<String> = HashSet::new();
+        let mut conflicts = Vec::new();
+        for change in changes {
+            if has_conflict_markers(&change.target_content) {
+                tracing::warn!(
+                    artifact = %artifact_type,
+                    name = %change.name,
+                    "Target still has merge conflict markers; not overwriting"
+                );
+                blocked.insert(change.name.clone());
+                deferred
+                    .skipped
+                    .push(SkipReason::UnresolvedMerge { item: change.name });
+                continue;
+            }
+            match change.kind {
+                ConflictKind::SourceChanged => {}
+                ConflictKind::TargetChanged => {
+                    tracing::info!(
+                        artifact = %artifact_type,
+                        name = %change.name,
+                        "Target modified since last sync; keeping target copy"
+                    );
+                    blocked.insert(change.name.clone());
+                    deferred
+                        .skipped
+                        .push(SkipReason::TargetModified { item: change.name });
+                }
+                ConflictKind::BothChanged => conflicts.push(change),
+            }
+        }
+
+        let mergeable: HashSet<&str> = items
+            .iter()
+            .filter(|i| i.content_format == ContentFormat::Markdown)
+            .map(|i| i.name.as_str())
+            .collect();
+        let mut merged: HashMap<String, MergeResult> = HashMap::new();
+        for resolved in resolve_conflicts(
+            conflicts,
+            self.conflict_strategy(params),
+            prompt_conflict_resolution,
+        ) {
+            if resolved.resolution == Resolution::Merge {
+                let conflict = &resolved.conflict;
+                let base = if mergeable.contains(conflict.name.as_str()) {
+                    let store = state.lock().unwrap_or_else(|e| e.into_inner());
+                    store
+                        .get(&self.baseline_key(artifact_type, &conflict.name))
+                        .and_then(|b| b.content.clone())
+                } else {
+                    None
+                };
+                if let Some(base) = base {
+                    let result =
+                        merge_three_way(&base, &conflict.source_content, &conflict.target_content);
+                    tracing::info!(
+                        artifact = %artifact_type,
+                        name = %conflict.name,
+                        status = %result.status,
+                        conflicts = result.conflicts,
+                        "Merged changes from both sides"
+                    );
+                    blocked.insert(conflict.name.clone());
+                    merged.insert(conflict.name.clone(), result);
+                    continue;
+                }
+                tracing::warn!(
+                    artifact = %artifact_type,
+                    name = %conflict.name,
+                    "No mergeable baseline content recorded; skipping conflict"
+                );
+                blocked.insert(conflict.name.clone());
+                deferred.skipped.push(SkipReason::Conflict {
+                    item: resolved.conflict.name,
+                    resolution: Resolution::Skip,
+                });
+                continue;
+            }
+            if resolved.resolution != Resolution::KeepSource {
+                tracing::warn!(
+                    artifact = %artifact_type,
+                    name = %resolved.conflict.name,
+                    resolution = %resolved.resolution,
+                    "Conflict: changed on both sides since last sync"
+                );
+                blocked.insert(resolved.conflict.name.clone());
+                deferred.skipped.push(SkipReason::Conflict {
+                    item: resolved.conflict.name,
+                    resolution: resolved.resolution,
+                });
+            }
+        }
+
+        let mut kept = Vec::with_capacity(items.len());
+        for item in items {
+            if let Some(result) = merged.remove(&item.name) {
+                deferred.merges.push(PendingMerge {
+                    source_model: deferred.source_models.remove(&item.name),
+                    source: item,
+                    result,
+                });
+            } else if !blocked.contains(&item.name) {
+                kept.push(item);
+            }
+        }
+        Ok(kept)
+    }
+
+    /// Writes merge results to the target and records them in `report`.
+    ///
+    /// Clean and merged results are also written back to the source file so
+    /// both sides converge, or held when the orchestrator is
+    /// [holding source writes](Self::holding_source_writes). Items merged in
+    /// the target's model names get the source's `model:` back before they
+    /// are written there. Conflicted results only go to the target, with
+    /// markers; their baseline records the source as it was, so resolving
+    /// the markers on the target reads as a target-only edit next time and a
+    /// later source edit merges against the pre-merge source.
+    fn apply_merges(
+        &self,
+        merges: Vec<PendingMerge>,
+        artifact_type: ArtifactType,
+        dry_run: bool,
+        write: impl FnOnce(&[Command]) -> Result<WriteReport>,
+        read_target: impl FnOnce() -> Result<Vec<Command>>,
+        report: &mut WriteReport,
+    ) -> Result<()> {
+        if merges.is_empty() {
+            return Ok(());
+        }
+        report.merges.extend(merges.iter().map(|m| MergeOutcome {
+            item: m.source.name.clone(),
+            status: m.result.status,
+            conflicts: m.result.conflicts,
+        }));
+        if dry_run {
+            return Ok(());
+        }
+
+        let merged: Vec<Command> = merges
+            .iter()
+            .map(|m| {
+                let mut item = m.source.clone();
+                item.content = m.result.content.clone().into_bytes();
+                item.hash = hash_content(&item.content);
+                item
+            })
+            .collect();
+        let written = write(&merged)?;
+        report.written += written.written;
+        report.warnings.extend(written.warnings);
+
+        let mut source_hashes = Vec::with_capacity(merges.len());
+        for (pending, item) in merges.iter().zip(&merged) {
+            if pending.result.status == MergeStatus::Conflicted {
+                source_hashes.push(pending.source.hash.clone());
+                continue;
+            }
+            let content = match &pending.source_model {
+                Some(model) => with_source_model(&pending.result.content, model).into_bytes(),
+                None => item.content.clone(),
+            };
+            let hash = hash_content(&content);
+            source_hashes.push(hash.clone());
+            if hash != pending.source.hash {
+                if let Some(held) = &self.held_source_writes {
+                    held.lock()
+                        .unwrap_or_else(|e| e.into_inner())
+                        .push((pending.source.source_path.clone(), content));
+                    continue;
+                }
+                std::fs::write(&pending.source.source_path, &content).with_context(|| {
+                    format!(
+                        "Failed to write merged {} back to {}",
+                        artifact_type,
+                        pending.source.source_path.display()
+                    )
+                })?;
+            }
+        }
+
+        let Some(state) = &self.state else {
+            return Ok(());
+        };
+        let landed: HashMap<String, Command> = read_target()?
+            .into_iter()
+            .map(|c| (c.name.clone(), c))
+            .collect();
+        let mut store = state.lock().unwrap_or_else(|e| e.into_inner());
+        for ((pending, item), source_hash) in merges.iter().zip(&merged).zip(source_hashes) {
+            let target = landed.get(&item.name);
+            let base = if pending.result.status == MergeStatus::Conflicted {
+                &pending.source.content
+            } else {
+                target.map_or(&item.content, |t| &t.content)
+            };
+            store.record_with_content(
+                self.baseline_key(artifact_type, &item.name),
+                source_hash,
+                target.map(|t| t.hash.clone()),
+                Some(String::from_utf8_lossy(base).into_owned()),
+            );
+        }
+        store.save()
+    }
+
+    /// Records ledger baselines for items written to the target.
+    ///
+    /// Re-reads the target so the stored target hash and merge-base content
+    /// reflect what the adapter actually wrote (after any format conversion).
+    fn record_baselines(
+        &self,
+        items: &[Command],
+        artifact_type: ArtifactType,
+        report: &WriteReport,
+        dry_run: bool,
+        read_target: impl FnOnce() -> Result<Vec<Command>>,
+    ) -> Result<()> {
+        let Some(state) = &self.state else {
+            return Ok(());
+        };
+        if dry_run || items.is_empty() {
+            return Ok(());
+        }
+
+        let not_written = would_overwrite_names(report);
+        let landed: HashMap<String, Command> = read_target()?
+            .into_iter()
+            .map(|c| (c.name.clone(), c))
+            .collect();
+
+        let mut store = state.lock().unwrap_or_else(|e| e.into_inner());
+        for item in items
+            .iter()
+            .filter(|i| !not_written.contains(i.name.as_str()))
+        {
+            let target = landed.get(&item.name);
+            store.record_with_content(
+                self.baseline_key(artifact_type, &item.name),
+                item.hash.clone(),
+                target.map(|t| t.hash.clone()),
+                target.map(|t| String::from_utf8_lossy(&t.content).into_owned()),
+            );
+        }
+        store.save()
+    }
+
+    /// The conflict strategy for this target: the one from `params` if set,
+    /// otherwise the policy's, otherwise `SkipAll`.
+    fn conflict_strategy(&self, params: &SyncParams) -> ConflictStrategy {
+        params
+            .conflict_strategy
+            .or_else(|| {
+                self.policy
+                    .as_ref()
+                    .and_then(|p| p.conflict_strategy(self.target.name()))
+            })
+            .unwrap_or(ConflictStrategy::SkipAll)
+    }
+
+    /// Applies the attached [`SyncPolicy`] to items of `kind` read from the
+    /// source. Without a policy every item is kept.
+    fn apply_policy(
+        &self,
+        kind: ArtifactType,
+        items: Vec<Command>,
+    ) -> (Vec<Command>, Vec<SkipReason>) {
+        match &self.policy {
+            Some(policy) => policy.apply(kind.into(), items, self.target.name()),
+            None => (items, Vec::new()),
+        }
+    }
+
+    /// Maps `model` for the target, recording it in `unmapped` when no rule
+    /// covers it. Returns the model to write, or `None` for no model.
+    fn map_model(
+        &self,
+        model: &str,
+        item: &str,
+        unmapped: &mut Vec<UnmappedModel>,
+    ) -> Option<String> {
+        let map = self.models.as_ref().unwrap_or_else(|| ModelMap::builtin());
+        let (source, target) = (self.source.name(), self.target.name());
+        match map.lookup(model, source, target) {
+            ModelLookup::Mapped(mapped) => Some(mapped),
+            ModelLookup::Omitted => None,
+            ModelLookup::Unmapped => {
+                let policy = map.unmapped_policy(target);
+                tracing::warn!(
+                    item = %item,
+                    model = %model,
+                    source = %source,
+                    target = %target,
+                    policy = %policy,
+                    "No model mapping"
+                );
+                unmapped.push(UnmappedModel {
+                    item: item.to_string(),
+                    model: model.to_string(),
+                    policy,
+                });
+                (policy != UnmappedPolicy::Drop).then(|| model.to_string())
+            }
+        }
+    }
+
+    /// Rewrites the frontmatter `model:` of each agent for the target. The
+    /// source hash is kept so ledger comparisons still see the source file.
+    /// Returns the original model of each rewritten agent, by name.
+    fn map_agent_models(
+        &self,
+        agents: &mut [Command],
+        unmapped: &mut Vec<UnmappedModel>,
+    ) -> HashMap<String, String> {
+        let mut originals = HashMap::new();
+        for agent in agents {
+            let Ok(content) = std::str::from_utf8(&agent.content) else {
+                continue;
+            };
+            let Some(model) = frontmatter_model(content) else {
+                continue;
+            };
+            let mapped = self.map_model(&model, &agent.name, unmapped);
+            if mapped.as_deref() != Some(model.as_str()) {
+                agent.content = set_frontmatter_model(content, mapped.as_deref()).into_bytes();
+                originals.insert(agent.name.clone(), model);
+            }
+        }
+        originals
     }
 
     /// Performs the sync operation.
@@ -230,10 +839,119 @@ impl<S: AgentAdapter, T: AgentAdapter> SyncOrchestrator<S, T> {
     /// to the target adapter, adapters may implement creative mappings for
     /// fields they don't "natively" support (e.g., Copilot maps commands to
     /// prompts, Codex converts agents to skills).
+    ///
+    /// With snapshots enabled (see [`Self::with_snapshots`]) the target's
+    /// managed files are captured before anything is written; the report
+    /// carries the snapshot id when the run changed at least one file.
     pub fn sync(&self, params: &SyncParams) -> Result<SyncReport> {
+        let snapshot = self.begin_snapshot(params)?;
+        let result = self.sync_artifacts(params);
+        let snapshot = snapshot.and_then(|dir| self.finish_snapshot(&dir));
+
+        let mut report = result?;
+        report.success = true;
+        report.snapshot = snapshot;
+        report.summary = report.format_summary(self.source.name(), self.target.name());
+
+        Ok(report)
+    }
+
+    /// Snapshots the target's managed files, and the pair's ledger baselines
+    /// when a ledger is attached, before a writing sync.
+    fn begin_snapshot(&self, params: &SyncParams) -> Result<Option<PathBuf>> {
+        let Some(config) = self.snapshots.as_ref().filter(|c| c.enabled) else {
+            return Ok(None);
+        };
+        let managed = self.target.managed_paths();
+        if params.dry_run || managed.is_empty() {
+            return Ok(None);
+        }
+
+        let root = self.target.managed_root();
+        let files = collect_managed_files(&root, &managed);
+        let (source, target) = (scoped_name(&self.source), scoped_name(&self.target));
+        let dir = create_snapshot(config, &source, &target, &root, &files)
+            .with_context(|| format!("Failed to snapshot {} before sync", self.target.name()))?;
+        if let Some(state) = &self.state {
+            let store = state.lock().unwrap_or_else(|e| e.into_inner());
+            save_baselines(&dir, &store.pair_entries(&source, &target))?;
+        }
+        Ok(Some(dir))
+    }
+
+    /// Finalizes the snapshot taken by [`Self::begin_snapshot`] and prunes
+    /// expired ones. Returns the snapshot id unless the sync changed nothing.
+    fn finish_snapshot(&self, dir: &Path) -> Option<String> {
+        let id = dir.file_name()?.to_string_lossy().into_owned();
+        let kept = match finalize_snapshot(dir, &self.target.managed_paths()) {
+            Ok(changed) => changed > 0,
+            Err(e) => {
+                tracing::warn!(snapshot = %dir.display(), error = %e, "Failed to finalize sync snapshot");
+                true
+            }
+        };
+        if let Some(config) = &self.snapshots {
+            if let Err(e) = prune_snapshots(config) {
+                tracing::warn!(error = %e, "Failed to prune old sync snapshots");
+            }
+        }
+        kept.then_some(id)
+    }
+
+    fn sync_artifacts(&self, params: &SyncParams) -> Result<SyncReport> {
         let mut report = SyncReport::new();
         let target_support = self.target.supported_fields();
 
+        // Map models before anything is written so an `error` policy stops
+        // the sync with the target untouched.
+        let mut unmapped = Vec::new();
+        let prefs = if params.sync_preferences {
+            let mut prefs = self.source.read_preferences()?;
+            if let Some(model) = prefs.model.take() {
+                prefs.model = self.map_model(&model, "preferences", &mut unmapped);
+            }
+            Some(prefs)
+        } else {
+            None
+        };
+        let mut agent_policy_skips = Vec::new();
+        let mut agent_models = HashMap::new();
+        let agents = if params.sync_agents {
+            let (agents, skipped) =
+                self.apply_policy(ArtifactType::Agent, self.source.read_agents()?);
+            agent_policy_skips = skipped;
+            // Apply plugin exclusion filter to agents
+            let mut agents: Vec<_> = if params.exclude_plugins.is_empty() {
+                agents
+            } else {
+                agents
+                    .into_iter()
+                    .filter(|a| {
+                        a.plugin_origin
+                            .as_ref()
+                            .is_none_or(|o| !params.is_plugin_excluded(&o.plugin_name))
+                    })
+                    .collect()
+            };
+            agent_models = self.map_agent_models(&mut agents, &mut unmapped);
+            Some(agents)
+        } else {
+            None
+        };
+        if !params.dry_run && unmapped.iter().any(|u| u.policy == UnmappedPolicy::Error) {
+            let models: Vec<String> = unmapped
+                .iter()
+                .map(|u| format!("{} ({})", u.model, u.item))
+                .collect();
+            bail!(
+                "No model mapping for {} → {}: {}. Add rules to ~/.skrills/models.toml or change its `unmapped` policy",
+                self.source.name(),
+                self.target.name(),
+                models.join(", ")
+            );
+        }
+        report.unmapped_models = unmapped;
+
         // Sync commands
         if params.sync_commands {
             if !target_support.commands {
@@ -242,7 +960,10 @@ impl<S: AgentAdapter, T: AgentAdapter> SyncOrchestrator<S, T> {
                     "Target does not natively support commands; delegating to adapter"
                 );
             }
-            let commands = self.source.read_commands(params.include_marketplace)?;
+            let (commands, policy_skips) = self.apply_policy(
+                ArtifactType::Command,
+                self.source.read_commands(params.include_marketplace)?,
+            );
             // Apply plugin exclusion filter to commands
             let commands: Vec<_> = if params.exclude_plugins.is_empty() {
                 commands
@@ -263,8 +984,16 @@ impl<S: AgentAdapter, T: AgentAdapter> SyncOrchestrator<S, T> {
                 |c| c.source_path.display().to_string(),
             );
             let include_marketplace = params.include_marketplace;
-            report.commands = sync_items(
+            let mut deferred = Deferred::default();
+            let commands = self.filter_conflicts(
                 commands,
+                ArtifactType::Command,
+                params,
+                || self.target.read_commands(include_marketplace),
+                &mut deferred,
+            )?;
+            report.commands = sync_items(
+                commands.clone(),
                 params.force,
                 params.dry_run,
                 params.skip_existing_commands,
@@ -272,6 +1001,23 @@ impl<S: AgentAdapter, T: AgentAdapter> SyncOrchestrator<S, T> {
                 || self.target.read_commands(include_marketplace),
                 |items| self.target.write_commands(items),
             )?;
+            self.record_baselines(
+                &commands,
+                ArtifactType::Command,
+                &report.commands,
+                params.dry_run,
+                || self.target.read_commands(include_marketplace),
+            )?;
+            self.apply_merges(
+                deferred.merges,
+                ArtifactType::Command,
+                params.dry_run,
+                |items| self.target.write_commands(items),
+                || self.target.read_commands(include_marketplace),
+                &mut report.commands,
+            )?;
+            report.commands.skipped.extend(deferred.skipped);
+            report.commands.skipped.extend(policy_skips);
             report.commands.duplicates = cmd_dups;
         }
 
@@ -283,7 +1029,8 @@ impl<S: AgentAdapter, T: AgentAdapter> SyncOrchestrator<S, T> {
                     "Target does not natively support skills; delegating to adapter"
                 );
             }
-            let skills = self.source.read_skills()?;
+            let (skills, policy_skips) =
+                self.apply_policy(ArtifactType::Skill, self.source.read_skills()?);
             // Apply plugin exclusion filter
             let (skills, excluded_count) = if params.exclude_plugins.is_empty() {
                 (skills, 0usize)
@@ -309,11 +1056,36 @@ impl<S: AgentAdapter, T: AgentAdapter> SyncOrchestrator<S, T> {
                 |s| s.name.clone(),
                 |s| s.source_path.display().to_string(),
             );
+            let mut deferred = Deferred::default();
+            let skills = self.filter_conflicts(
+                skills,
+                ArtifactType::Skill,
+                params,
+                || self.target.read_skills(),
+                &mut deferred,
+            )?;
             if !params.dry_run {
                 report.skills = self.target.write_skills(&skills)?;
             } else {
                 report.skills.written = skills.len();
             }
+            self.record_baselines(
+                &skills,
+                ArtifactType::Skill,
+                &report.skills,
+                params.dry_run,
+                || self.target.read_skills(),
+            )?;
+            self.apply_merges(
+                deferred.merges,
+                ArtifactType::Skill,
+                params.dry_run,
+                |items| self.target.write_skills(items),
+                || self.target.read_skills(),
+                &mut report.skills,
+            )?;
+            report.skills.skipped.extend(deferred.skipped);
+            report.skills.skipped.extend(policy_skips);
             report.skills.duplicates = skill_dups;
             // Report excluded plugins as skipped
             for _ in 0..excluded_count {
@@ -330,39 +1102,41 @@ impl<S: AgentAdapter, T: AgentAdapter> SyncOrchestrator<S, T> {
                 );
             }
             let servers = self.source.read_mcp_servers()?;
+            let (mut servers, policy_skips) = match &self.policy {
+                Some(policy) => policy.apply_mcp(servers, self.target.name()),
+                None => (servers, Vec::new()),
+            };
+            report.mcp_secrets = secrets::protect_mcp_servers(
+                &mut servers,
+                self.target.env_syntax(),
+                params.redact_secrets,
+            );
+            for secret in &report.mcp_secrets {
+                if secret.action == SecretAction::Plaintext {
+                    tracing::warn!(
+                        server = %secret.server,
+                        field = %secret.field,
+                        target = %self.target.name(),
+                        "Secret copied in plaintext; target has no env-reference syntax"
+                    );
+                }
+            }
             if !params.dry_run {
                 report.mcp_servers = self.target.write_mcp_servers(&servers)?;
             } else {
                 report.mcp_servers.written = servers.len();
             }
+            report.mcp_servers.skipped.extend(policy_skips);
         }
 
-        // Sync preferences (with model transformation)
-        if params.sync_preferences {
+        // Sync preferences (model mapped above)
+        if let Some(prefs) = prefs {
             if !target_support.preferences {
                 tracing::debug!(
                     target = %self.target.name(),
                     "Target does not natively support preferences; delegating to adapter"
                 );
             }
-            let mut prefs = self.source.read_preferences()?;
-
-            // Transform model name to target platform equivalent
-            if let Some(ref model) = prefs.model {
-                if let Some(transformed) =
-                    transform_model(model, self.source.name(), self.target.name())
-                {
-                    prefs.model = Some(transformed);
-                } else {
-                    tracing::debug!(
-                        model = %model,
-                        source = %self.source.name(),
-                        target = %self.target.name(),
-                        "Unknown model passed through without transformation"
-                    );
-                }
-            }
-
             if !params.dry_run {
                 report.preferences = self.target.write_preferences(&prefs)?;
             } else if prefs.model.is_some() {
@@ -370,33 +1144,47 @@ impl<S: AgentAdapter, T: AgentAdapter> SyncOrchestrator<S, T> {
             }
         }
 
-        // Sync agents (subagents)
-        if params.sync_agents {
+        // Sync agents (subagents, models mapped above)
+        if let Some(agents) = agents {
             if !target_support.agents {
                 tracing::debug!(
                     target = %self.target.name(),
                     "Target does not natively support agents; delegating to adapter"
                 );
             }
-            let agents = self.source.read_agents()?;
-            // Apply plugin exclusion filter to agents
-            let agents: Vec<_> = if params.exclude_plugins.is_empty() {
-                agents
-            } else {
-                agents
-                    .into_iter()
-                    .filter(|a| {
-                        a.plugin_origin
-                            .as_ref()
-                            .is_none_or(|o| !params.is_plugin_excluded(&o.plugin_name))
-                    })
-                    .collect()
+            let mut deferred = Deferred {
+                source_models: agent_models,
+                ..Default::default()
             };
+            let agents = self.filter_conflicts(
+                agents,
+                ArtifactType::Agent,
+                params,
+                || self.target.read_agents(),
+                &mut deferred,
+            )?;
             if !params.dry_run {
                 report.agents = self.target.write_agents(&agents)?;
             } else {
                 report.agents.written = agents.len();
             }
+            self.record_baselines(
+                &agents,
+                ArtifactType::Agent,
+                &report.agents,
+                params.dry_run,
+                || self.target.read_agents(),
+            )?;
+            self.apply_merges(
+                deferred.merges,
+                ArtifactType::Agent,
+                params.dry_run,
+                |items| self.target.write_agents(items),
+                || self.target.read_agents(),
+                &mut report.agents,
+            )?;
+            report.agents.skipped.extend(deferred.skipped);
+            report.agents.skipped.extend(agent_policy_skips);
         }
 
         // Sync hooks (lifecycle events)
@@ -407,12 +1195,38 @@ impl<S: AgentAdapter, T: AgentAdapter> SyncOrchestrator<S, T> {
                     "Target does not natively support hooks; delegating to adapter"
                 );
             }
-            let hooks = self.source.read_hooks()?;
+            let (hooks, policy_skips) =
+                self.apply_policy(ArtifactType::Hook, self.source.read_hooks()?);
+            let mut deferred = Deferred::default();
+            let hooks = self.filter_conflicts(
+                hooks,
+                ArtifactType::Hook,
+                params,
+                || self.target.read_hooks(),
+                &mut deferred,
+            )?;
             if !params.dry_run {
                 report.hooks = self.target.write_hooks(&hooks)?;
             } else {
                 report.hooks.written = hooks.len();
             }
+            self.record_baselines(
+                &hooks,
+                ArtifactType::Hook,
+                &report.hooks,
+                params.dry_run,
+                || self.target.read_hooks(),
+            )?;
+            self.apply_merges(
+                deferred.merges,
+                ArtifactType::Hook,
+                params.dry_run,
+                |items| self.target.write_hooks(items),
+                || self.target.read_hooks(),
+                &mut report.hooks,
+            )?;
+            report.hooks.skipped.extend(deferred.skipped);
+            report.hooks.skipped.extend(policy_skips);
         }
 
         // Sync instructions (CLAUDE.md → *.instructions.md / .cursor/rules/*.mdc)
@@ -423,9 +1237,18 @@ impl<S: AgentAdapter, T: AgentAdapter> SyncOrchestrator<S, T> {
                     "Target does not natively support instructions; delegating to adapter"
                 );
             }
-            let instructions = self.source.read_instructions()?;
-            report.instructions = sync_items(
+            let (instructions, policy_skips) =
+                self.apply_policy(ArtifactType::Instruction, self.source.read_instructions()?);
+            let mut deferred = Deferred::default();
+            let instructions = self.filter_conflicts(
                 instructions,
+                ArtifactType::Instruction,
+                params,
+                || self.target.read_instructions(),
+                &mut deferred,
+            )?;
+            report.instructions = sync_items(
+                instructions.clone(),
                 params.force,
                 params.dry_run,
                 params.skip_existing_instructions,
@@ -433,6 +1256,23 @@ impl<S: AgentAdapter, T: AgentAdapter> SyncOrchestrator<S, T> {
                 || self.target.read_instructions(),
                 |items| self.target.write_instructions(items),
             )?;
+            self.record_baselines(
+                &instructions,
+                ArtifactType::Instruction,
+                &report.instructions,
+                params.dry_run,
+                || self.target.read_instructions(),
+            )?;
+            self.apply_merges(
+                deferred.merges,
+                ArtifactType::Instruction,
+                params.dry_run,
+                |items| self.target.write_instructions(items),
+                || self.target.read_instructions(),
+                &mut report.instructions,
+            )?;
+            report.instructions.skipped.extend(deferred.skipped);
+            report.instructions.skipped.extend(policy_skips);
         }
 
         // Sync plugin assets (scripts, binaries, libraries)
@@ -470,9 +1310,6 @@ impl<S: AgentAdapter, T: AgentAdapter> SyncOrchestrator<S, T> {
             }
         }
 
-        report.success = true;
-        report.summary = report.format_summary(self.source.name(), self.target.name());
-
         Ok(report)
     }
 }
@@ -481,41 +1318,125 @@ impl<S: AgentAdapter, T: AgentAdapter> SyncOrchestrator<S, T> {
 ///
 /// This avoids the combinatorial match arm explosion that occurs when each
 /// (from, to) pair is constructed explicitly.
+///
+/// The orchestrator is backed by the default sync-state ledger
+/// (`~/.skrills/sync-state.json`) so repeated runs can tell source edits,
+/// target edits and true conflicts apart, and snapshots the target under
+/// `~/.skrills/snapshots/` so the run can be undone. The sync policy from
+/// `~/.skrills/sync.toml` (and, in project scope, the repository's
+/// `.skrills/sync.toml`) filters and renames what is synced.
+///
+/// With [`SyncScope::Project`] in `params.scope` both adapters point at the
+/// repository instead of the user-level directories.
 pub fn sync_between(from: &str, to: &str, params: &SyncParams) -> Result<SyncReport> {
-    let source = create_adapter(from)?;
-    let target = create_adapter(to)?;
-    SyncOrchestrator::new(source, target).sync(params)
+    let source = create_adapter_in(from, &params.scope)?;
+    let target = create_adapter_in(to, &params.scope)?;
+    let state = SyncStateStore::load_default()?;
+    SyncOrchestrator::new(source, target)
+        .with_state_store(state)
+        .with_snapshots(SnapshotConfig::default())
+        .with_model_map(ModelMap::load_default()?)
+        .with_policy(SyncPolicy::load_default(&params.scope)?)
+        .sync(params)
 }
 
-/// Validates that a platform name is recognized.
-///
-/// ```
-/// use skrills_sync::orchestrator::is_valid_platform;
+/// Names of the built-in platforms, in canonical order.
+pub const BUILTIN_PLATFORMS: &[&str] = &["claude", "codex", "copilot", "cursor"];
+
+/// Returns every platform that can be used as a sync source or target.
+///
+/// Built-in platforms come first, followed by external adapters registered in
+/// `~/.skrills/adapters.toml` (see [`crate::adapters::external`]). External
+/// adapters whose name shadows a built-in platform are ignored. A malformed
+/// `adapters.toml` is logged and treated as empty so the built-ins keep
+/// working.
+pub fn available_platforms() -> Vec<String> {
+    let mut names: Vec<String> = BUILTIN_PLATFORMS.iter().map(|s| s.to_string()).collect();
+    match crate::adapters::external::load_external_configs() {
+        Ok(configs) => {
+            let mut external: Vec<String> = configs
+                .into_iter()
+                .map(|c| c.name.to_lowercase())
+                .filter(|name| {
+                    if BUILTIN_PLATFORMS.contains(&name.as_str()) {
+                        tracing::warn!(
+                            adapter = %name,
+                            "external adapter shadows a built-in platform; ignoring"
+                        );
+                        false
+                    } else {
+                        !name.is_empty()
+                    }
+                })
+                .collect();
+            external.sort();
+            external.dedup();
+            names.extend(external);
+        }
+        Err(e) => {
+            tracing::warn!(error = %e, "failed to load external adapters");
+        }
+    }
+    names
+}
+
+/// Validates that a platform name is recognized.
+///
+/// Accepts the built-in platforms and any external adapter registered in
+/// `adapters.toml`.
+///
+/// ```
+/// use skrills_sync::orchestrator::is_valid_platform;
 ///
 /// assert!(is_valid_platform("claude"));
 /// assert!(is_valid_platform("cursor"));
 /// assert!(!is_valid_platform("vscode"));
 /// ```
 pub fn is_valid_platform(name: &str) -> bool {
-    matches!(
-        name.to_lowercase().as_str(),
-        "claude" | "codex" | "copilot" | "cursor"
-    )
+    let name = name.to_lowercase();
+    !name.is_empty() && available_platforms().contains(&name)
 }
 
 /// Creates an adapter for the given platform name.
 ///
-/// Returns a boxed `AgentAdapter` for the specified platform.
+/// Returns a boxed `AgentAdapter` for the specified platform. Names that are
+/// not built in are looked up in `adapters.toml`.
 pub fn create_adapter(platform: &str) -> Result<Box<dyn AgentAdapter>> {
+    create_adapter_in(platform, &SyncScope::User)
+}
+
+/// Creates an adapter for the given platform name in `scope`.
+///
+/// Only the built-in platforms have a project layout; external adapters are
+/// rejected in project scope.
+pub fn create_adapter_in(platform: &str, scope: &SyncScope) -> Result<Box<dyn AgentAdapter>> {
+    if let Some(dir) = scope.project_dir() {
+        let dir = dir.to_path_buf();
+        return match platform.to_lowercase().as_str() {
+            "claude" => Ok(Box::new(crate::adapters::ClaudeAdapter::for_project(dir))),
+            "codex" => Ok(Box::new(crate::adapters::CodexAdapter::for_project(dir))),
+            "copilot" => Ok(Box::new(crate::adapters::CopilotAdapter::for_project(dir))),
+            "cursor" => Ok(Box::new(crate::adapters::CursorAdapter::for_project(dir))),
+            _ => bail!(
+                "Platform '{}' does not support project scope. Use one of: {}",
+                platform,
+                BUILTIN_PLATFORMS.join(", ")
+            ),
+        };
+    }
     match platform.to_lowercase().as_str() {
         "claude" => Ok(Box::new(crate::adapters::ClaudeAdapter::new()?)),
         "codex" => Ok(Box::new(crate::adapters::CodexAdapter::new()?)),
         "copilot" => Ok(Box::new(crate::adapters::CopilotAdapter::new()?)),
         "cursor" => Ok(Box::new(crate::adapters::CursorAdapter::new()?)),
-        _ => bail!(
-            "Unknown platform '{}'. Use 'claude', 'codex', 'copilot', or 'cursor'",
-            platform
-        ),
+        _ => match crate::adapters::external::find_external_config(platform)? {
+            Some(config) => Ok(Box::new(crate::adapters::ExternalAdapter::new(config))),
+            None => bail!(
+                "Unknown platform '{}'. Use one of: {}",
+                platform,
+                available_platforms().join(", ")
+            ),
+        },
     }
 }
 
@@ -697,6 +1618,104 @@ mod tests {
         assert!(tgt_config.exists());
     }
 
+    #[test]
+    fn sync_policy_filters_and_renames_items() {
+        let src_dir = tempdir().unwrap();
+        let tgt_dir = tempdir().unwrap();
+        let cmd_dir = src_dir.path().join("commands");
+        fs::create_dir_all(&cmd_dir).unwrap();
+        for name in ["hello", "wip-draft", "deploy"] {
+            fs::write(cmd_dir.join(format!("{name}.md")), "# Command").unwrap();
+        }
+        let policy: SyncPolicy = toml::from_str(
+            r#"
+never_touch = ["commands/deploy"]
+
+[targets.codex.commands]
+exclude = ["wip-*"]
+rename = { "hello" = "greet" }
+"#,
+        )
+        .unwrap();
+
+        let orchestrator = SyncOrchestrator::new(
+            ClaudeAdapter::with_root(src_dir.path().to_path_buf()),
+            CodexAdapter::with_root(tgt_dir.path().to_path_buf()),
+        )
+        .with_policy(policy);
+        let params = SyncParams {
+            sync_mcp_servers: false,
+            sync_preferences: false,
+            sync_skills: false,
+            ..Default::default()
+        };
+        let report = orchestrator.sync(&params).unwrap();
+
+        assert_eq!(report.commands.written, 1);
+        assert!(tgt_dir.path().join("prompts/greet.md").exists());
+        assert!(!tgt_dir.path().join("prompts/hello.md").exists());
+        let rules: Vec<String> = report
+            .policy_exclusions()
+            .iter()
+            .map(|r| r.description())
+            .collect();
+        assert_eq!(rules.len(), 2);
+        assert!(rules
+            .iter()
+            .any(|r| r.contains("[targets.codex.commands] exclude \"wip-*\"")));
+        assert!(rules
+            .iter()
+            .any(|r| r.contains("never_touch \"commands/deploy\"")));
+    }
+
+    #[test]
+    fn sync_mcp_servers_moves_secrets_to_env_references() {
+        use crate::adapters::CursorAdapter;
+
+        let src_dir = tempdir().unwrap();
+        let tgt_dir = tempdir().unwrap();
+        fs::write(
+            src_dir.path().join("settings.json"),
+            r#"{"mcpServers": {"github": {
+                "command": "gh-mcp",
+                "env": {"GITHUB_TOKEN": "ghp_abcdefghijklmnop1234", "OWNER": "${GH_OWNER}"}
+            }}}"#,
+        )
+        .unwrap();
+        let params = SyncParams {
+            sync_commands: false,
+            sync_preferences: false,
+            sync_skills: false,
+            ..Default::default()
+        };
+
+        let orchestrator = SyncOrchestrator::new(
+            ClaudeAdapter::with_root(src_dir.path().to_path_buf()),
+            CursorAdapter::with_root(tgt_dir.path().to_path_buf()),
+        );
+        let report = orchestrator.sync(&params).unwrap();
+        let written = fs::read_to_string(tgt_dir.path().join("mcp.json")).unwrap();
+        assert!(written.contains("${env:GITHUB_TOKEN}"));
+        assert!(written.contains("${env:GH_OWNER}"));
+        assert!(!written.contains("ghp_"));
+        let vars: Vec<_> = report.mcp_secrets.iter().map(|s| &s.variable).collect();
+        assert_eq!(vars, ["GITHUB_TOKEN", "GH_OWNER"]);
+
+        // Codex has no env-reference syntax: redaction leaves the value out.
+        let orchestrator = SyncOrchestrator::new(
+            ClaudeAdapter::with_root(src_dir.path().to_path_buf()),
+            CodexAdapter::with_root(tgt_dir.path().to_path_buf()),
+        );
+        let params = SyncParams {
+            redact_secrets: true,
+            ..params
+        };
+        let report = orchestrator.sync(&params).unwrap();
+        let written = fs::read_to_string(tgt_dir.path().join("config.json")).unwrap();
+        assert!(!written.contains("ghp_"));
+        assert_eq!(report.mcp_secrets[0].action, SecretAction::Omitted);
+    }
+
     #[test]
     fn sync_transforms_model_claude_to_codex() {
         let src_dir = tempdir().unwrap();
@@ -790,6 +1809,194 @@ mod tests {
         assert_eq!(settings["model"], "custom-model-v1");
     }
 
+    #[test]
+    fn unmapped_model_is_reported() {
+        let src_dir = tempdir().unwrap();
+        let tgt_dir = tempdir().unwrap();
+        fs::write(
+            src_dir.path().join("settings.json"),
+            r#"{"model": "custom-model-v1"}"#,
+        )
+        .unwrap();
+
+        let orchestrator = SyncOrchestrator::new(
+            ClaudeAdapter::with_root(src_dir.path().to_path_buf()),
+            CodexAdapter::with_root(tgt_dir.path().to_path_buf()),
+        );
+        let params = SyncParams {
+            sync_commands: false,
+            sync_mcp_servers: false,
+            sync_skills: false,
+            dry_run: true,
+            ..Default::default()
+        };
+
+        let report = orchestrator.sync(&params).unwrap();
+        assert_eq!(
+            report.unmapped_models,
+            vec![UnmappedModel {
+                item: "preferences".into(),
+                model: "custom-model-v1".into(),
+                policy: UnmappedPolicy::Passthrough,
+            }]
+        );
+        assert!(report.summary.contains("custom-model-v1"));
+    }
+
+    #[test]
+    fn unmapped_error_policy_stops_sync_before_writing() {
+        let src_dir = tempdir().unwrap();
+        let tgt_dir = tempdir().unwrap();
+        fs::write(
+            src_dir.path().join("settings.json"),
+            r#"{"model": "custom-model-v1"}"#,
+        )
+        .unwrap();
+        fs::create_dir_all(src_dir.path().join("commands")).unwrap();
+        fs::write(src_dir.path().join("commands/hello.md"), "Hello").unwrap();
+
+        let map = ModelMap {
+            unmapped: UnmappedPolicy::Error,
+            ..Default::default()
+        };
+        let orchestrator = SyncOrchestrator::new(
+            ClaudeAdapter::with_root(src_dir.path().to_path_buf()),
+            CodexAdapter::with_root(tgt_dir.path().to_path_buf()),
+        )
+        .with_model_map(map);
+        let params = SyncParams {
+            sync_skills: false,
+            ..Default::default()
+        };
+
+        let err = orchestrator.sync(&params).unwrap_err();
+        assert!(err.to_string().contains("custom-model-v1"));
+        assert!(!tgt_dir.path().join("config.json").exists());
+        assert!(!tgt_dir.path().join("prompts/hello.md").exists());
+
+        // A dry run still reports the model instead of failing
+        let report = orchestrator
+            .sync(&SyncParams {
+                dry_run: true,
+                ..params
+            })
+            .unwrap();
+        assert_eq!(report.unmapped_models.len(), 1);
+        assert_eq!(report.unmapped_models[0].policy, UnmappedPolicy::Error);
+    }
+
+    #[test]
+    fn agent_frontmatter_model_is_mapped_for_target() {
+        use crate::adapters::CursorAdapter;
+
+        let src_dir = tempdir().unwrap();
+        let tgt_dir = tempdir().unwrap();
+        fs::create_dir_all(src_dir.path().join("agents")).unwrap();
+        fs::write(
+            src_dir.path().join("agents/scout.md"),
+            "---\nname: scout\nmodel: fast\n---\n\nLook around.\n",
+        )
+        .unwrap();
+        fs::write(
+            src_dir.path().join("agents/planner.md"),
+            "---\nname: planner\nmodel: inherit\n---\n\nPlan.\n",
+        )
+        .unwrap();
+
+        let orchestrator = SyncOrchestrator::new(
+            CursorAdapter::with_root(src_dir.path().to_path_buf()),
+            ClaudeAdapter::with_root(tgt_dir.path().to_path_buf()),
+        );
+        let params = SyncParams {
+            sync_commands: false,
+            sync_mcp_servers: false,
+            sync_preferences: false,
+            sync_skills: false,
+            sync_hooks: false,
+            sync_instructions: false,
+            ..Default::default()
+        };
+
+        let report = orchestrator.sync(&params).unwrap();
+        assert_eq!(report.agents.written, 2);
+        assert!(report.unmapped_models.is_empty());
+
+        let scout = fs::read_to_string(tgt_dir.path().join("agents/scout.md")).unwrap();
+        assert!(scout.contains("model: haiku"), "{scout}");
+        let planner = fs::read_to_string(tgt_dir.path().join("agents/planner.md")).unwrap();
+        assert!(!planner.contains("model:"), "{planner}");
+    }
+
+    #[test]
+    fn merged_agent_keeps_each_sides_model() {
+        use crate::adapters::CursorAdapter;
+
+        let src_dir = tempdir().unwrap();
+        let tgt_dir = tempdir().unwrap();
+        let ledger = tgt_dir.path().join("sync-state.json");
+        let src_file = src_dir.path().join("agents/scout.md");
+        let tgt_file = tgt_dir.path().join("agents/scout.md");
+        fs::create_dir_all(src_dir.path().join("agents")).unwrap();
+        fs::write(
+            &src_file,
+            "---\nname: scout\nmodel: fast\n---\n\nintro\nbody\nfooter\n",
+        )
+        .unwrap();
+        let params = SyncParams {
+            sync_commands: false,
+            sync_mcp_servers: false,
+            sync_preferences: false,
+            sync_skills: false,
+            sync_hooks: false,
+            sync_instructions: false,
+            conflict_strategy: Some(ConflictStrategy::Merge),
+            ..Default::default()
+        };
+        let sync = || {
+            SyncOrchestrator::new(
+                CursorAdapter::with_root(src_dir.path().to_path_buf()),
+                ClaudeAdapter::with_root(tgt_dir.path().to_path_buf()),
+            )
+            .with_state_store(SyncStateStore::load(&ledger).unwrap())
+            .sync(&params)
+            .unwrap()
+        };
+        let _ = sync();
+        assert!(fs::read_to_string(&tgt_file)
+            .unwrap()
+            .contains("model: haiku"));
+
+        fs::write(
+            &src_file,
+            "---\nname: scout\nmodel: fast\n---\n\nnew intro\nbody\nfooter\n",
+        )
+        .unwrap();
+        let target = fs::read_to_string(&tgt_file).unwrap();
+        fs::write(&tgt_file, target.replace("footer", "new footer")).unwrap();
+        let report = sync();
+
+        assert!(matches!(
+            report.agents.merges.as_slice(),
+            [MergeOutcome {
+                status: MergeStatus::Merged,
+                ..
+            }]
+        ));
+        let source = fs::read_to_string(&src_file).unwrap();
+        let target = fs::read_to_string(&tgt_file).unwrap();
+        assert!(source.contains("model: fast"), "{source}");
+        assert!(target.contains("model: haiku"), "{target}");
+        for side in [&source, &target] {
+            assert!(side.ends_with("new intro\nbody\nnew footer\n"), "{side}");
+        }
+
+        // Both sides match the recorded baseline: nothing left to merge.
+        let report = sync();
+        assert!(report.agents.merges.is_empty());
+        assert!(report.conflicts().is_empty());
+        assert_eq!(fs::read_to_string(&src_file).unwrap(), source);
+    }
+
     #[test]
     fn skip_existing_instructions_does_not_overwrite() {
         use crate::adapters::CopilotAdapter;
@@ -1043,6 +2250,480 @@ mod tests {
         assert_eq!(report.commands.skipped.len(), 1);
     }
 
+    fn commands_only() -> SyncParams {
+        SyncParams {
+            sync_commands: true,
+            sync_mcp_servers: false,
+            sync_preferences: false,
+            sync_skills: false,
+            sync_agents: false,
+            sync_hooks: false,
+            sync_instructions: false,
+            ..Default::default()
+        }
+    }
+
+    fn sync_with_ledger(
+        src: &std::path::Path,
+        tgt: &std::path::Path,
+        ledger: &std::path::Path,
+        params: &SyncParams,
+    ) -> SyncReport {
+        let store = SyncStateStore::load(ledger).unwrap();
+        SyncOrchestrator::new(
+            ClaudeAdapter::with_root(src.to_path_buf()),
+            CodexAdapter::with_root(tgt.to_path_buf()),
+        )
+        .with_state_store(store)
+        .sync(params)
+        .unwrap()
+    }
+
+    fn snapshot_config(root: &std::path::Path) -> crate::snapshot::SnapshotConfig {
+        crate::snapshot::SnapshotConfig {
+            snapshot_root: root.to_path_buf(),
+            retention_days: 30,
+            enabled: true,
+        }
+    }
+
+    fn sync_with_snapshots(
+        src: &std::path::Path,
+        tgt: &std::path::Path,
+        snapshots: &std::path::Path,
+        params: &SyncParams,
+    ) -> SyncReport {
+        SyncOrchestrator::new(
+            ClaudeAdapter::with_root(src.to_path_buf()),
+            CodexAdapter::with_root(tgt.to_path_buf()),
+        )
+        .with_snapshots(snapshot_config(snapshots))
+        .sync(params)
+        .unwrap()
+    }
+
+    #[test]
+    fn snapshot_taken_before_write_can_undo_the_sync() {
+        use crate::snapshot::{list_snapshots, load_snapshot, restore_snapshot};
+
+        let src_dir = tempdir().unwrap();
+        let tgt_dir = tempdir().unwrap();
+        let snap_dir = tempdir().unwrap();
+        fs::create_dir_all(src_dir.path().join("commands")).unwrap();
+        fs::create_dir_all(tgt_dir.path().join("prompts")).unwrap();
+        fs::write(src_dir.path().join("commands/hello.md"), "# new hello").unwrap();
+        fs::write(src_dir.path().join("commands/added.md"), "# added").unwrap();
+        fs::write(tgt_dir.path().join("prompts/hello.md"), "# old hello").unwrap();
+
+        let report = sync_with_snapshots(
+            src_dir.path(),
+            tgt_dir.path(),
+            snap_dir.path(),
+            &commands_only(),
+        );
+        let id = report.snapshot.clone().expect("sync changed files");
+        assert!(report.summary.contains(&id));
+
+        let manifest = load_snapshot(&snapshot_config(snap_dir.path()), &id).unwrap();
+        assert_eq!(manifest.source_name, "claude");
+        assert_eq!(manifest.target_name, "codex");
+        let paths: Vec<_> = manifest
+            .entries
+            .iter()
+            .map(|e| (e.relative_path.clone(), e.existed_before))
+            .collect();
+        assert_eq!(
+            paths,
+            vec![
+                (std::path::PathBuf::from("prompts/added.md"), false),
+                (std::path::PathBuf::from("prompts/hello.md"), true),
+            ]
+        );
+
+        let restored = restore_snapshot(&snapshot_config(snap_dir.path()), None).unwrap();
+        assert_eq!((restored.restored, restored.deleted), (1, 1));
+        assert_eq!(
+            fs::read_to_string(tgt_dir.path().join("prompts/hello.md")).unwrap(),
+            "# old hello"
+        );
+        assert!(!tgt_dir.path().join("prompts/added.md").exists());
+
+        // A second identical run changes nothing, so no snapshot is kept.
+        let report = sync_with_snapshots(
+            src_dir.path(),
+            tgt_dir.path(),
+            snap_dir.path(),
+            &commands_only(),
+        );
+        assert!(report.snapshot.is_some());
+        let report = sync_with_snapshots(
+            src_dir.path(),
+            tgt_dir.path(),
+            snap_dir.path(),
+            &commands_only(),
+        );
+        assert!(report.snapshot.is_none());
+        assert_eq!(
+            list_snapshots(&snapshot_config(snap_dir.path()))
+                .unwrap()
+                .len(),
+            2
+        );
+    }
+
+    #[test]
+    fn undo_rolls_back_ledger_so_next_sync_reapplies() {
+        use crate::snapshot::{list_snapshots, undo_sync};
+
+        let src_dir = tempdir().unwrap();
+        let tgt_dir = tempdir().unwrap();
+        let snap_dir = tempdir().unwrap();
+        let ledger = tgt_dir.path().join("sync-state.json");
+        fs::create_dir_all(src_dir.path().join("commands")).unwrap();
+        let src_file = src_dir.path().join("commands/hello.md");
+        let tgt_file = tgt_dir.path().join("prompts/hello.md");
+        fs::write(&src_file, "# v1").unwrap();
+
+        let sync = || {
+            SyncOrchestrator::new(
+                ClaudeAdapter::with_root(src_dir.path().to_path_buf()),
+                CodexAdapter::with_root(tgt_dir.path().to_path_buf()),
+            )
+            .with_state_store(SyncStateStore::load(&ledger).unwrap())
+            .with_snapshots(snapshot_config(snap_dir.path()))
+            .sync(&commands_only())
+            .unwrap()
+        };
+        let _ = sync();
+        fs::write(&src_file, "# v2").unwrap();
+        let _ = sync();
+        assert_eq!(fs::read_to_string(&tgt_file).unwrap(), "# v2");
+
+        let mut store = SyncStateStore::load(&ledger).unwrap();
+        let result = undo_sync(&snapshot_config(snap_dir.path()), None, Some(&mut store)).unwrap();
+        assert!(result.ledger_restored);
+        assert_eq!(fs::read_to_string(&tgt_file).unwrap(), "# v1");
+        // The undone snapshot is consumed; the first sync's one remains.
+        assert_eq!(
+            list_snapshots(&snapshot_config(snap_dir.path()))
+                .unwrap()
+                .len(),
+            1
+        );
+
+        // With the ledger rolled back the restored target is not mistaken
+        // for a target edit, so syncing again reapplies the source.
+        let report = sync();
+        assert!(report.conflicts().is_empty());
+        assert_eq!(report.commands.written, 1);
+        assert_eq!(fs::read_to_string(&tgt_file).unwrap(), "# v2");
+    }
+
+    #[test]
+    fn dry_run_takes_no_snapshot() {
+        let src_dir = tempdir().unwrap();
+        let tgt_dir = tempdir().unwrap();
+        let snap_dir = tempdir().unwrap();
+        fs::create_dir_all(src_dir.path().join("commands")).unwrap();
+        fs::write(src_dir.path().join("commands/hello.md"), "# hello").unwrap();
+
+        let params = SyncParams {
+            dry_run: true,
+            ..commands_only()
+        };
+        let report = sync_with_snapshots(src_dir.path(), tgt_dir.path(), snap_dir.path(), &params);
+        assert!(report.snapshot.is_none());
+        assert_eq!(fs::read_dir(snap_dir.path()).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn ledger_records_baseline_after_write() {
+        let src_dir = tempdir().unwrap();
+        let tgt_dir = tempdir().unwrap();
+        let ledger = tgt_dir.path().join("state/sync-state.json");
+        fs::create_dir_all(src_dir.path().join("commands")).unwrap();
+        fs::write(src_dir.path().join("commands/hello.md"), "# v1").unwrap();
+
+        let report = sync_with_ledger(src_dir.path(), tgt_dir.path(), &ledger, &commands_only());
+        assert_eq!(report.commands.written, 1);
+
+        let store = SyncStateStore::load(&ledger).unwrap();
+        let baseline = store
+            .get(&BaselineKey::new(
+                "claude",
+                "codex",
+                ArtifactType::Command,
+                "hello",
+            ))
+            .expect("baseline recorded");
+        let expected = crate::adapters::utils::hash_content(b"# v1");
+        assert_eq!(baseline.source_hash, expected);
+        assert_eq!(baseline.target_hash.as_deref(), Some(expected.as_str()));
+    }
+
+    #[test]
+    fn ledger_dry_run_records_nothing() {
+        let src_dir = tempdir().unwrap();
+        let tgt_dir = tempdir().unwrap();
+        let ledger = tgt_dir.path().join("sync-state.json");
+        fs::create_dir_all(src_dir.path().join("commands")).unwrap();
+        fs::write(src_dir.path().join("commands/hello.md"), "# v1").unwrap();
+
+        let params = SyncParams {
+            dry_run: true,
+            ..commands_only()
+        };
+        let _ = sync_with_ledger(src_dir.path(), tgt_dir.path(), &ledger, &params);
+        assert!(!ledger.exists());
+    }
+
+    #[test]
+    fn ledger_source_only_change_overwrites_target() {
+        let src_dir = tempdir().unwrap();
+        let tgt_dir = tempdir().unwrap();
+        let ledger = tgt_dir.path().join("sync-state.json");
+        fs::create_dir_all(src_dir.path().join("commands")).unwrap();
+        fs::write(src_dir.path().join("commands/hello.md"), "# v1").unwrap();
+        let _ = sync_with_ledger(src_dir.path(), tgt_dir.path(), &ledger, &commands_only());
+
+        fs::write(src_dir.path().join("commands/hello.md"), "# v2").unwrap();
+        let report = sync_with_ledger(src_dir.path(), tgt_dir.path(), &ledger, &commands_only());
+
+        assert_eq!(report.commands.written, 1);
+        assert_eq!(
+            fs::read_to_string(tgt_dir.path().join("prompts/hello.md")).unwrap(),
+            "# v2"
+        );
+    }
+
+    #[test]
+    fn ledger_target_only_change_is_kept() {
+        let src_dir = tempdir().unwrap();
+        let tgt_dir = tempdir().unwrap();
+        let ledger = tgt_dir.path().join("sync-state.json");
+        fs::create_dir_all(src_dir.path().join("commands")).unwrap();
+        fs::write(src_dir.path().join("commands/hello.md"), "# v1").unwrap();
+        let _ = sync_with_ledger(src_dir.path(), tgt_dir.path(), &ledger, &commands_only());
+
+        fs::write(
+            tgt_dir.path().join("prompts/hello.md"),
+            "# edited on target",
+        )
+        .unwrap();
+        let report = sync_with_ledger(src_dir.path(), tgt_dir.path(), &ledger, &commands_only());
+
+        assert_eq!(report.commands.written, 0);
+        assert!(matches!(
+            report.commands.skipped.as_slice(),
+            [SkipReason::TargetModified { item }] if item == "hello"
+        ));
+        assert_eq!(
+            fs::read_to_string(tgt_dir.path().join("prompts/hello.md")).unwrap(),
+            "# edited on target"
+        );
+    }
+
+    #[test]
+    fn ledger_both_changed_is_reported_as_conflict() {
+        let src_dir = tempdir().unwrap();
+        let tgt_dir = tempdir().unwrap();
+        let ledger = tgt_dir.path().join("sync-state.json");
+        fs::create_dir_all(src_dir.path().join("commands")).unwrap();
+        fs::write(src_dir.path().join("commands/hello.md"), "# v1").unwrap();
+        let _ = sync_with_ledger(src_dir.path(), tgt_dir.path(), &ledger, &commands_only());
+
+        fs::write(src_dir.path().join("commands/hello.md"), "# source edit").unwrap();
+        fs::write(tgt_dir.path().join("prompts/hello.md"), "# target edit").unwrap();
+        let report = sync_with_ledger(src_dir.path(), tgt_dir.path(), &ledger, &commands_only());
+
+        assert_eq!(report.commands.written, 0);
+        assert!(matches!(
+            report.commands.skipped.as_slice(),
+            [SkipReason::Conflict { item, resolution: Resolution::Skip }] if item == "hello"
+        ));
+        assert_eq!(
+            fs::read_to_string(tgt_dir.path().join("prompts/hello.md")).unwrap(),
+            "# target edit"
+        );
+
+        // ForceSource resolves the conflict in favor of the source.
+        let params = SyncParams {
+            conflict_strategy: Some(ConflictStrategy::ForceSource),
+            ..commands_only()
+        };
+        let report = sync_with_ledger(src_dir.path(), tgt_dir.path(), &ledger, &params);
+        assert_eq!(report.commands.written, 1);
+        assert_eq!(
+            fs::read_to_string(tgt_dir.path().join("prompts/hello.md")).unwrap(),
+            "# source edit"
+        );
+    }
+
+    #[test]
+    fn explicit_conflict_strategy_overrides_policy() {
+        let dir = tempdir().unwrap();
+        let orchestrator = || {
+            SyncOrchestrator::new(
+                ClaudeAdapter::with_root(dir.path().join("src")),
+                CodexAdapter::with_root(dir.path().join("tgt")),
+            )
+        };
+        let policy: SyncPolicy =
+            toml::from_str("[targets.codex]\nconflict_strategy = \"merge\"\n").unwrap();
+        let skip_all = SyncParams {
+            conflict_strategy: Some(ConflictStrategy::SkipAll),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            orchestrator().conflict_strategy(&SyncParams::default()),
+            ConflictStrategy::SkipAll
+        );
+        let with_policy = orchestrator().with_policy(policy);
+        assert_eq!(
+            with_policy.conflict_strategy(&SyncParams::default()),
+            ConflictStrategy::Merge
+        );
+        assert_eq!(
+            with_policy.conflict_strategy(&skip_all),
+            ConflictStrategy::SkipAll
+        );
+    }
+
+    fn merge_params() -> SyncParams {
+        SyncParams {
+            conflict_strategy: Some(ConflictStrategy::Merge),
+            ..commands_only()
+        }
+    }
+
+    #[test]
+    fn ledger_merge_combines_disjoint_edits_on_both_sides() {
+        let src_dir = tempdir().unwrap();
+        let tgt_dir = tempdir().unwrap();
+        let ledger = tgt_dir.path().join("sync-state.json");
+        let src_file = src_dir.path().join("commands/hello.md");
+        let tgt_file = tgt_dir.path().join("prompts/hello.md");
+        fs::create_dir_all(src_dir.path().join("commands")).unwrap();
+        fs::write(&src_file, "# Hello\nintro\nbody\nfooter\n").unwrap();
+        let _ = sync_with_ledger(src_dir.path(), tgt_dir.path(), &ledger, &merge_params());
+
+        fs::write(&src_file, "# Hello\nnew intro\nbody\nfooter\n").unwrap();
+        fs::write(&tgt_file, "# Hello\nintro\nbody\nnew footer\n").unwrap();
+        let report = sync_with_ledger(src_dir.path(), tgt_dir.path(), &ledger, &merge_params());
+
+        let merged = "# Hello\nnew intro\nbody\nnew footer\n";
+        assert_eq!(report.commands.written, 1);
+        assert!(matches!(
+            report.commands.merges.as_slice(),
+            [MergeOutcome { item, status: MergeStatus::Merged, conflicts: 0 }] if item == "hello"
+        ));
+        assert_eq!(fs::read_to_string(&tgt_file).unwrap(), merged);
+        assert_eq!(fs::read_to_string(&src_file).unwrap(), merged);
+
+        // Both sides now match the recorded baseline: nothing left to merge.
+        let report = sync_with_ledger(src_dir.path(), tgt_dir.path(), &ledger, &merge_params());
+        assert!(report.commands.merges.is_empty());
+        assert!(report.conflicts().is_empty());
+    }
+
+    #[test]
+    fn ledger_merge_conflict_writes_markers_until_resolved() {
+        let src_dir = tempdir().unwrap();
+        let tgt_dir = tempdir().unwrap();
+        let ledger = tgt_dir.path().join("sync-state.json");
+        let src_file = src_dir.path().join("commands/hello.md");
+        let tgt_file = tgt_dir.path().join("prompts/hello.md");
+        fs::create_dir_all(src_dir.path().join("commands")).unwrap();
+        fs::write(&src_file, "# Hello\nbody\n").unwrap();
+        let _ = sync_with_ledger(src_dir.path(), tgt_dir.path(), &ledger, &merge_params());
+
+        fs::write(&src_file, "# Hello\nsource body\n").unwrap();
+        fs::write(&tgt_file, "# Hello\ntarget body\n").unwrap();
+        let report = sync_with_ledger(src_dir.path(), tgt_dir.path(), &ledger, &merge_params());
+
+        assert!(matches!(
+            report.commands.merges.as_slice(),
+            [MergeOutcome {
+                status: MergeStatus::Conflicted,
+                conflicts: 1,
+                ..
+            }]
+        ));
+        assert_eq!(
+            fs::read_to_string(&tgt_file).unwrap(),
+            "# Hello\n<<<<<<< source\nsource body\n=======\ntarget body\n>>>>>>> target\n"
+        );
+        assert_eq!(
+            fs::read_to_string(&src_file).unwrap(),
+            "# Hello\nsource body\n"
+        );
+
+        // The marker file is never overwritten by a later sync.
+        let report = sync_with_ledger(src_dir.path(), tgt_dir.path(), &ledger, &commands_only());
+        assert!(matches!(
+            report.commands.skipped.as_slice(),
+            [SkipReason::UnresolvedMerge { item }] if item == "hello"
+        ));
+
+        // Resolving the markers on the target reads as a target-only edit.
+        fs::write(&tgt_file, "# Hello\nresolved body\n").unwrap();
+        let report = sync_with_ledger(src_dir.path(), tgt_dir.path(), &ledger, &merge_params());
+        assert!(matches!(
+            report.commands.skipped.as_slice(),
+            [SkipReason::TargetModified { item }] if item == "hello"
+        ));
+        assert_eq!(
+            fs::read_to_string(&tgt_file).unwrap(),
+            "# Hello\nresolved body\n"
+        );
+    }
+
+    #[test]
+    fn ledger_merge_dry_run_reports_without_writing() {
+        let src_dir = tempdir().unwrap();
+        let tgt_dir = tempdir().unwrap();
+        let ledger = tgt_dir.path().join("sync-state.json");
+        let tgt_file = tgt_dir.path().join("prompts/hello.md");
+        fs::create_dir_all(src_dir.path().join("commands")).unwrap();
+        fs::write(src_dir.path().join("commands/hello.md"), "a\nb\nc\n").unwrap();
+        let _ = sync_with_ledger(src_dir.path(), tgt_dir.path(), &ledger, &merge_params());
+
+        fs::write(src_dir.path().join("commands/hello.md"), "A\nb\nc\n").unwrap();
+        fs::write(&tgt_file, "a\nb\nC\n").unwrap();
+        let params = SyncParams {
+            dry_run: true,
+            ..merge_params()
+        };
+        let report = sync_with_ledger(src_dir.path(), tgt_dir.path(), &ledger, &params);
+
+        assert_eq!(report.commands.merges.len(), 1);
+        assert_eq!(report.commands.merges[0].status, MergeStatus::Merged);
+        assert_eq!(fs::read_to_string(&tgt_file).unwrap(), "a\nb\nC\n");
+    }
+
+    #[test]
+    fn ledger_force_bypasses_conflict_detection() {
+        let src_dir = tempdir().unwrap();
+        let tgt_dir = tempdir().unwrap();
+        let ledger = tgt_dir.path().join("sync-state.json");
+        fs::create_dir_all(src_dir.path().join("commands")).unwrap();
+        fs::write(src_dir.path().join("commands/hello.md"), "# v1").unwrap();
+        let _ = sync_with_ledger(src_dir.path(), tgt_dir.path(), &ledger, &commands_only());
+
+        fs::write(tgt_dir.path().join("prompts/hello.md"), "# target edit").unwrap();
+        let params = SyncParams {
+            force: true,
+            ..commands_only()
+        };
+        let report = sync_with_ledger(src_dir.path(), tgt_dir.path(), &ledger, &params);
+        assert_eq!(report.commands.written, 1);
+        assert_eq!(
+            fs::read_to_string(tgt_dir.path().join("prompts/hello.md")).unwrap(),
+            "# v1"
+        );
+    }
+
     #[test]
     fn create_adapter_all_platforms() {
         // These only verify the adapter constructs; the actual root may not exist
@@ -1053,4 +2734,201 @@ mod tests {
         assert_eq!(create_adapter("cursor").unwrap().name(), "cursor");
         assert!(create_adapter("vscode").is_err());
     }
+
+    #[test]
+    fn create_adapter_resolves_external_adapters() {
+        let home = tempdir().unwrap();
+        let config_path = home.path().join("adapters.toml");
+        let windsurf_root = home.path().join("windsurf");
+        fs::write(
+            &config_path,
+            format!(
+                "[adapter.windsurf]\nname = \"windsurf\"\nconfig_root = \"{}\"\nskills_dir = \"skills\"\n",
+                windsurf_root.display()
+            ),
+        )
+        .unwrap();
+
+        let _lock = skrills_test_utils::env_guard();
+        let _env = skrills_test_utils::set_env_var("SKRILLS_ADAPTERS_CONFIG", config_path.to_str());
+
+        assert!(is_valid_platform("windsurf"));
+        assert!(!is_valid_platform("aider"));
+        assert_eq!(
+            available_platforms(),
+            vec!["claude", "codex", "copilot", "cursor", "windsurf"]
+        );
+
+        let adapter = create_adapter("windsurf").unwrap();
+        assert_eq!(adapter.name(), "windsurf");
+        assert_eq!(adapter.config_root(), windsurf_root);
+        assert!(adapter.supported_fields().skills);
+
+        let err = create_adapter("aider").err().unwrap().to_string();
+        assert!(
+            err.contains("windsurf"),
+            "error should list known platforms: {err}"
+        );
+    }
+
+    #[test]
+    fn external_adapter_shadowing_builtin_is_ignored() {
+        let home = tempdir().unwrap();
+        let config_path = home.path().join("adapters.toml");
+        fs::write(
+            &config_path,
+            "[adapter.claude]\nname = \"claude\"\nconfig_root = \"/tmp/not-claude\"\n",
+        )
+        .unwrap();
+
+        let _lock = skrills_test_utils::env_guard();
+        let _env = skrills_test_utils::set_env_var("SKRILLS_ADAPTERS_CONFIG", config_path.to_str());
+
+        assert_eq!(available_platforms(), BUILTIN_PLATFORMS);
+    }
+
+    #[test]
+    fn sync_skills_from_claude_to_external_adapter() {
+        use crate::adapters::external::{ExternalAdapter, ExternalAdapterConfig, SkillFormat};
+
+        let src_dir = tempdir().unwrap();
+        let tgt_dir = tempdir().unwrap();
+        let skill_dir = src_dir.path().join("skills/hello");
+        fs::create_dir_all(&skill_dir).unwrap();
+        fs::write(
+            skill_dir.join("SKILL.md"),
+            "---\nname: hello\ndescription: Says hello\n---\n# Hello",
+        )
+        .unwrap();
+
+        let source = ClaudeAdapter::with_root(src_dir.path().to_path_buf());
+        let target = ExternalAdapter::new(ExternalAdapterConfig {
+            name: "windsurf".to_string(),
+            config_root: tgt_dir.path().display().to_string(),
+            skills_dir: Some("skills".to_string()),
+            commands_dir: None,
+            rules_dir: None,
+            mcp_config: None,
+            skill_format: SkillFormat::Markdown,
+        });
+
+        let params = SyncParams {
+            sync_commands: false,
+            sync_mcp_servers: false,
+            sync_preferences: false,
+            sync_skills: true,
+            ..Default::default()
+        };
+        let report = SyncOrchestrator::new(source, target).sync(&params).unwrap();
+
+        assert_eq!(report.skills.written, 1);
+        assert!(tgt_dir.path().join("skills/hello.md").exists());
+    }
+
+    #[test]
+    fn project_scope_syncs_repository_directories() {
+        let repo = tempdir().unwrap();
+        let ledger = repo.path().join("state/sync-state.json");
+        fs::create_dir_all(repo.path().join(".claude/commands")).unwrap();
+        fs::write(repo.path().join(".claude/commands/hello.md"), "# hello").unwrap();
+        fs::write(repo.path().join("CLAUDE.md"), "# Repo rules").unwrap();
+
+        let scope = SyncScope::Project {
+            dir: repo.path().to_path_buf(),
+        };
+        let params = SyncParams {
+            sync_instructions: true,
+            ..commands_only()
+        };
+        let report = SyncOrchestrator::new(
+            create_adapter_in("claude", &scope).unwrap(),
+            create_adapter_in("codex", &scope).unwrap(),
+        )
+        .with_state_store(SyncStateStore::load(&ledger).unwrap())
+        .sync(&params)
+        .unwrap();
+
+        assert_eq!(report.commands.written, 1);
+        assert_eq!(report.instructions.written, 1);
+        assert!(repo.path().join(".codex/prompts/hello.md").exists());
+        assert_eq!(
+            fs::read_to_string(repo.path().join("AGENTS.md")).unwrap(),
+            "<!-- skrills:begin repo-rules -->\n# Repo rules\n<!-- skrills:end repo-rules -->\n"
+        );
+
+        let store = SyncStateStore::load(&ledger).unwrap();
+        let qualified = |name: &str| format!("{}@{}", name, repo.path().display());
+        assert!(store
+            .get(&BaselineKey::new(
+                &qualified("claude"),
+                &qualified("codex"),
+                ArtifactType::Command,
+                "hello",
+            ))
+            .is_some());
+        assert!(store
+            .get(&BaselineKey::new(
+                "claude",
+                "codex",
+                ArtifactType::Command,
+                "hello"
+            ))
+            .is_none());
+    }
+
+    #[test]
+    fn project_scope_writes_copilot_repository_instructions() {
+        let repo = tempdir().unwrap();
+        fs::write(repo.path().join("CLAUDE.md"), "# Repo rules").unwrap();
+
+        let scope = SyncScope::Project {
+            dir: repo.path().to_path_buf(),
+        };
+        let params = SyncParams {
+            sync_commands: false,
+            sync_instructions: true,
+            ..commands_only()
+        };
+        let report = sync_between_in_scope("claude", "copilot", &scope, &params);
+
+        assert_eq!(report.instructions.written, 1);
+        assert!(
+            fs::read_to_string(repo.path().join(".github/copilot-instructions.md"))
+                .unwrap()
+                .contains("# Repo rules\n<!-- skrills:end repo-rules -->")
+        );
+    }
+
+    fn sync_between_in_scope(
+        from: &str,
+        to: &str,
+        scope: &SyncScope,
+        params: &SyncParams,
+    ) -> SyncReport {
+        SyncOrchestrator::new(
+            create_adapter_in(from, scope).unwrap(),
+            create_adapter_in(to, scope).unwrap(),
+        )
+        .sync(params)
+        .unwrap()
+    }
+
+    #[test]
+    fn sync_scope_resolves_project_directory() {
+        let repo = tempdir().unwrap();
+        let dir = repo.path().to_path_buf();
+
+        assert_eq!(SyncScope::resolve(None, None).unwrap(), SyncScope::User);
+        assert_eq!(
+            SyncScope::resolve(None, Some(dir.clone())).unwrap(),
+            SyncScope::Project { dir: dir.clone() }
+        );
+        assert!(SyncScope::resolve(Some("user"), Some(dir.clone())).is_err());
+        assert!(SyncScope::resolve(Some("project"), Some(dir.join("missing"))).is_err());
+        assert!(create_adapter_in("windsurf", &SyncScope::Project { dir })
+            .err()
+            .unwrap()
+            .to_string()
+            .contains("does not support project scope"));
+    }
 }
@@ -0,0 +1,885 @@
+//! Reviewable sync plans.
+//!
+//! [`plan_sync`] runs a sync against a staged copy of the target's
+//! [`managed_paths`](AgentAdapter::managed_paths) and records every file the
+//! run would create, modify or delete as a [`ChangeSet`]. Each change carries
+//! its path, before/after hashes, diff and new content. The plan also records
+//! the ledger baselines the run would leave behind. Plans serialize to JSON
+//! so they can be reviewed (e.g. in a pull request) before
+//! [`SyncPlan::apply`] writes exactly those files. Apply refuses to run if any
+//! target file changed since the plan was made.
+//!
+//! Planning writes nothing outside the stage: merge results the sync would
+//! write back to the source are recorded as source changes instead. A plan
+//! file is not trusted on apply: roots are recomputed from the adapters and
+//! every path must stay inside the adapter's managed paths.
+
+use crate::adapters::utils::hash_content;
+use crate::adapters::{AgentAdapter, ClaudeAdapter, CodexAdapter, CopilotAdapter, CursorAdapter};
+use crate::models::ModelMap;
+use crate::orchestrator::{
+    create_adapter_in, scoped_name, SourceWrite, SyncOrchestrator, SyncParams, SyncScope,
+};
+use crate::policy::SyncPolicy;
+use crate::preview::{
+    compute_diff, ArtifactKind, ChangeSet, FileChange, FileContent, PendingChange,
+};
+use crate::snapshot::{
+    collect_managed_files, create_snapshot, finalize_snapshot, prune_snapshots, save_baselines,
+    SnapshotConfig,
+};
+use crate::state::{default_state_path, StoredEntry, SyncStateStore};
+use crate::Result;
+use anyhow::{bail, Context};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+use time::format_description::well_known::Rfc3339;
+use time::OffsetDateTime;
+
+/// Version of the plan file format.
+pub const PLAN_VERSION: u32 = 1;
+
+/// A sync computed ahead of time, to be reviewed and applied later.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SyncPlan {
+    /// Plan file format version.
+    pub version: u32,
+    /// RFC 3339 time the plan was made.
+    pub created_at: String,
+    /// Source adapter as recorded in the ledger (e.g. `claude@/repo`).
+    pub source: String,
+    /// Target adapter as recorded in the ledger.
+    pub target: String,
+    /// Directory the change paths are relative to, for review. Apply uses
+    /// the target adapter's root and refuses a plan made for another one.
+    pub target_root: PathBuf,
+    /// Summary of the planned sync, as `sync` would print it.
+    pub summary: String,
+    /// Files the sync would create, modify or delete.
+    pub changes: ChangeSet,
+    /// Directory the source change paths are relative to.
+    #[serde(default)]
+    pub source_root: PathBuf,
+    /// Source files the sync would update with merge results.
+    #[serde(default)]
+    pub source_changes: ChangeSet,
+    /// Ledger baselines of the pair after the sync.
+    pub baselines: Vec<StoredEntry>,
+}
+
+/// Outcome of [`SyncPlan::apply`].
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AppliedPlan {
+    /// Files written or created.
+    pub written: usize,
+    /// Files deleted.
+    pub deleted: usize,
+    /// Snapshot that restores the target with `sync-undo`.
+    pub snapshot: Option<String>,
+}
+
+impl SyncPlan {
+    /// Returns true when the plan changes nothing.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty() && self.source_changes.is_empty()
+    }
+
+    /// Writes the plan as pretty-printed JSON.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let json = serde_json::to_string_pretty(self).context("Failed to serialize sync plan")?;
+        fs::write(path, json)
+            .with_context(|| format!("Failed to write sync plan: {}", path.display()))
+    }
+
+    /// Reads a plan written by [`Self::save`].
+    pub fn load(path: &Path) -> Result<Self> {
+        let raw = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read sync plan: {}", path.display()))?;
+        let plan: Self = serde_json::from_str(&raw)
+            .with_context(|| format!("Failed to parse sync plan: {}", path.display()))?;
+        if plan.version > PLAN_VERSION {
+            bail!(
+                "Sync plan {} has version {}, newer than supported version {}",
+                path.display(),
+                plan.version,
+                PLAN_VERSION
+            );
+        }
+        Ok(plan)
+    }
+
+    /// One line per changed file, for review.
+    pub fn format_changes(&self) -> String {
+        let mut out = String::new();
+        let tagged = files(&self.changes)
+            .map(|file| (file, ""))
+            .chain(files(&self.source_changes).map(|file| (file, " (source)")));
+        for (file, tag) in tagged {
+            let marker = match (&file.hash_before, &file.hash_after) {
+                (None, _) => '+',
+                (_, None) => '-',
+                _ => '~',
+            };
+            out.push_str(&format!("  {} {}{}\n", marker, file.path.display(), tag));
+        }
+        out
+    }
+
+    /// Applies the plan to the adapters it names, using the default ledger
+    /// and snapshot location.
+    pub fn apply(&self) -> Result<AppliedPlan> {
+        let source = adapter_named(&self.source)?;
+        let target = adapter_named(&self.target)?;
+        let mut store = SyncStateStore::load_default()?;
+        self.apply_with(&source, &target, &mut store, &SnapshotConfig::default())
+    }
+
+    /// Writes exactly the planned files into `target` (and merge results
+    /// into `source`), then records the planned baselines in `store`.
+    ///
+    /// The plan file is not trusted: paths are resolved against each
+    /// adapter's managed root, never the plan's. Nothing is written if the
+    /// plan names other adapters or roots, if any path is absolute, climbs
+    /// out of the root or lies outside the adapter's
+    /// [`managed_paths`](AgentAdapter::managed_paths), or if any file
+    /// drifted since planning. The target is snapshotted first when
+    /// `snapshots` is enabled, so the apply can be undone like any other
+    /// sync.
+    pub fn apply_with(
+        &self,
+        source: &impl AgentAdapter,
+        target: &impl AgentAdapter,
+        store: &mut SyncStateStore,
+        snapshots: &SnapshotConfig,
+    ) -> Result<AppliedPlan> {
+        let target_root = checked_root(target, &self.target, &self.target_root, &self.changes)?;
+        let source_root = if self.source_changes.is_empty() {
+            source.managed_root()
+        } else {
+            checked_root(
+                source,
+                &self.source,
+                &self.source_root,
+                &self.source_changes,
+            )?
+        };
+
+        let drifted: Vec<String> = drifted(&target_root, &self.changes)
+            .chain(drifted(&source_root, &self.source_changes))
+            .map(|p| p.display().to_string())
+            .collect();
+        if !drifted.is_empty() {
+            bail!(
+                "Refusing to apply: {} file(s) changed since the plan was made: {}. Create a new plan",
+                drifted.len(),
+                drifted.join(", ")
+            );
+        }
+
+        let mut applied = AppliedPlan::default();
+        if self.is_empty() {
+            return Ok(applied);
+        }
+
+        let paths: Vec<PathBuf> = files(&self.changes).map(|f| f.path.clone()).collect();
+        let snapshot = if snapshots.enabled {
+            let existing: Vec<PathBuf> = paths
+                .iter()
+                .filter(|p| target_root.join(p).is_file())
+                .cloned()
+                .collect();
+            let dir = create_snapshot(
+                snapshots,
+                &self.source,
+                &self.target,
+                &target_root,
+                &existing,
+            )?;
+            save_baselines(&dir, &store.pair_entries(&self.source, &self.target))?;
+            Some(dir)
+        } else {
+            None
+        };
+
+        write_files(&target_root, &self.changes, &mut applied)?;
+        write_files(&source_root, &self.source_changes, &mut applied)?;
+
+        store.replace_pair(&self.source, &self.target, self.baselines.clone());
+        store.save()?;
+
+        if let Some(dir) = snapshot {
+            if finalize_snapshot(&dir, &paths)? > 0 {
+                applied.snapshot = dir.file_name().map(|n| n.to_string_lossy().into_owned());
+            }
+            if let Err(e) = prune_snapshots(snapshots) {
+                tracing::warn!(error = %e, "Failed to prune old sync snapshots");
+            }
+        }
+        Ok(applied)
+    }
+}
+
+fn files(changes: &ChangeSet) -> impl Iterator<Item = &FileChange> {
+    changes.changes.iter().filter_map(|c| c.file.as_ref())
+}
+
+/// Files under `root` whose current hash no longer matches the plan's
+/// before-hash.
+fn drifted<'a>(root: &'a Path, changes: &'a ChangeSet) -> impl Iterator<Item = PathBuf> + 'a {
+    files(changes)
+        .filter(|file| current_hash(&root.join(&file.path)) != file.hash_before)
+        .map(|file| file.path.clone())
+}
+
+/// The adapter a plan recorded as `name` (`codex` or `codex@/repo`).
+fn adapter_named(name: &str) -> Result<Box<dyn AgentAdapter>> {
+    match name.split_once('@') {
+        Some((platform, dir)) => create_adapter_in(
+            platform,
+            &SyncScope::Project {
+                dir: PathBuf::from(dir),
+            },
+        ),
+        None => create_adapter_in(name, &SyncScope::User),
+    }
+}
+
+/// The managed root of `adapter`, after checking that the plan recorded the
+/// same adapter and root and that every changed path is a managed file.
+fn checked_root(
+    adapter: &impl AgentAdapter,
+    name: &str,
+    root: &Path,
+    changes: &ChangeSet,
+) -> Result<PathBuf> {
+    let actual = adapter.managed_root();
+    if scoped_name(adapter) != name || actual != root {
+        bail!(
+            "Refusing to apply: the plan names {} at {}, not {} at {}",
+            name,
+            root.display(),
+            scoped_name(adapter),
+            actual.display()
+        );
+    }
+    let managed = adapter.managed_paths();
+    let outside: Vec<String> = files(changes)
+        .filter(|file| !is_managed(&file.path, &managed))
+        .map(|file| file.path.display().to_string())
+        .collect();
+    if !outside.is_empty() {
+        bail!(
+            "Refusing to apply: {} path(s) are outside the {} managed files: {}",
+            outside.len(),
+            adapter.name(),
+            outside.join(", ")
+        );
+    }
+    Ok(actual)
+}
+
+/// Writes or deletes each file of `changes` under `root`.
+fn write_files(root: &Path, changes: &ChangeSet, applied: &mut AppliedPlan) -> Result<()> {
+    for file in files(changes) {
+        let path = root.join(&file.path);
+        match &file.content {
+            Some(content) => {
+                if let Some(parent) = path.parent() {
+                    fs::create_dir_all(parent)?;
+                }
+                fs::write(&path, content.decode()?)
+                    .with_context(|| format!("Failed to write {}", path.display()))?;
+                applied.written += 1;
+            }
+            None => {
+                fs::remove_file(&path)
+                    .with_context(|| format!("Failed to delete {}", path.display()))?;
+                applied.deleted += 1;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Plans a sync from `from` to `to` without touching the target.
+///
+/// Uses the same ledger, model map and sync policy as
+/// [`sync_between`](crate::orchestrator::sync_between). `params.dry_run` is
+/// ignored: the sync really runs, but against a staged copy. Only the
+/// built-in platforms can be planned.
+pub fn plan_sync(from: &str, to: &str, params: &SyncParams) -> Result<SyncPlan> {
+    let source = create_adapter_in(from, &params.scope)?;
+    let target = create_adapter_in(to, &params.scope)?;
+    let stage = Stage::create()?;
+    let staged = staged_adapter(to, &params.scope, &stage.root())?;
+    let ledger = SyncStateStore::load(&default_state_path()?)?;
+    let models = ModelMap::load_default()?;
+    let policy = SyncPolicy::load_default(&params.scope)?;
+
+    plan_staged(
+        source,
+        &target,
+        staged,
+        &stage,
+        &ledger,
+        |orchestrator| orchestrator.with_model_map(models).with_policy(policy),
+        params,
+    )
+}
+
+/// Plans a sync from `source` to `target` by syncing into `staged`, an
+/// adapter of the same platform rooted at `stage.root()`.
+fn plan_staged<S: AgentAdapter, T: AgentAdapter>(
+    source: S,
+    target: &impl AgentAdapter,
+    staged: T,
+    stage: &Stage,
+    ledger: &SyncStateStore,
+    configure: impl FnOnce(SyncOrchestrator<S, T>) -> SyncOrchestrator<S, T>,
+    params: &SyncParams,
+) -> Result<SyncPlan> {
+    let root = target.managed_root();
+    let managed = target.managed_paths();
+    stage.copy_from(&root, &managed)?;
+    let source_root = source.managed_root();
+    let source_managed = source.managed_paths();
+
+    // Merge results meant for the source are held back and planned as
+    // source changes, so planning writes nothing outside the stage.
+    // The staged target is recorded under its own name in a scratch ledger
+    // seeded with the real pair's baselines.
+    let source_name = scoped_name(&source);
+    let target_name = scoped_name(target);
+    let staged_name = scoped_name(&staged);
+    let mut scratch = SyncStateStore::load(&stage.ledger_path())?;
+    scratch.replace_pair(
+        &source_name,
+        &staged_name,
+        rekey(
+            ledger.pair_entries(&source_name, &target_name),
+            &staged_name,
+        ),
+    );
+
+    let params = SyncParams {
+        dry_run: false,
+        ..params.clone()
+    };
+    let orchestrator = configure(
+        SyncOrchestrator::new(source, staged)
+            .with_state_store(scratch)
+            .holding_source_writes(),
+    );
+    let report = orchestrator.sync(&params)?;
+    let held = orchestrator.take_held_source_writes();
+    let scratch = orchestrator
+        .into_state_store()
+        .context("Staged sync lost its ledger")?;
+
+    Ok(SyncPlan {
+        version: PLAN_VERSION,
+        created_at: OffsetDateTime::now_utc()
+            .format(&Rfc3339)
+            .unwrap_or_default(),
+        source: source_name.clone(),
+        target: target_name.clone(),
+        changes: diff_trees(&root, &stage.root(), &managed)?,
+        target_root: root,
+        summary: report.summary,
+        source_changes: source_changes(held, &source_root, &source_managed)?,
+        source_root,
+        baselines: rekey(
+            scratch.pair_entries(&source_name, &staged_name),
+            &target_name,
+        ),
+    })
+}
+
+/// Changes that turn the files under `managed` in `before` into those in
+/// `after`.
+pub(crate) fn diff_trees(before: &Path, after: &Path, managed: &[PathBuf]) -> Result<ChangeSet> {
+    let paths: BTreeSet<PathBuf> = collect_managed_files(before, managed)
+        .into_iter()
+        .chain(collect_managed_files(after, managed))
+        .collect();
+
+    let mut changes = ChangeSet::new();
+    for rel in paths {
+        let old = read_optional(&before.join(&rel))?;
+        let new = read_optional(&after.join(&rel))?;
+        if old != new {
+            changes.push(file_change(rel, old, new));
+        }
+    }
+    Ok(changes)
+}
+
+/// The change that turns `old` into `new` at `rel`.
+fn file_change(rel: PathBuf, old: Option<Vec<u8>>, new: Option<Vec<u8>>) -> PendingChange {
+    let is_binary = [&old, &new]
+        .into_iter()
+        .flatten()
+        .any(|bytes| std::str::from_utf8(bytes).is_err());
+    let diff_text = if is_binary {
+        "(binary file)".to_string()
+    } else {
+        compute_diff(
+            old.as_deref().unwrap_or_default(),
+            new.as_deref().unwrap_or_default(),
+        )
+    };
+    PendingChange {
+        kind: kind_of(&rel),
+        name: rel.display().to_string(),
+        diff_text,
+        is_new: old.is_none(),
+        file: Some(FileChange {
+            hash_before: old.as_deref().map(hash_content),
+            hash_after: new.as_deref().map(hash_content),
+            content: new.as_deref().map(FileContent::encode),
+            path: rel,
+        }),
+    }
+}
+
+/// Source changes for the merge results a staged sync held back.
+fn source_changes(
+    held: Vec<SourceWrite>,
+    source_root: &Path,
+    managed: &[PathBuf],
+) -> Result<ChangeSet> {
+    let mut changes = ChangeSet::new();
+    for (path, content) in held {
+        let rel = path
+            .strip_prefix(source_root)
+            .ok()
+            .filter(|rel| is_managed(rel, managed))
+            .with_context(|| {
+                format!(
+                    "Cannot plan a merge into {}: it is outside the source's managed files",
+                    path.display()
+                )
+            })?
+            .to_path_buf();
+        changes.push(file_change(rel, read_optional(&path)?, Some(content)));
+    }
+    Ok(changes)
+}
+
+/// Whether `rel` is a plain relative path under one of the `managed` paths.
+fn is_managed(rel: &Path, managed: &[PathBuf]) -> bool {
+    rel.components().all(|c| matches!(c, Component::Normal(_)))
+        && managed.iter().any(|m| rel.starts_with(m))
+}
+
+/// Best-effort artifact kind of a file under an adapter's managed root.
+fn kind_of(rel: &Path) -> ArtifactKind {
+    for component in rel.components() {
+        match component.as_os_str().to_str() {
+            Some("skills") => return ArtifactKind::Skill,
+            Some("commands" | "prompts") => return ArtifactKind::Command,
+            Some("agents") => return ArtifactKind::Agent,
+            Some("hooks") => return ArtifactKind::Hook,
+            Some("rules" | "instructions") => return ArtifactKind::Instruction,
+            Some("plugins") => return ArtifactKind::PluginAsset,
+            _ => {}
+        }
+    }
+    let file = rel
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    match file.as_str() {
+        "hooks.json" => ArtifactKind::Hook,
+        "mcp.json" | ".mcp.json" | "mcp-config.json" => ArtifactKind::McpServer,
+        "CLAUDE.md" | "AGENTS.md" | "copilot-instructions.md" => ArtifactKind::Instruction,
+        _ if file.ends_with(".instructions.md") => ArtifactKind::Instruction,
+        _ => ArtifactKind::Preference,
+    }
+}
+
+/// Moves ledger entries onto the target named `target`.
+fn rekey(entries: Vec<StoredEntry>, target: &str) -> Vec<StoredEntry> {
+    entries
+        .into_iter()
+        .map(|mut entry| {
+            entry.key.target = target.to_lowercase();
+            entry
+        })
+        .collect()
+}
+
+/// An adapter for `platform` whose managed root is `root`.
+pub(crate) fn staged_adapter(
+    platform: &str,
+    scope: &SyncScope,
+    root: &Path,
+) -> Result<Box<dyn AgentAdapter>> {
+    let root = root.to_path_buf();
+    let project = scope.is_project();
+    Ok(match platform.to_lowercase().as_str() {
+        "claude" if project => Box::new(ClaudeAdapter::for_project(root)),
+        "codex" if project => Box::new(CodexAdapter::for_project(root)),
+        "copilot" if project => Box::new(CopilotAdapter::for_project(root)),
+        "cursor" if project => Box::new(CursorAdapter::for_project(root)),
+        "claude" => Box::new(ClaudeAdapter::with_root(root)),
+        "codex" => Box::new(CodexAdapter::with_root(root)),
+        "copilot" => Box::new(CopilotAdapter::with_root(root)),
+        "cursor" => Box::new(CursorAdapter::with_root(root)),
+        _ => bail!(
+            "Sync plans support the built-in platforms only, not '{}'",
+            platform
+        ),
+    })
+}
+
+pub(crate) fn read_optional(path: &Path) -> Result<Option<Vec<u8>>> {
+    match fs::read(path) {
+        Ok(bytes) => Ok(Some(bytes)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e).with_context(|| format!("Failed to read {}", path.display())),
+    }
+}
+
+pub(crate) fn current_hash(path: &Path) -> Option<String> {
+    fs::read(path).ok().map(|bytes| hash_content(&bytes))
+}
+
+/// Scratch directory holding the staged target and ledger, removed on drop.
+pub(crate) struct Stage {
+    dir: PathBuf,
+}
+
+impl Stage {
+    pub(crate) fn create() -> Result<Self> {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_nanos())
+            .unwrap_or_default();
+        let dir =
+            std::env::temp_dir().join(format!("skrills-plan-{}-{}", std::process::id(), nanos));
+        fs::create_dir_all(dir.join("root"))
+            .with_context(|| format!("Failed to create {}", dir.display()))?;
+        Ok(Self { dir })
+    }
+
+    #[cfg(test)]
+    pub(crate) fn at(dir: PathBuf) -> Self {
+        fs::create_dir_all(dir.join("root")).unwrap();
+        Self { dir }
+    }
+
+    pub(crate) fn root(&self) -> PathBuf {
+        self.dir.join("root")
+    }
+
+    /// Copies the files under `managed` in `root` into the staged root.
+    pub(crate) fn copy_from(&self, root: &Path, managed: &[PathBuf]) -> Result<()> {
+        for rel in collect_managed_files(root, managed) {
+            let dest = self.root().join(&rel);
+            if let Some(parent) = dest.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::copy(root.join(&rel), &dest)
+                .with_context(|| format!("Failed to stage {}", root.join(&rel).display()))?;
+        }
+        Ok(())
+    }
+
+    fn ledger_path(&self) -> PathBuf {
+        self.dir.join("sync-state.json")
+    }
+}
+
+impl Drop for Stage {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.dir);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::conflict::ArtifactType;
+    use crate::state::BaselineKey;
+    use tempfile::tempdir;
+
+    /// Plans a Claude → Codex command sync between two temp roots.
+    fn plan_commands(src: &Path, tgt: &Path, stage: &Path, ledger: &SyncStateStore) -> SyncPlan {
+        plan_commands_with(src, tgt, stage, ledger, commands_only())
+    }
+
+    fn plan_commands_with(
+        src: &Path,
+        tgt: &Path,
+        stage: &Path,
+        ledger: &SyncStateStore,
+        params: SyncParams,
+    ) -> SyncPlan {
+        let stage = Stage::at(stage.to_path_buf());
+        plan_staged(
+            ClaudeAdapter::with_root(src.to_path_buf()),
+            &CodexAdapter::with_root(tgt.to_path_buf()),
+            CodexAdapter::with_root(stage.root()),
+            &stage,
+            ledger,
+            |o| o,
+            &params,
+        )
+        .unwrap()
+    }
+
+    fn commands_only() -> SyncParams {
+        SyncParams {
+            sync_commands: true,
+            sync_mcp_servers: false,
+            sync_preferences: false,
+            sync_skills: false,
+            sync_agents: false,
+            sync_hooks: false,
+            sync_instructions: false,
+            sync_plugin_assets: false,
+            ..Default::default()
+        }
+    }
+
+    fn no_snapshots() -> SnapshotConfig {
+        SnapshotConfig {
+            enabled: false,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn plan_records_changes_without_touching_target() {
+        let (src, tgt, stage, state) = (
+            tempdir().unwrap(),
+            tempdir().unwrap(),
+            tempdir().unwrap(),
+            tempdir().unwrap(),
+        );
+        fs::create_dir_all(src.path().join("commands")).unwrap();
+        fs::write(src.path().join("commands/hello.md"), "# Hello\n").unwrap();
+        fs::write(src.path().join("commands/deploy.md"), "# Deploy v2\n").unwrap();
+        fs::create_dir_all(tgt.path().join("prompts")).unwrap();
+        fs::write(tgt.path().join("prompts/deploy.md"), "# Deploy\n").unwrap();
+        let ledger = SyncStateStore::load(&state.path().join("state.json")).unwrap();
+
+        let plan = plan_commands(src.path(), tgt.path(), &stage.path().join("s"), &ledger);
+
+        assert!(!tgt.path().join("prompts/hello.md").exists());
+        assert!(!stage.path().join("s").exists(), "stage is removed");
+        let files: Vec<&FileChange> = files(&plan.changes).collect();
+        assert_eq!(files.len(), 2);
+        let hello = files.iter().find(|f| f.path.ends_with("hello.md")).unwrap();
+        assert_eq!(hello.hash_before, None);
+        assert_eq!(hello.content, Some(FileContent::Utf8("# Hello\n".into())));
+        let deploy = plan
+            .changes
+            .changes
+            .iter()
+            .find(|c| c.name.ends_with("deploy.md"))
+            .unwrap();
+        assert_eq!(deploy.kind, ArtifactKind::Command);
+        assert!(deploy.diff_text.contains("+# Deploy v2"));
+        assert!(plan
+            .baselines
+            .iter()
+            .any(|e| e.key.target == "codex" && e.key.name == "hello"));
+        assert!(plan.format_changes().contains("+ prompts/hello.md"));
+    }
+
+    #[test]
+    fn apply_writes_plan_and_records_baselines() {
+        let (src, tgt, stage, state, snaps) = (
+            tempdir().unwrap(),
+            tempdir().unwrap(),
+            tempdir().unwrap(),
+            tempdir().unwrap(),
+            tempdir().unwrap(),
+        );
+        fs::create_dir_all(src.path().join("commands")).unwrap();
+        fs::write(src.path().join("commands/hello.md"), "# Hello\n").unwrap();
+        let mut ledger = SyncStateStore::load(&state.path().join("state.json")).unwrap();
+        let plan = plan_commands(src.path(), tgt.path(), &stage.path().join("s"), &ledger);
+
+        let path = state.path().join("plan.json");
+        plan.save(&path).unwrap();
+        let plan = SyncPlan::load(&path).unwrap();
+        let config = SnapshotConfig {
+            snapshot_root: snaps.path().to_path_buf(),
+            ..Default::default()
+        };
+        let applied = plan
+            .apply_with(
+                &ClaudeAdapter::with_root(src.path().to_path_buf()),
+                &CodexAdapter::with_root(tgt.path().to_path_buf()),
+                &mut ledger,
+                &config,
+            )
+            .unwrap();
+
+        assert_eq!(applied.written, 1);
+        assert!(applied.snapshot.is_some());
+        assert_eq!(
+            fs::read_to_string(tgt.path().join("prompts/hello.md")).unwrap(),
+            "# Hello\n"
+        );
+        let key = BaselineKey::new("claude", "codex", ArtifactType::Command, "hello");
+        assert!(ledger.get(&key).is_some());
+    }
+
+    #[test]
+    fn apply_refuses_when_target_drifted() {
+        let (src, tgt, stage, state) = (
+            tempdir().unwrap(),
+            tempdir().unwrap(),
+            tempdir().unwrap(),
+            tempdir().unwrap(),
+        );
+        fs::create_dir_all(src.path().join("commands")).unwrap();
+        fs::write(src.path().join("commands/hello.md"), "# Hello\n").unwrap();
+        let mut ledger = SyncStateStore::load(&state.path().join("state.json")).unwrap();
+        let plan = plan_commands(src.path(), tgt.path(), &stage.path().join("s"), &ledger);
+
+        fs::create_dir_all(tgt.path().join("prompts")).unwrap();
+        fs::write(tgt.path().join("prompts/hello.md"), "# Mine\n").unwrap();
+
+        let source = ClaudeAdapter::with_root(src.path().to_path_buf());
+        let target = CodexAdapter::with_root(tgt.path().to_path_buf());
+        let err = plan
+            .apply_with(&source, &target, &mut ledger, &no_snapshots())
+            .unwrap_err();
+        assert!(err.to_string().contains("prompts/hello.md"));
+        assert_eq!(
+            fs::read_to_string(tgt.path().join("prompts/hello.md")).unwrap(),
+            "# Mine\n"
+        );
+        assert!(ledger.is_empty());
+    }
+
+    #[test]
+    fn apply_refuses_paths_outside_managed_files() {
+        let (src, tgt, stage, state, elsewhere) = (
+            tempdir().unwrap(),
+            tempdir().unwrap(),
+            tempdir().unwrap(),
+            tempdir().unwrap(),
+            tempdir().unwrap(),
+        );
+        fs::create_dir_all(src.path().join("commands")).unwrap();
+        fs::write(src.path().join("commands/hello.md"), "# Hello\n").unwrap();
+        let mut ledger = SyncStateStore::load(&state.path().join("state.json")).unwrap();
+        let plan = plan_commands(src.path(), tgt.path(), &stage.path().join("s"), &ledger);
+        let source = ClaudeAdapter::with_root(src.path().to_path_buf());
+        let target = CodexAdapter::with_root(tgt.path().to_path_buf());
+
+        let escape = elsewhere.path().join("escape.md");
+        for path in [
+            PathBuf::from("prompts/../../escape.md"),
+            escape.clone(),
+            PathBuf::from("notes.txt"),
+        ] {
+            let mut tampered = plan.clone();
+            tampered.changes.changes[0].file.as_mut().unwrap().path = path.clone();
+            let err = tampered
+                .apply_with(&source, &target, &mut ledger, &no_snapshots())
+                .unwrap_err();
+            assert!(err.to_string().contains("outside"), "{}", path.display());
+        }
+
+        let mut moved = plan.clone();
+        moved.target_root = elsewhere.path().to_path_buf();
+        let err = moved
+            .apply_with(&source, &target, &mut ledger, &no_snapshots())
+            .unwrap_err();
+        assert!(err.to_string().contains("plan names"));
+
+        let mut into_source = plan.clone();
+        into_source.source_root = src.path().to_path_buf();
+        into_source.source_changes = plan.changes.clone();
+        into_source.source_changes.changes[0]
+            .file
+            .as_mut()
+            .unwrap()
+            .path = PathBuf::from("../escape.md");
+        let err = into_source
+            .apply_with(&source, &target, &mut ledger, &no_snapshots())
+            .unwrap_err();
+        assert!(err.to_string().contains("outside"));
+
+        assert!(!escape.exists());
+        assert!(!tgt.path().join("notes.txt").exists());
+        assert!(!tgt.path().join("prompts/hello.md").exists());
+        assert!(ledger.is_empty());
+    }
+
+    #[test]
+    fn planning_a_merge_leaves_the_source_untouched() {
+        let (src, tgt, stage, state) = (
+            tempdir().unwrap(),
+            tempdir().unwrap(),
+            tempdir().unwrap(),
+            tempdir().unwrap(),
+        );
+        let src_file = src.path().join("commands/hello.md");
+        let tgt_file = tgt.path().join("prompts/hello.md");
+        fs::create_dir_all(src.path().join("commands")).unwrap();
+        fs::write(&src_file, "# Hello\nintro\nbody\nfooter\n").unwrap();
+        let mut ledger = SyncStateStore::load(&state.path().join("state.json")).unwrap();
+        let source = ClaudeAdapter::with_root(src.path().to_path_buf());
+        let target = CodexAdapter::with_root(tgt.path().to_path_buf());
+        let merge = || SyncParams {
+            conflict_strategy: Some(crate::conflict::ConflictStrategy::Merge),
+            ..commands_only()
+        };
+        plan_commands_with(
+            src.path(),
+            tgt.path(),
+            &stage.path().join("a"),
+            &ledger,
+            merge(),
+        )
+        .apply_with(&source, &target, &mut ledger, &no_snapshots())
+        .unwrap();
+
+        let edited = "# Hello\nnew intro\nbody\nfooter\n";
+        fs::write(&src_file, edited).unwrap();
+        fs::write(&tgt_file, "# Hello\nintro\nbody\nnew footer\n").unwrap();
+        let plan = plan_commands_with(
+            src.path(),
+            tgt.path(),
+            &stage.path().join("b"),
+            &ledger,
+            merge(),
+        );
+
+        assert_eq!(fs::read_to_string(&src_file).unwrap(), edited);
+        let merged = "# Hello\nnew intro\nbody\nnew footer\n";
+        let file = files(&plan.changes).next().unwrap();
+        assert_eq!(file.content, Some(FileContent::Utf8(merged.into())));
+        let file = files(&plan.source_changes).next().unwrap();
+        assert_eq!(file.path, PathBuf::from("commands/hello.md"));
+        assert_eq!(file.content, Some(FileContent::Utf8(merged.into())));
+        assert!(plan
+            .format_changes()
+            .contains("~ commands/hello.md (source)"));
+
+        plan.apply_with(&source, &target, &mut ledger, &no_snapshots())
+            .unwrap();
+        assert_eq!(fs::read_to_string(&tgt_file).unwrap(), merged);
+        assert_eq!(fs::read_to_string(&src_file).unwrap(), merged);
+
+        let plan = plan_commands_with(
+            src.path(),
+            tgt.path(),
+            &stage.path().join("c"),
+            &ledger,
+            merge(),
+        );
+        assert!(plan.is_empty(), "{}", plan.format_changes());
+    }
+}
@@ -0,0 +1,999 @@
+//! Plugin-level sync: installing a Claude plugin into another CLI as a unit.
+//!
+//! Regular syncs flatten marketplace plugins into loose skills, commands and
+//! hooks, so nothing remembers which target files came from which plugin.
+//! This module treats a plugin from Claude's cache
+//! (`plugins/cache/<publisher>/<plugin>/<version>/`) as one versioned
+//! [`PluginBundle`] instead:
+//!
+//! - Codex and Copilot receive the plugin's skills, commands, agents and hooks
+//!   through the target adapter's normal writers. `${CLAUDE_PLUGIN_ROOT}` is
+//!   rewritten to the plugin's cache directory so scripts keep working.
+//! - Cursor loads plugin content from Claude's cache itself, so it only
+//!   receives the plugin manifest under `plugins/local/<plugin>/`.
+//!
+//! Every file an install creates or changes is recorded, with its previous
+//! content, in a [`PluginRegistry`] (`~/.skrills/plugins.json`).
+//! [`uninstall`] restores exactly those files, leaving any the user edited
+//! since in place. [`upgrade`] stages the new version over the reverted old
+//! one and writes only the difference, with the same care for edited files.
+
+use crate::adapters::utils::collect_module_files;
+use crate::adapters::{version_tuple, AgentAdapter, ClaudeAdapter};
+use crate::common::{Command, PluginAsset, PluginOrigin};
+use crate::hooks::{self, HookHandler};
+use crate::orchestrator::{create_adapter_in, SyncScope};
+use crate::plan::{current_hash, diff_trees, read_optional, staged_adapter, Stage};
+use crate::preview::{ChangeSet, FileContent};
+use crate::report::SkipReason;
+use crate::Result;
+use anyhow::{bail, Context};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+use time::format_description::well_known::Rfc3339;
+use time::OffsetDateTime;
+use walkdir::WalkDir;
+
+/// Registry file name under `~/.skrills/`.
+const REGISTRY_FILE_NAME: &str = "plugins.json";
+
+/// Current on-disk registry version.
+const REGISTRY_VERSION: u32 = 1;
+
+/// Placeholder Claude expands to the plugin's directory.
+const PLUGIN_ROOT_VAR: &str = "${CLAUDE_PLUGIN_ROOT}";
+
+/// Targets a plugin can be installed into.
+pub const PLUGIN_TARGETS: &[&str] = &["codex", "copilot", "cursor"];
+
+/// Returns the default registry path (`~/.skrills/plugins.json`).
+pub fn default_registry_path() -> Result<PathBuf> {
+    let home = dirs::home_dir().context("Could not determine home directory")?;
+    Ok(home.join(".skrills").join(REGISTRY_FILE_NAME))
+}
+
+/// One version of a plugin in Claude's cache, split into syncable items.
+#[derive(Debug, Clone)]
+pub struct PluginBundle {
+    pub origin: PluginOrigin,
+    /// The version directory in the cache.
+    pub path: PathBuf,
+    pub skills: Vec<Command>,
+    pub commands: Vec<Command>,
+    pub agents: Vec<Command>,
+    /// Hook items in the canonical model (see [`crate::hooks`]).
+    pub hooks: Vec<Command>,
+    /// `.claude-plugin/plugin.json`, synthesized when the plugin has none.
+    pub manifest: Option<PluginAsset>,
+}
+
+impl PluginBundle {
+    /// `publisher/plugin`, the identifier shown to users.
+    pub fn id(&self) -> String {
+        plugin_id(&self.origin.publisher, &self.origin.plugin_name)
+    }
+}
+
+fn plugin_id(publisher: &str, plugin: &str) -> String {
+    format!("{}/{}", publisher, plugin)
+}
+
+/// Lists the latest cached version of every plugin under `claude_root`.
+pub fn list_plugins(claude_root: &Path) -> Result<Vec<PluginBundle>> {
+    let cache = claude_root.join("plugins/cache");
+    let manifest_path = Path::new(".claude-plugin").join("plugin.json");
+    let manifests: Vec<PluginAsset> = ClaudeAdapter::with_root(claude_root.to_path_buf())
+        .read_plugin_assets(true)?
+        .into_iter()
+        .filter(|a| a.relative_path == manifest_path)
+        .collect();
+    let mut bundles = Vec::new();
+    for publisher in sorted_dirs(&cache)? {
+        for plugin in sorted_dirs(&publisher)? {
+            let latest = sorted_dirs(&plugin)?.into_iter().max_by_key(|dir| {
+                version_tuple(&dir.file_name().unwrap_or_default().to_string_lossy())
+            });
+            let Some(version) = latest else { continue };
+            let origin = PluginOrigin {
+                publisher: dir_name(&publisher),
+                plugin_name: dir_name(&plugin),
+                version: dir_name(&version),
+            };
+            bundles.push(read_bundle(origin, &version, &manifests)?);
+        }
+    }
+    Ok(bundles)
+}
+
+/// Finds a plugin by `plugin` or `publisher/plugin`.
+pub fn find_plugin(claude_root: &Path, name: &str) -> Result<PluginBundle> {
+    let mut matches: Vec<PluginBundle> = list_plugins(claude_root)?
+        .into_iter()
+        .filter(|b| b.origin.plugin_name == name || b.id() == name)
+        .collect();
+    match matches.len() {
+        0 => bail!(
+            "Plugin '{}' not found in {}",
+            name,
+            claude_root.join("plugins/cache").display()
+        ),
+        1 => Ok(matches.remove(0)),
+        _ => bail!(
+            "Plugin '{}' is published by several marketplaces ({}); use publisher/plugin",
+            name,
+            matches
+                .iter()
+                .map(PluginBundle::id)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    }
+}
+
+fn sorted_dirs(dir: &Path) -> Result<Vec<PathBuf>> {
+    if !dir.is_dir() {
+        return Ok(Vec::new());
+    }
+    let mut dirs: Vec<PathBuf> = fs::read_dir(dir)
+        .with_context(|| format!("Failed to read {}", dir.display()))?
+        .filter_map(|e| e.ok().map(|e| e.path()))
+        .filter(|p| p.is_dir() && !dir_name(p).starts_with('.'))
+        .collect();
+    dirs.sort();
+    Ok(dirs)
+}
+
+fn dir_name(path: &Path) -> String {
+    path.file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+/// Reads the items of the plugin version at `dir`.
+fn read_bundle(
+    origin: PluginOrigin,
+    dir: &Path,
+    manifests: &[PluginAsset],
+) -> Result<PluginBundle> {
+    let plugin_root = dir.display().to_string();
+    let item = |name: String, path: &Path| -> Result<Command> {
+        let content = expand_plugin_root(fs::read(path)?, &plugin_root);
+        let mut command = Command::new(name, content, path.to_path_buf());
+        command.modified = fs::metadata(path)
+            .and_then(|m| m.modified())
+            .unwrap_or(SystemTime::UNIX_EPOCH);
+        command.plugin_origin = Some(origin.clone());
+        Ok(command)
+    };
+
+    let mut skills = Vec::new();
+    for entry in WalkDir::new(dir.join("skills"))
+        .follow_links(false)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file() && e.file_name() == "SKILL.md")
+    {
+        let skill_dir = entry.path().parent().unwrap_or(dir);
+        let mut skill = item(dir_name(skill_dir), entry.path())?;
+        skill.modules = collect_module_files(skill_dir);
+        skills.push(skill);
+    }
+
+    let markdown_in = |sub: &str| -> Result<Vec<Command>> {
+        let mut items = Vec::new();
+        for path in files_in(&dir.join(sub))? {
+            if path.extension().is_some_and(|e| e == "md") {
+                let stem = path
+                    .file_stem()
+                    .map(|s| s.to_string_lossy().into_owned())
+                    .unwrap_or_default();
+                items.push(item(stem, &path)?);
+            }
+        }
+        Ok(items)
+    };
+    let commands = markdown_in("commands")?;
+    let agents = markdown_in("agents")?;
+    let hooks = read_plugin_hooks(&dir.join("hooks/hooks.json"), &plugin_root, &origin)?;
+
+    let manifest = manifests
+        .iter()
+        .find(|a| a.publisher == origin.publisher && a.plugin_name == origin.plugin_name)
+        .cloned();
+
+    Ok(PluginBundle {
+        origin,
+        path: dir.to_path_buf(),
+        skills,
+        commands,
+        agents,
+        hooks,
+        manifest,
+    })
+}
+
+fn files_in(dir: &Path) -> Result<Vec<PathBuf>> {
+    if !dir.is_dir() {
+        return Ok(Vec::new());
+    }
+    let mut files: Vec<PathBuf> = fs::read_dir(dir)?
+        .filter_map(|e| e.ok().map(|e| e.path()))
+        .filter(|p| p.is_file())
+        .collect();
+    files.sort();
+    Ok(files)
+}
+
+fn expand_plugin_root(content: Vec<u8>, plugin_root: &str) -> Vec<u8> {
+    match String::from_utf8(content) {
+        Ok(text) => text.replace(PLUGIN_ROOT_VAR, plugin_root).into_bytes(),
+        Err(e) => e.into_bytes(),
+    }
+}
+
+/// Reads a plugin's `hooks/hooks.json` (`{"hooks": {"<Event>": [groups]}}`)
+/// as canonical hook items.
+fn read_plugin_hooks(
+    path: &Path,
+    plugin_root: &str,
+    origin: &PluginOrigin,
+) -> Result<Vec<Command>> {
+    #[derive(Deserialize)]
+    struct PluginHooks {
+        #[serde(default)]
+        hooks: std::collections::BTreeMap<String, serde_json::Value>,
+    }
+
+    let Some(raw) = read_optional(path)? else {
+        return Ok(Vec::new());
+    };
+    let file: PluginHooks = serde_json::from_slice(&expand_plugin_root(raw, plugin_root))
+        .with_context(|| format!("Failed to parse plugin hooks: {}", path.display()))?;
+    let modified = fs::metadata(path)
+        .and_then(|m| m.modified())
+        .unwrap_or(SystemTime::UNIX_EPOCH);
+
+    let mut items = Vec::new();
+    for (event, groups) in file.hooks {
+        let raw = Command::new(
+            event.clone(),
+            serde_json::to_vec(&groups)?,
+            path.to_path_buf(),
+        );
+        let handlers: Vec<HookHandler> = match hooks::parse_handlers(&raw) {
+            Ok(handlers) => handlers,
+            Err(_) => continue,
+        };
+        let mut item = hooks::hook_command(event, &handlers, path.to_path_buf(), modified)?;
+        item.plugin_origin = Some(origin.clone());
+        items.push(item);
+    }
+    Ok(items)
+}
+
+/// A target file written by a plugin install.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InstalledFile {
+    /// Path relative to the install's `target_root`.
+    pub path: PathBuf,
+    /// Hash of the file as the install left it; `None` if it deleted it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hash: Option<String>,
+    /// Content before the install; `None` if the install created the file.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub previous: Option<FileContent>,
+}
+
+/// One plugin version installed into one target.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PluginInstall {
+    pub publisher: String,
+    pub plugin: String,
+    pub version: String,
+    /// Target platform (`codex`, `copilot` or `cursor`).
+    pub target: String,
+    /// Directory the file paths are relative to.
+    pub target_root: PathBuf,
+    /// RFC 3339 time of the install.
+    pub installed_at: String,
+    pub files: Vec<InstalledFile>,
+}
+
+impl PluginInstall {
+    /// `publisher/plugin`, as in [`PluginBundle::id`].
+    pub fn id(&self) -> String {
+        plugin_id(&self.publisher, &self.plugin)
+    }
+
+    fn matches(&self, name: &str) -> bool {
+        self.plugin == name || self.id() == name
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct RegistryFile {
+    version: u32,
+    #[serde(default)]
+    installs: Vec<PluginInstall>,
+}
+
+/// Durable record of which target files belong to which installed plugin.
+#[derive(Debug, Clone)]
+pub struct PluginRegistry {
+    path: PathBuf,
+    installs: Vec<PluginInstall>,
+}
+
+impl PluginRegistry {
+    /// Loads the registry at `path`; a missing file is an empty registry.
+    pub fn load(path: &Path) -> Result<Self> {
+        let installs = match read_optional(path)? {
+            Some(raw) => {
+                let file: RegistryFile = serde_json::from_slice(&raw).with_context(|| {
+                    format!("Failed to parse plugin registry: {}", path.display())
+                })?;
+                if file.version > REGISTRY_VERSION {
+                    bail!(
+                        "Plugin registry {} has version {}, newer than supported version {}",
+                        path.display(),
+                        file.version,
+                        REGISTRY_VERSION
+                    );
+                }
+                file.installs
+            }
+            None => Vec::new(),
+        };
+        Ok(Self {
+            path: path.to_path_buf(),
+            installs,
+        })
+    }
+
+    /// Loads the registry from [`default_registry_path`].
+    pub fn load_default() -> Result<Self> {
+        Self::load(&default_registry_path()?)
+    }
+
+    /// Writes the registry back to disk.
+    pub fn save(&self) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let file = RegistryFile {
+            version: REGISTRY_VERSION,
+            installs: self.installs.clone(),
+        };
+        fs::write(&self.path, serde_json::to_string_pretty(&file)?)
+            .with_context(|| format!("Failed to write plugin registry: {}", self.path.display()))
+    }
+
+    /// Every recorded install.
+    pub fn installs(&self) -> &[PluginInstall] {
+        &self.installs
+    }
+
+    /// The install of `name` (`plugin` or `publisher/plugin`) into `target`.
+    pub fn get(&self, name: &str, target: &str) -> Option<&PluginInstall> {
+        self.installs
+            .iter()
+            .find(|i| i.matches(name) && i.target == target)
+    }
+
+    fn take(&mut self, name: &str, target: &str) -> Option<PluginInstall> {
+        let index = self
+            .installs
+            .iter()
+            .position(|i| i.matches(name) && i.target == target)?;
+        Some(self.installs.remove(index))
+    }
+}
+
+/// Outcome of an install.
+#[derive(Debug, Clone)]
+pub struct InstallReport {
+    pub install: PluginInstall,
+    /// Items the target could not take (e.g. hook events it lacks).
+    pub skipped: Vec<SkipReason>,
+    pub warnings: Vec<String>,
+}
+
+/// Outcome of an uninstall.
+#[derive(Debug, Clone, Default)]
+pub struct UninstallReport {
+    /// Files deleted because the install created them.
+    pub removed: usize,
+    /// Files put back to their content before the install.
+    pub restored: usize,
+    /// Files left in place because they changed since the install.
+    pub kept: Vec<PathBuf>,
+}
+
+/// Installs `bundle` into the user-level `target`.
+///
+/// Refuses if the plugin is already installed there; use [`upgrade`] to
+/// move to a newer version.
+pub fn install(
+    bundle: &PluginBundle,
+    target: &str,
+    registry: &mut PluginRegistry,
+) -> Result<InstallReport> {
+    let target = target.to_lowercase();
+    if !PLUGIN_TARGETS.contains(&target.as_str()) {
+        bail!(
+            "Plugins can be installed into {}, not '{}'",
+            PLUGIN_TARGETS.join(", "),
+            target
+        );
+    }
+    if let Some(existing) = registry.get(&bundle.id(), &target) {
+        bail!(
+            "{} {} is already installed into {}; use `skrills plugin upgrade`",
+            existing.id(),
+            existing.version,
+            target
+        );
+    }
+
+    let adapter = create_adapter_in(&target, &SyncScope::User)?;
+    let stage = Stage::create()?;
+    let staged = staged_adapter(&target, &SyncScope::User, &stage.root())?;
+    let report = install_staged(bundle, &target, &adapter, &staged, &stage)?;
+    registry.installs.push(report.install.clone());
+    registry.save()?;
+    Ok(report)
+}
+
+/// Removes the install of `name` from `target`.
+pub fn uninstall(
+    name: &str,
+    target: &str,
+    registry: &mut PluginRegistry,
+) -> Result<UninstallReport> {
+    let target = target.to_lowercase();
+    let Some(install) = registry.take(name, &target) else {
+        bail!("Plugin '{}' is not installed into {}", name, target);
+    };
+    let report = remove_files(&install, &install.target_root)?;
+    registry.save()?;
+    Ok(report)
+}
+
+/// Replaces the install of `bundle`'s plugin in `target` with `bundle`.
+///
+/// The new version is staged on top of the old one with the old install
+/// reverted, and only the difference is written. Files the user edited since
+/// the old install are left alone and reported as kept. A failed write rolls
+/// every written file back, and the registry is saved once, at the end.
+///
+/// Returns `None` when the installed version is already `bundle`'s.
+pub fn upgrade(
+    bundle: &PluginBundle,
+    target: &str,
+    registry: &mut PluginRegistry,
+) -> Result<Option<(UninstallReport, InstallReport)>> {
+    let target = target.to_lowercase();
+    let current = match registry.get(&bundle.id(), &target) {
+        None => bail!("{} is not installed into {}", bundle.id(), target),
+        Some(current) if current.version == bundle.origin.version => return Ok(None),
+        Some(current) => current.clone(),
+    };
+
+    let adapter = create_adapter_in(&target, &SyncScope::User)?;
+    let stage = Stage::create()?;
+    let staged = staged_adapter(&target, &SyncScope::User, &stage.root())?;
+    let reports = upgrade_staged(bundle, &current, &adapter, &staged, &stage)?;
+    registry.take(&bundle.id(), &target);
+    registry.installs.push(reports.1.install.clone());
+    registry.save()?;
+    Ok(Some(reports))
+}
+
+/// Writes `bundle` through `staged` (a copy of `adapter`'s managed files),
+/// then applies and records the difference in `adapter`'s tree.
+fn install_staged(
+    bundle: &PluginBundle,
+    target: &str,
+    adapter: &dyn AgentAdapter,
+    staged: &dyn AgentAdapter,
+    stage: &Stage,
+) -> Result<InstallReport> {
+    let root = adapter.managed_root();
+    let managed = adapter.managed_paths();
+    stage.copy_from(&root, &managed)?;
+
+    let (skipped, warnings) = write_bundle(bundle, target, staged)?;
+    let changes = diff_trees(&root, &stage.root(), &managed)?;
+    let files = apply_changes(&root, changes, &[])?;
+    Ok(InstallReport {
+        install: new_install(bundle, target, root, files),
+        skipped,
+        warnings,
+    })
+}
+
+/// Like [`install_staged`], but over `current`, an install of an older
+/// version: the stage has `current` reverted before `bundle` is written.
+fn upgrade_staged(
+    bundle: &PluginBundle,
+    current: &PluginInstall,
+    adapter: &dyn AgentAdapter,
+    staged: &dyn AgentAdapter,
+    stage: &Stage,
+) -> Result<(UninstallReport, InstallReport)> {
+    let root = adapter.managed_root();
+    let managed = adapter.managed_paths();
+    stage.copy_from(&root, &managed)?;
+
+    let kept = remove_files(current, &stage.root())?.kept;
+    let (skipped, warnings) = write_bundle(bundle, &current.target, staged)?;
+    let mut changes = diff_trees(&root, &stage.root(), &managed)?;
+    changes
+        .changes
+        .retain(|c| c.file.as_ref().is_some_and(|f| !kept.contains(&f.path)));
+    let previous: Vec<InstalledFile> = current
+        .files
+        .iter()
+        .filter(|f| !kept.contains(&f.path))
+        .cloned()
+        .collect();
+    let files = apply_changes(&root, changes, &previous)?;
+
+    let mut removed = UninstallReport {
+        kept,
+        ..Default::default()
+    };
+    for old in previous
+        .iter()
+        .filter(|old| !files.iter().any(|f| f.path == old.path))
+    {
+        match old.previous {
+            Some(_) => removed.restored += 1,
+            None => removed.removed += 1,
+        }
+    }
+    let installed = InstallReport {
+        install: new_install(bundle, &current.target, root, files),
+        skipped,
+        warnings,
+    };
+    Ok((removed, installed))
+}
+
+/// Writes the parts of `bundle` that `target` takes through `staged`.
+fn write_bundle(
+    bundle: &PluginBundle,
+    target: &str,
+    staged: &dyn AgentAdapter,
+) -> Result<(Vec<SkipReason>, Vec<String>)> {
+    let mut skipped = Vec::new();
+    let mut warnings = Vec::new();
+    if target == "cursor" {
+        let manifest = bundle
+            .manifest
+            .as_ref()
+            .with_context(|| format!("{} has no plugin manifest", bundle.id()))?;
+        let path = staged
+            .config_root()
+            .join("plugins/local")
+            .join(crate::adapters::utils::sanitize_name(
+                &bundle.origin.plugin_name,
+            ))
+            .join(".cursor-plugin/plugin.json");
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&path, &manifest.content)?;
+    } else {
+        let reports = [
+            staged.write_skills(&bundle.skills)?,
+            staged.write_commands(&bundle.commands)?,
+            staged.write_agents(&bundle.agents)?,
+            staged.write_hooks(&bundle.hooks)?,
+        ];
+        for report in reports {
+            skipped.extend(
+                report
+                    .skipped
+                    .into_iter()
+                    .filter(|r| !matches!(r, SkipReason::Unchanged { .. })),
+            );
+            warnings.extend(report.warnings);
+        }
+    }
+    Ok((skipped, warnings))
+}
+
+/// Writes `changes` under `root` and returns the files the install owns.
+///
+/// `previous` are the records of the install being replaced, if any: their
+/// files keep the content from before that install, and those the changes
+/// leave alone stay owned. Every file is read before anything is written; if
+/// a write fails, the files already written are put back.
+fn apply_changes(
+    root: &Path,
+    changes: ChangeSet,
+    previous: &[InstalledFile],
+) -> Result<Vec<InstalledFile>> {
+    let mut planned = Vec::new();
+    for file in changes.changes.into_iter().filter_map(|c| c.file) {
+        let path = root.join(&file.path);
+        let current = read_optional(&path)?;
+        let original = match previous.iter().find(|f| f.path == file.path) {
+            Some(record) => record
+                .previous
+                .as_ref()
+                .map(FileContent::decode)
+                .transpose()?,
+            None => current.clone(),
+        };
+        let content = file.content.as_ref().map(FileContent::decode).transpose()?;
+        planned.push((file, path, current, original, content));
+    }
+
+    let mut written: Vec<(&Path, &Option<Vec<u8>>)> = Vec::new();
+    for (_, path, current, _, content) in &planned {
+        if let Err(e) = write_optional(path, content.as_deref()) {
+            for (path, before) in written.into_iter().rev() {
+                if let Err(e) = write_optional(path, before.as_deref()) {
+                    tracing::warn!(path = %path.display(), error = %e, "Failed to roll back plugin file");
+                }
+            }
+            return Err(e);
+        }
+        written.push((path, current));
+    }
+
+    let mut files: Vec<InstalledFile> = previous
+        .iter()
+        .filter(|old| !planned.iter().any(|(file, ..)| file.path == old.path))
+        .cloned()
+        .collect();
+    for (file, _, _, original, content) in planned {
+        if content != original {
+            files.push(InstalledFile {
+                path: file.path,
+                hash: file.hash_after,
+                previous: original.as_deref().map(FileContent::encode),
+            });
+        }
+    }
+    files.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(files)
+}
+
+/// Writes `content` to `path`, or deletes it when `content` is `None`.
+fn write_optional(path: &Path, content: Option<&[u8]>) -> Result<()> {
+    match content {
+        Some(content) => {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(path, content).with_context(|| format!("Failed to write {}", path.display()))
+        }
+        None => match fs::remove_file(path) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => {
+                Err(e).with_context(|| format!("Failed to delete {}", path.display()))
+            }
+            _ => Ok(()),
+        },
+    }
+}
+
+fn new_install(
+    bundle: &PluginBundle,
+    target: &str,
+    target_root: PathBuf,
+    files: Vec<InstalledFile>,
+) -> PluginInstall {
+    PluginInstall {
+        publisher: bundle.origin.publisher.clone(),
+        plugin: bundle.origin.plugin_name.clone(),
+        version: bundle.origin.version.clone(),
+        target: target.to_string(),
+        target_root,
+        installed_at: OffsetDateTime::now_utc()
+            .format(&Rfc3339)
+            .unwrap_or_default(),
+        files,
+    }
+}
+
+/// Puts every file of `install` under `root` back the way it was before the
+/// install.
+fn remove_files(install: &PluginInstall, root: &Path) -> Result<UninstallReport> {
+    let mut report = UninstallReport::default();
+    for file in install.files.iter().rev() {
+        let path = root.join(&file.path);
+        if current_hash(&path) != file.hash {
+            report.kept.push(file.path.clone());
+            continue;
+        }
+        match &file.previous {
+            Some(previous) => {
+                if let Some(parent) = path.parent() {
+                    fs::create_dir_all(parent)?;
+                }
+                fs::write(&path, previous.decode()?)
+                    .with_context(|| format!("Failed to restore {}", path.display()))?;
+                report.restored += 1;
+            }
+            None => {
+                fs::remove_file(&path)
+                    .with_context(|| format!("Failed to delete {}", path.display()))?;
+                remove_empty_parents(&path, root);
+                report.removed += 1;
+            }
+        }
+    }
+    Ok(report)
+}
+
+fn remove_empty_parents(path: &Path, root: &Path) {
+    let mut dir = path.parent();
+    while let Some(current) = dir {
+        if current == root || !current.starts_with(root) || fs::remove_dir(current).is_err() {
+            break;
+        }
+        dir = current.parent();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::{CodexAdapter, CursorAdapter};
+    use tempfile::tempdir;
+
+    fn cache_plugin(claude: &Path, version: &str) -> PathBuf {
+        let dir = claude.join("plugins/cache/market/tidy").join(version);
+        fs::create_dir_all(dir.join("skills/tidy-up")).unwrap();
+        fs::write(
+            dir.join("skills/tidy-up/SKILL.md"),
+            format!(
+                "---\nname: tidy-up\n---\nRun ${{CLAUDE_PLUGIN_ROOT}}/tidy.sh ({})\n",
+                version
+            ),
+        )
+        .unwrap();
+        fs::create_dir_all(dir.join("commands")).unwrap();
+        fs::write(dir.join("commands/tidy.md"), "# Tidy\n").unwrap();
+        fs::create_dir_all(dir.join("hooks")).unwrap();
+        fs::write(
+            dir.join("hooks/hooks.json"),
+            r#"{"hooks": {"Stop": [{"hooks": [{"type": "command", "command": "${CLAUDE_PLUGIN_ROOT}/done.sh"}]}]}}"#,
+        )
+        .unwrap();
+        dir
+    }
+
+    fn install_codex(bundle: &PluginBundle, codex: &Path, stage: &Path) -> InstallReport {
+        let stage = Stage::at(stage.to_path_buf());
+        install_staged(
+            bundle,
+            "codex",
+            &CodexAdapter::with_root(codex.to_path_buf()),
+            &CodexAdapter::with_root(stage.root()),
+            &stage,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn lists_latest_version_with_items() {
+        let claude = tempdir().unwrap();
+        cache_plugin(claude.path(), "1.2.0");
+        let latest = cache_plugin(claude.path(), "1.10.0");
+
+        let plugins = list_plugins(claude.path()).unwrap();
+        assert_eq!(plugins.len(), 1);
+        let bundle = &plugins[0];
+        assert_eq!(bundle.id(), "market/tidy");
+        assert_eq!(bundle.origin.version, "1.10.0");
+        assert_eq!(bundle.skills[0].name, "tidy-up");
+        assert_eq!(bundle.commands[0].name, "tidy");
+        assert_eq!(bundle.hooks[0].name, "Stop");
+        let skill = String::from_utf8(bundle.skills[0].content.clone()).unwrap();
+        assert!(skill.contains(&format!("Run {}/tidy.sh", latest.display())));
+        assert!(bundle.manifest.is_some(), "manifest is synthesized");
+        assert!(find_plugin(claude.path(), "tidy").is_ok());
+        assert!(find_plugin(claude.path(), "market/tidy").is_ok());
+        assert!(find_plugin(claude.path(), "messy").is_err());
+    }
+
+    #[test]
+    fn install_records_files_and_uninstall_restores_them() {
+        let (claude, codex, stage) = (tempdir().unwrap(), tempdir().unwrap(), tempdir().unwrap());
+        cache_plugin(claude.path(), "1.0.0");
+        fs::write(codex.path().join("config.toml"), "model = \"o3\"\n").unwrap();
+        let bundle = find_plugin(claude.path(), "tidy").unwrap();
+
+        let report = install_codex(&bundle, codex.path(), &stage.path().join("s"));
+
+        let files: Vec<&Path> = report
+            .install
+            .files
+            .iter()
+            .map(|f| f.path.as_path())
+            .collect();
+        assert!(files.contains(&Path::new("skills/tidy-up/SKILL.md")));
+        assert!(files.contains(&Path::new("prompts/tidy.md")));
+        assert!(files.contains(&Path::new("config.toml")));
+        let config = fs::read_to_string(codex.path().join("config.toml")).unwrap();
+        assert!(config.contains("done.sh"));
+        assert!(!stage.path().join("s").exists());
+
+        let removed = remove_files(&report.install, codex.path()).unwrap();
+        assert_eq!(removed.restored, 1);
+        assert!(removed.kept.is_empty());
+        assert!(!codex.path().join("skills/tidy-up").exists());
+        assert!(!codex.path().join("prompts/tidy.md").exists());
+        assert_eq!(
+            fs::read_to_string(codex.path().join("config.toml")).unwrap(),
+            "model = \"o3\"\n"
+        );
+    }
+
+    #[test]
+    fn uninstall_keeps_files_edited_since_install() {
+        let (claude, codex, stage) = (tempdir().unwrap(), tempdir().unwrap(), tempdir().unwrap());
+        cache_plugin(claude.path(), "1.0.0");
+        let bundle = find_plugin(claude.path(), "tidy").unwrap();
+        let report = install_codex(&bundle, codex.path(), &stage.path().join("s"));
+
+        fs::write(codex.path().join("prompts/tidy.md"), "# Mine\n").unwrap();
+        let removed = remove_files(&report.install, codex.path()).unwrap();
+
+        assert_eq!(removed.kept, [PathBuf::from("prompts/tidy.md")]);
+        assert!(codex.path().join("prompts/tidy.md").exists());
+        assert!(!codex.path().join("skills/tidy-up/SKILL.md").exists());
+    }
+
+    #[test]
+    fn upgrade_replaces_the_install_and_keeps_user_edits() {
+        let (claude, codex, stage) = (tempdir().unwrap(), tempdir().unwrap(), tempdir().unwrap());
+        cache_plugin(claude.path(), "1.0.0");
+        fs::write(codex.path().join("config.toml"), "model = \"o3\"\n").unwrap();
+        let old = find_plugin(claude.path(), "tidy").unwrap();
+        let current = install_codex(&old, codex.path(), &stage.path().join("a")).install;
+
+        cache_plugin(claude.path(), "2.0.0");
+        fs::write(
+            claude
+                .path()
+                .join("plugins/cache/market/tidy/2.0.0/commands/tidy.md"),
+            "# Tidy v2\n",
+        )
+        .unwrap();
+        fs::write(codex.path().join("prompts/tidy.md"), "# Mine\n").unwrap();
+        let new = find_plugin(claude.path(), "tidy").unwrap();
+        let stage = Stage::at(stage.path().join("b"));
+        let (removed, installed) = upgrade_staged(
+            &new,
+            &current,
+            &CodexAdapter::with_root(codex.path().to_path_buf()),
+            &CodexAdapter::with_root(stage.root()),
+            &stage,
+        )
+        .unwrap();
+
+        assert_eq!(removed.kept, [PathBuf::from("prompts/tidy.md")]);
+        assert_eq!(
+            fs::read_to_string(codex.path().join("prompts/tidy.md")).unwrap(),
+            "# Mine\n"
+        );
+        let skill = fs::read_to_string(codex.path().join("skills/tidy-up/SKILL.md")).unwrap();
+        assert!(skill.contains("(2.0.0)"));
+        let install = installed.install;
+        assert_eq!(install.version, "2.0.0");
+        assert!(!install
+            .files
+            .iter()
+            .any(|f| f.path == Path::new("prompts/tidy.md")));
+
+        // The upgraded install still restores the state before version 1.
+        let removed = remove_files(&install, codex.path()).unwrap();
+        assert!(removed.kept.is_empty());
+        assert!(!codex.path().join("skills/tidy-up").exists());
+        assert_eq!(
+            fs::read_to_string(codex.path().join("config.toml")).unwrap(),
+            "model = \"o3\"\n"
+        );
+    }
+
+    #[test]
+    fn failed_write_rolls_back_applied_files() {
+        let root = tempdir().unwrap();
+        fs::write(root.path().join("a.md"), "old\n").unwrap();
+        fs::write(root.path().join("blocker"), "a file, not a directory\n").unwrap();
+        let mut changes = ChangeSet::new();
+        for (path, content) in [
+            ("a.md", "new\n"),
+            ("b.md", "new\n"),
+            ("blocker/c.md", "new\n"),
+        ] {
+            changes.push(crate::preview::PendingChange {
+                kind: crate::preview::ArtifactKind::Command,
+                name: path.to_string(),
+                diff_text: String::new(),
+                is_new: false,
+                file: Some(crate::preview::FileChange {
+                    path: PathBuf::from(path),
+                    hash_before: None,
+                    hash_after: None,
+                    content: Some(FileContent::Utf8(content.into())),
+                }),
+            });
+        }
+
+        assert!(apply_changes(root.path(), changes, &[]).is_err());
+        assert_eq!(
+            fs::read_to_string(root.path().join("a.md")).unwrap(),
+            "old\n"
+        );
+        assert!(!root.path().join("b.md").exists());
+    }
+
+    #[test]
+    fn cursor_install_writes_only_the_manifest() {
+        let (claude, cursor, stage) = (tempdir().unwrap(), tempdir().unwrap(), tempdir().unwrap());
+        cache_plugin(claude.path(), "1.0.0");
+        let bundle = find_plugin(claude.path(), "tidy").unwrap();
+        let stage = Stage::at(stage.path().join("s"));
+
+        let report = install_staged(
+            &bundle,
+            "cursor",
+            &CursorAdapter::with_root(cursor.path().to_path_buf()),
+            &CursorAdapter::with_root(stage.root()),
+            &stage,
+        )
+        .unwrap();
+
+        assert_eq!(
+            report
+                .install
+                .files
+                .iter()
+                .map(|f| f.path.clone())
+                .collect::<Vec<_>>(),
+            [PathBuf::from(
+                "plugins/local/tidy/.cursor-plugin/plugin.json"
+            )]
+        );
+        assert!(!cursor.path().join("skills").exists());
+    }
+
+    #[test]
+    fn registry_round_trips_and_rejects_duplicate_installs() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("plugins.json");
+        let mut registry = PluginRegistry::load(&path).unwrap();
+        registry.installs.push(PluginInstall {
+            publisher: "market".into(),
+            plugin: "tidy".into(),
+            version: "1.0.0".into(),
+            target: "codex".into(),
+            target_root: dir.path().to_path_buf(),
+            installed_at: String::new(),
+            files: vec![],
+        });
+        registry.save().unwrap();
+
+        let mut registry = PluginRegistry::load(&path).unwrap();
+        assert!(registry.get("market/tidy", "codex").is_some());
+        assert!(registry.get("tidy", "cursor").is_none());
+
+        let claude = tempdir().unwrap();
+        cache_plugin(claude.path(), "1.0.0");
+        let bundle = find_plugin(claude.path(), "tidy").unwrap();
+        let err = install(&bundle, "codex", &mut registry).unwrap_err();
+        assert!(err.to_string().contains("already installed"));
+        assert!(install(&bundle, "claude", &mut registry).is_err());
+
+        uninstall("tidy", "codex", &mut registry).unwrap();
+        assert!(PluginRegistry::load(&path).unwrap().installs().is_empty());
+    }
+}
@@ -0,0 +1,506 @@
+//! Declarative sync policy loaded from `sync.toml`.
+//!
+//! [`SyncParams`](crate::SyncParams) only carries per-run flags. A
+//! [`SyncPolicy`] is the persistent, versionable counterpart: glob
+//! include/exclude lists and rename maps per artifact section, per-target
+//! overrides of both, a per-target conflict strategy, and a `never_touch`
+//! list of items skrills must never write.
+//!
+//! `~/.skrills/sync.toml` applies to every sync; in project scope the
+//! repository's `.skrills/sync.toml` is layered on top of it:
+//!
+//! ```toml
+//! # "<section>/<glob>" or a bare glob matching any section. Checked against
+//! # the name written on the target, after renames.
+//! never_touch = ["skills/internal-*", "deploy"]
+//!
+//! [skills]
+//! include = ["*"]
+//! exclude = ["experimental-*"]
+//! rename = { "review" = "code-review" }
+//!
+//! [targets.cursor]
+//! conflict_strategy = "merge"
+//!
+//! [targets.cursor.mcp_servers]
+//! exclude = ["local-*"]
+//! ```
+//!
+//! Sections are `skills`, `commands`, `agents`, `hooks`, `instructions` and
+//! `mcp_servers`. Patterns are the case-insensitive `*`/`?` globs of
+//! [`crate::models`]. Every exclusion carries the rule that caused it so
+//! reports can explain why an item was left out.
+
+use crate::common::{Command, McpServer};
+use crate::conflict::{ArtifactType, ConflictStrategy};
+use crate::models::glob_match;
+use crate::orchestrator::SyncScope;
+use crate::report::SkipReason;
+use crate::Result;
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// File name of the policy under `~/.skrills` and a project's `.skrills`.
+pub const POLICY_FILE_NAME: &str = "sync.toml";
+
+/// An artifact section of the policy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PolicySection {
+    Skills,
+    Commands,
+    Agents,
+    Hooks,
+    Instructions,
+    McpServers,
+}
+
+impl PolicySection {
+    /// Name of the section's table in `sync.toml`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Skills => "skills",
+            Self::Commands => "commands",
+            Self::Agents => "agents",
+            Self::Hooks => "hooks",
+            Self::Instructions => "instructions",
+            Self::McpServers => "mcp_servers",
+        }
+    }
+}
+
+impl fmt::Display for PolicySection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<ArtifactType> for PolicySection {
+    fn from(kind: ArtifactType) -> Self {
+        match kind {
+            ArtifactType::Skill => Self::Skills,
+            ArtifactType::Command => Self::Commands,
+            ArtifactType::Agent => Self::Agents,
+            ArtifactType::Hook => Self::Hooks,
+            ArtifactType::Instruction => Self::Instructions,
+        }
+    }
+}
+
+/// Include/exclude globs and renames for one section.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SectionRules {
+    /// When non-empty, only names matching one of these globs are synced.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub include: Vec<String>,
+    /// Names matching any of these globs are not synced.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub exclude: Vec<String>,
+    /// Source name → name written on the target.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub rename: BTreeMap<String, String>,
+}
+
+impl SectionRules {
+    fn layer(&mut self, other: SectionRules) {
+        if !other.include.is_empty() {
+            self.include = other.include;
+        }
+        self.exclude.extend(other.exclude);
+        self.rename.extend(other.rename);
+    }
+}
+
+/// Rules that apply either to every target or to a single one.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PolicyRules {
+    /// Items never written: `<section>/<glob>` or a bare glob.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub never_touch: Vec<String>,
+    #[serde(default)]
+    pub skills: SectionRules,
+    #[serde(default)]
+    pub commands: SectionRules,
+    #[serde(default)]
+    pub agents: SectionRules,
+    #[serde(default)]
+    pub hooks: SectionRules,
+    #[serde(default)]
+    pub instructions: SectionRules,
+    #[serde(default)]
+    pub mcp_servers: SectionRules,
+}
+
+impl PolicyRules {
+    /// Rules of `section`.
+    pub fn section(&self, section: PolicySection) -> &SectionRules {
+        match section {
+            PolicySection::Skills => &self.skills,
+            PolicySection::Commands => &self.commands,
+            PolicySection::Agents => &self.agents,
+            PolicySection::Hooks => &self.hooks,
+            PolicySection::Instructions => &self.instructions,
+            PolicySection::McpServers => &self.mcp_servers,
+        }
+    }
+
+    fn layer(&mut self, other: PolicyRules) {
+        self.never_touch.extend(other.never_touch);
+        self.skills.layer(other.skills);
+        self.commands.layer(other.commands);
+        self.agents.layer(other.agents);
+        self.hooks.layer(other.hooks);
+        self.instructions.layer(other.instructions);
+        self.mcp_servers.layer(other.mcp_servers);
+    }
+
+    /// Checks `name` against the include/exclude lists of `section`,
+    /// returning the rule that excludes it. `table` labels the rule.
+    fn excluded_by(&self, section: PolicySection, name: &str, table: &str) -> Option<String> {
+        let rules = self.section(section);
+        if !rules.include.is_empty() && !rules.include.iter().any(|p| glob_match(p, name)) {
+            return Some(format!("{table}{section}] include (no pattern matches)"));
+        }
+        rules
+            .exclude
+            .iter()
+            .find(|p| glob_match(p, name))
+            .map(|p| format!("{table}{section}] exclude \"{p}\""))
+    }
+
+    /// Returns the `never_touch` entry protecting `name` in `section`.
+    fn never_touch_by(&self, section: PolicySection, name: &str) -> Option<&str> {
+        self.never_touch
+            .iter()
+            .find(|entry| match entry.split_once('/') {
+                Some((s, pattern)) => s == section.as_str() && glob_match(pattern, name),
+                None => glob_match(entry, name),
+            })
+            .map(String::as_str)
+    }
+}
+
+/// Overrides for one target platform.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TargetPolicy {
+    /// Conflict strategy for syncs into this target.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub conflict_strategy: Option<ConflictStrategy>,
+    /// Rules checked in addition to the top-level ones.
+    #[serde(flatten)]
+    pub rules: PolicyRules,
+}
+
+/// Outcome of checking one item against a [`SyncPolicy`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PolicyDecision {
+    /// Sync the item under this target name.
+    Sync(String),
+    /// Leave the item out because of this rule.
+    Excluded(String),
+}
+
+/// Persistent sync policy.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SyncPolicy {
+    /// Rules for every target.
+    #[serde(flatten)]
+    pub rules: PolicyRules,
+    /// Per-target overrides keyed by platform name.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub targets: BTreeMap<String, TargetPolicy>,
+}
+
+impl SyncPolicy {
+    /// Loads a policy file.
+    pub fn load(path: &Path) -> Result<Self> {
+        let raw =
+            fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+        toml::from_str(&raw).with_context(|| format!("parsing {}", path.display()))
+    }
+
+    /// Loads [`policy_config_path`] and, in project scope, layers the
+    /// repository's `.skrills/sync.toml` over it. Missing files contribute
+    /// nothing.
+    ///
+    /// Layering keeps both files' excludes, `never_touch` entries and
+    /// renames (the project wins on the same source name); a non-empty
+    /// project `include` list and a project conflict strategy replace the
+    /// user ones.
+    pub fn load_default(scope: &SyncScope) -> Result<Self> {
+        let mut policy = match policy_config_path() {
+            Some(path) if path.exists() => Self::load(&path)?,
+            _ => Self::default(),
+        };
+        if let Some(dir) = scope.project_dir() {
+            let path = dir.join(".skrills").join(POLICY_FILE_NAME);
+            if path.exists() {
+                policy.layer(Self::load(&path)?);
+            }
+        }
+        Ok(policy)
+    }
+
+    fn layer(&mut self, other: SyncPolicy) {
+        self.rules.layer(other.rules);
+        for (target, overrides) in other.targets {
+            let entry = self.targets.entry(target).or_default();
+            entry.rules.layer(overrides.rules);
+            if overrides.conflict_strategy.is_some() {
+                entry.conflict_strategy = overrides.conflict_strategy;
+            }
+        }
+    }
+
+    /// Conflict strategy configured for syncs into `target`, if any.
+    pub fn conflict_strategy(&self, target: &str) -> Option<ConflictStrategy> {
+        self.targets.get(target)?.conflict_strategy
+    }
+
+    /// Decides whether the item `name` of `section` is synced to `target`,
+    /// and under which name.
+    ///
+    /// Include/exclude lists see the source name; renames apply next, and
+    /// `never_touch` is checked against the resulting target name.
+    pub fn decide(&self, section: PolicySection, name: &str, target: &str) -> PolicyDecision {
+        let overrides = self.targets.get(target).map(|t| &t.rules);
+        let target_table = format!("[targets.{target}.");
+
+        if let Some(rule) = self
+            .rules
+            .excluded_by(section, name, "[")
+            .or_else(|| overrides.and_then(|o| o.excluded_by(section, name, &target_table)))
+        {
+            return PolicyDecision::Excluded(format!("{POLICY_FILE_NAME} {rule}"));
+        }
+
+        let renamed = overrides
+            .and_then(|o| o.section(section).rename.get(name))
+            .or_else(|| self.rules.section(section).rename.get(name))
+            .map_or(name, String::as_str);
+
+        if let Some(entry) = self.rules.never_touch_by(section, renamed) {
+            return PolicyDecision::Excluded(format!("{POLICY_FILE_NAME} never_touch \"{entry}\""));
+        }
+        if let Some(entry) = overrides.and_then(|o| o.never_touch_by(section, renamed)) {
+            return PolicyDecision::Excluded(format!(
+                "{POLICY_FILE_NAME} [targets.{target}] never_touch \"{entry}\""
+            ));
+        }
+        PolicyDecision::Sync(renamed.to_string())
+    }
+
+    /// Filters and renames `items` for `target`, returning the kept items
+    /// and a [`SkipReason::ExcludedByConfig`] for each excluded one.
+    ///
+    /// Renamed items also get their frontmatter `name:` rewritten; the source
+    /// hash is kept so ledger comparisons still see the source file.
+    pub fn apply(
+        &self,
+        section: PolicySection,
+        items: Vec<Command>,
+        target: &str,
+    ) -> (Vec<Command>, Vec<SkipReason>) {
+        let mut skipped = Vec::new();
+        let kept = items
+            .into_iter()
+            .filter_map(|mut item| match self.decide(section, &item.name, target) {
+                PolicyDecision::Sync(name) => {
+                    if name != item.name {
+                        if let Ok(content) = std::str::from_utf8(&item.content) {
+                            item.content =
+                                set_frontmatter_name(content, &item.name, &name).into_bytes();
+                        }
+                        item.name = name;
+                    }
+                    Some(item)
+                }
+                PolicyDecision::Excluded(pattern) => {
+                    skipped.push(excluded(section, &item.name, pattern));
+                    None
+                }
+            })
+            .collect();
+        (kept, skipped)
+    }
+
+    /// [`Self::apply`] for MCP servers.
+    pub fn apply_mcp(
+        &self,
+        servers: HashMap<String, McpServer>,
+        target: &str,
+    ) -> (HashMap<String, McpServer>, Vec<SkipReason>) {
+        let mut skipped = Vec::new();
+        let mut kept = HashMap::new();
+        for (name, mut server) in servers {
+            match self.decide(PolicySection::McpServers, &name, target) {
+                PolicyDecision::Sync(renamed) => {
+                    server.name = renamed.clone();
+                    kept.insert(renamed, server);
+                }
+                PolicyDecision::Excluded(pattern) => {
+                    skipped.push(excluded(PolicySection::McpServers, &name, pattern));
+                }
+            }
+        }
+        (kept, skipped)
+    }
+}
+
+fn excluded(section: PolicySection, name: &str, pattern: String) -> SkipReason {
+    SkipReason::ExcludedByConfig {
+        item: format!("{section}/{name}"),
+        pattern,
+    }
+}
+
+/// Returns the user policy path.
+///
+/// `$SKRILLS_SYNC_POLICY` overrides the default `~/.skrills/sync.toml`.
+pub fn policy_config_path() -> Option<PathBuf> {
+    if let Ok(custom) = std::env::var("SKRILLS_SYNC_POLICY") {
+        return Some(PathBuf::from(custom));
+    }
+    Some(dirs::home_dir()?.join(".skrills").join(POLICY_FILE_NAME))
+}
+
+/// Rewrites a top-level frontmatter `name: <old>` line to `new`. Content
+/// without one is returned unchanged.
+fn set_frontmatter_name(content: &str, old: &str, new: &str) -> String {
+    let (Some(frontmatter), _) = crate::adapters::utils::split_frontmatter(content) else {
+        return content.to_string();
+    };
+    let Some(line) = frontmatter.lines().find(|line| {
+        line.strip_prefix("name:")
+            .is_some_and(|v| v.trim().trim_matches(|c| c == '"' || c == '\'') == old)
+    }) else {
+        return content.to_string();
+    };
+    content.replacen(line, &format!("name: {new}"), 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::utils::test_helpers::make_command;
+
+    const POLICY: &str = r#"
+never_touch = ["skills/internal-*", "deploy"]
+
+[skills]
+exclude = ["experimental-*"]
+rename = { "review" = "code-review" }
+
+[targets.cursor]
+conflict_strategy = "merge"
+never_touch = ["agents/*"]
+
+[targets.cursor.skills]
+include = ["code-*", "review", "commit"]
+"#;
+
+    fn policy() -> SyncPolicy {
+        toml::from_str(POLICY).unwrap()
+    }
+
+    #[test]
+    fn decide_explains_the_excluding_rule() {
+        let policy = policy();
+        let decide = |section, name, target| policy.decide(section, name, target);
+
+        assert_eq!(
+            decide(PolicySection::Skills, "experimental-x", "codex"),
+            PolicyDecision::Excluded("sync.toml [skills] exclude \"experimental-*\"".into())
+        );
+        assert_eq!(
+            decide(PolicySection::Skills, "lint", "cursor"),
+            PolicyDecision::Excluded(
+                "sync.toml [targets.cursor.skills] include (no pattern matches)".into()
+            )
+        );
+        assert_eq!(
+            decide(PolicySection::Skills, "internal-tools", "codex"),
+            PolicyDecision::Excluded("sync.toml never_touch \"skills/internal-*\"".into())
+        );
+        assert_eq!(
+            decide(PolicySection::Commands, "deploy", "codex"),
+            PolicyDecision::Excluded("sync.toml never_touch \"deploy\"".into())
+        );
+        assert_eq!(
+            decide(PolicySection::Agents, "builder", "cursor"),
+            PolicyDecision::Excluded("sync.toml [targets.cursor] never_touch \"agents/*\"".into())
+        );
+        assert_eq!(
+            decide(PolicySection::Agents, "builder", "codex"),
+            PolicyDecision::Sync("builder".into())
+        );
+        assert_eq!(
+            decide(PolicySection::Skills, "review", "cursor"),
+            PolicyDecision::Sync("code-review".into())
+        );
+        assert_eq!(
+            policy.conflict_strategy("cursor"),
+            Some(ConflictStrategy::Merge)
+        );
+        assert_eq!(policy.conflict_strategy("codex"), None);
+    }
+
+    #[test]
+    fn apply_renames_and_reports_exclusions() {
+        let items = vec![
+            make_command("review", "---\nname: review\n---\n# Review\n"),
+            make_command("experimental-x", "# X\n"),
+        ];
+        let (kept, skipped) = policy().apply(PolicySection::Skills, items, "codex");
+
+        assert_eq!(kept.len(), 1);
+        assert_eq!(kept[0].name, "code-review");
+        assert_eq!(kept[0].content, b"---\nname: code-review\n---\n# Review\n");
+        assert!(matches!(
+            &skipped[..],
+            [SkipReason::ExcludedByConfig { item, .. }] if item == "skills/experimental-x"
+        ));
+    }
+
+    #[test]
+    fn project_policy_layers_over_user_policy() {
+        let mut policy = policy();
+        policy.layer(
+            toml::from_str(
+                r#"
+[skills]
+exclude = ["wip-*"]
+rename = { "review" = "pr-review" }
+
+[targets.cursor]
+conflict_strategy = "force_source"
+"#,
+            )
+            .unwrap(),
+        );
+
+        assert_eq!(policy.rules.skills.exclude, ["experimental-*", "wip-*"]);
+        assert_eq!(
+            policy.decide(PolicySection::Skills, "review", "codex"),
+            PolicyDecision::Sync("pr-review".into())
+        );
+        assert_eq!(
+            policy.conflict_strategy("cursor"),
+            Some(ConflictStrategy::ForceSource)
+        );
+        // Unset project values keep the user ones.
+        assert_eq!(policy.targets["cursor"].rules.skills.include.len(), 3);
+    }
+
+    #[test]
+    fn empty_policy_syncs_everything() {
+        let policy = SyncPolicy::default();
+        assert_eq!(
+            policy.decide(PolicySection::McpServers, "github", "claude"),
+            PolicyDecision::Sync("github".into())
+        );
+    }
+}
@@ -1,10 +1,17 @@
 //! Interactive sync preview: collects pending changes, computes diffs, and lets
 //! the user accept or reject each file before writing.
+//!
+//! A [`ChangeSet`] whose changes carry a [`FileChange`] is what a
+//! [`SyncPlan`](crate::plan::SyncPlan) serializes for review.
 
+use base64::Engine;
+use serde::{Deserialize, Serialize};
 use similar::TextDiff;
+use std::path::PathBuf;
 
 /// The kind of artifact being synced.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub enum ArtifactKind {
     Command,
     Skill,
@@ -32,7 +39,7 @@ impl std::fmt::Display for ArtifactKind {
 }
 
 /// A single pending change that has not yet been written.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PendingChange {
     /// What type of artifact this is.
     pub kind: ArtifactKind,
@@ -42,10 +49,54 @@ pub struct PendingChange {
     pub diff_text: String,
     /// True when the file is entirely new (no previous content).
     pub is_new: bool,
+    /// The file write behind the change, when it is known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file: Option<FileChange>,
+}
+
+/// The exact file write a change performs.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileChange {
+    /// Path relative to the target's managed root.
+    pub path: PathBuf,
+    /// Hash of the file before the change; `None` when it does not exist.
+    pub hash_before: Option<String>,
+    /// Hash of the file after the change; `None` when it is deleted.
+    pub hash_after: Option<String>,
+    /// Content to write; `None` when the file is deleted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<FileContent>,
+}
+
+/// File content, stored as text when it is valid UTF-8.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "encoding", content = "data", rename_all = "kebab-case")]
+pub enum FileContent {
+    Utf8(String),
+    Base64(String),
+}
+
+impl FileContent {
+    /// Encodes `bytes`, as text when possible.
+    pub fn encode(bytes: &[u8]) -> Self {
+        match std::str::from_utf8(bytes) {
+            Ok(text) => Self::Utf8(text.to_string()),
+            Err(_) => Self::Base64(base64::engine::general_purpose::STANDARD.encode(bytes)),
+        }
+    }
+
+    /// Decodes the content back to bytes.
+    pub fn decode(&self) -> crate::Result<Vec<u8>> {
+        match self {
+            Self::Utf8(text) => Ok(text.as_bytes().to_vec()),
+            Self::Base64(data) => Ok(base64::engine::general_purpose::STANDARD.decode(data)?),
+        }
+    }
 }
 
 /// A collection of pending changes to be reviewed.
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(transparent)]
 pub struct ChangeSet {
     pub changes: Vec<PendingChange>,
 }
@@ -207,6 +258,7 @@ mod tests {
             name: "hello".into(),
             diff_text: String::new(),
             is_new: true,
+            file: None,
         });
         assert_eq!(cs.len(), 1);
         assert!(!cs.is_empty());
@@ -227,4 +279,16 @@ mod tests {
         assert_eq!(format!("{}", ArtifactKind::Instruction), "instruction");
         assert_eq!(format!("{}", ArtifactKind::PluginAsset), "plugin-asset");
     }
+
+    #[test]
+    fn file_content_round_trips_text_and_binary() {
+        let text = FileContent::encode(b"# Skill\n");
+        assert_eq!(text, FileContent::Utf8("# Skill\n".into()));
+        let binary = FileContent::encode(&[0xff, 0x00, 0xfe]);
+        assert!(matches!(binary, FileContent::Base64(_)));
+        assert_eq!(binary.decode().unwrap(), vec![0xff, 0x00, 0xfe]);
+
+        let json = serde_json::to_string(&binary).unwrap();
+        assert!(json.contains("\"encoding\":\"base64\""));
+    }
 }
@@ -31,6 +31,17 @@ pub enum SkipReason {
     WouldOverwrite { item: String },
     /// Excluded by --exclude-plugins filter
     PluginExcluded,
+    /// Target copy was edited since the last sync while the source was not
+    TargetModified { item: String },
+    /// Both sides changed since the last sync and the source did not win
+    Conflict {
+        item: String,
+        resolution: crate::conflict::Resolution,
+    },
+    /// Target copy still holds conflict markers from an earlier merge
+    UnresolvedMerge { item: String },
+    /// Hook event the target CLI has no equivalent for
+    UnsupportedHookEvent { event: String, target: String },
 }
 
 impl SkipReason {
@@ -66,6 +77,21 @@ impl SkipReason {
                 format!("{} already exists on target (would overwrite)", item)
             }
             Self::PluginExcluded => "excluded by --exclude-plugins filter".to_string(),
+            Self::TargetModified { item } => {
+                format!("{} modified on target since last sync", item)
+            }
+            Self::Conflict { item, resolution } => {
+                format!(
+                    "{} changed on both sides since last sync ({})",
+                    item, resolution
+                )
+            }
+            Self::UnresolvedMerge { item } => {
+                format!("{} has unresolved merge conflict markers on target", item)
+            }
+            Self::UnsupportedHookEvent { event, target } => {
+                format!("Hook event '{}' has no {} equivalent", event, target)
+            }
         }
     }
 
@@ -80,10 +106,45 @@ impl SkipReason {
             Self::ParseError { .. } => Some("Fix the source file syntax"),
             Self::WouldOverwrite { .. } => Some("Use --skip-existing-commands to keep target copy"),
             Self::PluginExcluded => None,
+            Self::TargetModified { .. } => {
+                Some("Sync in the other direction or use --force to overwrite the target edit")
+            }
+            Self::Conflict { .. } => {
+                Some("Resolve interactively or use --force to keep the source")
+            }
+            Self::UnresolvedMerge { .. } => {
+                Some("Edit the target copy to resolve the <<<<<<< / >>>>>>> hunks, then sync again")
+            }
+            Self::UnsupportedHookEvent { .. } => {
+                Some("Run this hook from a supported event, or keep it on the source CLI only")
+            }
         }
     }
 }
 
+/// Result of merging one item changed on both sides.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MergeOutcome {
+    /// Item name.
+    pub item: String,
+    /// Whether the merge was clean, merged, or left conflict markers.
+    pub status: crate::merge::MergeStatus,
+    /// Number of conflict hunks written to the target.
+    #[serde(default)]
+    pub conflicts: usize,
+}
+
+/// A model id the [`crate::models::ModelMap`] had no rule for.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UnmappedModel {
+    /// Item carrying the model (`preferences` or an agent name).
+    pub item: String,
+    /// Source model id.
+    pub model: String,
+    /// Policy applied to the model.
+    pub policy: crate::models::UnmappedPolicy,
+}
+
 /// Report for a write operation on a single artifact type.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct WriteReport {
@@ -96,6 +157,9 @@ pub struct WriteReport {
     /// Number of duplicate items dropped before sync
     #[serde(default)]
     pub duplicates: usize,
+    /// Three-way merges performed for items changed on both sides
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub merges: Vec<MergeOutcome>,
 }
 
 /// Complete sync report across all artifact types.
@@ -114,6 +178,15 @@ pub struct SyncReport {
     pub success: bool,
     /// Summary message
     pub summary: String,
+    /// Id of the snapshot taken before writing, if the run changed any files
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snapshot: Option<String>,
+    /// Models no mapping rule covered for the target
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub unmapped_models: Vec<UnmappedModel>,
+    /// Environment variables the synced MCP servers need on the target
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub mcp_secrets: Vec<crate::secrets::McpSecret>,
 }
 
 impl SyncReport {
@@ -149,6 +222,59 @@ impl SyncReport {
             + self.plugin_assets.skipped.len()
     }
 
+    /// Returns skip reasons caused by target-side edits or unresolved
+    /// conflicts detected against the sync-state ledger.
+    pub fn conflicts(&self) -> Vec<&SkipReason> {
+        [
+            &self.skills,
+            &self.commands,
+            &self.agents,
+            &self.hooks,
+            &self.instructions,
+        ]
+        .into_iter()
+        .flat_map(|r| r.skipped.iter())
+        .filter(|r| {
+            matches!(
+                r,
+                SkipReason::TargetModified { .. }
+                    | SkipReason::Conflict { .. }
+                    | SkipReason::UnresolvedMerge { .. }
+            )
+        })
+        .collect()
+    }
+
+    /// Returns items the sync policy left out, each naming its rule.
+    pub fn policy_exclusions(&self) -> Vec<&SkipReason> {
+        [
+            &self.skills,
+            &self.commands,
+            &self.mcp_servers,
+            &self.agents,
+            &self.hooks,
+            &self.instructions,
+        ]
+        .into_iter()
+        .flat_map(|r| r.skipped.iter())
+        .filter(|r| matches!(r, SkipReason::ExcludedByConfig { .. }))
+        .collect()
+    }
+
+    /// Returns three-way merge outcomes across all artifact types.
+    pub fn merges(&self) -> Vec<&MergeOutcome> {
+        [
+            &self.skills,
+            &self.commands,
+            &self.agents,
+            &self.hooks,
+            &self.instructions,
+        ]
+        .into_iter()
+        .flat_map(|r| r.merges.iter())
+        .collect()
+    }
+
     /// Generates a formatted summary for display.
     pub fn format_summary(&self, source: &str, target: &str) -> String {
         fn line(label: &str, r: &WriteReport) -> String {
@@ -177,6 +303,71 @@ impl SyncReport {
             out.push_str(&line("Plugin Assets:", &self.plugin_assets));
         }
 
+        let conflicts = self.conflicts();
+        if !conflicts.is_empty() {
+            out.push_str(&format!(
+                "\n  ⚠ {} item(s) changed on the target since last sync; target copy kept:\n",
+                conflicts.len()
+            ));
+            for reason in conflicts {
+                out.push_str(&format!("    - {}\n", reason.description()));
+            }
+        }
+
+        let excluded = self.policy_exclusions();
+        if !excluded.is_empty() {
+            out.push_str(&format!(
+                "\n  {} item(s) excluded by sync policy:\n",
+                excluded.len()
+            ));
+            for reason in excluded {
+                out.push_str(&format!("    - {}\n", reason.description()));
+            }
+        }
+
+        let merges = self.merges();
+        if !merges.is_empty() {
+            out.push_str(&format!(
+                "\n  {} item(s) changed on both sides were merged:\n",
+                merges.len()
+            ));
+            for merge in merges {
+                if merge.conflicts > 0 {
+                    out.push_str(&format!(
+                        "    - {} ({}, {} hunk(s) with markers on target)\n",
+                        merge.item, merge.status, merge.conflicts
+                    ));
+                } else {
+                    out.push_str(&format!("    - {} ({})\n", merge.item, merge.status));
+                }
+            }
+        }
+
+        if !self.unmapped_models.is_empty() {
+            out.push_str(&format!(
+                "\n  ⚠ {} model(s) have no mapping for {}; add rules to ~/.skrills/models.toml:\n",
+                self.unmapped_models.len(),
+                target
+            ));
+            for unmapped in &self.unmapped_models {
+                out.push_str(&format!(
+                    "    - {}: {} ({})\n",
+                    unmapped.item, unmapped.model, unmapped.policy
+                ));
+            }
+        }
+
+        if !self.mcp_secrets.is_empty() {
+            out.push_str(&format!(
+                "\n  ⚠ {} MCP secret(s) found; {} reads referenced ones from the environment:\n",
+                self.mcp_secrets.len(),
+                target
+            ));
+            for secret in &self.mcp_secrets {
+                out.push_str(&format!("    - {}\n", secret.description()));
+            }
+        }
+
         let total_dups = self.skills.duplicates
             + self.commands.duplicates
             + self.agents.duplicates
@@ -189,6 +380,13 @@ impl SyncReport {
                 total_dups
             ));
         }
+
+        if let Some(id) = &self.snapshot {
+            out.push_str(&format!(
+                "\nSnapshot {} saved; run `skrills sync-undo --id {}` to roll back.\n",
+                id, id
+            ));
+        }
         out
     }
 }
@@ -407,6 +605,7 @@ mod tests {
                 }],
                 warnings: vec!["Warning 1".to_string()],
                 duplicates: 0,
+                merges: Vec::new(),
             };
 
             assert_eq!(report.written, 5);
@@ -597,5 +796,85 @@ mod tests {
                 "Summary should omit Plugin Assets line when empty"
             );
         }
+
+        #[test]
+        fn given_conflict_skips_when_format_summary_then_lists_them() {
+            let mut report = SyncReport::new();
+            report.skills.skipped.push(SkipReason::Conflict {
+                item: "commit".to_string(),
+                resolution: crate::conflict::Resolution::Skip,
+            });
+            report.commands.skipped.push(SkipReason::TargetModified {
+                item: "review".to_string(),
+            });
+            report.commands.skipped.push(SkipReason::PluginExcluded);
+
+            assert_eq!(report.conflicts().len(), 2);
+            let summary = report.format_summary("claude", "cursor");
+            assert!(summary.contains("2 item(s) changed on the target"));
+            assert!(summary.contains("commit changed on both sides"));
+            assert!(summary.contains("review modified on target"));
+        }
+
+        #[test]
+        fn given_merges_when_format_summary_then_lists_outcomes() {
+            use crate::merge::MergeStatus;
+
+            let mut report = SyncReport::new();
+            report.skills.merges.push(MergeOutcome {
+                item: "commit".to_string(),
+                status: MergeStatus::Merged,
+                conflicts: 0,
+            });
+            report.commands.merges.push(MergeOutcome {
+                item: "review".to_string(),
+                status: MergeStatus::Conflicted,
+                conflicts: 2,
+            });
+
+            assert_eq!(report.merges().len(), 2);
+            let summary = report.format_summary("claude", "codex");
+            assert!(summary.contains("2 item(s) changed on both sides were merged"));
+            assert!(summary.contains("commit (merged)"));
+            assert!(summary.contains("review (conflicted, 2 hunk(s) with markers on target)"));
+        }
+
+        #[test]
+        fn given_policy_exclusions_when_format_summary_then_names_rules() {
+            let mut report = SyncReport::new();
+            report
+                .mcp_servers
+                .skipped
+                .push(SkipReason::ExcludedByConfig {
+                    item: "mcp_servers/local-db".to_string(),
+                    pattern: "sync.toml [mcp_servers] exclude \"local-*\"".to_string(),
+                });
+            report.skills.skipped.push(SkipReason::PluginExcluded);
+
+            assert_eq!(report.policy_exclusions().len(), 1);
+            let summary = report.format_summary("claude", "cursor");
+            assert!(summary.contains("1 item(s) excluded by sync policy"));
+            assert!(summary.contains(
+                "mcp_servers/local-db excluded by pattern: sync.toml [mcp_servers] exclude \"local-*\""
+            ));
+        }
+
+        #[test]
+        fn given_mcp_secrets_when_format_summary_then_lists_variables() {
+            use crate::secrets::{McpSecret, SecretAction};
+
+            let mut report = SyncReport::new();
+            report.mcp_secrets.push(McpSecret {
+                server: "github".to_string(),
+                field: "env.GITHUB_TOKEN".to_string(),
+                variable: "GITHUB_TOKEN".to_string(),
+                action: SecretAction::Referenced,
+                length: None,
+            });
+
+            let summary = report.format_summary("claude", "cursor");
+            assert!(summary.contains("1 MCP secret(s) found; cursor reads referenced ones"));
+            assert!(summary.contains("github env.GITHUB_TOKEN → $GITHUB_TOKEN (referenced)"));
+        }
     }
 }
@@ -0,0 +1,459 @@
+//! Section-aware instruction documents.
+//!
+//! Instruction files such as `CLAUDE.md` and `AGENTS.md` often mix synced
+//! guidance with hand-written, tool-specific notes. Rather than overwrite the
+//! whole target file, skrills splits the source into [`Section`]s at its
+//! `#` and `##` headings and keeps each one in a *managed region* of the
+//! target:
+//!
+//! ```markdown
+//! <!-- skrills:begin build -->
+//! ## Build
+//! Run `cargo build`.
+//! <!-- skrills:end build -->
+//! ```
+//!
+//! [`merge_managed`] rewrites only those regions. Text outside them is left
+//! exactly as written. A hand-written section with the same heading as a
+//! source section is left alone (and reported) unless it already matches the
+//! source, in which case it is adopted as a managed region. Regions whose
+//! section was removed from the source are dropped.
+//!
+//! [`diff_sections`] compares two documents section by section, ignoring the
+//! region markers, for `skill-diff --instructions`.
+
+use crate::adapters::utils::sanitize_name_kebab;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+const BEGIN_PREFIX: &str = "<!-- skrills:begin ";
+const END_PREFIX: &str = "<!-- skrills:end ";
+const MARKER_SUFFIX: &str = " -->";
+
+/// Key of the text before the first heading.
+pub const PREAMBLE_KEY: &str = "preamble";
+
+/// Deepest heading level that starts a new section; deeper headings stay
+/// part of their parent.
+const MAX_SECTION_LEVEL: usize = 2;
+
+/// A run of markdown starting at a heading (or the start of the document).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Section {
+    /// Stable identifier: the heading as kebab-case, suffixed `-2`, `-3`, ...
+    /// for repeated headings, or [`PREAMBLE_KEY`].
+    pub key: String,
+    /// Heading text without the `#` markers.
+    pub heading: Option<String>,
+    /// The section's lines, heading included, each ending in a newline.
+    pub text: String,
+}
+
+/// Splits `content` into sections at `#` and `##` headings.
+///
+/// Headings inside fenced code blocks are ignored, as are managed-region
+/// markers, so a document written by [`merge_managed`] parses like its
+/// source.
+pub fn parse_sections(content: &str) -> Vec<Section> {
+    split_sections(content.lines().filter(|line| marker(line).is_none()))
+}
+
+fn split_sections<'a>(lines: impl Iterator<Item = &'a str>) -> Vec<Section> {
+    let mut sections: Vec<Section> = Vec::new();
+    let mut seen: HashMap<String, usize> = HashMap::new();
+    let mut fence: Option<&str> = None;
+
+    for line in lines {
+        let trimmed = line.trim_start();
+        let heading = if fence.is_some() { None } else { heading(line) };
+        for delim in ["```", "~~~"] {
+            if trimmed.starts_with(delim) {
+                match fence {
+                    Some(open) if open == delim => fence = None,
+                    None => fence = Some(delim),
+                    Some(_) => {}
+                }
+            }
+        }
+
+        if let Some(title) = heading {
+            let base = match sanitize_name_kebab(title) {
+                slug if slug.is_empty() => "section".to_string(),
+                slug => slug,
+            };
+            let count = seen.entry(base.clone()).or_default();
+            *count += 1;
+            let key = match *count {
+                1 => base,
+                n => format!("{}-{}", base, n),
+            };
+            sections.push(Section {
+                key,
+                heading: Some(title.to_string()),
+                text: String::new(),
+            });
+        } else if sections.is_empty() {
+            sections.push(Section {
+                key: PREAMBLE_KEY.to_string(),
+                heading: None,
+                text: String::new(),
+            });
+        }
+        let current = sections.last_mut().expect("a section was just pushed");
+        current.text.push_str(line);
+        current.text.push('\n');
+    }
+    sections
+}
+
+/// Returns the title of a section-starting ATX heading.
+fn heading(line: &str) -> Option<&str> {
+    if line.starts_with("    ") || line.starts_with('\t') {
+        return None;
+    }
+    let trimmed = line.trim_start();
+    let level = trimmed.chars().take_while(|c| *c == '#').count();
+    if level == 0 || level > MAX_SECTION_LEVEL {
+        return None;
+    }
+    let rest = &trimmed[level..];
+    if !rest.is_empty() && !rest.starts_with([' ', '\t']) {
+        return None;
+    }
+    Some(rest.trim().trim_end_matches('#').trim_end())
+}
+
+enum Marker<'a> {
+    Begin(&'a str),
+    End(&'a str),
+}
+
+fn marker(line: &str) -> Option<Marker<'_>> {
+    let line = line.trim();
+    let key = |prefix: &str| {
+        line.strip_prefix(prefix)
+            .and_then(|rest| rest.strip_suffix(MARKER_SUFFIX))
+            .map(str::trim)
+    };
+    key(BEGIN_PREFIX)
+        .map(Marker::Begin)
+        .or_else(|| key(END_PREFIX).map(Marker::End))
+}
+
+/// A piece of an existing target document.
+enum Block<'a> {
+    /// Lines between a begin marker and its end marker.
+    Managed(&'a str),
+    /// Consecutive lines outside any managed region.
+    Unmanaged(Vec<&'a str>),
+}
+
+/// Splits `content` into managed regions and the text around them.
+///
+/// A begin marker without a matching end marker is kept as plain text, so a
+/// damaged file never loses content.
+fn blocks(content: &str) -> Vec<Block<'_>> {
+    let lines: Vec<&str> = content.lines().collect();
+    let mut blocks = Vec::new();
+    let mut plain = Vec::new();
+    let mut i = 0;
+    while i < lines.len() {
+        if let Some(Marker::Begin(key)) = marker(lines[i]) {
+            let end = lines[i + 1..]
+                .iter()
+                .position(|l| matches!(marker(l), Some(Marker::End(k)) if k == key));
+            if let Some(offset) = end {
+                if !plain.is_empty() {
+                    blocks.push(Block::Unmanaged(std::mem::take(&mut plain)));
+                }
+                blocks.push(Block::Managed(key));
+                i += offset + 2;
+                continue;
+            }
+        }
+        plain.push(lines[i]);
+        i += 1;
+    }
+    if !plain.is_empty() {
+        blocks.push(Block::Unmanaged(plain));
+    }
+    blocks
+}
+
+/// Result of [`merge_managed`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SectionMerge {
+    /// The merged document.
+    pub content: String,
+    /// Headings of source sections not written because the target has a
+    /// different hand-written section under the same heading.
+    pub shadowed: Vec<String>,
+}
+
+/// Writes the sections of `source` into the managed regions of `existing`.
+///
+/// Existing regions are replaced with the current source section or dropped
+/// if the source no longer has it. Source sections without a region are
+/// appended at the end, except those shadowed by a hand-written section with
+/// the same heading (see the module docs).
+pub fn merge_managed(existing: &str, source: &str) -> SectionMerge {
+    let sections = parse_sections(source);
+    let by_key: HashMap<&str, &Section> = sections.iter().map(|s| (s.key.as_str(), s)).collect();
+    let mut placed: HashSet<&str> = HashSet::new();
+    let mut shadowed = Vec::new();
+    let mut out = String::new();
+
+    for block in blocks(existing) {
+        match block {
+            Block::Managed(key) => {
+                if let Some(section) = by_key.get(key) {
+                    if placed.insert(section.key.as_str()) {
+                        push_region(&mut out, section);
+                    }
+                }
+            }
+            Block::Unmanaged(lines) => {
+                for local in split_sections(lines.into_iter()) {
+                    let matched = by_key
+                        .get(local.key.as_str())
+                        .filter(|s| !placed.contains(s.key.as_str()))
+                        .filter(|_| !local.text.trim().is_empty());
+                    let Some(section) = matched else {
+                        out.push_str(&local.text);
+                        continue;
+                    };
+                    placed.insert(section.key.as_str());
+                    if local.text.trim_end() == section.text.trim_end() {
+                        push_region(&mut out, section);
+                    } else {
+                        out.push_str(&local.text);
+                        shadowed.push(
+                            section
+                                .heading
+                                .clone()
+                                .unwrap_or_else(|| PREAMBLE_KEY.to_string()),
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    let mut separated = out.is_empty() || out.ends_with("\n\n");
+    for section in &sections {
+        if placed.contains(section.key.as_str()) {
+            continue;
+        }
+        if !separated {
+            if !out.ends_with('\n') {
+                out.push('\n');
+            }
+            out.push('\n');
+            separated = true;
+        }
+        push_region(&mut out, section);
+    }
+
+    SectionMerge {
+        content: out,
+        shadowed,
+    }
+}
+
+/// [`merge_managed`] over raw file contents.
+///
+/// Falls back to replacing the whole file with `source` when either side is
+/// not UTF-8.
+pub fn merge_managed_bytes(existing: Option<&[u8]>, source: &[u8]) -> (Vec<u8>, Vec<String>) {
+    let existing = existing.map(std::str::from_utf8).unwrap_or(Ok(""));
+    match (existing, std::str::from_utf8(source)) {
+        (Ok(existing), Ok(source)) => {
+            let merged = merge_managed(existing, source);
+            (merged.content.into_bytes(), merged.shadowed)
+        }
+        _ => (source.to_vec(), Vec::new()),
+    }
+}
+
+fn push_region(out: &mut String, section: &Section) {
+    out.push_str(&format!(
+        "{}{}{}\n",
+        BEGIN_PREFIX, section.key, MARKER_SUFFIX
+    ));
+    out.push_str(&section.text);
+    out.push_str(&format!("{}{}{}\n", END_PREFIX, section.key, MARKER_SUFFIX));
+}
+
+/// How a section differs between two documents.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SectionStatus {
+    Identical,
+    Changed,
+    OnlyInLeft,
+    OnlyInRight,
+}
+
+/// One section compared across two documents.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SectionDrift {
+    pub key: String,
+    pub heading: Option<String>,
+    pub status: SectionStatus,
+    /// Section text in the left document.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub left: Option<String>,
+    /// Section text in the right document.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub right: Option<String>,
+}
+
+/// Compares `left` and `right` section by section.
+///
+/// Sections are matched by key, in `left`'s order followed by sections only
+/// `right` has. Trailing whitespace and managed-region markers are ignored.
+pub fn diff_sections(left: &str, right: &str) -> Vec<SectionDrift> {
+    let left = parse_sections(left);
+    let right = parse_sections(right);
+    let right_by_key: HashMap<&str, &Section> = right.iter().map(|s| (s.key.as_str(), s)).collect();
+    let left_keys: HashSet<&str> = left.iter().map(|s| s.key.as_str()).collect();
+
+    let mut drift = Vec::new();
+    for section in &left {
+        let other = right_by_key.get(section.key.as_str());
+        let status = match other {
+            None => SectionStatus::OnlyInLeft,
+            Some(o) if o.text.trim_end() == section.text.trim_end() => SectionStatus::Identical,
+            Some(_) => SectionStatus::Changed,
+        };
+        drift.push(SectionDrift {
+            key: section.key.clone(),
+            heading: section.heading.clone(),
+            status,
+            left: Some(section.text.clone()),
+            right: other.map(|o| o.text.clone()),
+        });
+    }
+    for section in right.iter().filter(|s| !left_keys.contains(s.key.as_str())) {
+        drift.push(SectionDrift {
+            key: section.key.clone(),
+            heading: section.heading.clone(),
+            status: SectionStatus::OnlyInRight,
+            left: None,
+            right: Some(section.text.clone()),
+        });
+    }
+    drift
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = "# Project\nIntro.\n\n## Build\nRun `cargo build`.\n\n### Flags\nUse --release.\n\n## Test\nRun `cargo test`.\n";
+
+    #[test]
+    fn splits_at_top_two_heading_levels() {
+        let sections = parse_sections(SOURCE);
+        let keys: Vec<&str> = sections.iter().map(|s| s.key.as_str()).collect();
+        assert_eq!(keys, ["project", "build", "test"]);
+        assert!(sections[1].text.contains("### Flags"));
+        assert_eq!(sections[2].heading.as_deref(), Some("Test"));
+
+        let fenced = parse_sections("Intro\n```sh\n# not a heading\n```\n## A\n## A\n");
+        let keys: Vec<&str> = fenced.iter().map(|s| s.key.as_str()).collect();
+        assert_eq!(keys, [PREAMBLE_KEY, "a", "a-2"]);
+    }
+
+    #[test]
+    fn merge_into_empty_target_wraps_every_section() {
+        let merged = merge_managed("", SOURCE);
+        assert!(merged
+            .content
+            .starts_with("<!-- skrills:begin project -->\n# Project\n"));
+        assert!(merged
+            .content
+            .contains("Run `cargo test`.\n<!-- skrills:end test -->\n"));
+        assert_eq!(
+            parse_sections(&merged.content),
+            parse_sections(SOURCE),
+            "markers are invisible to the parser"
+        );
+        // Merging again changes nothing.
+        assert_eq!(
+            merge_managed(&merged.content, SOURCE).content,
+            merged.content
+        );
+    }
+
+    #[test]
+    fn merge_updates_managed_regions_and_keeps_hand_written_text() {
+        let target = format!(
+            "{}\n## Codex notes\nUse the sandbox.\n",
+            merge_managed("", SOURCE).content
+        );
+        let source = SOURCE.replace("cargo test", "cargo nextest run").replace(
+            "## Build\nRun `cargo build`.\n\n### Flags\nUse --release.\n\n",
+            "",
+        );
+
+        let merged = merge_managed(&target, &source);
+
+        assert!(merged.content.contains("Run `cargo nextest run`."));
+        assert!(
+            !merged.content.contains("## Build"),
+            "removed section dropped"
+        );
+        assert!(merged
+            .content
+            .ends_with("## Codex notes\nUse the sandbox.\n"));
+        assert!(merged.shadowed.is_empty());
+    }
+
+    #[test]
+    fn hand_written_sections_with_source_headings_are_not_overwritten() {
+        let target = "# Project\nIntro.\n\n## Test\nRun the Codex test harness.\n";
+        let merged = merge_managed(target, SOURCE);
+
+        // Identical sections are adopted, differing ones left alone.
+        assert!(merged.content.starts_with(
+            "<!-- skrills:begin project -->\n# Project\nIntro.\n\n<!-- skrills:end project -->\n"
+        ));
+        assert!(merged
+            .content
+            .contains("## Test\nRun the Codex test harness.\n"));
+        assert!(!merged.content.contains("Run `cargo test`."));
+        assert_eq!(merged.shadowed, ["Test"]);
+        // New sections are appended after a blank line.
+        assert!(merged
+            .content
+            .contains("harness.\n\n<!-- skrills:begin build -->\n## Build\n"));
+    }
+
+    #[test]
+    fn unterminated_region_is_kept_as_text() {
+        let target = "<!-- skrills:begin test -->\n## Test\nmine\n";
+        let merged = merge_managed(target, "## Test\ntheirs\n");
+        assert!(merged.content.contains("mine"));
+        assert_eq!(merged.shadowed, ["Test"]);
+    }
+
+    #[test]
+    fn diff_reports_section_drift() {
+        let agents = merge_managed("", &SOURCE.replace("cargo test", "cargo nextest run")).content;
+        let agents = format!("{}## Sandbox\nOn.\n", agents);
+
+        let drift = diff_sections(SOURCE, &agents);
+        let status: Vec<(&str, SectionStatus)> =
+            drift.iter().map(|d| (d.key.as_str(), d.status)).collect();
+        assert_eq!(
+            status,
+            [
+                ("project", SectionStatus::Identical),
+                ("build", SectionStatus::Identical),
+                ("test", SectionStatus::Changed),
+                ("sandbox", SectionStatus::OnlyInRight),
+            ]
+        );
+    }
+}
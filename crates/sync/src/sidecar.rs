@@ -0,0 +1,230 @@
+//! Metadata sidecars for adapters whose on-disk format is lossy.
+//!
+//! Some targets cannot represent everything a source artifact carries: Cursor
+//! skills have no frontmatter (name, dependencies, version and tags are
+//! stripped) and Cursor agents drop `tools`/`isolation`. A lossy adapter keeps
+//! a [`SidecarManifest`] in its config root that records, per written item,
+//! the original document, the fields the target format dropped, and the hash
+//! of what was written.
+//!
+//! When the adapter is later read as a sync *source*, items whose content
+//! still hashes to what skrills wrote are rehydrated with the original
+//! document, so a Claude → Cursor → Claude round-trip is lossless. Items edited
+//! in the target no longer match and are read as-is.
+
+use crate::adapters::utils::{hash_content, split_frontmatter};
+use crate::common::Command;
+use crate::conflict::ArtifactType;
+use crate::Result;
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// File name of the sidecar manifest inside an adapter's config root.
+pub const SIDECAR_FILE: &str = ".skrills-sidecar.json";
+
+/// What skrills knows about one item written to a lossy target.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SidecarEntry {
+    /// Hash of the content written to the target.
+    pub written_hash: String,
+    /// The document as the source provided it, frontmatter included.
+    pub original: String,
+    /// Frontmatter fields the target format could not represent.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dropped: Vec<String>,
+}
+
+/// Per-target record of the original metadata of items written lossily.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SidecarManifest {
+    /// Entries keyed by `<artifact type>/<item name>`.
+    #[serde(default)]
+    entries: BTreeMap<String, SidecarEntry>,
+    #[serde(skip)]
+    dirty: bool,
+}
+
+impl SidecarManifest {
+    /// Path of the sidecar manifest for an adapter rooted at `root`.
+    pub fn path(root: &Path) -> PathBuf {
+        root.join(SIDECAR_FILE)
+    }
+
+    /// Loads the sidecar of `root`. A missing file yields an empty manifest;
+    /// a malformed one is logged and ignored so syncing keeps working.
+    pub fn load(root: &Path) -> Self {
+        let path = Self::path(root);
+        let Ok(content) = fs::read_to_string(&path) else {
+            return Self::default();
+        };
+        serde_json::from_str(&content).unwrap_or_else(|e| {
+            tracing::warn!(path = %path.display(), error = %e, "Ignoring malformed sidecar manifest");
+            Self::default()
+        })
+    }
+
+    /// Writes the manifest back to `root` if it changed since loading.
+    pub fn save(&self, root: &Path) -> Result<()> {
+        if !self.dirty {
+            return Ok(());
+        }
+        let path = Self::path(root);
+        let json = serde_json::to_string_pretty(self).context("Failed to serialize sidecar")?;
+        fs::write(&path, json)
+            .with_context(|| format!("Failed to write sidecar: {}", path.display()))
+    }
+
+    /// Returns the entry recorded for `name`, if any.
+    pub fn get(&self, kind: ArtifactType, name: &str) -> Option<&SidecarEntry> {
+        self.entries.get(&key(kind, name))
+    }
+
+    /// Records that `original` was written to the target as `written`.
+    ///
+    /// Items written verbatim need no sidecar, so their entry is removed.
+    pub fn record(
+        &mut self,
+        kind: ArtifactType,
+        name: &str,
+        original: &[u8],
+        written: &[u8],
+        dropped: Vec<String>,
+    ) {
+        let key = key(kind, name);
+        if original == written {
+            self.dirty |= self.entries.remove(&key).is_some();
+            return;
+        }
+        let entry = SidecarEntry {
+            written_hash: hash_content(written),
+            original: String::from_utf8_lossy(original).into_owned(),
+            dropped,
+        };
+        if self.entries.get(&key) != Some(&entry) {
+            self.entries.insert(key, entry);
+            self.dirty = true;
+        }
+    }
+
+    /// Replaces the content of each item that is still exactly what skrills
+    /// wrote with the original document recorded for it.
+    pub fn rehydrate(&self, kind: ArtifactType, items: &mut [Command]) {
+        for item in items {
+            let Some(entry) = self.get(kind, &item.name) else {
+                continue;
+            };
+            if item.hash != entry.written_hash {
+                tracing::debug!(
+                    item = %item.name,
+                    "Target copy edited since sync; not rehydrating"
+                );
+                continue;
+            }
+            item.content = entry.original.clone().into_bytes();
+            item.hash = hash_content(&item.content);
+        }
+    }
+}
+
+/// Names of the top-level frontmatter fields of `content` that are not in
+/// `kept`.
+pub fn dropped_fields(content: &str, kept: &[&str]) -> Vec<String> {
+    let (Some(frontmatter), _) = split_frontmatter(content) else {
+        return Vec::new();
+    };
+    frontmatter
+        .lines()
+        .filter(|line| !line.starts_with([' ', '\t', '-', '#']))
+        .filter_map(|line| line.split_once(':').map(|(k, _)| k.trim()))
+        .filter(|k| !k.is_empty() && !kept.contains(k))
+        .map(str::to_string)
+        .collect()
+}
+
+fn key(kind: ArtifactType, name: &str) -> String {
+    format!("{}/{}", kind.as_str(), name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::utils::test_helpers::make_command;
+    use tempfile::tempdir;
+
+    const ORIGINAL: &str =
+        "---\nname: review\nversion: 1.2.0\ndependencies:\n  - lint\n---\n\n# Review\n";
+
+    #[test]
+    fn rehydrates_unchanged_items_only() {
+        let mut sidecar = SidecarManifest::default();
+        sidecar.record(
+            ArtifactType::Skill,
+            "review",
+            ORIGINAL.as_bytes(),
+            b"# Review\n",
+            vec!["version".into()],
+        );
+        sidecar.record(
+            ArtifactType::Skill,
+            "edited",
+            ORIGINAL.as_bytes(),
+            b"# Edited\n",
+            Vec::new(),
+        );
+
+        let mut items = vec![
+            make_command("review", "# Review\n"),
+            make_command("edited", "# Edited by hand\n"),
+        ];
+        sidecar.rehydrate(ArtifactType::Skill, &mut items);
+
+        assert_eq!(items[0].content, ORIGINAL.as_bytes());
+        assert_eq!(items[0].hash, hash_content(ORIGINAL.as_bytes()));
+        assert_eq!(items[1].content, b"# Edited by hand\n");
+    }
+
+    #[test]
+    fn save_and_load_roundtrip() {
+        let root = tempdir().unwrap();
+        let mut sidecar = SidecarManifest::default();
+        sidecar.record(
+            ArtifactType::Agent,
+            "builder",
+            ORIGINAL.as_bytes(),
+            b"---\nname: builder\n---\n",
+            vec!["tools".into()],
+        );
+        sidecar.save(root.path()).unwrap();
+
+        let loaded = SidecarManifest::load(root.path());
+        let entry = loaded.get(ArtifactType::Agent, "builder").unwrap();
+        assert_eq!(entry.original, ORIGINAL);
+        assert_eq!(entry.dropped, vec!["tools"]);
+        assert!(loaded.get(ArtifactType::Skill, "builder").is_none());
+    }
+
+    #[test]
+    fn verbatim_writes_clear_the_entry() {
+        let root = tempdir().unwrap();
+        let mut sidecar = SidecarManifest::default();
+        sidecar.record(ArtifactType::Skill, "a", b"x", b"y", Vec::new());
+        sidecar.record(ArtifactType::Skill, "a", b"y", b"y", Vec::new());
+        assert!(sidecar.get(ArtifactType::Skill, "a").is_none());
+
+        // Nothing recorded and nothing changed: no file is created.
+        SidecarManifest::default().save(root.path()).unwrap();
+        assert!(!SidecarManifest::path(root.path()).exists());
+    }
+
+    #[test]
+    fn dropped_fields_lists_top_level_keys() {
+        assert_eq!(
+            dropped_fields(ORIGINAL, &["name"]),
+            vec!["version", "dependencies"]
+        );
+        assert!(dropped_fields("# No frontmatter\n", &[]).is_empty());
+    }
+}
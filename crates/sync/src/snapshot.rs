@@ -2,19 +2,27 @@
 //!
 //! Before each sync write phase, a snapshot is taken of the target files
 //! that will be overwritten. This enables 1-command rollback via
-//! `skrills sync-rollback`.
+//! `skrills sync-undo`.
+//!
+//! The orchestrator snapshots every file under the target adapter's
+//! [`managed_paths`](crate::adapters::AgentAdapter::managed_paths) before
+//! writing and calls [`finalize_snapshot`] afterwards, which records the
+//! after-hashes, adds files the sync created, and drops files it left alone.
 //!
 //! Snapshots are stored at `~/.skrills/snapshots/<timestamp>/` by default
 //! and auto-pruned after a configurable retention period (30 days).
 
 use crate::adapters::utils::hash_content;
+use crate::state::{StoredEntry, SyncStateStore};
 use crate::Result;
 use anyhow::{bail, Context};
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeSet;
 use std::fs;
-use std::path::{Path, PathBuf};
+use std::path::{Component, Path, PathBuf};
+use time::format_description::well_known::Rfc3339;
 use time::OffsetDateTime;
+use walkdir::WalkDir;
 
 /// Default retention period for snapshots in days.
 pub const DEFAULT_RETENTION_DAYS: u64 = 30;
@@ -25,6 +33,9 @@ const SNAPSHOTS_DIR_NAME: &str = "snapshots";
 /// Manifest file name within each snapshot directory.
 const MANIFEST_FILE: &str = "manifest.json";
 
+/// Ledger baselines of the synced pair as they were before the sync.
+const BASELINES_FILE: &str = "baselines.json";
+
 /// Sub-directory within each snapshot directory where file backups are stored.
 const FILES_DIR: &str = "files";
 
@@ -48,7 +59,8 @@ pub struct SnapshotConfig {
 impl Default for SnapshotConfig {
     fn default() -> Self {
         Self {
-            snapshot_root: default_snapshot_root().unwrap_or_else(|_| PathBuf::from("/tmp/skrills-snapshots")),
+            snapshot_root: default_snapshot_root()
+                .unwrap_or_else(|_| PathBuf::from("/tmp/skrills-snapshots")),
             retention_days: DEFAULT_RETENTION_DAYS,
             enabled: true,
         }
@@ -102,17 +114,56 @@ pub struct SnapshotSummary {
     pub path: PathBuf,
 }
 
+/// A snapshot together with its per-file entries, as listed by
+/// [`snapshot_history`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnapshotRecord {
+    /// Listing fields of the snapshot.
+    #[serde(flatten)]
+    pub summary: SnapshotSummary,
+    /// Files the sync changed, with their before and after hashes.
+    pub entries: Vec<SnapshotEntry>,
+}
+
 /// Result of a restore operation.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RestoreResult {
+    /// Id of the restored snapshot.
+    pub id: String,
     /// Number of files restored.
     pub restored: usize,
     /// Number of files that were created by sync and deleted during rollback.
     pub deleted: usize,
+    /// Number of files that could not be restored or deleted.
+    pub failed: usize,
+    /// Whether the sync-state ledger was rolled back as well (see [`undo_sync`]).
+    #[serde(default)]
+    pub ledger_restored: bool,
     /// Warnings encountered during restore.
     pub warnings: Vec<String>,
 }
 
+impl RestoreResult {
+    /// Formats the result as a short human-readable summary.
+    pub fn format_summary(&self) -> String {
+        let mut out = format!(
+            "Restored snapshot {}: {} file(s) restored, {} created file(s) removed",
+            self.id, self.restored, self.deleted
+        );
+        if self.failed > 0 {
+            out.push_str(&format!(", {} failed", self.failed));
+        }
+        out.push('\n');
+        if self.ledger_restored {
+            out.push_str("Sync-state ledger rolled back to before the sync.\n");
+        }
+        for warning in &self.warnings {
+            out.push_str(&format!("  ⚠ {}\n", warning));
+        }
+        out
+    }
+}
+
 /// Result of a prune operation.
 #[derive(Debug, Clone)]
 pub struct PruneResult {
@@ -139,16 +190,24 @@ pub fn create_snapshot(
     }
 
     let now = OffsetDateTime::now_utc();
-    let ts_str = now
-        .format(&time::format_description::well_known::Rfc3339)
-        .context("Failed to format timestamp")?;
-    // Directory name: use a filesystem-safe version of the timestamp
+    let ts_str = now.format(&Rfc3339).context("Failed to format timestamp")?;
+    // Directory name: use a filesystem-safe version of the timestamp,
+    // suffixed when several syncs land within the same instant.
     let dir_name = ts_str.replace(':', "-");
-    let snapshot_dir = config.snapshot_root.join(&dir_name);
+    let mut snapshot_dir = config.snapshot_root.join(&dir_name);
+    let mut suffix = 1;
+    while snapshot_dir.exists() {
+        suffix += 1;
+        snapshot_dir = config.snapshot_root.join(format!("{dir_name}-{suffix}"));
+    }
     let files_dir = snapshot_dir.join(FILES_DIR);
 
-    fs::create_dir_all(&files_dir)
-        .with_context(|| format!("Failed to create snapshot directory: {}", snapshot_dir.display()))?;
+    fs::create_dir_all(&files_dir).with_context(|| {
+        format!(
+            "Failed to create snapshot directory: {}",
+            snapshot_dir.display()
+        )
+    })?;
 
     let mut entries = Vec::with_capacity(target_paths.len());
 
@@ -193,10 +252,7 @@ pub fn create_snapshot(
         entries,
     };
 
-    let manifest_json = serde_json::to_string_pretty(&manifest)
-        .context("Failed to serialize snapshot manifest")?;
-    fs::write(snapshot_dir.join(MANIFEST_FILE), manifest_json)
-        .context("Failed to write snapshot manifest")?;
+    write_manifest(&snapshot_dir, &manifest)?;
 
     tracing::info!(
         snapshot = %snapshot_dir.display(),
@@ -232,17 +288,20 @@ pub fn list_snapshots(config: &SnapshotConfig) -> Result<Vec<SnapshotSummary>> {
 
         match read_manifest(&manifest_path) {
             Ok(manifest) => {
-                summaries.push(SnapshotSummary {
-                    id: entry
-                        .file_name()
-                        .to_string_lossy()
-                        .to_string(),
-                    timestamp: manifest.timestamp.clone(),
-                    source_name: manifest.source_name.clone(),
-                    target_name: manifest.target_name.clone(),
-                    file_count: manifest.entries.len(),
-                    path: path.clone(),
-                });
+                let created = OffsetDateTime::parse(&manifest.timestamp, &Rfc3339)
+                    .map(OffsetDateTime::unix_timestamp_nanos)
+                    .unwrap_or(i128::from(manifest.unix_timestamp) * 1_000_000_000);
+                summaries.push((
+                    created,
+                    SnapshotSummary {
+                        id: entry.file_name().to_string_lossy().to_string(),
+                        timestamp: manifest.timestamp.clone(),
+                        source_name: manifest.source_name.clone(),
+                        target_name: manifest.target_name.clone(),
+                        file_count: manifest.entries.len(),
+                        path: path.clone(),
+                    },
+                ));
             }
             Err(e) => {
                 tracing::warn!(
@@ -254,10 +313,11 @@ pub fn list_snapshots(config: &SnapshotConfig) -> Result<Vec<SnapshotSummary>> {
         }
     }
 
-    // Sort newest first by timestamp string (ISO 8601 sorts lexicographically)
-    summaries.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
+    // Newest first. Compare parsed instants: RFC 3339 strings with and
+    // without fractional seconds do not sort lexicographically.
+    summaries.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| b.1.id.cmp(&a.1.id)));
 
-    Ok(summaries)
+    Ok(summaries.into_iter().map(|(_, summary)| summary).collect())
 }
 
 /// Restores the target environment from a snapshot.
@@ -267,33 +327,31 @@ pub fn restore_snapshot(
     config: &SnapshotConfig,
     snapshot_id: Option<&str>,
 ) -> Result<RestoreResult> {
-    let snapshot_dir = if let Some(id) = snapshot_id {
-        let dir = config.snapshot_root.join(id);
-        if !dir.exists() {
-            bail!("Snapshot not found: {}", id);
-        }
-        dir
-    } else {
-        // Find the most recent snapshot
-        let snapshots = list_snapshots(config)?;
-        if snapshots.is_empty() {
-            bail!("No snapshots available to restore");
-        }
-        snapshots[0].path.clone()
-    };
-
+    let snapshot_dir = resolve_snapshot(config, snapshot_id)?;
     let manifest_path = snapshot_dir.join(MANIFEST_FILE);
-    let manifest = read_manifest(&manifest_path)
-        .with_context(|| format!("Failed to read snapshot manifest: {}", manifest_path.display()))?;
+    let manifest = read_manifest(&manifest_path).with_context(|| {
+        format!(
+            "Failed to read snapshot manifest: {}",
+            manifest_path.display()
+        )
+    })?;
 
     let files_dir = snapshot_dir.join(FILES_DIR);
     let mut restored = 0;
     let mut deleted = 0;
+    let mut failed = 0;
     let mut warnings = Vec::new();
 
     for entry in &manifest.entries {
         let target_path = manifest.target_root.join(&entry.relative_path);
 
+        if !entry.hash_after.is_empty() && current_hash(&target_path) != entry.hash_after {
+            warnings.push(format!(
+                "{} changed after the sync; those edits are discarded",
+                entry.relative_path.display()
+            ));
+        }
+
         if entry.existed_before {
             // Restore the backup
             let backup_path = files_dir.join(&entry.relative_path);
@@ -304,6 +362,7 @@ pub fn restore_snapshot(
                 match fs::copy(&backup_path, &target_path) {
                     Ok(_) => restored += 1,
                     Err(e) => {
+                        failed += 1;
                         warnings.push(format!(
                             "Failed to restore {}: {}",
                             entry.relative_path.display(),
@@ -312,6 +371,7 @@ pub fn restore_snapshot(
                     }
                 }
             } else {
+                failed += 1;
                 warnings.push(format!(
                     "Backup file missing for {}",
                     entry.relative_path.display()
@@ -321,8 +381,12 @@ pub fn restore_snapshot(
             // File was created by sync, remove it
             if target_path.exists() {
                 match fs::remove_file(&target_path) {
-                    Ok(()) => deleted += 1,
+                    Ok(()) => {
+                        deleted += 1;
+                        remove_empty_parents(&target_path, &manifest.target_root);
+                    }
                     Err(e) => {
+                        failed += 1;
                         warnings.push(format!(
                             "Failed to delete {}: {}",
                             entry.relative_path.display(),
@@ -343,20 +407,130 @@ pub fn restore_snapshot(
     );
 
     Ok(RestoreResult {
+        id: snapshot_id_of(&snapshot_dir),
         restored,
         deleted,
+        failed,
+        ledger_restored: false,
         warnings,
     })
 }
 
+/// Undoes the sync recorded in a snapshot.
+///
+/// Restores the target files like [`restore_snapshot`] and, when `ledger` is
+/// given and the snapshot carries the pair's baselines (see
+/// [`save_baselines`]), rolls the ledger back too so the next sync does not
+/// mistake the restored files for target edits. The snapshot is deleted once
+/// every file was restored, so repeated undos walk back through history.
+pub fn undo_sync(
+    config: &SnapshotConfig,
+    snapshot_id: Option<&str>,
+    ledger: Option<&mut SyncStateStore>,
+) -> Result<RestoreResult> {
+    let snapshot_dir = resolve_snapshot(config, snapshot_id)?;
+    let id = snapshot_id_of(&snapshot_dir);
+    let mut result = restore_snapshot(config, Some(&id))?;
+
+    if let Some(store) = ledger {
+        let baselines_path = snapshot_dir.join(BASELINES_FILE);
+        if baselines_path.exists() {
+            let manifest = read_manifest(&snapshot_dir.join(MANIFEST_FILE))?;
+            let data = fs::read_to_string(&baselines_path).with_context(|| {
+                format!(
+                    "Failed to read snapshot baselines: {}",
+                    baselines_path.display()
+                )
+            })?;
+            let entries: Vec<StoredEntry> =
+                serde_json::from_str(&data).context("Failed to parse snapshot baselines")?;
+            store.replace_pair(&manifest.source_name, &manifest.target_name, entries);
+            store.save()?;
+            result.ledger_restored = true;
+        }
+    }
+
+    if result.failed == 0 {
+        fs::remove_dir_all(&snapshot_dir).with_context(|| {
+            format!(
+                "Failed to remove restored snapshot: {}",
+                snapshot_dir.display()
+            )
+        })?;
+    }
+    Ok(result)
+}
+
+/// Stores the pair's ledger baselines from before the sync alongside the
+/// snapshot, for [`undo_sync`].
+pub fn save_baselines(snapshot_dir: &Path, entries: &[StoredEntry]) -> Result<()> {
+    let json =
+        serde_json::to_string_pretty(entries).context("Failed to serialize snapshot baselines")?;
+    fs::write(snapshot_dir.join(BASELINES_FILE), json).context("Failed to write snapshot baselines")
+}
+
+/// Lists snapshots newest first with their per-file entries, keeping at most
+/// `limit` of them.
+pub fn snapshot_history(
+    config: &SnapshotConfig,
+    limit: Option<usize>,
+) -> Result<Vec<SnapshotRecord>> {
+    list_snapshots(config)?
+        .into_iter()
+        .take(limit.unwrap_or(usize::MAX))
+        .map(|summary| {
+            let manifest = read_manifest(&summary.path.join(MANIFEST_FILE))?;
+            Ok(SnapshotRecord {
+                summary,
+                entries: manifest.entries,
+            })
+        })
+        .collect()
+}
+
+/// Formats [`snapshot_history`] output, one block per snapshot with a line
+/// per file and its shortened before → after hashes.
+pub fn format_history(records: &[SnapshotRecord]) -> String {
+    if records.is_empty() {
+        return "No sync snapshots recorded.\n".to_string();
+    }
+    let short = |hash: &str| {
+        if hash.is_empty() {
+            "-".to_string()
+        } else {
+            hash.chars().take(12).collect()
+        }
+    };
+    let mut out = String::new();
+    for record in records {
+        let s = &record.summary;
+        out.push_str(&format!(
+            "{}  {} → {}  ({} file(s))\n",
+            s.id, s.source_name, s.target_name, s.file_count
+        ));
+        for entry in &record.entries {
+            let change = match (entry.existed_before, entry.hash_after.is_empty()) {
+                (false, _) => "created",
+                (true, true) => "removed",
+                (true, false) => "modified",
+            };
+            out.push_str(&format!(
+                "    {:<8}  {}  {} → {}\n",
+                change,
+                entry.relative_path.display(),
+                short(&entry.hash_before),
+                short(&entry.hash_after)
+            ));
+        }
+    }
+    out
+}
+
 /// Prunes snapshots older than the retention period.
 pub fn prune_snapshots(config: &SnapshotConfig) -> Result<PruneResult> {
     let root = &config.snapshot_root;
     if !root.exists() {
-        return Ok(PruneResult {
-            pruned: 0,
-            kept: 0,
-        });
+        return Ok(PruneResult { pruned: 0, kept: 0 });
     }
 
     let now = OffsetDateTime::now_utc();
@@ -417,32 +591,34 @@ pub fn prune_snapshots(config: &SnapshotConfig) -> Result<PruneResult> {
     Ok(PruneResult { pruned, kept })
 }
 
-/// Collects the set of relative paths that a sync operation would write to.
+/// Collects every file under the `managed` paths of `target_root`.
 ///
-/// This examines the target adapter's config root and the items being synced
-/// to build a list of files that will be affected.
-pub fn collect_target_paths(
-    target_root: &Path,
-    commands: &[crate::common::Command],
-    skills: &[crate::common::Command],
-    hooks: &[crate::common::Command],
-    agents: &[crate::common::Command],
-    instructions: &[crate::common::Command],
-) -> Vec<PathBuf> {
-    // BTreeSet encodes both invariants this function promises: uniqueness
-    // (callers can't observe duplicates) and deterministic sorted ordering
-    // (snapshot manifests compare byte-for-byte across runs). Using the type
-    // for the contract beats remembering to call `.sort().dedup()` after
-    // every future edit to the loop body.
+/// Each managed path is relative to `target_root` and may name a file or a
+/// directory (walked recursively, symlinks not followed). Missing paths are
+/// ignored, as are absolute paths and paths that climb out of the root. The
+/// result is relative to `target_root`, unique and sorted so snapshot
+/// manifests compare byte-for-byte across runs.
+pub fn collect_managed_files(target_root: &Path, managed: &[PathBuf]) -> Vec<PathBuf> {
     let mut paths = BTreeSet::new();
 
-    for item_set in [commands, skills, hooks, agents, instructions] {
-        for item in item_set {
-            // source_path is absolute; the target-side identifier is just
-            // the file name (commands/skills/hooks share a flat namespace
-            // on most adapters).
-            if let Some(file_name) = item.source_path.file_name() {
-                paths.insert(PathBuf::from(file_name));
+    for rel in managed {
+        if rel.is_absolute() || rel.components().any(|c| c == Component::ParentDir) {
+            continue;
+        }
+        let abs = target_root.join(rel);
+        if abs.is_file() {
+            paths.insert(rel.clone());
+            continue;
+        }
+        if !abs.is_dir() {
+            continue;
+        }
+        for entry in WalkDir::new(&abs).into_iter().filter_map(|e| e.ok()) {
+            if !entry.file_type().is_file() {
+                continue;
+            }
+            if let Ok(rel_path) = entry.path().strip_prefix(target_root) {
+                paths.insert(rel_path.to_path_buf());
             }
         }
     }
@@ -450,8 +626,122 @@ pub fn collect_target_paths(
     paths.into_iter().collect()
 }
 
+/// Completes a snapshot taken by [`create_snapshot`] once the sync has written.
+///
+/// Fills in `hash_after` for every captured file, adds the files under
+/// `managed` that the sync created, and drops entries (and their backups)
+/// that the sync did not change. A snapshot left with no entries is deleted.
+/// Returns the number of changed files that remain recorded.
+pub fn finalize_snapshot(snapshot_dir: &Path, managed: &[PathBuf]) -> Result<usize> {
+    let mut manifest = read_manifest(&snapshot_dir.join(MANIFEST_FILE))?;
+    let root = manifest.target_root.clone();
+    let files_dir = snapshot_dir.join(FILES_DIR);
+
+    let captured: BTreeSet<PathBuf> = manifest
+        .entries
+        .iter()
+        .map(|e| e.relative_path.clone())
+        .collect();
+    for rel_path in collect_managed_files(&root, managed) {
+        if !captured.contains(&rel_path) {
+            manifest.entries.push(SnapshotEntry {
+                relative_path: rel_path,
+                hash_before: String::new(),
+                hash_after: String::new(),
+                existed_before: false,
+            });
+        }
+    }
+
+    manifest.entries.retain_mut(|entry| {
+        entry.hash_after = current_hash(&root.join(&entry.relative_path));
+        let changed = entry.hash_after != entry.hash_before;
+        if !changed && entry.existed_before {
+            fs::remove_file(files_dir.join(&entry.relative_path)).ok();
+        }
+        changed
+    });
+    manifest
+        .entries
+        .sort_by(|a, b| a.relative_path.cmp(&b.relative_path));
+
+    if manifest.entries.is_empty() {
+        fs::remove_dir_all(snapshot_dir).with_context(|| {
+            format!(
+                "Failed to remove empty snapshot: {}",
+                snapshot_dir.display()
+            )
+        })?;
+        return Ok(0);
+    }
+
+    write_manifest(snapshot_dir, &manifest)?;
+    Ok(manifest.entries.len())
+}
+
+/// Loads the manifest of the snapshot `snapshot_id`.
+pub fn load_snapshot(config: &SnapshotConfig, snapshot_id: &str) -> Result<SnapshotManifest> {
+    read_manifest(&snapshot_dir(config, snapshot_id)?.join(MANIFEST_FILE))
+}
+
 // Internal helpers
 
+/// Resolves `snapshot_id`, or the most recent snapshot when `None`.
+fn resolve_snapshot(config: &SnapshotConfig, snapshot_id: Option<&str>) -> Result<PathBuf> {
+    if let Some(id) = snapshot_id {
+        return snapshot_dir(config, id);
+    }
+    match list_snapshots(config)?.into_iter().next() {
+        Some(latest) => Ok(latest.path),
+        None => bail!("No snapshots available to restore"),
+    }
+}
+
+fn snapshot_id_of(snapshot_dir: &Path) -> String {
+    snapshot_dir
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+/// Resolves a snapshot id to its directory, rejecting ids that would escape
+/// the snapshot root.
+fn snapshot_dir(config: &SnapshotConfig, id: &str) -> Result<PathBuf> {
+    if id.is_empty() || id.contains(['/', '\\']) || id.contains("..") {
+        bail!("Invalid snapshot id: {}", id);
+    }
+    let dir = config.snapshot_root.join(id);
+    if !dir.join(MANIFEST_FILE).exists() {
+        bail!("Snapshot not found: {}", id);
+    }
+    Ok(dir)
+}
+
+/// Hash of the file at `path`, or an empty string if it cannot be read.
+fn current_hash(path: &Path) -> String {
+    fs::read(path)
+        .map(|content| hash_content(&content))
+        .unwrap_or_default()
+}
+
+/// Removes directories left empty by deleting `path`, stopping at `root`.
+fn remove_empty_parents(path: &Path, root: &Path) {
+    let mut dir = path.parent();
+    while let Some(current) = dir {
+        if current == root || !current.starts_with(root) || fs::remove_dir(current).is_err() {
+            break;
+        }
+        dir = current.parent();
+    }
+}
+
+fn write_manifest(snapshot_dir: &Path, manifest: &SnapshotManifest) -> Result<()> {
+    let manifest_json =
+        serde_json::to_string_pretty(manifest).context("Failed to serialize snapshot manifest")?;
+    fs::write(snapshot_dir.join(MANIFEST_FILE), manifest_json)
+        .context("Failed to write snapshot manifest")
+}
+
 fn read_manifest(path: &Path) -> Result<SnapshotManifest> {
     let data = fs::read_to_string(path)
         .with_context(|| format!("Failed to read manifest: {}", path.display()))?;
@@ -936,56 +1226,208 @@ mod tests {
     }
 
     // ==========================================
-    // collect_target_paths Tests
+    // collect_managed_files / finalize_snapshot Tests
     // ==========================================
 
     #[test]
-    fn collect_target_paths_deduplicates_and_sorts() {
-        use crate::common::Command;
-
-        let cmds = vec![
-            Command::new(
-                "hello".into(),
-                b"content".to_vec(),
-                PathBuf::from("/src/hello.md"),
-            ),
-            Command::new(
-                "greet".into(),
-                b"content".to_vec(),
-                PathBuf::from("/src/greet.md"),
-            ),
+    fn collect_managed_files_walks_dirs_and_sorts() {
+        let target_dir = tempdir().unwrap();
+        let root = target_dir.path();
+        fs::create_dir_all(root.join("skills/beta")).unwrap();
+        fs::create_dir_all(root.join("skills/alpha")).unwrap();
+        fs::write(root.join("skills/beta/SKILL.md"), "b").unwrap();
+        fs::write(root.join("skills/alpha/SKILL.md"), "a").unwrap();
+        fs::write(root.join("mcp.json"), "{}").unwrap();
+        fs::write(root.join("unmanaged.txt"), "x").unwrap();
+
+        let managed = vec![
+            PathBuf::from("skills"),
+            PathBuf::from("mcp.json"),
+            PathBuf::from("skills"),
+            PathBuf::from("missing"),
+            PathBuf::from("../outside"),
         ];
-        let skills = vec![Command::new(
-            "hello".into(),
-            b"content".to_vec(),
-            PathBuf::from("/src/hello.md"), // same file name as commands
-        )];
-
-        let paths = collect_target_paths(
-            Path::new("/target"),
-            &cmds,
-            &skills,
-            &[],
-            &[],
-            &[],
+        let paths = collect_managed_files(root, &managed);
+
+        assert_eq!(
+            paths,
+            vec![
+                PathBuf::from("mcp.json"),
+                PathBuf::from("skills/alpha/SKILL.md"),
+                PathBuf::from("skills/beta/SKILL.md"),
+            ]
         );
+    }
 
-        // Dedup: the duplicate hello.md (cmd and skill) collapses to one entry.
-        assert_eq!(paths.len(), 2);
-        assert!(paths.contains(&PathBuf::from("greet.md")));
-        assert!(paths.contains(&PathBuf::from("hello.md")));
+    #[test]
+    fn finalize_snapshot_keeps_only_changed_and_created_files() {
+        let target_dir = tempdir().unwrap();
+        let snapshot_root = tempdir().unwrap();
+        let root = target_dir.path();
+        fs::create_dir_all(root.join("commands")).unwrap();
+        fs::write(root.join("commands/kept.md"), "same").unwrap();
+        fs::write(root.join("commands/edited.md"), "before").unwrap();
+
+        let config = SnapshotConfig {
+            snapshot_root: snapshot_root.path().to_path_buf(),
+            retention_days: 30,
+            enabled: true,
+        };
+        let managed = vec![PathBuf::from("commands")];
+        let before = collect_managed_files(root, &managed);
+        let snapshot_dir = create_snapshot(&config, "claude", "cursor", root, &before).unwrap();
+
+        fs::write(root.join("commands/edited.md"), "after").unwrap();
+        fs::write(root.join("commands/new.md"), "created").unwrap();
+
+        assert_eq!(finalize_snapshot(&snapshot_dir, &managed).unwrap(), 2);
+
+        let manifest = read_manifest(&snapshot_dir.join(MANIFEST_FILE)).unwrap();
+        let edited = &manifest.entries[0];
+        assert_eq!(edited.relative_path, PathBuf::from("commands/edited.md"));
+        assert!(edited.existed_before);
+        assert_eq!(edited.hash_after, hash_content(b"after"));
+        let created = &manifest.entries[1];
+        assert_eq!(created.relative_path, PathBuf::from("commands/new.md"));
+        assert!(!created.existed_before);
+        assert!(created.hash_before.is_empty());
+        assert!(!snapshot_dir
+            .join(FILES_DIR)
+            .join("commands/kept.md")
+            .exists());
 
-        // Ordering: callers (e.g., snapshot manifests) rely on deterministic
-        // output. Verify the contract instead of just hoping for it.
+        let result = restore_snapshot(&config, None).unwrap();
+        assert_eq!((result.restored, result.deleted), (1, 1));
         assert_eq!(
-            paths,
-            vec![PathBuf::from("greet.md"), PathBuf::from("hello.md")]
+            fs::read_to_string(root.join("commands/edited.md")).unwrap(),
+            "before"
         );
+        assert!(!root.join("commands/new.md").exists());
+    }
+
+    #[test]
+    fn finalize_snapshot_removes_snapshot_without_changes() {
+        let target_dir = tempdir().unwrap();
+        let snapshot_root = tempdir().unwrap();
+        fs::write(target_dir.path().join("mcp.json"), "{}").unwrap();
+        let config = SnapshotConfig {
+            snapshot_root: snapshot_root.path().to_path_buf(),
+            retention_days: 30,
+            enabled: true,
+        };
+        let managed = vec![PathBuf::from("mcp.json")];
+        let snapshot_dir = create_snapshot(
+            &config,
+            "claude",
+            "cursor",
+            target_dir.path(),
+            &collect_managed_files(target_dir.path(), &managed),
+        )
+        .unwrap();
 
-        // Stronger dedup check: build a set from the result and confirm no
-        // collapse happened, guards against future impls that drop dedup.
-        let unique: std::collections::HashSet<_> = paths.iter().cloned().collect();
-        assert_eq!(unique.len(), paths.len(), "result must be unique");
+        assert_eq!(finalize_snapshot(&snapshot_dir, &managed).unwrap(), 0);
+        assert!(!snapshot_dir.exists());
+        assert!(list_snapshots(&config).unwrap().is_empty());
+    }
+
+    #[test]
+    fn restore_removes_directories_left_empty() {
+        let target_dir = tempdir().unwrap();
+        let snapshot_root = tempdir().unwrap();
+        let root = target_dir.path();
+        let config = SnapshotConfig {
+            snapshot_root: snapshot_root.path().to_path_buf(),
+            retention_days: 30,
+            enabled: true,
+        };
+        let managed = vec![PathBuf::from("skills")];
+        let snapshot_dir = create_snapshot(&config, "claude", "codex", root, &[]).unwrap();
+        fs::create_dir_all(root.join("skills/alpha")).unwrap();
+        fs::write(root.join("skills/alpha/SKILL.md"), "new").unwrap();
+        finalize_snapshot(&snapshot_dir, &managed).unwrap();
+
+        restore_snapshot(&config, None).unwrap();
+        assert!(!root.join("skills/alpha").exists());
+        assert!(root.exists());
+    }
+
+    #[test]
+    fn restore_warns_about_edits_made_after_sync() {
+        let target_dir = tempdir().unwrap();
+        let snapshot_root = tempdir().unwrap();
+        let root = target_dir.path();
+        fs::write(root.join("mcp.json"), "before").unwrap();
+        let config = SnapshotConfig {
+            snapshot_root: snapshot_root.path().to_path_buf(),
+            retention_days: 30,
+            enabled: true,
+        };
+        let managed = vec![PathBuf::from("mcp.json")];
+        let snapshot_dir = create_snapshot(
+            &config,
+            "claude",
+            "cursor",
+            root,
+            &collect_managed_files(root, &managed),
+        )
+        .unwrap();
+        fs::write(root.join("mcp.json"), "synced").unwrap();
+        finalize_snapshot(&snapshot_dir, &managed).unwrap();
+        fs::write(root.join("mcp.json"), "hand edit").unwrap();
+
+        let result = restore_snapshot(&config, None).unwrap();
+        assert_eq!(result.restored, 1);
+        assert_eq!(result.warnings.len(), 1);
+        assert!(result.warnings[0].contains("changed after the sync"));
+        assert_eq!(fs::read_to_string(root.join("mcp.json")).unwrap(), "before");
+    }
+
+    #[test]
+    fn snapshot_ids_cannot_escape_the_root() {
+        let snapshot_root = tempdir().unwrap();
+        let config = SnapshotConfig {
+            snapshot_root: snapshot_root.path().join("snapshots"),
+            retention_days: 30,
+            enabled: true,
+        };
+        for id in ["..", "../x", "a/b", ""] {
+            let err = restore_snapshot(&config, Some(id)).unwrap_err();
+            assert!(err.to_string().contains("Invalid snapshot id"), "{id}");
+            assert!(load_snapshot(&config, id).is_err());
+        }
+    }
+
+    #[test]
+    fn list_snapshots_orders_fractional_timestamps() {
+        let snapshot_root = tempdir().unwrap();
+        let config = SnapshotConfig {
+            snapshot_root: snapshot_root.path().to_path_buf(),
+            retention_days: 30,
+            enabled: true,
+        };
+        for (id, ts) in [
+            ("a", "2026-04-10T10:00:00.5Z"),
+            ("b", "2026-04-10T10:00:00Z"),
+        ] {
+            let dir = snapshot_root.path().join(id);
+            fs::create_dir_all(&dir).unwrap();
+            let manifest = SnapshotManifest {
+                timestamp: ts.to_string(),
+                unix_timestamp: 1_776_024_000,
+                source_name: "claude".to_string(),
+                target_name: "cursor".to_string(),
+                target_root: PathBuf::from("/target"),
+                entries: vec![],
+            };
+            write_manifest(&dir, &manifest).unwrap();
+        }
+
+        let ids: Vec<_> = list_snapshots(&config)
+            .unwrap()
+            .into_iter()
+            .map(|s| s.id)
+            .collect();
+        assert_eq!(ids, vec!["a", "b"]);
     }
 
     // ==========================================
@@ -0,0 +1,438 @@
+//! Persistent sync-state ledger used as the baseline for conflict detection.
+//!
+//! After every successful write the orchestrator records, per
+//! `(source, target, artifact type, name)`, the hash of the source item it
+//! synced and the hash of the target item as it landed on disk. The next run
+//! reads these hashes back so [`crate::conflict`] can tell a source-only edit
+//! from a target-only edit or a true conflict where both sides changed. The
+//! target content as it landed is kept too, as the common ancestor for
+//! [`crate::merge::merge_three_way`].
+//!
+//! The ledger lives at `~/.skrills/sync-state.json` by default and is written
+//! atomically (temp file + rename) so an interrupted sync never leaves a
+//! truncated file behind.
+
+use crate::conflict::ArtifactType;
+use crate::Result;
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// File name of the ledger under `~/.skrills/`.
+const STATE_FILE_NAME: &str = "sync-state.json";
+
+/// Current on-disk schema version.
+const STATE_VERSION: u32 = 1;
+
+/// Returns the default ledger path (`~/.skrills/sync-state.json`).
+pub fn default_state_path() -> Result<PathBuf> {
+    let home = dirs::home_dir().context("Could not determine home directory")?;
+    Ok(home.join(".skrills").join(STATE_FILE_NAME))
+}
+
+/// Identifies one synced artifact for one source/target pair.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct BaselineKey {
+    /// Source adapter name (e.g., "claude").
+    pub source: String,
+    /// Target adapter name (e.g., "cursor").
+    pub target: String,
+    /// Artifact type of the item.
+    pub artifact_type: ArtifactType,
+    /// Item name as reported by the source adapter.
+    pub name: String,
+}
+
+impl BaselineKey {
+    /// Creates a key, normalizing adapter names to lowercase.
+    pub fn new(source: &str, target: &str, artifact_type: ArtifactType, name: &str) -> Self {
+        Self {
+            source: source.to_lowercase(),
+            target: target.to_lowercase(),
+            artifact_type,
+            name: name.to_string(),
+        }
+    }
+}
+
+/// Hashes recorded for an artifact at the time of its last successful sync.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Baseline {
+    /// Hash of the source item that was synced.
+    pub source_hash: String,
+    /// Hash of the item as read back from the target after writing.
+    ///
+    /// `None` when the target adapter stores the item under a different name
+    /// (e.g. a format conversion), in which case target-side edits cannot be
+    /// detected for this item.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_hash: Option<String>,
+    /// Content of the item at the last sync, the base for three-way merges.
+    ///
+    /// `None` for ledgers written before merges existed and whenever
+    /// `target_hash` is `None`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    /// Unix timestamp (seconds) of the sync that recorded this baseline.
+    pub synced_at: i64,
+}
+
+/// A ledger entry as stored on disk.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StoredEntry {
+    /// Which artifact the baseline belongs to.
+    #[serde(flatten)]
+    pub key: BaselineKey,
+    /// The recorded hashes and content.
+    #[serde(flatten)]
+    pub baseline: Baseline,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct StateFile {
+    version: u32,
+    #[serde(default)]
+    entries: Vec<StoredEntry>,
+}
+
+/// Durable store of per-artifact sync baselines.
+///
+/// ```
+/// use skrills_sync::conflict::ArtifactType;
+/// use skrills_sync::state::{BaselineKey, SyncStateStore};
+///
+/// let dir = std::env::temp_dir().join("skrills-sync-state-doctest");
+/// let path = dir.join("sync-state.json");
+/// let mut store = SyncStateStore::load(&path).unwrap();
+/// let key = BaselineKey::new("claude", "cursor", ArtifactType::Skill, "commit");
+/// store.record(key.clone(), "abc".into(), Some("def".into()));
+/// store.save().unwrap();
+///
+/// let reloaded = SyncStateStore::load(&path).unwrap();
+/// assert_eq!(reloaded.get(&key).unwrap().source_hash, "abc");
+/// # std::fs::remove_dir_all(&dir).ok();
+/// ```
+#[derive(Debug, Clone)]
+pub struct SyncStateStore {
+    path: PathBuf,
+    entries: HashMap<BaselineKey, Baseline>,
+    dirty: bool,
+}
+
+impl SyncStateStore {
+    /// Loads the ledger at `path`, returning an empty store if it does not exist.
+    pub fn load(path: &Path) -> Result<Self> {
+        let entries = if path.exists() {
+            let raw = fs::read_to_string(path)
+                .with_context(|| format!("Failed to read sync state: {}", path.display()))?;
+            let file: StateFile = serde_json::from_str(&raw)
+                .with_context(|| format!("Failed to parse sync state: {}", path.display()))?;
+            if file.version > STATE_VERSION {
+                anyhow::bail!(
+                    "Sync state {} has version {}, newer than supported version {}",
+                    path.display(),
+                    file.version,
+                    STATE_VERSION
+                );
+            }
+            file.entries
+                .into_iter()
+                .map(|e| (e.key, e.baseline))
+                .collect()
+        } else {
+            HashMap::new()
+        };
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            entries,
+            dirty: false,
+        })
+    }
+
+    /// Loads the ledger from [`default_state_path`].
+    pub fn load_default() -> Result<Self> {
+        Self::load(&default_state_path()?)
+    }
+
+    /// Path of the backing file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the recorded baseline for `key`, if any.
+    pub fn get(&self, key: &BaselineKey) -> Option<&Baseline> {
+        self.entries.get(key)
+    }
+
+    /// Records (or replaces) the baseline for `key`.
+    pub fn record(&mut self, key: BaselineKey, source_hash: String, target_hash: Option<String>) {
+        self.record_with_content(key, source_hash, target_hash, None);
+    }
+
+    /// Records (or replaces) the baseline for `key`, keeping `content` as the
+    /// merge base.
+    pub fn record_with_content(
+        &mut self,
+        key: BaselineKey,
+        source_hash: String,
+        target_hash: Option<String>,
+        content: Option<String>,
+    ) {
+        let synced_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs() as i64)
+            .unwrap_or(0);
+        self.entries.insert(
+            key,
+            Baseline {
+                source_hash,
+                target_hash,
+                content,
+                synced_at,
+            },
+        );
+        self.dirty = true;
+    }
+
+    /// Removes the baseline for `key`, returning it if present.
+    pub fn forget(&mut self, key: &BaselineKey) -> Option<Baseline> {
+        let removed = self.entries.remove(key);
+        if removed.is_some() {
+            self.dirty = true;
+        }
+        removed
+    }
+
+    /// Returns every baseline recorded for the `source` → `target` pair.
+    pub fn pair_entries(&self, source: &str, target: &str) -> Vec<StoredEntry> {
+        let (source, target) = (source.to_lowercase(), target.to_lowercase());
+        let mut entries: Vec<StoredEntry> = self
+            .entries
+            .iter()
+            .filter(|(key, _)| key.source == source && key.target == target)
+            .map(|(key, baseline)| StoredEntry {
+                key: key.clone(),
+                baseline: baseline.clone(),
+            })
+            .collect();
+        entries.sort_by(|a, b| {
+            (a.key.artifact_type.as_str(), &a.key.name)
+                .cmp(&(b.key.artifact_type.as_str(), &b.key.name))
+        });
+        entries
+    }
+
+    /// Replaces every baseline of the `source` → `target` pair with `entries`,
+    /// e.g. to roll the ledger back together with an undone sync.
+    pub fn replace_pair(&mut self, source: &str, target: &str, entries: Vec<StoredEntry>) {
+        let (source, target) = (source.to_lowercase(), target.to_lowercase());
+        self.entries
+            .retain(|key, _| key.source != source || key.target != target);
+        self.entries.extend(
+            entries
+                .into_iter()
+                .filter(|e| e.key.source == source && e.key.target == target)
+                .map(|e| (e.key, e.baseline)),
+        );
+        self.dirty = true;
+    }
+
+    /// Number of recorded baselines.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true when no baselines are recorded.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Writes the ledger to disk if it changed since it was loaded.
+    pub fn save(&mut self) -> Result<()> {
+        if !self.dirty {
+            return Ok(());
+        }
+
+        let mut entries: Vec<StoredEntry> = self
+            .entries
+            .iter()
+            .map(|(key, baseline)| StoredEntry {
+                key: key.clone(),
+                baseline: baseline.clone(),
+            })
+            .collect();
+        // Deterministic order keeps the file diff-friendly.
+        entries.sort_by(|a, b| {
+            (
+                &a.key.source,
+                &a.key.target,
+                a.key.artifact_type.as_str(),
+                &a.key.name,
+            )
+                .cmp(&(
+                    &b.key.source,
+                    &b.key.target,
+                    b.key.artifact_type.as_str(),
+                    &b.key.name,
+                ))
+        });
+
+        let file = StateFile {
+            version: STATE_VERSION,
+            entries,
+        };
+        let json = serde_json::to_string_pretty(&file)?;
+
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let tmp = self.path.with_extension("json.tmp");
+        fs::write(&tmp, json)
+            .with_context(|| format!("Failed to write sync state: {}", tmp.display()))?;
+        fs::rename(&tmp, &self.path)
+            .with_context(|| format!("Failed to replace sync state: {}", self.path.display()))?;
+
+        self.dirty = false;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn key(name: &str) -> BaselineKey {
+        BaselineKey::new("claude", "cursor", ArtifactType::Skill, name)
+    }
+
+    #[test]
+    fn load_missing_file_yields_empty_store() {
+        let dir = tempdir().unwrap();
+        let store = SyncStateStore::load(&dir.path().join("missing.json")).unwrap();
+        assert!(store.is_empty());
+    }
+
+    #[test]
+    fn record_and_reload_round_trips() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("nested/sync-state.json");
+
+        let mut store = SyncStateStore::load(&path).unwrap();
+        store.record(key("a"), "src-a".into(), Some("tgt-a".into()));
+        store.record(key("b"), "src-b".into(), None);
+        store.save().unwrap();
+
+        let reloaded = SyncStateStore::load(&path).unwrap();
+        assert_eq!(reloaded.len(), 2);
+        let a = reloaded.get(&key("a")).unwrap();
+        assert_eq!(a.source_hash, "src-a");
+        assert_eq!(a.target_hash.as_deref(), Some("tgt-a"));
+        assert!(reloaded.get(&key("b")).unwrap().target_hash.is_none());
+    }
+
+    #[test]
+    fn keys_are_scoped_by_pair_and_artifact_type() {
+        let dir = tempdir().unwrap();
+        let mut store = SyncStateStore::load(&dir.path().join("s.json")).unwrap();
+        store.record(key("a"), "h".into(), None);
+
+        assert!(store
+            .get(&BaselineKey::new(
+                "claude",
+                "codex",
+                ArtifactType::Skill,
+                "a"
+            ))
+            .is_none());
+        assert!(store
+            .get(&BaselineKey::new(
+                "claude",
+                "cursor",
+                ArtifactType::Command,
+                "a"
+            ))
+            .is_none());
+        assert!(store
+            .get(&BaselineKey::new(
+                "Claude",
+                "Cursor",
+                ArtifactType::Skill,
+                "a"
+            ))
+            .is_some());
+    }
+
+    #[test]
+    fn content_round_trips_and_is_optional() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("s.json");
+        let mut store = SyncStateStore::load(&path).unwrap();
+        store.record_with_content(
+            key("a"),
+            "s".into(),
+            Some("t".into()),
+            Some("body\n".into()),
+        );
+        store.record(key("b"), "s".into(), None);
+        store.save().unwrap();
+
+        let raw = fs::read_to_string(&path).unwrap();
+        assert_eq!(raw.matches("\"content\"").count(), 1);
+        let reloaded = SyncStateStore::load(&path).unwrap();
+        assert_eq!(
+            reloaded.get(&key("a")).unwrap().content.as_deref(),
+            Some("body\n")
+        );
+        assert!(reloaded.get(&key("b")).unwrap().content.is_none());
+    }
+
+    #[test]
+    fn save_without_changes_does_not_create_file() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("s.json");
+        let mut store = SyncStateStore::load(&path).unwrap();
+        store.save().unwrap();
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn forget_removes_entry() {
+        let dir = tempdir().unwrap();
+        let mut store = SyncStateStore::load(&dir.path().join("s.json")).unwrap();
+        store.record(key("a"), "h".into(), None);
+        assert!(store.forget(&key("a")).is_some());
+        assert!(store.get(&key("a")).is_none());
+    }
+
+    #[test]
+    fn replace_pair_only_touches_that_pair() {
+        let dir = tempdir().unwrap();
+        let mut store = SyncStateStore::load(&dir.path().join("s.json")).unwrap();
+        let other = BaselineKey::new("claude", "codex", ArtifactType::Skill, "a");
+        store.record(key("a"), "old".into(), None);
+        store.record(other.clone(), "kept".into(), None);
+        let before = store.pair_entries("Claude", "Cursor");
+        assert_eq!(before.len(), 1);
+
+        store.record(key("a"), "new".into(), None);
+        store.record(key("b"), "added".into(), None);
+        store.replace_pair("claude", "cursor", before);
+
+        assert_eq!(store.get(&key("a")).unwrap().source_hash, "old");
+        assert!(store.get(&key("b")).is_none());
+        assert_eq!(store.get(&other).unwrap().source_hash, "kept");
+    }
+
+    #[test]
+    fn rejects_newer_schema_version() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("s.json");
+        fs::write(&path, r#"{"version": 99, "entries": []}"#).unwrap();
+        assert!(SyncStateStore::load(&path).is_err());
+    }
+}
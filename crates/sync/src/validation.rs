@@ -5,8 +5,8 @@
 
 use crate::common::Command;
 use skrills_validate::{
-    autofix_frontmatter, is_codex_compatible, is_copilot_compatible, validate_skill,
-    AutofixOptions, AutofixResult, ValidationResult, ValidationTarget,
+    autofix_cursor, autofix_frontmatter, is_codex_compatible, is_copilot_compatible,
+    validate_skill, AutofixOptions, AutofixResult, ValidationResult, ValidationTarget,
 };
 use tracing::warn;
 
@@ -94,6 +94,7 @@ pub fn validate_skill_for_sync(
         ValidationTarget::Claude => validation.is_claude_valid(),
         ValidationTarget::Codex => validation.is_codex_valid(),
         ValidationTarget::Copilot => validation.is_copilot_valid(),
+        ValidationTarget::Cursor => validation.is_cursor_valid(),
         ValidationTarget::Both => validation.is_claude_valid() && validation.is_codex_valid(),
         ValidationTarget::All => validation.is_valid_for_all(),
     };
@@ -110,7 +111,12 @@ pub fn validate_skill_for_sync(
             suggested_description: None,
         };
 
-        match autofix_frontmatter(&skill.source_path, &content, &autofix_opts) {
+        let fixed = if target == ValidationTarget::Cursor {
+            autofix_cursor(&skill.source_path, &content, &autofix_opts)
+        } else {
+            autofix_frontmatter(&skill.source_path, &content, &autofix_opts)
+        };
+        match fixed {
             Ok(result) => {
                 if result.modified {
                     can_sync = true;
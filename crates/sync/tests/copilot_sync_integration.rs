@@ -11,8 +11,9 @@
 use skrills_sync::{
     adapters::traits::AgentAdapter,
     adapters::{ClaudeAdapter, CodexAdapter, CopilotAdapter},
+    conflict::ConflictStrategy,
     orchestrator::{SyncOrchestrator, SyncParams},
-    ContentFormat,
+    ContentFormat, SyncScope,
 };
 use std::fs;
 use tempfile::TempDir;
@@ -210,6 +211,9 @@ mod copilot_to_claude_tests {
             interactive: false,
             exclude_plugins: vec![],
             full_plugin_mirror: false,
+            conflict_strategy: Some(ConflictStrategy::SkipAll),
+            scope: SyncScope::User,
+            redact_secrets: false,
         };
 
         let orchestrator = SyncOrchestrator::new(source, target);
@@ -265,6 +269,9 @@ mod copilot_to_claude_tests {
             interactive: false,
             exclude_plugins: vec![],
             full_plugin_mirror: false,
+            conflict_strategy: Some(ConflictStrategy::SkipAll),
+            scope: SyncScope::User,
+            redact_secrets: false,
         };
 
         let orchestrator = SyncOrchestrator::new(source, target);
@@ -313,6 +320,9 @@ mod copilot_to_claude_tests {
             interactive: false,
             exclude_plugins: vec![],
             full_plugin_mirror: false,
+            conflict_strategy: Some(ConflictStrategy::SkipAll),
+            scope: SyncScope::User,
+            redact_secrets: false,
         };
 
         let orchestrator = SyncOrchestrator::new(source, target);
@@ -365,6 +375,9 @@ mod claude_to_copilot_tests {
             interactive: false,
             exclude_plugins: vec![],
             full_plugin_mirror: false,
+            conflict_strategy: Some(ConflictStrategy::SkipAll),
+            scope: SyncScope::User,
+            redact_secrets: false,
         };
 
         let orchestrator = SyncOrchestrator::new(source, target);
@@ -419,6 +432,9 @@ mod claude_to_copilot_tests {
             interactive: false,
             exclude_plugins: vec![],
             full_plugin_mirror: false,
+            conflict_strategy: Some(ConflictStrategy::SkipAll),
+            scope: SyncScope::User,
+            redact_secrets: false,
         };
 
         let orchestrator = SyncOrchestrator::new(source, target);
@@ -473,6 +489,9 @@ mod claude_to_copilot_tests {
             interactive: false,
             exclude_plugins: vec![],
             full_plugin_mirror: false,
+            conflict_strategy: Some(ConflictStrategy::SkipAll),
+            scope: SyncScope::User,
+            redact_secrets: false,
         };
 
         let orchestrator = SyncOrchestrator::new(source, target);
@@ -529,6 +548,9 @@ mod copilot_to_codex_tests {
             interactive: false,
             exclude_plugins: vec![],
             full_plugin_mirror: false,
+            conflict_strategy: Some(ConflictStrategy::SkipAll),
+            scope: SyncScope::User,
+            redact_secrets: false,
         };
 
         let orchestrator = SyncOrchestrator::new(source, target);
@@ -577,6 +599,9 @@ mod copilot_to_codex_tests {
             interactive: false,
             exclude_plugins: vec![],
             full_plugin_mirror: false,
+            conflict_strategy: Some(ConflictStrategy::SkipAll),
+            scope: SyncScope::User,
+            redact_secrets: false,
         };
 
         let orchestrator = SyncOrchestrator::new(source, target);
@@ -630,6 +655,9 @@ mod copilot_dry_run_tests {
             interactive: false,
             exclude_plugins: vec![],
             full_plugin_mirror: false,
+            conflict_strategy: Some(ConflictStrategy::SkipAll),
+            scope: SyncScope::User,
+            redact_secrets: false,
         };
 
         let orchestrator = SyncOrchestrator::new(source, target);
@@ -910,6 +938,9 @@ This is a test agent for Copilot.
             interactive: false,
             exclude_plugins: vec![],
             full_plugin_mirror: false,
+            conflict_strategy: Some(ConflictStrategy::SkipAll),
+            scope: SyncScope::User,
+            redact_secrets: false,
         };
 
         let orchestrator = SyncOrchestrator::new(source, target);
@@ -984,6 +1015,9 @@ This is a test agent for Copilot.
             interactive: false,
             exclude_plugins: vec![],
             full_plugin_mirror: false,
+            conflict_strategy: Some(ConflictStrategy::SkipAll),
+            scope: SyncScope::User,
+            redact_secrets: false,
         };
 
         let orchestrator = SyncOrchestrator::new(source, target);
@@ -1041,6 +1075,9 @@ This is a test agent for Copilot.
             interactive: false,
             exclude_plugins: vec![],
             full_plugin_mirror: false,
+            conflict_strategy: Some(ConflictStrategy::SkipAll),
+            scope: SyncScope::User,
+            redact_secrets: false,
         };
 
         let orchestrator = SyncOrchestrator::new(source, target);
@@ -1096,6 +1133,9 @@ This is a test agent for Copilot.
             interactive: false,
             exclude_plugins: vec![],
             full_plugin_mirror: false,
+            conflict_strategy: Some(ConflictStrategy::SkipAll),
+            scope: SyncScope::User,
+            redact_secrets: false,
         };
 
         let orchestrator = SyncOrchestrator::new(source, target);
@@ -1151,6 +1191,9 @@ This is a test agent for Copilot.
             interactive: false,
             exclude_plugins: vec![],
             full_plugin_mirror: false,
+            conflict_strategy: Some(ConflictStrategy::SkipAll),
+            scope: SyncScope::User,
+            redact_secrets: false,
         };
 
         let orchestrator = SyncOrchestrator::new(source, target);
@@ -1210,6 +1253,9 @@ This is a test agent for Copilot.
             interactive: false,
             exclude_plugins: vec![],
             full_plugin_mirror: false,
+            conflict_strategy: Some(ConflictStrategy::SkipAll),
+            scope: SyncScope::User,
+            redact_secrets: false,
         };
 
         let orchestrator = SyncOrchestrator::new(source, target);
@@ -19,11 +19,13 @@ use skrills_sync::{
     adapters::traits::AgentAdapter,
     adapters::{ClaudeAdapter, CodexAdapter},
     common::{Command, ContentFormat},
+    conflict::ConflictStrategy,
     orchestrator::{SyncOrchestrator, SyncParams},
     validation::{
         skill_is_codex_compatible, validate_skill_for_sync, validate_skills_for_sync,
         SyncValidationOptions,
     },
+    SyncScope,
 };
 use skrills_validate::ValidationTarget;
 use std::fs;
@@ -238,6 +240,9 @@ mod basic_sync_tests {
             interactive: false,
             exclude_plugins: vec![],
             full_plugin_mirror: false,
+            conflict_strategy: Some(ConflictStrategy::SkipAll),
+            scope: SyncScope::User,
+            redact_secrets: false,
         };
 
         let orchestrator = SyncOrchestrator::new(source, target);
@@ -280,6 +285,9 @@ mod basic_sync_tests {
             interactive: false,
             exclude_plugins: vec![],
             full_plugin_mirror: false,
+            conflict_strategy: Some(ConflictStrategy::SkipAll),
+            scope: SyncScope::User,
+            redact_secrets: false,
         };
 
         let orchestrator = SyncOrchestrator::new(source, target);
@@ -327,6 +335,9 @@ mod basic_sync_tests {
             interactive: false,
             exclude_plugins: vec![],
             full_plugin_mirror: false,
+            conflict_strategy: Some(ConflictStrategy::SkipAll),
+            scope: SyncScope::User,
+            redact_secrets: false,
         };
 
         let orchestrator = SyncOrchestrator::new(source, target);
@@ -371,6 +382,9 @@ mod basic_sync_tests {
             interactive: false,
             exclude_plugins: vec![],
             full_plugin_mirror: false,
+            conflict_strategy: Some(ConflictStrategy::SkipAll),
+            scope: SyncScope::User,
+            redact_secrets: false,
         };
 
         let orchestrator = SyncOrchestrator::new(source, target);
@@ -417,6 +431,9 @@ mod basic_sync_tests {
             interactive: false,
             exclude_plugins: vec![],
             full_plugin_mirror: false,
+            conflict_strategy: Some(ConflictStrategy::SkipAll),
+            scope: SyncScope::User,
+            redact_secrets: false,
         };
 
         let orchestrator = SyncOrchestrator::new(source, target);
@@ -462,6 +479,9 @@ mod basic_sync_tests {
             interactive: false,
             exclude_plugins: vec![],
             full_plugin_mirror: false,
+            conflict_strategy: Some(ConflictStrategy::SkipAll),
+            scope: SyncScope::User,
+            redact_secrets: false,
         };
 
         let orchestrator = SyncOrchestrator::new(source, target);
@@ -1374,6 +1394,9 @@ mod case_sensitivity_tests {
             interactive: false,
             exclude_plugins: vec![],
             full_plugin_mirror: false,
+            conflict_strategy: Some(ConflictStrategy::SkipAll),
+            scope: SyncScope::User,
+            redact_secrets: false,
         };
 
         let orchestrator = SyncOrchestrator::new(source, target);
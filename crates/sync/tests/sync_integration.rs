@@ -6,7 +6,9 @@
 use skrills_sync::{
     adapters::traits::AgentAdapter,
     adapters::{ClaudeAdapter, CodexAdapter},
+    conflict::ConflictStrategy,
     orchestrator::{SyncOrchestrator, SyncParams},
+    SyncScope,
 };
 use std::fs;
 use tempfile::TempDir;
@@ -140,6 +142,9 @@ mod sync_direction_tests {
             interactive: false,
             exclude_plugins: vec![],
             full_plugin_mirror: false,
+            conflict_strategy: Some(ConflictStrategy::SkipAll),
+            scope: SyncScope::User,
+            redact_secrets: false,
         };
 
         // Perform sync
@@ -197,6 +202,9 @@ mod sync_direction_tests {
             interactive: false,
             exclude_plugins: vec![],
             full_plugin_mirror: false,
+            conflict_strategy: Some(ConflictStrategy::SkipAll),
+            scope: SyncScope::User,
+            redact_secrets: false,
         };
 
         let orchestrator = SyncOrchestrator::new(source, target);
@@ -255,6 +263,9 @@ mod sync_skip_existing_tests {
             interactive: false,
             exclude_plugins: vec![],
             full_plugin_mirror: false,
+            conflict_strategy: Some(ConflictStrategy::SkipAll),
+            scope: SyncScope::User,
+            redact_secrets: false,
         };
 
         // Debug: Show what commands are being synced
@@ -328,6 +339,9 @@ mod sync_dry_run_tests {
             interactive: false,
             exclude_plugins: vec![],
             full_plugin_mirror: false,
+            conflict_strategy: Some(ConflictStrategy::SkipAll),
+            scope: SyncScope::User,
+            redact_secrets: false,
         };
 
         let orchestrator = SyncOrchestrator::new(source, target);
@@ -402,6 +416,9 @@ mod sync_force_overwrite_tests {
             interactive: false,
             exclude_plugins: vec![],
             full_plugin_mirror: false,
+            conflict_strategy: Some(ConflictStrategy::SkipAll),
+            scope: SyncScope::User,
+            redact_secrets: false,
         };
 
         let orchestrator = SyncOrchestrator::new(source, target);
@@ -455,6 +472,9 @@ mod sync_error_handling_tests {
             interactive: false,
             exclude_plugins: vec![],
             full_plugin_mirror: false,
+            conflict_strategy: Some(ConflictStrategy::SkipAll),
+            scope: SyncScope::User,
+            redact_secrets: false,
         };
 
         let orchestrator = SyncOrchestrator::new(source, target);
@@ -502,6 +522,9 @@ mod sync_error_handling_tests {
             interactive: false,
             exclude_plugins: vec![],
             full_plugin_mirror: false,
+            conflict_strategy: Some(ConflictStrategy::SkipAll),
+            scope: SyncScope::User,
+            redact_secrets: false,
         };
 
         let orchestrator = SyncOrchestrator::new(source, target);
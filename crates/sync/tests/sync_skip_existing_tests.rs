@@ -15,8 +15,10 @@ use skrills_sync::{
     adapters::traits::AgentAdapter,
     adapters::{ClaudeAdapter, CodexAdapter},
     common::{Command, ContentFormat},
+    conflict::ConflictStrategy,
     orchestrator::{SyncOrchestrator, SyncParams},
     report::SkipReason,
+    SyncScope,
 };
 
 /// Test setup for sync operations
@@ -142,6 +144,9 @@ mod skip_existing_commands_tests {
             interactive: false,
             exclude_plugins: vec![],
             full_plugin_mirror: false,
+            conflict_strategy: Some(ConflictStrategy::SkipAll),
+            scope: SyncScope::User,
+            redact_secrets: false,
         };
 
         let orchestrator = SyncOrchestrator::new(source_adapter, target_adapter);
@@ -194,6 +199,9 @@ mod skip_existing_commands_tests {
             interactive: false,
             exclude_plugins: vec![],
             full_plugin_mirror: false,
+            conflict_strategy: Some(ConflictStrategy::SkipAll),
+            scope: SyncScope::User,
+            redact_secrets: false,
         };
 
         let orchestrator = SyncOrchestrator::new(source_adapter, target_adapter);
@@ -268,6 +276,9 @@ mod skip_existing_commands_tests {
             interactive: false,
             exclude_plugins: vec![],
             full_plugin_mirror: false,
+            conflict_strategy: Some(ConflictStrategy::SkipAll),
+            scope: SyncScope::User,
+            redact_secrets: false,
         };
 
         let orchestrator = SyncOrchestrator::new(source_adapter, target_adapter);
@@ -327,6 +338,9 @@ mod skip_existing_commands_tests {
             interactive: false,
             exclude_plugins: vec![],
             full_plugin_mirror: false,
+            conflict_strategy: Some(ConflictStrategy::SkipAll),
+            scope: SyncScope::User,
+            redact_secrets: false,
         };
 
         let orchestrator = SyncOrchestrator::new(source_adapter, target_adapter);
@@ -391,6 +405,9 @@ mod skip_existing_commands_tests {
             interactive: false,
             exclude_plugins: vec![],
             full_plugin_mirror: false,
+            conflict_strategy: Some(ConflictStrategy::SkipAll),
+            scope: SyncScope::User,
+            redact_secrets: false,
         };
 
         let orchestrator = SyncOrchestrator::new(source_adapter, target_adapter);
@@ -440,6 +457,9 @@ mod skip_existing_commands_tests {
             interactive: false,
             exclude_plugins: vec![],
             full_plugin_mirror: false,
+            conflict_strategy: Some(ConflictStrategy::SkipAll),
+            scope: SyncScope::User,
+            redact_secrets: false,
         };
 
         let orchestrator = SyncOrchestrator::new(source_adapter, target_adapter);
@@ -494,6 +514,9 @@ mod skip_existing_commands_tests {
             interactive: false,
             exclude_plugins: vec![],
             full_plugin_mirror: false,
+            conflict_strategy: Some(ConflictStrategy::SkipAll),
+            scope: SyncScope::User,
+            redact_secrets: false,
         };
 
         // Sync from Codex (new source) to Claude (new target)
@@ -554,6 +577,9 @@ mod skip_existing_commands_tests {
             interactive: false,
             exclude_plugins: vec![],
             full_plugin_mirror: false,
+            conflict_strategy: Some(ConflictStrategy::SkipAll),
+            scope: SyncScope::User,
+            redact_secrets: false,
         };
 
         let orchestrator = SyncOrchestrator::new(source_adapter, target_adapter);
@@ -611,6 +637,9 @@ mod skip_existing_commands_tests {
             interactive: false,
             exclude_plugins: vec![],
             full_plugin_mirror: false,
+            conflict_strategy: Some(ConflictStrategy::SkipAll),
+            scope: SyncScope::User,
+            redact_secrets: false,
         };
 
         let orchestrator = SyncOrchestrator::new(source_adapter, target_adapter);
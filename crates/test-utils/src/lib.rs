@@ -122,6 +122,48 @@ impl TestFixture {
         let skill = skill_md(name, description, body);
         self.create_skill(name, &skill)
     }
+
+    /// Path of the file-backed skill registry in the temp environment.
+    pub fn registry_path(&self) -> PathBuf {
+        self.tempdir.path().join("registry")
+    }
+
+    /// `file://` source URL of [`Self::registry_path`].
+    pub fn registry_source(&self) -> String {
+        format!("file://{}", self.registry_path().display())
+    }
+
+    /// Publish `(name, version, SKILL.md)` entries to the registry, replacing
+    /// its index.
+    ///
+    /// Each skill is stored under `skills/<name>-<version>/SKILL.md` and
+    /// listed in `index.json` with its SHA-256.
+    pub fn publish_registry(&self, skills: &[(&str, &str, &str)]) -> std::io::Result<()> {
+        use sha2::{Digest, Sha256};
+
+        let registry = self.registry_path();
+        let mut entries = Vec::new();
+        for (name, version, body) in skills {
+            let base = format!("skills/{name}-{version}");
+            let dir = registry.join(&base);
+            std::fs::create_dir_all(&dir)?;
+            std::fs::write(dir.join("SKILL.md"), body)?;
+            entries.push(serde_json::json!({
+                "name": name,
+                "version": version,
+                "path": base,
+                "files": [{
+                    "path": "SKILL.md",
+                    "sha256": format!("{:x}", Sha256::digest(body.as_bytes())),
+                }]
+            }));
+        }
+        std::fs::create_dir_all(&registry)?;
+        std::fs::write(
+            registry.join("index.json"),
+            serde_json::json!({ "version": 1, "skills": entries }).to_string(),
+        )
+    }
 }
 
 /// Build the standard `---\nname: ...\ndescription: ...\n---\nBODY` SKILL.md
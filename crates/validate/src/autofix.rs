@@ -4,7 +4,12 @@
 //! to make skills compatible with Codex CLI, Copilot CLI, and Cursor.
 
 use crate::codex::{MAX_DESCRIPTION_LENGTH, MAX_NAME_LENGTH};
-use crate::frontmatter::{generate_frontmatter, has_frontmatter, parse_frontmatter};
+use crate::common::{ValidationResult, ValidationTarget};
+use crate::cursor::{parse_mdc_frontmatter, split_globs};
+use crate::frontmatter::{
+    generate_frontmatter, has_frontmatter, parse_frontmatter, split_frontmatter,
+};
+use serde_yaml::{Mapping, Value};
 use std::fs;
 use std::path::Path;
 
@@ -37,6 +42,25 @@ pub struct AutofixOptions {
     pub suggested_description: Option<String>,
 }
 
+/// Whether autofix has something to repair in `result` for `target`.
+///
+/// Claude Code accepts any skill. For Codex and Copilot, autofix repairs
+/// the frontmatter they require; for Cursor, the MDC rule fields. Use
+/// [`autofix_cursor`] for `ValidationTarget::Cursor` and
+/// [`autofix_frontmatter`] otherwise.
+pub fn needs_autofix(result: &ValidationResult, target: ValidationTarget) -> bool {
+    match target {
+        ValidationTarget::Claude => false,
+        ValidationTarget::Codex | ValidationTarget::Both => !result.codex_valid,
+        ValidationTarget::Copilot => !result.copilot_valid,
+        ValidationTarget::All => !result.codex_valid || !result.copilot_valid,
+        ValidationTarget::Cursor => result
+            .issues
+            .iter()
+            .any(|i| i.target == ValidationTarget::Cursor),
+    }
+}
+
 /// Generate a name from a file path.
 pub fn derive_name_from_path(path: &Path) -> String {
     path.file_stem()
@@ -287,9 +311,102 @@ pub fn autofix_frontmatter(
         format!("{}\n{}", generate_frontmatter(&name, &description), body)
     };
 
+    finish(path, new_content, modified, changes, options)
+}
+
+/// Fix Cursor rule frontmatter so the rule validates for Cursor.
+///
+/// Converts quoted `alwaysApply` values to booleans, rewrites `globs` as a
+/// comma-separated string without empty patterns, drops `globs` from
+/// `alwaysApply: true` rules, and adds a `description` to rules that would
+/// otherwise only apply when @-mentioned. Other frontmatter fields are kept,
+/// but YAML comments and quoting are not preserved in rewritten frontmatter.
+pub fn autofix_cursor(
+    path: &Path,
+    content: &str,
+    options: &AutofixOptions,
+) -> Result<AutofixResult, String> {
+    let (raw, body, _) = split_frontmatter(content);
+    let mut fields = match raw {
+        Some(ref raw) => parse_mdc_frontmatter(raw)?,
+        None => Mapping::new(),
+    };
+    let mut changes = Vec::new();
+
+    let always = match fields.get("alwaysApply") {
+        Some(Value::Bool(b)) => *b,
+        Some(Value::String(s)) if s.trim().eq_ignore_ascii_case("true") => {
+            fields.insert("alwaysApply".into(), Value::Bool(true));
+            changes.push("Converted alwaysApply to a boolean".to_string());
+            true
+        }
+        Some(Value::String(s)) if s.trim().eq_ignore_ascii_case("false") => {
+            fields.insert("alwaysApply".into(), Value::Bool(false));
+            changes.push("Converted alwaysApply to a boolean".to_string());
+            false
+        }
+        _ => false,
+    };
+
+    if let Some(globs) = fields.get("globs").cloned() {
+        if always {
+            fields.remove("globs");
+            changes.push("Removed globs from alwaysApply rule".to_string());
+        } else if let Ok(patterns) = split_globs(&globs) {
+            let patterns: Vec<String> = patterns.into_iter().filter(|p| !p.is_empty()).collect();
+            if patterns.is_empty() {
+                fields.remove("globs");
+                changes.push("Removed empty globs".to_string());
+            } else {
+                let normalized = Value::String(patterns.join(","));
+                if normalized != globs {
+                    fields.insert("globs".into(), normalized);
+                    changes.push(format!("Normalized globs: {}", patterns.join(",")));
+                }
+            }
+        }
+    }
+
+    let has_globs = fields.contains_key("globs");
+    let has_description = matches!(
+        fields.get("description"),
+        Some(Value::String(d)) if !d.trim().is_empty()
+    );
+    if !always && !has_globs && !has_description {
+        let description = options
+            .suggested_description
+            .clone()
+            .unwrap_or_else(|| derive_description_from_content(&body));
+        fields.insert("description".into(), Value::String(description));
+        if !fields.contains_key("alwaysApply") {
+            fields.insert("alwaysApply".into(), Value::Bool(false));
+        }
+        changes.push("Added description".to_string());
+    }
+
+    let modified = !changes.is_empty();
+    let new_content = if modified {
+        let yaml = serde_yaml::to_string(&fields)
+            .map_err(|e| format!("Failed to render frontmatter: {e}"))?;
+        format!("---\n{yaml}---\n{body}")
+    } else {
+        content.to_string()
+    };
+
+    finish(path, new_content, modified, changes, options)
+}
+
+/// Builds the autofix result, writing it (and a backup) when requested.
+fn finish(
+    path: &Path,
+    new_content: String,
+    modified: bool,
+    changes: Vec<String>,
+    options: &AutofixOptions,
+) -> Result<AutofixResult, String> {
     let mut result = AutofixResult {
         modified,
-        content: new_content.clone(),
+        content: new_content,
         changes,
         backup_path: None,
     };
@@ -303,7 +420,7 @@ pub fn autofix_frontmatter(
             result.backup_path = Some(backup_path);
         }
 
-        fs::write(path, &new_content).map_err(|e| format!("Failed to write changes: {e}"))?;
+        fs::write(path, &result.content).map_err(|e| format!("Failed to write changes: {e}"))?;
     }
 
     Ok(result)
@@ -325,6 +442,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_needs_autofix_follows_the_selected_target() {
+        use crate::validate_skill;
+
+        let path = Path::new("skills/demo/SKILL.md");
+        let valid = "---\nname: demo\ndescription: A demo skill\n---\n# Demo\nBody.";
+        for target in [
+            ValidationTarget::All,
+            ValidationTarget::Both,
+            ValidationTarget::Codex,
+            ValidationTarget::Copilot,
+        ] {
+            assert!(!needs_autofix(&validate_skill(path, valid, target), target));
+        }
+
+        let bare = "# Demo\nBody.";
+        let copilot = validate_skill(path, bare, ValidationTarget::Copilot);
+        assert!(needs_autofix(&copilot, ValidationTarget::Copilot));
+        let claude = validate_skill(path, bare, ValidationTarget::Claude);
+        assert!(!needs_autofix(&claude, ValidationTarget::Claude));
+    }
+
     #[test]
     fn test_derive_description_from_content() {
         let content = "# My Skill\n\nThis does something useful.";
@@ -511,4 +650,57 @@ mod tests {
             "Backup should contain the original content"
         );
     }
+
+    #[test]
+    fn test_autofix_cursor_fixes_frontmatter() {
+        let content = "---\nname: demo\nalwaysApply: \"false\"\nglobs:\n  - src/**/*.rs\n  - \"\"\n---\n# Body\n";
+        let result =
+            autofix_cursor(Path::new("/test/demo.mdc"), content, &Default::default()).unwrap();
+
+        assert!(result.modified);
+        assert!(result.content.contains("name: demo"));
+        assert!(result.content.contains("alwaysApply: false"));
+        assert!(result.content.contains("globs: src/**/*.rs"));
+        assert!(result.content.ends_with("---\n# Body\n"));
+        assert!(
+            crate::cursor::validate_cursor(Path::new("demo.mdc"), &result.content)
+                .issues
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_autofix_cursor_drops_globs_on_always_apply() {
+        let content = "---\nalwaysApply: true\nglobs: \"*.rs\"\n---\nBody";
+        let result =
+            autofix_cursor(Path::new("/test/r.mdc"), content, &Default::default()).unwrap();
+
+        assert!(result.modified);
+        assert!(!result.content.contains("globs"));
+    }
+
+    #[test]
+    fn test_autofix_cursor_adds_description() {
+        let result = autofix_cursor(
+            Path::new("/test/r.mdc"),
+            "# Database Rules\nUse migrations.",
+            &Default::default(),
+        )
+        .unwrap();
+
+        assert!(result
+            .content
+            .starts_with("---\ndescription: Database Rules\nalwaysApply: false\n---\n"));
+        assert!(result.changes.iter().any(|c| c == "Added description"));
+    }
+
+    #[test]
+    fn test_autofix_cursor_leaves_valid_rule_alone() {
+        let content = "---\ndescription: d\nglobs: \"*.ts,*.tsx\"\n---\nBody";
+        let result =
+            autofix_cursor(Path::new("/test/r.mdc"), content, &Default::default()).unwrap();
+
+        assert!(!result.modified);
+        assert_eq!(result.content, content);
+    }
 }
@@ -7,14 +7,18 @@
 
 #[allow(unused_imports)] // Severity used in tests
 use crate::common::{Severity, ValidationIssue, ValidationResult, ValidationTarget};
-use crate::frontmatter::{parse_frontmatter, ParsedSkill};
+use crate::frontmatter::parse_frontmatter;
+use crate::lint::{frontmatter_error, lint_parsed, LintConfig};
 use std::path::Path;
 
-/// Maximum recommended skill size for Claude Code (64KB).
-const MAX_RECOMMENDED_SIZE: usize = 64 * 1024;
-
 /// Validate a skill for Claude Code compatibility.
 pub fn validate_claude(path: &Path, content: &str) -> ValidationResult {
+    validate_claude_with(path, content, &LintConfig::default())
+}
+
+/// Validate a skill for Claude Code compatibility, running content quality
+/// checks with the given lint configuration.
+pub fn validate_claude_with(path: &Path, content: &str, lint: &LintConfig) -> ValidationResult {
     let name = path
         .file_stem()
         .and_then(|s| s.to_str())
@@ -27,13 +31,10 @@ pub fn validate_claude(path: &Path, content: &str) -> ValidationResult {
     let parsed = match parse_frontmatter(content) {
         Ok(p) => p,
         Err(e) => {
-            result.add_issue(
-                ValidationIssue::error(
-                    ValidationTarget::Claude,
-                    format!("Invalid frontmatter: {e}"),
-                )
-                .with_line(1),
-            );
+            let message = format!("Invalid frontmatter: {e}");
+            if let Some(issue) = frontmatter_error(ValidationTarget::Claude, message, lint) {
+                result.add_issue(issue.with_line(1));
+            }
             return result;
         }
     };
@@ -45,94 +46,11 @@ pub fn validate_claude(path: &Path, content: &str) -> ValidationResult {
         }
     }
 
-    validate_claude_content(&mut result, &parsed, content.len());
-
-    result
-}
-
-fn validate_claude_content(result: &mut ValidationResult, parsed: &ParsedSkill, total_size: usize) {
-    // Check file size
-    if total_size > MAX_RECOMMENDED_SIZE {
-        result.add_issue(
-            ValidationIssue::warning(
-                ValidationTarget::Claude,
-                format!(
-                    "Skill size ({} bytes) exceeds recommended maximum ({} bytes)",
-                    total_size, MAX_RECOMMENDED_SIZE
-                ),
-            )
-            .with_suggestion("Consider splitting into smaller, focused skills"),
-        );
-    }
-
-    // Check for empty content
-    if parsed.content.trim().is_empty() {
-        result.add_issue(ValidationIssue::error(
-            ValidationTarget::Claude,
-            "Skill has no content",
-        ));
-    }
-
-    // Info: suggest adding frontmatter for better organization
-    if parsed.frontmatter.is_none() {
-        result.add_issue(
-            ValidationIssue::info(ValidationTarget::Claude, "No frontmatter present")
-                .with_suggestion(
-                    "Adding frontmatter (name, description) improves skill discoverability",
-                ),
-        );
-    } else if let Some(ref fm) = parsed.frontmatter {
-        // Check for missing recommended fields
-        if fm.name.is_none() {
-            result.add_issue(
-                ValidationIssue::info(ValidationTarget::Claude, "Frontmatter missing 'name' field")
-                    .with_suggestion("Add a 'name' field for better identification"),
-            );
-        }
-        if fm.description.is_none() {
-            result.add_issue(
-                ValidationIssue::info(
-                    ValidationTarget::Claude,
-                    "Frontmatter missing 'description' field",
-                )
-                .with_suggestion("Add a 'description' field for better discoverability"),
-            );
-        }
-    }
-
-    // Check for common markdown issues
-    check_markdown_quality(result, &parsed.content, parsed.content_start_line);
-}
-
-fn check_markdown_quality(result: &mut ValidationResult, content: &str, start_line: usize) {
-    // Check for very long lines (might indicate formatting issues)
-    for (i, line) in content.lines().enumerate() {
-        if line.len() > 500 && !line.starts_with("```") && !line.starts_with("    ") {
-            result.add_issue(
-                ValidationIssue::info(
-                    ValidationTarget::Claude,
-                    format!(
-                        "Very long line ({} chars) may indicate formatting issues",
-                        line.len()
-                    ),
-                )
-                .with_line(start_line + i),
-            );
-            break; // Only report once
-        }
+    for issue in lint_parsed(content, &parsed, ValidationTarget::Claude, lint) {
+        result.add_issue(issue);
     }
 
-    // Check for unclosed code blocks
-    let code_fence_count = content.matches("```").count();
-    if !code_fence_count.is_multiple_of(2) {
-        result.add_issue(
-            ValidationIssue::warning(
-                ValidationTarget::Claude,
-                "Unclosed code block detected (odd number of ``` markers)",
-            )
-            .with_suggestion("Ensure all code blocks have opening and closing ``` markers"),
-        );
-    }
+    result
 }
 
 #[cfg(test)]
@@ -181,4 +99,17 @@ mod tests {
             .iter()
             .any(|i| i.message.contains("Unclosed code block")));
     }
+
+    #[test]
+    fn test_lint_config_controls_quality_checks() {
+        let content = "# Just Content\nNo frontmatter here.";
+        let config = LintConfig::parse("[rules]\nmissing-frontmatter = \"error\"\n").unwrap();
+        let result = validate_claude_with(&PathBuf::from("skill.md"), content, &config);
+
+        assert!(!result.claude_valid);
+        assert_eq!(
+            result.issues[0].rule.as_deref(),
+            Some("missing-frontmatter")
+        );
+    }
 }
@@ -4,9 +4,13 @@
 //! - YAML frontmatter is required
 //! - `name` field required (max 100 characters)
 //! - `description` field required (max 500 characters)
+//!
+//! Each requirement is a `codex-*` lint rule, so `lint.toml` can change its
+//! severity or limit.
 
-use crate::common::{ValidationIssue, ValidationResult, ValidationTarget};
-use crate::frontmatter::{parse_frontmatter, ParsedSkill};
+use crate::common::{ValidationResult, ValidationTarget};
+use crate::frontmatter::parse_frontmatter;
+use crate::lint::{frontmatter_error, lint_parsed, LintConfig};
 use std::path::Path;
 
 /// Maximum length for skill name in Codex.
@@ -17,6 +21,12 @@ pub const MAX_DESCRIPTION_LENGTH: usize = 500;
 
 /// Validate a skill for Codex CLI compatibility.
 pub fn validate_codex(path: &Path, content: &str) -> ValidationResult {
+    validate_codex_with(path, content, &LintConfig::default())
+}
+
+/// Validate a skill for Codex CLI compatibility, with the `codex-*` rules
+/// configured by `lint`.
+pub fn validate_codex_with(path: &Path, content: &str, lint: &LintConfig) -> ValidationResult {
     let name = path
         .file_stem()
         .and_then(|s| s.to_str())
@@ -29,145 +39,24 @@ pub fn validate_codex(path: &Path, content: &str) -> ValidationResult {
     let parsed = match parse_frontmatter(content) {
         Ok(p) => p,
         Err(e) => {
-            result.add_issue(
-                ValidationIssue::error(
-                    ValidationTarget::Codex,
-                    format!("Invalid YAML frontmatter: {e}"),
-                )
-                .with_line(1),
-            );
+            let message = format!("Invalid YAML frontmatter: {e}");
+            if let Some(issue) = frontmatter_error(ValidationTarget::Codex, message, lint) {
+                result.add_issue(issue.with_line(1));
+            }
             return result;
         }
     };
 
-    // Frontmatter is required for Codex
-    if parsed.frontmatter.is_none() {
-        result.add_issue(
-            ValidationIssue::error(
-                ValidationTarget::Codex,
-                "Codex CLI requires YAML frontmatter with name and description",
-            )
-            .with_suggestion(
-                "Add frontmatter: ---\\nname: skill-name\\ndescription: Description\\n---",
-            ),
-        );
-        return result;
-    }
-
-    let fm = parsed
-        .frontmatter
-        .as_ref()
-        .expect("frontmatter required for Codex validation");
-
     // Update name from frontmatter
-    if let Some(ref n) = fm.name {
+    if let Some(n) = parsed.frontmatter.as_ref().and_then(|fm| fm.name.as_ref()) {
         result.name = n.clone();
     }
 
-    // Validate required fields
-    validate_codex_frontmatter(&mut result, fm);
-
-    // Validate content
-    validate_codex_content(&mut result, &parsed);
-
-    result
-}
-
-fn validate_codex_frontmatter(
-    result: &mut ValidationResult,
-    fm: &crate::frontmatter::SkillFrontmatter,
-) {
-    // Name is required
-    match &fm.name {
-        None => {
-            result.add_issue(
-                ValidationIssue::error(ValidationTarget::Codex, "Missing required 'name' field")
-                    .with_line(2)
-                    .with_suggestion("Add 'name: your-skill-name' to frontmatter"),
-            );
-        }
-        Some(name) => {
-            if name.is_empty() {
-                result.add_issue(
-                    ValidationIssue::error(ValidationTarget::Codex, "'name' field cannot be empty")
-                        .with_line(2),
-                );
-            } else if name.len() > MAX_NAME_LENGTH {
-                result.add_issue(
-                    ValidationIssue::error(
-                        ValidationTarget::Codex,
-                        format!(
-                            "'name' exceeds maximum length ({} > {} chars)",
-                            name.len(),
-                            MAX_NAME_LENGTH
-                        ),
-                    )
-                    .with_line(2),
-                );
-            }
-
-            // Check for invalid characters in name
-            if name.contains('\n') || name.contains('\r') {
-                result.add_issue(
-                    ValidationIssue::error(
-                        ValidationTarget::Codex,
-                        "'name' cannot contain newlines",
-                    )
-                    .with_line(2),
-                );
-            }
-        }
+    for issue in lint_parsed(content, &parsed, ValidationTarget::Codex, lint) {
+        result.add_issue(issue);
     }
 
-    // Description is required
-    match &fm.description {
-        None => {
-            result.add_issue(
-                ValidationIssue::error(
-                    ValidationTarget::Codex,
-                    "Missing required 'description' field",
-                )
-                .with_line(3)
-                .with_suggestion(
-                    "Add 'description: Brief description of what the skill does' to frontmatter",
-                ),
-            );
-        }
-        Some(desc) => {
-            if desc.is_empty() {
-                result.add_issue(
-                    ValidationIssue::error(
-                        ValidationTarget::Codex,
-                        "'description' field cannot be empty",
-                    )
-                    .with_line(3),
-                );
-            } else if desc.len() > MAX_DESCRIPTION_LENGTH {
-                result.add_issue(
-                    ValidationIssue::error(
-                        ValidationTarget::Codex,
-                        format!(
-                            "'description' exceeds maximum length ({} > {} chars)",
-                            desc.len(),
-                            MAX_DESCRIPTION_LENGTH
-                        ),
-                    )
-                    .with_line(3)
-                    .with_suggestion("Shorten the description to 500 characters or less"),
-                );
-            }
-        }
-    }
-}
-
-fn validate_codex_content(result: &mut ValidationResult, parsed: &ParsedSkill) {
-    // Check for empty content
-    if parsed.content.trim().is_empty() {
-        result.add_issue(ValidationIssue::error(
-            ValidationTarget::Codex,
-            "Skill has no content after frontmatter",
-        ));
-    }
+    result
 }
 
 /// Check if a skill is Codex-compatible.
@@ -285,4 +174,24 @@ mod tests {
             "---\ndescription: desc\n---\n# Missing name"
         ));
     }
+
+    #[test]
+    fn codex_rules_follow_the_lint_config() {
+        let content = "---\nname: has-name-only\n---\n# Content";
+        let lint = LintConfig::parse("[rules]\ncodex-description = \"off\"\n").unwrap();
+        let result = validate_codex_with(&PathBuf::from("skill.md"), content, &lint);
+        assert!(result.codex_valid);
+        assert!(result.issues.is_empty());
+
+        let content = "---\nname: a-rather-long-name\ndescription: desc\n---\n# Content";
+        let lint = LintConfig::parse("[rules.codex-name-length]\nmax_length = 10\n").unwrap();
+        let result = validate_codex_with(&PathBuf::from("skill.md"), content, &lint);
+        assert!(!result.codex_valid);
+        assert_eq!(result.issues.len(), 1);
+        assert_eq!(result.issues[0].rule.as_deref(), Some("codex-name-length"));
+        assert_eq!(
+            result.issues[0].message,
+            "'name' exceeds maximum length (18 > 10 chars)"
+        );
+    }
 }
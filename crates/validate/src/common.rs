@@ -24,7 +24,9 @@ pub enum ValidationTarget {
     Codex,
     /// GitHub Copilot CLI validation (strict, requires frontmatter).
     Copilot,
-    /// All targets (Claude, Codex, and Copilot).
+    /// Cursor rule validation (MDC frontmatter, globs, rule size).
+    Cursor,
+    /// All targets (Claude, Codex, Copilot, and Cursor for `.mdc` rules).
     All,
     /// Both Claude and Codex (legacy, use All for new code).
     Both,
@@ -43,6 +45,9 @@ pub struct ValidationIssue {
     pub line: Option<usize>,
     /// Suggested fix, if available.
     pub suggestion: Option<String>,
+    /// Lint rule that raised the issue, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rule: Option<String>,
 }
 
 impl ValidationIssue {
@@ -54,6 +59,7 @@ impl ValidationIssue {
             message: message.into(),
             line: None,
             suggestion: None,
+            rule: None,
         }
     }
 
@@ -65,6 +71,7 @@ impl ValidationIssue {
             message: message.into(),
             line: None,
             suggestion: None,
+            rule: None,
         }
     }
 
@@ -76,6 +83,7 @@ impl ValidationIssue {
             message: message.into(),
             line: None,
             suggestion: None,
+            rule: None,
         }
     }
 
@@ -90,6 +98,12 @@ impl ValidationIssue {
         self.suggestion = Some(suggestion.into());
         self
     }
+
+    /// Tag the issue with the lint rule that raised it.
+    pub fn with_rule(mut self, rule: impl Into<String>) -> Self {
+        self.rule = Some(rule.into());
+        self
+    }
 }
 
 /// Result of validating a single skill.
@@ -116,6 +130,10 @@ pub struct ValidationResult {
     /// Use `is_copilot_valid()` for read access; modified only via `add_issue()`.
     #[doc(hidden)]
     pub copilot_valid: bool,
+    /// Whether the skill is valid as a Cursor rule.
+    /// Use `is_cursor_valid()` for read access; modified only via `add_issue()`.
+    #[doc(hidden)]
+    pub cursor_valid: bool,
 }
 
 impl ValidationResult {
@@ -128,6 +146,7 @@ impl ValidationResult {
             claude_valid: true,
             codex_valid: true,
             copilot_valid: true,
+            cursor_valid: true,
         }
     }
 
@@ -149,10 +168,16 @@ impl ValidationResult {
         self.copilot_valid
     }
 
+    /// Returns whether the skill is valid as a Cursor rule.
+    #[inline]
+    pub fn is_cursor_valid(&self) -> bool {
+        self.cursor_valid
+    }
+
     /// Returns whether the skill is valid for all targets.
     #[inline]
     pub fn is_valid_for_all(&self) -> bool {
-        self.claude_valid && self.codex_valid && self.copilot_valid
+        self.claude_valid && self.codex_valid && self.copilot_valid && self.cursor_valid
     }
 
     /// Add an issue to the result, updating validity flags if it's an error.
@@ -162,6 +187,7 @@ impl ValidationResult {
                 ValidationTarget::Claude => self.claude_valid = false,
                 ValidationTarget::Codex => self.codex_valid = false,
                 ValidationTarget::Copilot => self.copilot_valid = false,
+                ValidationTarget::Cursor => self.cursor_valid = false,
                 ValidationTarget::Both => {
                     self.claude_valid = false;
                     self.codex_valid = false;
@@ -170,6 +196,7 @@ impl ValidationResult {
                     self.claude_valid = false;
                     self.codex_valid = false;
                     self.copilot_valid = false;
+                    self.cursor_valid = false;
                 }
             }
         }
@@ -281,6 +308,7 @@ mod tests {
             assert!(result.claude_valid);
             assert!(result.codex_valid);
             assert!(result.copilot_valid);
+            assert!(result.cursor_valid);
             assert!(result.issues.is_empty());
             assert_eq!(result.name, "test");
         }
@@ -347,6 +375,23 @@ mod tests {
             assert!(!result.claude_valid);
             assert!(!result.codex_valid);
             assert!(!result.copilot_valid);
+            assert!(!result.cursor_valid);
+        }
+
+        #[test]
+        fn given_result_when_cursor_error_added_then_only_cursor_invalid() {
+            let mut result = ValidationResult::new("test.md".into(), "test".to_string());
+
+            result.add_issue(ValidationIssue::error(
+                ValidationTarget::Cursor,
+                "cursor issue",
+            ));
+
+            assert!(result.claude_valid);
+            assert!(result.codex_valid);
+            assert!(result.copilot_valid);
+            assert!(!result.cursor_valid);
+            assert!(!result.is_valid_for_all());
         }
 
         #[test]
@@ -457,7 +502,8 @@ mod tests {
             assert_eq!(ValidationTarget::Claude, ValidationTarget::Claude);
             assert_ne!(ValidationTarget::Claude, ValidationTarget::Codex);
             assert_ne!(ValidationTarget::Codex, ValidationTarget::Copilot);
-            assert_ne!(ValidationTarget::Copilot, ValidationTarget::Both);
+            assert_ne!(ValidationTarget::Copilot, ValidationTarget::Cursor);
+            assert_ne!(ValidationTarget::Cursor, ValidationTarget::Both);
             assert_ne!(ValidationTarget::Both, ValidationTarget::All);
         }
     }
@@ -485,6 +531,7 @@ mod tests {
                 Just(ValidationTarget::Claude),
                 Just(ValidationTarget::Codex),
                 Just(ValidationTarget::Copilot),
+                Just(ValidationTarget::Cursor),
                 Just(ValidationTarget::Both),
                 Just(ValidationTarget::All),
             ]
@@ -510,6 +557,7 @@ mod tests {
                     assert!(result.is_claude_valid());
                     assert!(result.is_codex_valid());
                     assert!(result.is_copilot_valid());
+                    assert!(result.is_cursor_valid());
                 }
             }
 
@@ -5,9 +5,13 @@
 //! - `name` field required (max 100 characters)
 //! - `description` field required (max 500 characters)
 //! - Content should be under 30,000 characters (warning)
+//!
+//! Each requirement is a `copilot-*` lint rule, so `lint.toml` can change its
+//! severity or limit.
 
-use crate::common::{ValidationIssue, ValidationResult, ValidationTarget};
-use crate::frontmatter::{parse_frontmatter, ParsedSkill};
+use crate::common::{ValidationResult, ValidationTarget};
+use crate::frontmatter::parse_frontmatter;
+use crate::lint::{frontmatter_error, lint_parsed, Finding, LintConfig, LintInput, RuleOptions};
 use std::path::Path;
 
 /// Maximum length for skill name in Copilot.
@@ -32,6 +36,12 @@ pub const MAX_CONTENT_LENGTH: usize = 30_000;
 
 /// Validate a skill for Copilot CLI compatibility.
 pub fn validate_copilot(path: &Path, content: &str) -> ValidationResult {
+    validate_copilot_with(path, content, &LintConfig::default())
+}
+
+/// Validate a skill for Copilot CLI compatibility, with the `copilot-*` rules
+/// configured by `lint`.
+pub fn validate_copilot_with(path: &Path, content: &str, lint: &LintConfig) -> ValidationResult {
     let name = path
         .file_stem()
         .and_then(|s| s.to_str())
@@ -44,167 +54,42 @@ pub fn validate_copilot(path: &Path, content: &str) -> ValidationResult {
     let parsed = match parse_frontmatter(content) {
         Ok(p) => p,
         Err(e) => {
-            result.add_issue(
-                ValidationIssue::error(
-                    ValidationTarget::Copilot,
-                    format!("Invalid YAML frontmatter: {e}"),
-                )
-                .with_line(1),
-            );
+            let message = format!("Invalid YAML frontmatter: {e}");
+            if let Some(issue) = frontmatter_error(ValidationTarget::Copilot, message, lint) {
+                result.add_issue(issue.with_line(1));
+            }
             return result;
         }
     };
 
-    // Frontmatter is required for Copilot
-    if parsed.frontmatter.is_none() {
-        result.add_issue(
-            ValidationIssue::error(
-                ValidationTarget::Copilot,
-                "Copilot CLI requires YAML frontmatter with name and description",
-            )
-            .with_suggestion(
-                "Add frontmatter: ---\\nname: skill-name\\ndescription: Description\\n---",
-            ),
-        );
-        return result;
-    }
-
-    // SAFETY: We just checked `parsed.frontmatter.is_none()` above and returned early,
-    // so this unwrap is guaranteed to succeed. Using `let Some` pattern for defense in depth.
-    let Some(fm) = parsed.frontmatter.as_ref() else {
-        // This branch should never be reached due to the early return above,
-        // but we handle it defensively to prevent potential panics if code is refactored.
-        return result;
-    };
-
     // Update name from frontmatter
-    if let Some(ref n) = fm.name {
+    if let Some(n) = parsed.frontmatter.as_ref().and_then(|fm| fm.name.as_ref()) {
         result.name = n.clone();
     }
 
-    // Validate required fields
-    validate_copilot_frontmatter(&mut result, fm);
-
-    // Validate content
-    validate_copilot_content(&mut result, &parsed);
-
-    result
-}
-
-fn validate_copilot_frontmatter(
-    result: &mut ValidationResult,
-    fm: &crate::frontmatter::SkillFrontmatter,
-) {
-    // Name is required
-    match &fm.name {
-        None => {
-            result.add_issue(
-                ValidationIssue::error(ValidationTarget::Copilot, "Missing required 'name' field")
-                    .with_line(2)
-                    .with_suggestion("Add 'name: your-skill-name' to frontmatter"),
-            );
-        }
-        Some(name) => {
-            if name.is_empty() {
-                result.add_issue(
-                    ValidationIssue::error(
-                        ValidationTarget::Copilot,
-                        "'name' field cannot be empty",
-                    )
-                    .with_line(2),
-                );
-            } else if name.len() > MAX_NAME_LENGTH {
-                result.add_issue(
-                    ValidationIssue::error(
-                        ValidationTarget::Copilot,
-                        format!(
-                            "'name' exceeds maximum length ({} > {} chars)",
-                            name.len(),
-                            MAX_NAME_LENGTH
-                        ),
-                    )
-                    .with_line(2),
-                );
-            }
-
-            // Check for invalid characters in name
-            if name.contains('\n') || name.contains('\r') {
-                result.add_issue(
-                    ValidationIssue::error(
-                        ValidationTarget::Copilot,
-                        "'name' cannot contain newlines",
-                    )
-                    .with_line(2),
-                );
-            }
-        }
+    for issue in lint_parsed(content, &parsed, ValidationTarget::Copilot, lint) {
+        result.add_issue(issue);
     }
 
-    // Description is required
-    match &fm.description {
-        None => {
-            result.add_issue(
-                ValidationIssue::error(
-                    ValidationTarget::Copilot,
-                    "Missing required 'description' field",
-                )
-                .with_line(3)
-                .with_suggestion(
-                    "Add 'description: Brief description of what the skill does' to frontmatter",
-                ),
-            );
-        }
-        Some(desc) => {
-            if desc.is_empty() {
-                result.add_issue(
-                    ValidationIssue::error(
-                        ValidationTarget::Copilot,
-                        "'description' field cannot be empty",
-                    )
-                    .with_line(3),
-                );
-            } else if desc.len() > MAX_DESCRIPTION_LENGTH {
-                result.add_issue(
-                    ValidationIssue::error(
-                        ValidationTarget::Copilot,
-                        format!(
-                            "'description' exceeds maximum length ({} > {} chars)",
-                            desc.len(),
-                            MAX_DESCRIPTION_LENGTH
-                        ),
-                    )
-                    .with_line(3)
-                    .with_suggestion("Shorten the description to 500 characters or less"),
-                );
-            }
-        }
-    }
+    result
 }
 
-fn validate_copilot_content(result: &mut ValidationResult, parsed: &ParsedSkill) {
-    // Check for empty content
-    if parsed.content.trim().is_empty() {
-        result.add_issue(ValidationIssue::error(
-            ValidationTarget::Copilot,
-            "Skill has no content after frontmatter",
-        ));
-        return;
-    }
-
-    // Copilot-specific: warn if content exceeds 30,000 characters
-    let content_len = parsed.content.len();
-    if content_len > MAX_CONTENT_LENGTH {
-        result.add_issue(
-            ValidationIssue::warning(
-                ValidationTarget::Copilot,
-                format!(
-                    "Content exceeds recommended limit ({} > {} chars)",
-                    content_len, MAX_CONTENT_LENGTH
-                ),
-            )
-            .with_suggestion("Consider breaking this skill into smaller, focused skills"),
-        );
+/// `copilot-content-length`: warns when the body of a skill with frontmatter
+/// exceeds `max_chars`.
+pub(crate) fn check_content_length(
+    input: &LintInput<'_>,
+    options: &RuleOptions<'_>,
+) -> Vec<Finding> {
+    let max = options.integer("max_chars", MAX_CONTENT_LENGTH);
+    let content_len = input.parsed.content.len();
+    if input.parsed.frontmatter.is_none() || content_len <= max {
+        return Vec::new();
     }
+    vec![Finding::new(format!(
+        "Content exceeds recommended limit ({} > {} chars)",
+        content_len, max
+    ))
+    .suggest("Consider breaking this skill into smaller, focused skills")]
 }
 
 /// Check if a skill is Copilot-compatible.
@@ -0,0 +1,506 @@
+//! Cursor rule validation.
+//!
+//! The Cursor adapter turns skills into `.mdc` rules whose YAML frontmatter
+//! decides when Cursor applies them:
+//! - `alwaysApply` must be a boolean
+//! - `globs` must be a comma-separated string or a list of relative glob
+//!   patterns with balanced `[]`/`{}`
+//! - `description` must be a non-empty string when present
+//! - Rules that are neither `alwaysApply` nor scoped by `globs` need a
+//!   `description`, otherwise Cursor only applies them when @-mentioned (warning)
+//! - `globs` are ignored on `alwaysApply: true` rules (warning)
+//! - Rules should stay under 500 lines (warning)
+//!
+//! Each check is a `cursor-*` lint rule, so `lint.toml` can change its
+//! severity or limit.
+
+use crate::common::{ValidationResult, ValidationTarget};
+use crate::frontmatter::{split_frontmatter, ParsedSkill};
+use crate::lint::{frontmatter_error, lint_parsed, Finding, LintConfig, LintInput, RuleOptions};
+use serde_yaml::{Mapping, Value};
+use std::path::Path;
+
+/// Maximum recommended rule length in lines.
+///
+/// Cursor's rule guidance is to keep each rule under 500 lines; longer rules
+/// are injected into every matching request and crowd out code context.
+pub const MAX_RULE_LINES: usize = 500;
+
+/// Validate a skill or `.mdc` rule for Cursor compatibility.
+pub fn validate_cursor(path: &Path, content: &str) -> ValidationResult {
+    validate_cursor_with(path, content, &LintConfig::default())
+}
+
+/// Validate a skill or `.mdc` rule for Cursor compatibility, with the
+/// `cursor-*` rules configured by `lint`.
+pub fn validate_cursor_with(path: &Path, content: &str, lint: &LintConfig) -> ValidationResult {
+    let name = path
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("unknown")
+        .to_string();
+
+    let mut result = ValidationResult::new(path.to_path_buf(), name);
+
+    let (raw, body, content_start) = split_frontmatter(content);
+    let fields = match raw.as_deref().map(parse_mdc_frontmatter).transpose() {
+        Ok(fields) => fields,
+        Err(e) => {
+            if let Some(issue) = frontmatter_error(ValidationTarget::Cursor, e, lint) {
+                result.add_issue(issue.with_line(1));
+            }
+            return result;
+        }
+    };
+    if let Some(Value::String(n)) = fields.as_ref().and_then(|f| f.get("name")) {
+        result.name = n.clone();
+    }
+
+    let parsed = ParsedSkill {
+        frontmatter: None,
+        content: body,
+        raw_frontmatter: raw,
+        content_start_line: content_start,
+    };
+    for issue in lint_parsed(content, &parsed, ValidationTarget::Cursor, lint) {
+        result.add_issue(issue);
+    }
+
+    result
+}
+
+/// Parses MDC frontmatter into a mapping, keeping values untyped so the
+/// validator can report wrongly typed fields instead of failing outright.
+///
+/// Cursor writes bare `globs: *.ts` lines, which plain YAML reads as an
+/// alias; those values are retried as strings before giving up.
+pub(crate) fn parse_mdc_frontmatter(raw: &str) -> Result<Mapping, String> {
+    let parsed = serde_yaml::from_str::<Value>(raw).or_else(|e| match quote_bare_globs(raw) {
+        Some(quoted) => serde_yaml::from_str::<Value>(&quoted).map_err(|_| e),
+        None => Err(e),
+    });
+    match parsed {
+        Ok(Value::Mapping(m)) => Ok(m),
+        Ok(Value::Null) => Ok(Mapping::new()),
+        Ok(_) => Err("Frontmatter must be a YAML mapping".to_string()),
+        Err(e) => Err(format!("Invalid YAML frontmatter: {e}")),
+    }
+}
+
+/// Quotes a bare top-level `globs:` value, if there is one.
+fn quote_bare_globs(raw: &str) -> Option<String> {
+    let mut changed = false;
+    let lines: Vec<String> = raw
+        .lines()
+        .map(|line| {
+            let value = line.strip_prefix("globs:").map(str::trim);
+            match value {
+                Some(v) if !v.is_empty() && !v.starts_with(['"', '\'', '[', '|', '>']) => {
+                    changed = true;
+                    format!("globs: '{}'", v.replace('\'', "''"))
+                }
+                _ => line.to_string(),
+            }
+        })
+        .collect();
+    changed.then(|| lines.join("\n"))
+}
+
+/// Splits a `globs` value into patterns.
+///
+/// Strings are split on commas outside `{}` groups, so `*.{ts,tsx}` stays
+/// one pattern. Patterns are trimmed; empty entries are kept so callers can
+/// report them.
+pub(crate) fn split_globs(value: &Value) -> Result<Vec<String>, String> {
+    match value {
+        Value::Null => Ok(Vec::new()),
+        Value::String(s) => Ok(split_glob_list(s)),
+        Value::Sequence(items) => items
+            .iter()
+            .map(|item| match item {
+                Value::String(s) => Ok(s.trim().to_string()),
+                _ => Err("'globs' list entries must be strings".to_string()),
+            })
+            .collect(),
+        _ => Err("'globs' must be a comma-separated string or a list of strings".to_string()),
+    }
+}
+
+fn split_glob_list(s: &str) -> Vec<String> {
+    if s.trim().is_empty() {
+        return Vec::new();
+    }
+    let mut patterns = Vec::new();
+    let mut current = String::new();
+    let mut depth = 0usize;
+    for c in s.chars() {
+        match c {
+            '{' => depth += 1,
+            '}' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                patterns.push(current.trim().to_string());
+                current.clear();
+                continue;
+            }
+            _ => {}
+        }
+        current.push(c);
+    }
+    patterns.push(current.trim().to_string());
+    patterns
+}
+
+/// Checks a single glob pattern, returning a description of the problem.
+pub(crate) fn check_glob(pattern: &str) -> Result<(), String> {
+    if pattern.is_empty() {
+        return Err("empty glob pattern".to_string());
+    }
+    if pattern.starts_with('/') || pattern.as_bytes().get(1) == Some(&b':') {
+        return Err(format!(
+            "'{pattern}' is absolute; globs match paths relative to the project root"
+        ));
+    }
+
+    let mut braces = 0usize;
+    let mut in_class = false;
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                chars.next();
+            }
+            '[' if !in_class => in_class = true,
+            ']' if in_class => in_class = false,
+            '{' if !in_class => braces += 1,
+            '}' if !in_class => {
+                if braces == 0 {
+                    return Err(format!("'{pattern}' has an unmatched '}}'"));
+                }
+                braces -= 1;
+            }
+            _ => {}
+        }
+    }
+    if in_class {
+        return Err(format!("'{pattern}' has an unclosed '['"));
+    }
+    if braces > 0 {
+        return Err(format!("'{pattern}' has an unclosed '{{'"));
+    }
+    Ok(())
+}
+
+/// A finding placed on the line of frontmatter `key`, when it is present.
+fn finding_at(input: &LintInput<'_>, key: &str, message: impl Into<String>) -> Finding {
+    let finding = Finding::new(message);
+    match input.key_line(key) {
+        Some(line) => finding.at(line),
+        None => finding,
+    }
+}
+
+/// `alwaysApply`, treating a missing or mistyped value as `false`.
+fn always_apply(fields: &Mapping) -> bool {
+    matches!(fields.get("alwaysApply"), Some(Value::Bool(true)))
+}
+
+/// `globs`, or none when they are missing or malformed.
+fn globs(fields: &Mapping) -> Vec<String> {
+    fields
+        .get("globs")
+        .and_then(|v| split_globs(v).ok())
+        .unwrap_or_default()
+}
+
+/// `cursor-frontmatter`: rules without frontmatter are only applied when
+/// @-mentioned.
+pub(crate) fn check_frontmatter(input: &LintInput<'_>, _: &RuleOptions<'_>) -> Vec<Finding> {
+    if input.parsed.raw_frontmatter.is_some() {
+        return Vec::new();
+    }
+    vec![
+        Finding::new("Rule has no frontmatter; Cursor only applies it when @-mentioned")
+            .at(1)
+            .suggest(
+                "Add frontmatter: ---\\ndescription: When to use this rule\\nalwaysApply: false\\n---",
+            ),
+    ]
+}
+
+/// `cursor-always-apply`: `alwaysApply` must be a boolean.
+pub(crate) fn check_always_apply(input: &LintInput<'_>, _: &RuleOptions<'_>) -> Vec<Finding> {
+    let value = input.fields.as_ref().and_then(|f| f.get("alwaysApply"));
+    match value {
+        None | Some(Value::Null) | Some(Value::Bool(_)) => Vec::new(),
+        Some(other) => {
+            let mut finding = finding_at(input, "alwaysApply", "'alwaysApply' must be a boolean");
+            if let Value::String(s) = other {
+                finding = finding.suggest(format!(
+                    "Use an unquoted boolean: alwaysApply: {}",
+                    s.trim().eq_ignore_ascii_case("true")
+                ));
+            }
+            vec![finding]
+        }
+    }
+}
+
+/// `cursor-description`: `description` must be a non-empty string.
+pub(crate) fn check_description(input: &LintInput<'_>, _: &RuleOptions<'_>) -> Vec<Finding> {
+    let value = input.fields.as_ref().and_then(|f| f.get("description"));
+    let message = match value {
+        None | Some(Value::Null) => return Vec::new(),
+        Some(Value::String(d)) if d.trim().is_empty() => "'description' field cannot be empty",
+        Some(Value::String(_)) => return Vec::new(),
+        Some(_) => "'description' must be a string",
+    };
+    vec![finding_at(input, "description", message)]
+}
+
+/// `cursor-globs`: `globs` must be a list of valid relative patterns.
+pub(crate) fn check_globs(input: &LintInput<'_>, _: &RuleOptions<'_>) -> Vec<Finding> {
+    let Some(value) = input.fields.as_ref().and_then(|f| f.get("globs")) else {
+        return Vec::new();
+    };
+    match split_globs(value) {
+        Ok(globs) => globs
+            .iter()
+            .filter_map(|pattern| check_glob(pattern).err())
+            .map(|e| {
+                finding_at(input, "globs", format!("Invalid glob: {e}"))
+                    .suggest("Use relative patterns such as src/**/*.ts")
+            })
+            .collect(),
+        Err(e) => vec![finding_at(input, "globs", e)],
+    }
+}
+
+/// `cursor-globs-ignored`: Cursor ignores `globs` on `alwaysApply: true`
+/// rules.
+pub(crate) fn check_globs_ignored(input: &LintInput<'_>, _: &RuleOptions<'_>) -> Vec<Finding> {
+    let Some(fields) = &input.fields else {
+        return Vec::new();
+    };
+    if !always_apply(fields) || globs(fields).is_empty() {
+        return Vec::new();
+    }
+    vec![finding_at(
+        input,
+        "globs",
+        "'globs' are ignored because 'alwaysApply' is true",
+    )
+    .suggest("Remove 'globs' or set 'alwaysApply: false'")]
+}
+
+/// `cursor-manual-only`: a rule that is neither always applied, scoped by
+/// globs nor described is only applied when @-mentioned.
+pub(crate) fn check_manual_only(input: &LintInput<'_>, _: &RuleOptions<'_>) -> Vec<Finding> {
+    let Some(fields) = &input.fields else {
+        return Vec::new();
+    };
+    let described = matches!(
+        fields.get("description"),
+        Some(Value::String(d)) if !d.trim().is_empty()
+    );
+    if always_apply(fields) || !globs(fields).is_empty() || described {
+        return Vec::new();
+    }
+    vec![Finding::new(
+        "Rule has no 'description', 'globs' or 'alwaysApply'; Cursor only applies it when @-mentioned",
+    )
+    .at(2)
+    .suggest("Add a 'description' so the agent can decide when to apply the rule")]
+}
+
+/// `cursor-empty-content`: a rule needs content after its frontmatter.
+pub(crate) fn check_empty_content(input: &LintInput<'_>, _: &RuleOptions<'_>) -> Vec<Finding> {
+    if !input.parsed.content.trim().is_empty() {
+        return Vec::new();
+    }
+    vec![Finding::new("Rule has no content after frontmatter")]
+}
+
+/// `cursor-rule-length`: warns when a rule exceeds `max_lines`.
+pub(crate) fn check_rule_length(input: &LintInput<'_>, options: &RuleOptions<'_>) -> Vec<Finding> {
+    let max = options.integer("max_lines", MAX_RULE_LINES);
+    let lines = input.content.lines().count();
+    if input.parsed.content.trim().is_empty() || lines <= max {
+        return Vec::new();
+    }
+    vec![Finding::new(format!(
+        "Rule exceeds recommended length ({} > {} lines)",
+        lines, max
+    ))
+    .suggest("Split the rule into smaller, focused rules")]
+}
+
+/// Finds the 1-indexed line of a top-level frontmatter key.
+pub(crate) fn frontmatter_line(content: &str, content_start: usize, key: &str) -> Option<usize> {
+    content
+        .lines()
+        .take(content_start.saturating_sub(1))
+        .position(|l| {
+            l.strip_prefix(key)
+                .is_some_and(|rest| rest.trim_start().starts_with(':'))
+        })
+        .map(|i| i + 1)
+}
+
+/// Check if a skill is Cursor-compatible.
+pub fn is_cursor_compatible(content: &str) -> bool {
+    validate_cursor(Path::new("rule.mdc"), content).is_cursor_valid()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::Severity;
+    use std::path::PathBuf;
+
+    fn validate(content: &str) -> ValidationResult {
+        validate_cursor(&PathBuf::from("rule.mdc"), content)
+    }
+
+    fn has_message(result: &ValidationResult, needle: &str) -> bool {
+        result.issues.iter().any(|i| i.message.contains(needle))
+    }
+
+    #[test]
+    fn given_skill_with_description_when_validated_then_cursor_valid() {
+        let result =
+            validate("---\nname: test-skill\ndescription: A test skill\n---\n# Content\nBody.");
+
+        assert!(result.is_cursor_valid());
+        assert_eq!(result.name, "test-skill");
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn given_each_rule_mode_when_validated_then_no_issues() {
+        for content in [
+            "---\nalwaysApply: true\n---\nAlways.",
+            "---\nglobs: src/**/*.ts, tests/**/*.ts\nalwaysApply: false\n---\nScoped.",
+            "---\nglobs:\n  - \"*.{ts,tsx}\"\n---\nScoped list.",
+            "---\ndescription: Use for SQL\nalwaysApply: false\n---\nRequested.",
+        ] {
+            let result = validate(content);
+            assert!(result.issues.is_empty(), "{content}: {:?}", result.issues);
+        }
+    }
+
+    #[test]
+    fn given_no_frontmatter_when_validated_then_manual_warning() {
+        let result = validate("# Just content");
+
+        assert!(result.is_cursor_valid());
+        assert!(has_message(&result, "only applies it when @-mentioned"));
+    }
+
+    #[test]
+    fn given_invalid_yaml_when_validated_then_cursor_invalid() {
+        let result = validate("---\ndescription: [unclosed\n---\nBody");
+
+        assert!(!result.is_cursor_valid());
+        assert!(has_message(&result, "Invalid YAML frontmatter"));
+    }
+
+    #[test]
+    fn given_quoted_always_apply_when_validated_then_error_with_suggestion() {
+        let result = validate("---\ndescription: d\nalwaysApply: \"true\"\n---\nBody");
+
+        assert!(!result.is_cursor_valid());
+        let issue = result
+            .issues
+            .iter()
+            .find(|i| i.message.contains("'alwaysApply' must be a boolean"))
+            .unwrap();
+        assert_eq!(issue.line, Some(3));
+        assert_eq!(
+            issue.suggestion.as_deref(),
+            Some("Use an unquoted boolean: alwaysApply: true")
+        );
+    }
+
+    #[test]
+    fn given_empty_description_when_validated_then_cursor_invalid() {
+        let result = validate("---\ndescription: \"\"\n---\nBody");
+
+        assert!(!result.is_cursor_valid());
+        assert!(has_message(&result, "'description' field cannot be empty"));
+    }
+
+    #[test]
+    fn given_bad_globs_when_validated_then_each_is_reported() {
+        let result = validate("---\nglobs: \"src/**/*.{ts\"\n---\nBody");
+
+        assert!(!result.is_cursor_valid());
+        assert!(has_message(&result, "unclosed '{'"));
+
+        let result = validate("---\nglobs: /abs/*.rs, lib/[a-z.rs,\n---\nBody");
+        assert!(!result.is_cursor_valid());
+        assert!(has_message(&result, "is absolute"));
+        assert!(has_message(&result, "unclosed '['"));
+        assert!(has_message(&result, "empty glob pattern"));
+    }
+
+    #[test]
+    fn given_bare_cursor_globs_when_validated_then_parsed_as_string() {
+        let result = validate("---\nglobs: *.ts,*.tsx\nalwaysApply: false\n---\nBody");
+
+        assert!(result.issues.is_empty(), "{:?}", result.issues);
+    }
+
+    #[test]
+    fn given_non_string_globs_when_validated_then_cursor_invalid() {
+        let result = validate("---\nglobs: 42\n---\nBody");
+
+        assert!(!result.is_cursor_valid());
+        assert!(has_message(&result, "'globs' must be"));
+    }
+
+    #[test]
+    fn given_always_apply_with_globs_when_validated_then_warning() {
+        let result = validate("---\nalwaysApply: true\nglobs: \"*.rs\"\n---\nBody");
+
+        assert!(result.is_cursor_valid());
+        let issue = result
+            .issues
+            .iter()
+            .find(|i| i.message.contains("ignored"))
+            .unwrap();
+        assert_eq!(issue.severity, Severity::Warning);
+        assert_eq!(issue.line, Some(3));
+    }
+
+    #[test]
+    fn given_empty_body_when_validated_then_cursor_invalid() {
+        let result = validate("---\nalwaysApply: true\n---\n");
+
+        assert!(!result.is_cursor_valid());
+        assert!(has_message(&result, "no content"));
+    }
+
+    #[test]
+    fn given_long_rule_when_validated_then_size_warning() {
+        let body = "line\n".repeat(MAX_RULE_LINES + 1);
+        let result = validate(&format!("---\nalwaysApply: true\n---\n{body}"));
+
+        assert!(result.is_cursor_valid());
+        assert!(has_message(&result, "exceeds recommended length"));
+    }
+
+    #[test]
+    fn split_globs_keeps_brace_groups_together() {
+        let value = Value::String("*.{ts,tsx}, docs/**".into());
+        assert_eq!(split_globs(&value).unwrap(), vec!["*.{ts,tsx}", "docs/**"]);
+        assert!(split_globs(&Value::String(" ".into())).unwrap().is_empty());
+    }
+
+    #[test]
+    fn is_cursor_compatible_matches_validation() {
+        assert!(is_cursor_compatible("---\ndescription: d\n---\nBody"));
+        assert!(!is_cursor_compatible(
+            "---\nalwaysApply: yes please\n---\nBody"
+        ));
+    }
+}
@@ -1,7 +1,7 @@
-//! Skill validation for Claude Code, Codex CLI, and GitHub Copilot CLI.
+//! Skill validation for Claude Code, Codex CLI, GitHub Copilot CLI, and Cursor.
 //!
 //! Validates `SKILL.md` files against Claude Code (permissive), Codex CLI (strict),
-//! and GitHub Copilot CLI (strict) requirements.
+//! GitHub Copilot CLI (strict), and Cursor rule (MDC frontmatter) requirements.
 //!
 //! # Example
 //!
@@ -35,17 +35,21 @@ pub mod claude;
 pub mod codex;
 pub mod common;
 pub mod copilot;
+pub mod cursor;
 pub mod frontmatter;
+pub mod lint;
 #[cfg(feature = "watch")]
 pub mod watch;
 
 pub use autofix::{
-    autofix_frontmatter, scaffold_body, to_kebab_case, AutofixOptions, AutofixResult,
+    autofix_cursor, autofix_frontmatter, needs_autofix, scaffold_body, to_kebab_case,
+    AutofixOptions, AutofixResult,
 };
 pub use common::{Severity, ValidationIssue, ValidationResult, ValidationTarget};
 pub use frontmatter::{
     generate_frontmatter, has_frontmatter, parse_frontmatter, ParsedSkill, SkillFrontmatter,
 };
+pub use lint::{LintConfig, LintRule, RULES as LINT_RULES};
 
 use std::path::Path;
 use walkdir::WalkDir;
@@ -64,6 +68,7 @@ fn merge_validation_results(results: Vec<ValidationResult>, path: &Path) -> Vali
         merged.claude_valid &= result.claude_valid;
         merged.codex_valid &= result.codex_valid;
         merged.copilot_valid &= result.copilot_valid;
+        merged.cursor_valid &= result.cursor_valid;
 
         for issue in result.issues {
             if !merged.issues.iter().any(|i| i.message == issue.message) {
@@ -77,28 +82,54 @@ fn merge_validation_results(results: Vec<ValidationResult>, path: &Path) -> Vali
 
 /// Validates a single skill file.
 pub fn validate_skill(path: &Path, content: &str, target: ValidationTarget) -> ValidationResult {
+    validate_skill_with(path, content, target, &LintConfig::default())
+}
+
+/// Validates a single skill file with the given lint configuration.
+///
+/// Every target's checks are lint rules, so `lint` applies to all targets:
+/// the content-quality rules run with Claude Code validation and the
+/// `codex-*`, `copilot-*` and `cursor-*` rules with their own targets.
+pub fn validate_skill_with(
+    path: &Path,
+    content: &str,
+    target: ValidationTarget,
+    lint: &LintConfig,
+) -> ValidationResult {
     match target {
-        ValidationTarget::Claude => claude::validate_claude(path, content),
-        ValidationTarget::Codex => codex::validate_codex(path, content),
-        ValidationTarget::Copilot => copilot::validate_copilot(path, content),
+        ValidationTarget::Claude => claude::validate_claude_with(path, content, lint),
+        ValidationTarget::Codex => codex::validate_codex_with(path, content, lint),
+        ValidationTarget::Copilot => copilot::validate_copilot_with(path, content, lint),
+        ValidationTarget::Cursor => cursor::validate_cursor_with(path, content, lint),
         ValidationTarget::Both => merge_validation_results(
             vec![
-                claude::validate_claude(path, content),
-                codex::validate_codex(path, content),
-            ],
-            path,
-        ),
-        ValidationTarget::All => merge_validation_results(
-            vec![
-                claude::validate_claude(path, content),
-                codex::validate_codex(path, content),
-                copilot::validate_copilot(path, content),
+                claude::validate_claude_with(path, content, lint),
+                codex::validate_codex_with(path, content, lint),
             ],
             path,
         ),
+        ValidationTarget::All => {
+            let mut results = vec![
+                claude::validate_claude_with(path, content, lint),
+                codex::validate_codex_with(path, content, lint),
+                copilot::validate_copilot_with(path, content, lint),
+            ];
+            if is_cursor_rule(path) {
+                results.push(cursor::validate_cursor_with(path, content, lint));
+            }
+            merge_validation_results(results, path)
+        }
     }
 }
 
+/// Whether `path` is a Cursor `.mdc` rule.
+///
+/// `ValidationTarget::All` only runs the Cursor checks on rules; skills are
+/// checked against Cursor with `ValidationTarget::Cursor`.
+fn is_cursor_rule(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext == "mdc")
+}
+
 /// Validates all skills in a directory.
 ///
 /// Recursively walks the directory looking for `SKILL.md` files,
@@ -158,6 +189,11 @@ pub fn is_copilot_compatible(content: &str) -> bool {
     copilot::is_copilot_compatible(content)
 }
 
+/// Checks if a skill is usable as a Cursor rule.
+pub fn is_cursor_compatible(content: &str) -> bool {
+    cursor::is_cursor_compatible(content)
+}
+
 /// Summary of validation results.
 #[derive(Debug, Default)]
 pub struct ValidationSummary {
@@ -169,8 +205,10 @@ pub struct ValidationSummary {
     pub codex_valid: usize,
     /// Number of skills valid for GitHub Copilot CLI.
     pub copilot_valid: usize,
+    /// Number of skills valid as Cursor rules.
+    pub cursor_valid: usize,
     /// Number of skills that pass validation for all targets simultaneously
-    /// (intersection of claude_valid, codex_valid, and copilot_valid).
+    /// (intersection of claude_valid, codex_valid, copilot_valid, and cursor_valid).
     pub all_valid: usize,
     /// Total number of error-level issues.
     pub error_count: usize,
@@ -196,7 +234,10 @@ impl ValidationSummary {
             if result.copilot_valid {
                 summary.copilot_valid += 1;
             }
-            if result.claude_valid && result.codex_valid && result.copilot_valid {
+            if result.cursor_valid {
+                summary.cursor_valid += 1;
+            }
+            if result.is_valid_for_all() {
                 summary.all_valid += 1;
             }
             summary.error_count += result.error_count();
@@ -231,6 +272,23 @@ mod tests {
         assert!(!result.copilot_valid);
     }
 
+    #[test]
+    fn test_validate_skill_all_runs_cursor_checks_only_on_rules() {
+        // A skill without a description is only @-mention scoped as a Cursor rule.
+        let content = "---\nname: test\n---\n# Content\nBody.";
+        let skill = validate_skill(Path::new("SKILL.md"), content, ValidationTarget::All);
+        assert!(skill
+            .issues
+            .iter()
+            .all(|i| i.target != ValidationTarget::Cursor));
+
+        let rule = validate_skill(Path::new("test.mdc"), content, ValidationTarget::All);
+        assert!(rule
+            .issues
+            .iter()
+            .any(|i| i.target == ValidationTarget::Cursor));
+    }
+
     #[test]
     fn test_validate_skill_copilot() {
         let content = "---\nname: test\ndescription: A test skill\n---\n# Content\nBody.";
@@ -249,6 +307,7 @@ mod tests {
                 claude_valid: true,
                 codex_valid: true,
                 copilot_valid: true,
+                cursor_valid: true,
             },
             ValidationResult {
                 path: "b.md".into(),
@@ -257,6 +316,7 @@ mod tests {
                 claude_valid: true,
                 codex_valid: false,
                 copilot_valid: true,
+                cursor_valid: true,
             },
             ValidationResult {
                 path: "c.md".into(),
@@ -265,6 +325,7 @@ mod tests {
                 claude_valid: true,
                 codex_valid: true,
                 copilot_valid: false,
+                cursor_valid: false,
             },
         ];
 
@@ -273,6 +334,7 @@ mod tests {
         assert_eq!(summary.claude_valid, 3);
         assert_eq!(summary.codex_valid, 2);
         assert_eq!(summary.copilot_valid, 2);
+        assert_eq!(summary.cursor_valid, 2);
         assert_eq!(summary.all_valid, 1);
     }
 
@@ -0,0 +1,1188 @@
+//! Configurable lint rules for skill content.
+//!
+//! Each rule has a stable ID, the targets it checks, a default severity and
+//! optional settings. Content-quality rules run with the Claude Code checks;
+//! the Codex, Copilot and Cursor requirements are rules of their own
+//! (`codex-*`, `copilot-*`, `cursor-*`), so a team can relax or tighten them
+//! too.
+//! [`LintConfig`] is read from `lint.toml` and can change a rule's severity,
+//! turn it off or on, and set its options:
+//!
+//! ```toml
+//! [rules]
+//! long-line = "off"
+//!
+//! [rules.required-sections]
+//! severity = "error"
+//! sections = ["When to use", "Examples"]
+//!
+//! [rules.max-heading-depth]
+//! severity = "warning"
+//! max_depth = 3
+//!
+//! [rules.codex-description-length]
+//! max_length = 300
+//! ```
+//!
+//! Findings can be suppressed inline with HTML comments:
+//! - `<!-- skrills-disable rule-id -->` on its own line disables the rule
+//!   until `<!-- skrills-enable rule-id -->` or the end of the file; after
+//!   other content it disables the rule for that line only.
+//! - `<!-- skrills-disable-next-line rule-id -->` disables it for the next line.
+//!
+//! Without rule IDs a comment applies to every rule. Findings that are not
+//! tied to a line are suppressed by any `skrills-disable` of their rule.
+
+use crate::common::{Severity, ValidationIssue, ValidationTarget};
+use crate::frontmatter::{parse_frontmatter, ParsedSkill};
+use crate::{codex, copilot, cursor, Result};
+use anyhow::{anyhow, bail};
+use regex::Regex;
+use serde::Serialize;
+use serde_yaml::Mapping;
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::sync::LazyLock;
+
+/// Type of a rule option in `lint.toml`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OptionKind {
+    /// A non-negative integer.
+    Integer,
+    /// An array of strings.
+    StringList,
+}
+
+/// An option a rule reads from its `lint.toml` table.
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct RuleOption {
+    /// Key in the rule's table.
+    pub name: &'static str,
+    /// Expected value type.
+    pub kind: OptionKind,
+    /// What the option controls, including its default.
+    pub description: &'static str,
+}
+
+/// A problem reported by a rule.
+pub(crate) struct Finding {
+    message: String,
+    line: Option<usize>,
+    suggestion: Option<String>,
+}
+
+impl Finding {
+    pub(crate) fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            line: None,
+            suggestion: None,
+        }
+    }
+
+    pub(crate) fn at(mut self, line: usize) -> Self {
+        self.line = Some(line);
+        self
+    }
+
+    pub(crate) fn suggest(mut self, suggestion: impl Into<String>) -> Self {
+        self.suggestion = Some(suggestion.into());
+        self
+    }
+}
+
+/// Content handed to each rule.
+pub(crate) struct LintInput<'a> {
+    pub(crate) content: &'a str,
+    pub(crate) parsed: &'a ParsedSkill,
+    /// Target the issues are raised against.
+    pub(crate) target: ValidationTarget,
+    /// Untyped frontmatter fields, for the Cursor rules.
+    pub(crate) fields: Option<Mapping>,
+}
+
+impl LintInput<'_> {
+    /// Line of the top-level frontmatter `key`, if present.
+    pub(crate) fn key_line(&self, key: &str) -> Option<usize> {
+        cursor::frontmatter_line(self.content, self.parsed.content_start_line, key)
+    }
+
+    /// Body lines with their line numbers in the file.
+    fn body_lines(&self) -> impl Iterator<Item = (usize, &str)> {
+        let start = self.parsed.content_start_line;
+        self.parsed
+            .content
+            .lines()
+            .enumerate()
+            .map(move |(i, line)| (start + i, line))
+    }
+
+    /// Body lines outside fenced code blocks.
+    fn prose_lines(&self) -> impl Iterator<Item = (usize, &str)> {
+        let mut in_fence = false;
+        self.body_lines().filter(move |(_, line)| {
+            if line.trim_start().starts_with("```") {
+                in_fence = !in_fence;
+                return false;
+            }
+            !in_fence
+        })
+    }
+
+    /// ATX headings as (line, depth, text).
+    fn headings(&self) -> impl Iterator<Item = (usize, usize, &str)> {
+        self.prose_lines().filter_map(|(line, text)| {
+            let depth = text.chars().take_while(|&c| c == '#').count();
+            let rest = &text[depth..];
+            ((1..=6).contains(&depth) && rest.starts_with(' '))
+                .then(|| (line, depth, rest.trim().trim_end_matches('#').trim()))
+        })
+    }
+}
+
+/// Options of one rule, read from its `lint.toml` table.
+pub(crate) struct RuleOptions<'a>(Option<&'a toml::Table>);
+
+impl RuleOptions<'_> {
+    pub(crate) fn integer(&self, name: &str, default: usize) -> usize {
+        self.0
+            .and_then(|t| t.get(name))
+            .and_then(toml::Value::as_integer)
+            .and_then(|v| usize::try_from(v).ok())
+            .unwrap_or(default)
+    }
+
+    fn strings(&self, name: &str, default: &[&str]) -> Vec<String> {
+        match self.0.and_then(|t| t.get(name)).and_then(|v| v.as_array()) {
+            Some(values) => values
+                .iter()
+                .filter_map(|v| v.as_str().map(str::to_string))
+                .collect(),
+            None => default.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+}
+
+/// A lint rule in the catalog.
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct LintRule {
+    /// Stable ID used in `lint.toml` and suppression comments.
+    pub id: &'static str,
+    /// What the rule checks.
+    pub description: &'static str,
+    /// Targets whose validation runs the rule.
+    pub targets: &'static [ValidationTarget],
+    /// Severity when not configured; `None` if the rule is off by default.
+    pub default_severity: Option<Severity>,
+    /// Options the rule accepts.
+    pub options: &'static [RuleOption],
+    /// The check; `None` for [`INVALID_FRONTMATTER`], which the validators
+    /// report themselves.
+    #[serde(skip)]
+    check: Option<RuleCheck>,
+}
+
+type RuleCheck = fn(&LintInput<'_>, &RuleOptions<'_>) -> Vec<Finding>;
+
+/// ID of the rule for frontmatter that does not parse.
+pub const INVALID_FRONTMATTER: &str = "invalid-frontmatter";
+
+const CLAUDE: &[ValidationTarget] = &[ValidationTarget::Claude];
+const CODEX: &[ValidationTarget] = &[ValidationTarget::Codex];
+const COPILOT: &[ValidationTarget] = &[ValidationTarget::Copilot];
+const CURSOR: &[ValidationTarget] = &[ValidationTarget::Cursor];
+
+/// Maximum recommended skill size for Claude Code (64KB).
+const DEFAULT_MAX_BYTES: usize = 64 * 1024;
+const DEFAULT_MAX_LINE_LENGTH: usize = 500;
+const DEFAULT_MAX_HEADING_DEPTH: usize = 3;
+const DEFAULT_REQUIRED_SECTIONS: &[&str] = &["When to use"];
+
+/// Every lint rule, in the order they run.
+pub const RULES: &[LintRule] = &[
+    LintRule {
+        id: "skill-size",
+        description: "Skill file is larger than the recommended maximum",
+        targets: CLAUDE,
+        default_severity: Some(Severity::Warning),
+        options: &[RuleOption {
+            name: "max_bytes",
+            kind: OptionKind::Integer,
+            description: "Largest allowed file size in bytes (default: 65536)",
+        }],
+        check: Some(check_skill_size),
+    },
+    LintRule {
+        id: "empty-content",
+        description: "Skill has no content after its frontmatter",
+        targets: CLAUDE,
+        default_severity: Some(Severity::Error),
+        options: &[],
+        check: Some(check_empty_content),
+    },
+    LintRule {
+        id: "missing-frontmatter",
+        description: "Skill has no YAML frontmatter",
+        targets: CLAUDE,
+        default_severity: Some(Severity::Info),
+        options: &[],
+        check: Some(check_missing_frontmatter),
+    },
+    LintRule {
+        id: "missing-name",
+        description: "Frontmatter has no `name` field",
+        targets: CLAUDE,
+        default_severity: Some(Severity::Info),
+        options: &[],
+        check: Some(check_missing_name),
+    },
+    LintRule {
+        id: "missing-description",
+        description: "Frontmatter has no `description` field",
+        targets: CLAUDE,
+        default_severity: Some(Severity::Info),
+        options: &[],
+        check: Some(check_missing_description),
+    },
+    LintRule {
+        id: "long-line",
+        description: "A line is long enough to suggest a formatting problem",
+        targets: CLAUDE,
+        default_severity: Some(Severity::Info),
+        options: &[RuleOption {
+            name: "max_length",
+            kind: OptionKind::Integer,
+            description: "Longest allowed line in bytes (default: 500)",
+        }],
+        check: Some(check_long_line),
+    },
+    LintRule {
+        id: "unclosed-code-block",
+        description: "A ``` code fence is never closed",
+        targets: CLAUDE,
+        default_severity: Some(Severity::Warning),
+        options: &[],
+        check: Some(check_unclosed_code_block),
+    },
+    LintRule {
+        id: "required-sections",
+        description: "Skill lacks a required section heading",
+        targets: CLAUDE,
+        default_severity: None,
+        options: &[RuleOption {
+            name: "sections",
+            kind: OptionKind::StringList,
+            description: "Headings that must appear, case-insensitive (default: [\"When to use\"])",
+        }],
+        check: Some(check_required_sections),
+    },
+    LintRule {
+        id: "max-heading-depth",
+        description: "A heading is nested deeper than allowed",
+        targets: CLAUDE,
+        default_severity: None,
+        options: &[RuleOption {
+            name: "max_depth",
+            kind: OptionKind::Integer,
+            description: "Deepest allowed heading level (default: 3)",
+        }],
+        check: Some(check_max_heading_depth),
+    },
+    LintRule {
+        id: "banned-phrases",
+        description: "Skill uses a banned phrase",
+        targets: CLAUDE,
+        default_severity: None,
+        options: &[RuleOption {
+            name: "phrases",
+            kind: OptionKind::StringList,
+            description: "Phrases to reject, case-insensitive (default: none)",
+        }],
+        check: Some(check_banned_phrases),
+    },
+    LintRule {
+        id: "invalid-frontmatter",
+        description: "Frontmatter is not valid YAML",
+        targets: &[
+            ValidationTarget::Claude,
+            ValidationTarget::Codex,
+            ValidationTarget::Copilot,
+            ValidationTarget::Cursor,
+        ],
+        default_severity: Some(Severity::Error),
+        options: &[],
+        check: None,
+    },
+    LintRule {
+        id: "codex-frontmatter",
+        description: "Codex CLI requires YAML frontmatter",
+        targets: CODEX,
+        default_severity: Some(Severity::Error),
+        options: &[],
+        check: Some(check_required_frontmatter),
+    },
+    LintRule {
+        id: "codex-name",
+        description: "Frontmatter `name` is missing, empty or spans lines",
+        targets: CODEX,
+        default_severity: Some(Severity::Error),
+        options: &[],
+        check: Some(check_required_name),
+    },
+    LintRule {
+        id: "codex-name-length",
+        description: "Frontmatter `name` is longer than Codex CLI allows",
+        targets: CODEX,
+        default_severity: Some(Severity::Error),
+        options: &[RuleOption {
+            name: "max_length",
+            kind: OptionKind::Integer,
+            description: "Longest allowed name in bytes (default: 100)",
+        }],
+        check: Some(check_name_length),
+    },
+    LintRule {
+        id: "codex-description",
+        description: "Frontmatter `description` is missing or empty",
+        targets: CODEX,
+        default_severity: Some(Severity::Error),
+        options: &[],
+        check: Some(check_required_description),
+    },
+    LintRule {
+        id: "codex-description-length",
+        description: "Frontmatter `description` is longer than Codex CLI allows",
+        targets: CODEX,
+        default_severity: Some(Severity::Error),
+        options: &[RuleOption {
+            name: "max_length",
+            kind: OptionKind::Integer,
+            description: "Longest allowed description in bytes (default: 500)",
+        }],
+        check: Some(check_description_length),
+    },
+    LintRule {
+        id: "codex-empty-content",
+        description: "Skill has no content after its frontmatter",
+        targets: CODEX,
+        default_severity: Some(Severity::Error),
+        options: &[],
+        check: Some(check_strict_empty_content),
+    },
+    LintRule {
+        id: "copilot-frontmatter",
+        description: "Copilot CLI requires YAML frontmatter",
+        targets: COPILOT,
+        default_severity: Some(Severity::Error),
+        options: &[],
+        check: Some(check_required_frontmatter),
+    },
+    LintRule {
+        id: "copilot-name",
+        description: "Frontmatter `name` is missing, empty or spans lines",
+        targets: COPILOT,
+        default_severity: Some(Severity::Error),
+        options: &[],
+        check: Some(check_required_name),
+    },
+    LintRule {
+        id: "copilot-name-length",
+        description: "Frontmatter `name` is longer than Copilot CLI allows",
+        targets: COPILOT,
+        default_severity: Some(Severity::Error),
+        options: &[RuleOption {
+            name: "max_length",
+            kind: OptionKind::Integer,
+            description: "Longest allowed name in bytes (default: 100)",
+        }],
+        check: Some(check_name_length),
+    },
+    LintRule {
+        id: "copilot-description",
+        description: "Frontmatter `description` is missing or empty",
+        targets: COPILOT,
+        default_severity: Some(Severity::Error),
+        options: &[],
+        check: Some(check_required_description),
+    },
+    LintRule {
+        id: "copilot-description-length",
+        description: "Frontmatter `description` is longer than Copilot CLI allows",
+        targets: COPILOT,
+        default_severity: Some(Severity::Error),
+        options: &[RuleOption {
+            name: "max_length",
+            kind: OptionKind::Integer,
+            description: "Longest allowed description in bytes (default: 500)",
+        }],
+        check: Some(check_description_length),
+    },
+    LintRule {
+        id: "copilot-empty-content",
+        description: "Skill has no content after its frontmatter",
+        targets: COPILOT,
+        default_severity: Some(Severity::Error),
+        options: &[],
+        check: Some(check_strict_empty_content),
+    },
+    LintRule {
+        id: "copilot-content-length",
+        description: "Skill body is longer than Copilot handles well",
+        targets: COPILOT,
+        default_severity: Some(Severity::Warning),
+        options: &[RuleOption {
+            name: "max_chars",
+            kind: OptionKind::Integer,
+            description: "Longest recommended body in bytes (default: 30000)",
+        }],
+        check: Some(copilot::check_content_length),
+    },
+    LintRule {
+        id: "cursor-frontmatter",
+        description: "Rule has no frontmatter, so Cursor only applies it when @-mentioned",
+        targets: CURSOR,
+        default_severity: Some(Severity::Warning),
+        options: &[],
+        check: Some(cursor::check_frontmatter),
+    },
+    LintRule {
+        id: "cursor-always-apply",
+        description: "`alwaysApply` is not a boolean",
+        targets: CURSOR,
+        default_severity: Some(Severity::Error),
+        options: &[],
+        check: Some(cursor::check_always_apply),
+    },
+    LintRule {
+        id: "cursor-description",
+        description: "`description` is empty or not a string",
+        targets: CURSOR,
+        default_severity: Some(Severity::Error),
+        options: &[],
+        check: Some(cursor::check_description),
+    },
+    LintRule {
+        id: "cursor-globs",
+        description: "`globs` is not a list of valid relative patterns",
+        targets: CURSOR,
+        default_severity: Some(Severity::Error),
+        options: &[],
+        check: Some(cursor::check_globs),
+    },
+    LintRule {
+        id: "cursor-globs-ignored",
+        description: "`globs` are set on an `alwaysApply: true` rule",
+        targets: CURSOR,
+        default_severity: Some(Severity::Warning),
+        options: &[],
+        check: Some(cursor::check_globs_ignored),
+    },
+    LintRule {
+        id: "cursor-manual-only",
+        description: "Rule has no `description`, `globs` or `alwaysApply`",
+        targets: CURSOR,
+        default_severity: Some(Severity::Warning),
+        options: &[],
+        check: Some(cursor::check_manual_only),
+    },
+    LintRule {
+        id: "cursor-empty-content",
+        description: "Rule has no content after its frontmatter",
+        targets: CURSOR,
+        default_severity: Some(Severity::Error),
+        options: &[],
+        check: Some(cursor::check_empty_content),
+    },
+    LintRule {
+        id: "cursor-rule-length",
+        description: "Rule is longer than Cursor recommends",
+        targets: CURSOR,
+        default_severity: Some(Severity::Warning),
+        options: &[RuleOption {
+            name: "max_lines",
+            kind: OptionKind::Integer,
+            description: "Longest recommended rule in lines (default: 500)",
+        }],
+        check: Some(cursor::check_rule_length),
+    },
+];
+
+/// Looks up a rule by ID.
+pub fn find_rule(id: &str) -> Option<&'static LintRule> {
+    RULES.iter().find(|r| r.id == id)
+}
+
+fn check_skill_size(input: &LintInput<'_>, options: &RuleOptions<'_>) -> Vec<Finding> {
+    let max = options.integer("max_bytes", DEFAULT_MAX_BYTES);
+    if input.content.len() <= max {
+        return Vec::new();
+    }
+    vec![Finding::new(format!(
+        "Skill size ({} bytes) exceeds recommended maximum ({} bytes)",
+        input.content.len(),
+        max
+    ))
+    .suggest("Consider splitting into smaller, focused skills")]
+}
+
+fn check_empty_content(input: &LintInput<'_>, _: &RuleOptions<'_>) -> Vec<Finding> {
+    if input.parsed.content.trim().is_empty() {
+        vec![Finding::new("Skill has no content")]
+    } else {
+        Vec::new()
+    }
+}
+
+fn check_missing_frontmatter(input: &LintInput<'_>, _: &RuleOptions<'_>) -> Vec<Finding> {
+    if input.parsed.frontmatter.is_some() {
+        return Vec::new();
+    }
+    vec![Finding::new("No frontmatter present")
+        .suggest("Adding frontmatter (name, description) improves skill discoverability")]
+}
+
+fn check_missing_name(input: &LintInput<'_>, _: &RuleOptions<'_>) -> Vec<Finding> {
+    match &input.parsed.frontmatter {
+        Some(fm) if fm.name.is_none() => vec![Finding::new("Frontmatter missing 'name' field")
+            .suggest("Add a 'name' field for better identification")],
+        _ => Vec::new(),
+    }
+}
+
+fn check_missing_description(input: &LintInput<'_>, _: &RuleOptions<'_>) -> Vec<Finding> {
+    match &input.parsed.frontmatter {
+        Some(fm) if fm.description.is_none() => {
+            vec![Finding::new("Frontmatter missing 'description' field")
+                .suggest("Add a 'description' field for better discoverability")]
+        }
+        _ => Vec::new(),
+    }
+}
+
+fn check_long_line(input: &LintInput<'_>, options: &RuleOptions<'_>) -> Vec<Finding> {
+    let max = options.integer("max_length", DEFAULT_MAX_LINE_LENGTH);
+    // Only the first long line is reported.
+    input
+        .body_lines()
+        .find(|(_, line)| line.len() > max && !line.starts_with("```") && !line.starts_with("    "))
+        .map(|(number, line)| {
+            Finding::new(format!(
+                "Very long line ({} chars) may indicate formatting issues",
+                line.len()
+            ))
+            .at(number)
+        })
+        .into_iter()
+        .collect()
+}
+
+fn check_unclosed_code_block(input: &LintInput<'_>, _: &RuleOptions<'_>) -> Vec<Finding> {
+    if input
+        .parsed
+        .content
+        .matches("```")
+        .count()
+        .is_multiple_of(2)
+    {
+        return Vec::new();
+    }
+    vec![
+        Finding::new("Unclosed code block detected (odd number of ``` markers)")
+            .suggest("Ensure all code blocks have opening and closing ``` markers"),
+    ]
+}
+
+fn check_required_sections(input: &LintInput<'_>, options: &RuleOptions<'_>) -> Vec<Finding> {
+    let headings: Vec<String> = input
+        .headings()
+        .map(|(_, _, text)| text.to_lowercase())
+        .collect();
+    options
+        .strings("sections", DEFAULT_REQUIRED_SECTIONS)
+        .into_iter()
+        .filter(|section| !headings.contains(&section.trim().to_lowercase()))
+        .map(|section| {
+            Finding::new(format!("Missing required section \"{section}\""))
+                .suggest(format!("Add a \"## {section}\" section"))
+        })
+        .collect()
+}
+
+fn check_max_heading_depth(input: &LintInput<'_>, options: &RuleOptions<'_>) -> Vec<Finding> {
+    let max = options.integer("max_depth", DEFAULT_MAX_HEADING_DEPTH);
+    input
+        .headings()
+        .filter(|(_, depth, _)| *depth > max)
+        .map(|(line, depth, text)| {
+            Finding::new(format!(
+                "Heading \"{text}\" is at depth {depth}, deeper than the maximum of {max}"
+            ))
+            .at(line)
+        })
+        .collect()
+}
+
+fn check_banned_phrases(input: &LintInput<'_>, options: &RuleOptions<'_>) -> Vec<Finding> {
+    let phrases: Vec<(String, String)> = options
+        .strings("phrases", &[])
+        .into_iter()
+        .filter(|p| !p.trim().is_empty())
+        .map(|p| (p.to_lowercase(), p))
+        .collect();
+    let mut findings = Vec::new();
+    for (line, text) in input.prose_lines() {
+        let lower = text.to_lowercase();
+        for (needle, phrase) in &phrases {
+            if lower.contains(needle.as_str()) {
+                findings.push(Finding::new(format!("Banned phrase \"{phrase}\"")).at(line));
+            }
+        }
+    }
+    findings
+}
+
+/// Product name used in the messages of target-specific rules.
+fn cli_name(target: ValidationTarget) -> &'static str {
+    match target {
+        ValidationTarget::Copilot => "Copilot CLI",
+        ValidationTarget::Cursor => "Cursor",
+        _ => "Codex CLI",
+    }
+}
+
+fn check_required_frontmatter(input: &LintInput<'_>, _: &RuleOptions<'_>) -> Vec<Finding> {
+    if input.parsed.frontmatter.is_some() {
+        return Vec::new();
+    }
+    vec![Finding::new(format!(
+        "{} requires YAML frontmatter with name and description",
+        cli_name(input.target)
+    ))
+    .suggest("Add frontmatter: ---\\nname: skill-name\\ndescription: Description\\n---")]
+}
+
+fn check_required_name(input: &LintInput<'_>, _: &RuleOptions<'_>) -> Vec<Finding> {
+    let Some(fm) = &input.parsed.frontmatter else {
+        return Vec::new();
+    };
+    let Some(name) = &fm.name else {
+        return vec![Finding::new("Missing required 'name' field")
+            .at(2)
+            .suggest("Add 'name: your-skill-name' to frontmatter")];
+    };
+    let mut findings = Vec::new();
+    if name.is_empty() {
+        findings.push(Finding::new("'name' field cannot be empty").at(2));
+    }
+    if name.contains('\n') || name.contains('\r') {
+        findings.push(Finding::new("'name' cannot contain newlines").at(2));
+    }
+    findings
+}
+
+fn check_name_length(input: &LintInput<'_>, options: &RuleOptions<'_>) -> Vec<Finding> {
+    let default = match input.target {
+        ValidationTarget::Copilot => copilot::MAX_NAME_LENGTH,
+        _ => codex::MAX_NAME_LENGTH,
+    };
+    let max = options.integer("max_length", default);
+    match input
+        .parsed
+        .frontmatter
+        .as_ref()
+        .and_then(|fm| fm.name.as_ref())
+    {
+        Some(name) if name.len() > max => vec![Finding::new(format!(
+            "'name' exceeds maximum length ({} > {} chars)",
+            name.len(),
+            max
+        ))
+        .at(2)],
+        _ => Vec::new(),
+    }
+}
+
+fn check_required_description(input: &LintInput<'_>, _: &RuleOptions<'_>) -> Vec<Finding> {
+    let Some(fm) = &input.parsed.frontmatter else {
+        return Vec::new();
+    };
+    match &fm.description {
+        None => vec![Finding::new("Missing required 'description' field")
+            .at(3)
+            .suggest("Add 'description: Brief description of what the skill does' to frontmatter")],
+        Some(desc) if desc.is_empty() => {
+            vec![Finding::new("'description' field cannot be empty").at(3)]
+        }
+        Some(_) => Vec::new(),
+    }
+}
+
+fn check_description_length(input: &LintInput<'_>, options: &RuleOptions<'_>) -> Vec<Finding> {
+    let default = match input.target {
+        ValidationTarget::Copilot => copilot::MAX_DESCRIPTION_LENGTH,
+        _ => codex::MAX_DESCRIPTION_LENGTH,
+    };
+    let max = options.integer("max_length", default);
+    let description = input
+        .parsed
+        .frontmatter
+        .as_ref()
+        .and_then(|fm| fm.description.as_ref());
+    match description {
+        Some(desc) if desc.len() > max => vec![Finding::new(format!(
+            "'description' exceeds maximum length ({} > {} chars)",
+            desc.len(),
+            max
+        ))
+        .at(3)
+        .suggest(format!(
+            "Shorten the description to {max} characters or less"
+        ))],
+        _ => Vec::new(),
+    }
+}
+
+/// Empty body of a skill whose frontmatter is present, for the targets that
+/// require frontmatter.
+fn check_strict_empty_content(input: &LintInput<'_>, _: &RuleOptions<'_>) -> Vec<Finding> {
+    if input.parsed.frontmatter.is_none() || !input.parsed.content.trim().is_empty() {
+        return Vec::new();
+    }
+    vec![Finding::new("Skill has no content after frontmatter")]
+}
+
+/// Effective level of a rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Level {
+    Off,
+    On(Severity),
+}
+
+fn parse_level(value: &str) -> Result<Level> {
+    match value.to_ascii_lowercase().as_str() {
+        "off" => Ok(Level::Off),
+        "info" => Ok(Level::On(Severity::Info)),
+        "warning" => Ok(Level::On(Severity::Warning)),
+        "error" => Ok(Level::On(Severity::Error)),
+        other => bail!("Invalid severity '{other}'; use error, warning, info or off"),
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct RuleConfig {
+    level: Option<Level>,
+    options: toml::Table,
+}
+
+/// Per-rule severities and options from `lint.toml`.
+#[derive(Debug, Clone, Default)]
+pub struct LintConfig {
+    rules: BTreeMap<String, RuleConfig>,
+}
+
+impl LintConfig {
+    /// Loads `path`; a missing file yields the default configuration.
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| anyhow!("Failed to read {}: {e}", path.display()))?;
+        Self::parse(&text).map_err(|e| anyhow!("{}: {e}", path.display()))
+    }
+
+    /// Parses `lint.toml` content, rejecting unknown rules and options.
+    pub fn parse(text: &str) -> Result<Self> {
+        let table: toml::Table = toml::from_str(text)?;
+        let mut config = Self::default();
+        for (key, value) in table {
+            if key != "rules" {
+                bail!("Unknown key '{key}'; rules are configured under [rules]");
+            }
+            let Some(rules) = value.as_table() else {
+                bail!("[rules] must be a table");
+            };
+            for (id, value) in rules {
+                let rule = find_rule(id).ok_or_else(|| anyhow!("Unknown lint rule '{id}'"))?;
+                let entry = config.rules.entry(id.clone()).or_default();
+                match value {
+                    toml::Value::String(level) => entry.level = Some(parse_level(level)?),
+                    toml::Value::Table(settings) => {
+                        for (name, value) in settings {
+                            if name == "severity" {
+                                let level = value.as_str().ok_or_else(|| {
+                                    anyhow!("rules.{id}.severity must be a string")
+                                })?;
+                                entry.level = Some(parse_level(level)?);
+                                continue;
+                            }
+                            let option =
+                                rule.options
+                                    .iter()
+                                    .find(|o| o.name == name)
+                                    .ok_or_else(|| {
+                                        anyhow!("Unknown option '{name}' for lint rule '{id}'")
+                                    })?;
+                            let valid = match option.kind {
+                                OptionKind::Integer => value.as_integer().is_some_and(|v| v >= 0),
+                                OptionKind::StringList => value
+                                    .as_array()
+                                    .is_some_and(|a| a.iter().all(toml::Value::is_str)),
+                            };
+                            if !valid {
+                                bail!(
+                                    "rules.{id}.{name} must be {}",
+                                    match option.kind {
+                                        OptionKind::Integer => "a non-negative integer",
+                                        OptionKind::StringList => "an array of strings",
+                                    }
+                                );
+                            }
+                            entry.options.insert(name.clone(), value.clone());
+                        }
+                    }
+                    _ => bail!("rules.{id} must be a severity or a table"),
+                }
+            }
+        }
+        Ok(config)
+    }
+
+    /// Layers `other` over this configuration: its severities replace these
+    /// and its options are merged in, key by key.
+    pub fn layer(&mut self, other: LintConfig) {
+        for (id, rule) in other.rules {
+            let entry = self.rules.entry(id).or_default();
+            if rule.level.is_some() {
+                entry.level = rule.level;
+            }
+            entry.options.extend(rule.options);
+        }
+    }
+
+    /// Severity `rule` reports at, or `None` if it is off.
+    pub fn severity(&self, rule: &LintRule) -> Option<Severity> {
+        match self.rules.get(rule.id).and_then(|r| r.level) {
+            Some(Level::Off) => None,
+            Some(Level::On(severity)) => Some(severity),
+            None => rule.default_severity,
+        }
+    }
+
+    /// Options configured for `rule`.
+    pub fn options(&self, rule: &LintRule) -> Option<&toml::Table> {
+        self.rules
+            .get(rule.id)
+            .map(|r| &r.options)
+            .filter(|o| !o.is_empty())
+    }
+}
+
+// RATIONALE: compile-time constant pattern; see skrills-analyze deps.rs.
+static DIRECTIVE_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"<!--\s*skrills-(disable-next-line|disable|enable)\b([^>]*?)-->")
+        .expect("DIRECTIVE_REGEX: compile-time constant")
+});
+
+/// Rules a suppression comment names; empty means every rule.
+type RuleIds = Vec<String>;
+
+/// Inline `skrills-disable` comments of a file.
+#[derive(Default)]
+struct Suppressions {
+    /// Rules disabled from a line until an optional closing line.
+    ranges: Vec<(RuleIds, usize, Option<usize>)>,
+    /// Rules disabled on a single line.
+    lines: Vec<(RuleIds, usize)>,
+}
+
+fn names(ids: &RuleIds, rule: &str) -> bool {
+    ids.is_empty() || ids.iter().any(|id| id == rule)
+}
+
+impl Suppressions {
+    fn parse(content: &str) -> Self {
+        let mut suppressions = Self::default();
+        for (i, line) in content.lines().enumerate() {
+            let number = i + 1;
+            let alone = DIRECTIVE_REGEX.replace_all(line, "").trim().is_empty();
+            for cap in DIRECTIVE_REGEX.captures_iter(line) {
+                let ids: RuleIds = cap[2]
+                    .split(|c: char| c.is_whitespace() || c == ',')
+                    .filter(|s| !s.is_empty())
+                    .map(str::to_string)
+                    .collect();
+                match &cap[1] {
+                    "disable-next-line" => suppressions.lines.push((ids, number + 1)),
+                    "disable" if alone => suppressions.ranges.push((ids, number, None)),
+                    "disable" => suppressions.lines.push((ids, number)),
+                    _ => {
+                        for (open, _, end) in &mut suppressions.ranges {
+                            let closes = if ids.is_empty() {
+                                true
+                            } else {
+                                ids.iter().any(|id| names(open, id))
+                            };
+                            if end.is_none() && closes {
+                                *end = Some(number);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        suppressions
+    }
+
+    fn suppresses(&self, rule: &str, line: Option<usize>) -> bool {
+        let Some(line) = line else {
+            return self.ranges.iter().any(|(ids, _, _)| names(ids, rule))
+                || self.lines.iter().any(|(ids, _)| names(ids, rule));
+        };
+        self.ranges.iter().any(|(ids, start, end)| {
+            names(ids, rule) && *start <= line && end.is_none_or(|end| line <= end)
+        }) || self
+            .lines
+            .iter()
+            .any(|(ids, at)| *at == line && names(ids, rule))
+    }
+}
+
+/// Runs the enabled lint rules of `target` against a skill.
+///
+/// Issues are raised against `target` and tagged with their rule ID. A file
+/// whose frontmatter does not parse yields no lint issues; validators
+/// report that separately, as [`INVALID_FRONTMATTER`].
+pub fn lint(content: &str, target: ValidationTarget, config: &LintConfig) -> Vec<ValidationIssue> {
+    match parse_frontmatter(content) {
+        Ok(parsed) => lint_parsed(content, &parsed, target, config),
+        Err(_) => Vec::new(),
+    }
+}
+
+/// Runs the enabled rules of `target` against a parsed skill.
+///
+/// For Cursor, `parsed` only needs its raw frontmatter, body and content
+/// start; the rules read the untyped fields.
+pub(crate) fn lint_parsed(
+    content: &str,
+    parsed: &ParsedSkill,
+    target: ValidationTarget,
+    config: &LintConfig,
+) -> Vec<ValidationIssue> {
+    let fields = match target {
+        ValidationTarget::Cursor => parsed
+            .raw_frontmatter
+            .as_deref()
+            .and_then(|raw| cursor::parse_mdc_frontmatter(raw).ok()),
+        _ => None,
+    };
+    let input = LintInput {
+        content,
+        parsed,
+        target,
+        fields,
+    };
+    let suppressions = Suppressions::parse(content);
+    let mut issues = Vec::new();
+    for rule in RULES.iter().filter(|r| r.targets.contains(&target)) {
+        let (Some(check), Some(severity)) = (rule.check, config.severity(rule)) else {
+            continue;
+        };
+        let options = RuleOptions(config.options(rule));
+        for finding in check(&input, &options) {
+            if suppressions.suppresses(rule.id, finding.line) {
+                continue;
+            }
+            let mut issue = new_issue(severity, target, finding.message).with_rule(rule.id);
+            if let Some(line) = finding.line {
+                issue = issue.with_line(line);
+            }
+            if let Some(suggestion) = finding.suggestion {
+                issue = issue.with_suggestion(suggestion);
+            }
+            issues.push(issue);
+        }
+    }
+    issues
+}
+
+/// The issue for frontmatter that does not parse, at the severity
+/// [`INVALID_FRONTMATTER`] is configured to, or `None` if it is off.
+pub(crate) fn frontmatter_error(
+    target: ValidationTarget,
+    message: impl Into<String>,
+    config: &LintConfig,
+) -> Option<ValidationIssue> {
+    let rule = find_rule(INVALID_FRONTMATTER).expect("invalid-frontmatter is in RULES");
+    config
+        .severity(rule)
+        .map(|severity| new_issue(severity, target, message).with_rule(rule.id))
+}
+
+fn new_issue(
+    severity: Severity,
+    target: ValidationTarget,
+    message: impl Into<String>,
+) -> ValidationIssue {
+    match severity {
+        Severity::Error => ValidationIssue::error(target, message),
+        Severity::Warning => ValidationIssue::warning(target, message),
+        Severity::Info => ValidationIssue::info(target, message),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SKILL: &str = "---\nname: demo\ndescription: Demo skill\n---\n# Demo\n\n## Usage\nRun it.\n\n#### Deep detail\nSimply do it.\n";
+
+    fn rules_hit(issues: &[ValidationIssue]) -> Vec<(&str, Severity, Option<usize>)> {
+        issues
+            .iter()
+            .map(|i| (i.rule.as_deref().unwrap(), i.severity, i.line))
+            .collect()
+    }
+
+    #[test]
+    fn rule_ids_are_unique_and_kebab_case() {
+        let mut ids: Vec<_> = RULES.iter().map(|r| r.id).collect();
+        assert!(ids
+            .iter()
+            .all(|id| id.chars().all(|c| c.is_ascii_lowercase() || c == '-')));
+        ids.sort();
+        ids.dedup();
+        assert_eq!(ids.len(), RULES.len());
+    }
+
+    #[test]
+    fn house_style_rules_are_off_until_configured() {
+        assert!(lint(SKILL, ValidationTarget::Claude, &LintConfig::default()).is_empty());
+
+        let config = LintConfig::parse(
+            r#"
+[rules.required-sections]
+severity = "error"
+sections = ["When to use", "usage"]
+
+[rules.max-heading-depth]
+severity = "warning"
+
+[rules.banned-phrases]
+severity = "info"
+phrases = ["simply"]
+"#,
+        )
+        .unwrap();
+        let issues = lint(SKILL, ValidationTarget::Claude, &config);
+        assert_eq!(
+            rules_hit(&issues),
+            [
+                ("required-sections", Severity::Error, None),
+                ("max-heading-depth", Severity::Warning, Some(10)),
+                ("banned-phrases", Severity::Info, Some(11)),
+            ]
+        );
+        assert_eq!(
+            issues[0].message,
+            "Missing required section \"When to use\""
+        );
+    }
+
+    #[test]
+    fn severities_can_be_changed_or_turned_off() {
+        let content = "# No frontmatter\n";
+        let config = LintConfig::parse("[rules]\nmissing-frontmatter = \"error\"\n").unwrap();
+        let issues = lint(content, ValidationTarget::Claude, &config);
+        assert_eq!(
+            rules_hit(&issues),
+            [("missing-frontmatter", Severity::Error, None)]
+        );
+
+        let config = LintConfig::parse("[rules]\nmissing-frontmatter = \"off\"\n").unwrap();
+        assert!(lint(content, ValidationTarget::Claude, &config).is_empty());
+    }
+
+    #[test]
+    fn rules_run_only_for_their_targets() {
+        let content = "# No frontmatter\n";
+        let config = LintConfig::parse("[rules]\ncodex-frontmatter = \"warning\"\n").unwrap();
+        let issues = lint(content, ValidationTarget::Codex, &config);
+        assert_eq!(
+            rules_hit(&issues),
+            [("codex-frontmatter", Severity::Warning, None)]
+        );
+        assert_eq!(issues[0].target, ValidationTarget::Codex);
+
+        let issues = lint(content, ValidationTarget::Copilot, &config);
+        assert_eq!(
+            rules_hit(&issues),
+            [("copilot-frontmatter", Severity::Error, None)]
+        );
+    }
+
+    #[test]
+    fn project_config_layers_over_user_config() {
+        let mut config = LintConfig::parse(
+            "[rules.long-line]\nseverity = \"warning\"\nmax_length = 80\n[rules]\nskill-size = \"off\"\n",
+        )
+        .unwrap();
+        config.layer(LintConfig::parse("[rules.long-line]\nmax_length = 10\n").unwrap());
+        let rule = find_rule("long-line").unwrap();
+        assert_eq!(config.severity(rule), Some(Severity::Warning));
+        assert_eq!(
+            config.options(rule).unwrap()["max_length"].as_integer(),
+            Some(10)
+        );
+        assert_eq!(config.severity(find_rule("skill-size").unwrap()), None);
+    }
+
+    #[test]
+    fn invalid_configuration_is_rejected() {
+        for (text, expected) in [
+            (
+                "[rules]\nno-such-rule = \"error\"\n",
+                "Unknown lint rule 'no-such-rule'",
+            ),
+            (
+                "[rules]\nlong-line = \"fatal\"\n",
+                "Invalid severity 'fatal'",
+            ),
+            ("[rules.long-line]\nwidth = 3\n", "Unknown option 'width'"),
+            (
+                "[rules.long-line]\nmax_length = \"80\"\n",
+                "must be a non-negative integer",
+            ),
+            ("[lint]\n", "Unknown key 'lint'"),
+        ] {
+            let err = LintConfig::parse(text).unwrap_err().to_string();
+            assert!(err.contains(expected), "{text}: {err}");
+        }
+    }
+
+    #[test]
+    fn inline_comments_suppress_findings() {
+        let config = LintConfig::parse(
+            "[rules.banned-phrases]\nseverity = \"warning\"\nphrases = [\"simply\", \"just\"]\n",
+        )
+        .unwrap();
+        let content = "# Demo\n\
+Simply one. <!-- skrills-disable banned-phrases -->\n\
+<!-- skrills-disable-next-line -->\n\
+Simply two.\n\
+Simply three.\n\
+<!-- skrills-disable banned-phrases -->\n\
+Just four.\n\
+<!-- skrills-enable banned-phrases -->\n\
+Just five.\n";
+        let issues = lint(content, ValidationTarget::Claude, &config);
+        let lines: Vec<_> = issues.iter().map(|i| i.line.unwrap()).collect();
+        assert_eq!(lines, [5, 9]);
+
+        let unclosed =
+            "---\nname: demo\ndescription: Demo\n---\n<!-- skrills-disable unclosed-code-block -->\n```\ncode\n";
+        assert!(lint(unclosed, ValidationTarget::Claude, &LintConfig::default()).is_empty());
+    }
+}